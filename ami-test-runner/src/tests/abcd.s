@@ -0,0 +1,680 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\abcd.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+abcd_byte_data_register_with_extend_clear
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_data_register_with_extend_clear",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000034,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C3,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000046,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ABCD.B D0,D1
+
+;===========================================
+
+abcd_byte_data_register_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_data_register_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000034,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001e ; ENZO-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C3,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000047,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ABCD.B D0,D1
+
+;===========================================
+
+abcd_byte_data_register_decimal_carry_from_low_digit
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_data_register_decimal_carry_from_low_digit",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000008,$00000009,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C3,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000008,$00000017,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ABCD.B D0,D1
+
+;===========================================
+
+abcd_byte_data_register_carry_leave_zero_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_data_register_carry_leave_zero_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000099,$00000001,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C3,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000099,$00000000,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0015 ; SR=E-Z-C
+
+.assert_code
+ ABCD.B D0,D1
+
+;===========================================
+
+abcd_byte_data_register_carry_leave_zero_cleared
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_data_register_carry_leave_zero_cleared",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000099,$00000001,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C3,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000099,$00000000,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0011 ; SR=E---C
+
+.assert_code
+ ABCD.B D0,D1
+
+;===========================================
+
+abcd_byte_data_register_overflow_negative
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_data_register_overflow_negative",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000045,$00000038,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001a ; EN-O-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C3,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000045,$00000084,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $000a ; SR=-N-O-
+
+.assert_code
+ ABCD.B D0,D1
+
+;===========================================
+
+abcd_byte_data_register_carry_overflow_negative
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_data_register_carry_overflow_negative",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000099,$00000099,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001a ; EN-O-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C3,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000099,$00000099,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $001b ; SR=EN-OC
+
+.assert_code
+ ABCD.B D0,D1
+
+;===========================================
+
+abcd_byte_address_register_with_extend_clear
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_address_register_with_extend_clear",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $12,$34
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C9,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $12,$46
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ABCD.B -(A3),-(A4)
+
+;===========================================
+
+abcd_byte_address_register_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_address_register_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $12,$34
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001e ; ENZO-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C9,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $12,$47
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ABCD.B -(A3),-(A4)
+
+;===========================================
+
+abcd_byte_address_register_decimal_carry_from_low_digit
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_address_register_decimal_carry_from_low_digit",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $08,$09
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C9,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $08,$17
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ABCD.B -(A3),-(A4)
+
+;===========================================
+
+abcd_byte_address_register_carry_leave_zero_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_address_register_carry_leave_zero_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $99,$01
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C9,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $99,$00
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0015 ; SR=E-Z-C
+
+.assert_code
+ ABCD.B -(A3),-(A4)
+
+;===========================================
+
+abcd_byte_address_register_carry_leave_zero_cleared
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_address_register_carry_leave_zero_cleared",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $99,$01
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C9,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $99,$00
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0011 ; SR=E---C
+
+.assert_code
+ ABCD.B -(A3),-(A4)
+
+;===========================================
+
+abcd_byte_address_register_overflow_negative
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_address_register_overflow_negative",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $45,$38
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001a ; EN-O-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C9,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $45,$84
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $000a ; SR=-N-O-
+
+.assert_code
+ ABCD.B -(A3),-(A4)
+
+;===========================================
+
+abcd_byte_address_register_carry_overflow_negative
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "abcd_byte_address_register_carry_overflow_negative",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $99,$99
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001a ; EN-O-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $C9,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $99,$99
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $001b ; SR=EN-OC
+
+.assert_code
+ ABCD.B -(A3),-(A4)
+
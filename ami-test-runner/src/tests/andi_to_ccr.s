@@ -0,0 +1,228 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\andi_to_ccr.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+andi_to_ccr_b_00_ccr_1f
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "andi_to_ccr_b_00_ccr_1f",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $02,$3C,$00,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ANDI.B #$00,CCR
+
+;===========================================
+
+andi_to_ccr_b_1f_ccr_00
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "andi_to_ccr_b_1f_ccr_00",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $02,$3C,$00,$1F
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ANDI.B #$1F,CCR
+
+;===========================================
+
+andi_to_ccr_b_15_ccr_1f
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "andi_to_ccr_b_15_ccr_1f",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $02,$3C,$00,$15
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0015 ; SR=E-Z-C
+
+.assert_code
+ ANDI.B #$15,CCR
+
+;===========================================
+
+andi_to_ccr_b_0a_ccr_05
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "andi_to_ccr_b_0a_ccr_05",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0005 ; --Z-C
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $02,$3C,$00,$0A
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ANDI.B #$0A,CCR
+
+;===========================================
+
+andi_to_ccr_b_e0_ccr_1f
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "andi_to_ccr_b_e0_ccr_1f",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $02,$3C,$00,$E0
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ ANDI.B #$E0,CCR
+
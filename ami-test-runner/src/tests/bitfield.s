@@ -0,0 +1,626 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\bitfield.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+bfextu_data_register
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfextu_data_register",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $12345678,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0003 ; ---OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $E9,$C0,$12,$08
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $12345678,$00000034,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ BFEXTU D0{8:8},D1
+
+;===========================================
+
+bfextu_data_register_wraps_around
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfextu_data_register_wraps_around",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $12345678,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $E9,$C0,$17,$08
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $12345678,$00000081,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ BFEXTU D0{28:8},D1
+
+;===========================================
+
+bfexts_data_register_register_offset_and_width
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfexts_data_register_register_offset_and_width",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $f0000000,$000000d1,$00000000,$00000004,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $EB,$C0,$18,$A3
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $f0000000,$ffffffff,$00000000,$00000004,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ BFEXTS D0{D2:D3},D1
+
+;===========================================
+
+bftst_memory_z_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bftst_memory_z_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $F0
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0010 ; E----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $E8,$D0,$01,$04
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $F0
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0014 ; SR=E-Z--
+
+.assert_code
+ BFTST (A0){4:4}
+
+;===========================================
+
+bfextu_memory_spans_bytes
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfextu_memory_spans_bytes",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $03,$4B,$BF,$FF
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $E9,$D0,$11,$90
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $03,$4B,$BF,$FF
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$0000d2ef,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ BFEXTU (A0){6:16},D1
+
+;===========================================
+
+bfextu_memory_negative_offset
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfextu_memory_negative_offset",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $A5,$A5
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$fffffffc,$00000008,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050001,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $E9,$D0,$18,$A3
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $A5,$A5
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$0000005a,$fffffffc,$00000008,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050001,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ BFEXTU (A0){D2:D3},D1
+
+;===========================================
+
+bfchg_data_register
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfchg_data_register",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $0f000000,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $EA,$C0,$01,$04
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000000,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ BFCHG D0{4:4}
+
+;===========================================
+
+bfclr_memory_full_width
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfclr_memory_full_width",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000005,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $FF,$FF,$FF,$FF,$FF
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $EC,$D0,$01,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000005,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $F0,$00,$00,$00,$0F
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ BFCLR (A0){4:32}
+
+;===========================================
+
+bfset_data_register
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfset_data_register",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000000,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $EE,$C0,$07,$02
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $0000000c,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ BFSET D0{28:2}
+
+;===========================================
+
+bfffo_data_register
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfffo_data_register",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $04000000,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $ED,$C0,$10,$88
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $04000000,$00000005,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ BFFFO D0{2:8},D1
+
+;===========================================
+
+bfffo_data_register_no_bit_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfffo_data_register_no_bit_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $c0000000,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $ED,$C0,$10,$88
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $c0000000,$0000000a,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ BFFFO D0{2:8},D1
+
+;===========================================
+
+bfins_memory
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfins_memory",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000003,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $FF,$FF,$FF
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000abc,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0004 ; --Z--
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $EF,$D0,$11,$0C
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000003,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $FA,$BC,$FF
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000abc,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ BFINS D1,(A0){4:12}
+
+;===========================================
+
+bfins_data_register_z_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "bfins_data_register_z_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $ffffffff,$fffffff0,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $EF,$C0,$10,$04
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $0fffffff,$fffffff0,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ BFINS D1,D0{0:4}
+
@@ -0,0 +1,322 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\cas.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+cas_l_equal
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cas_l_equal",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $12,$34,$56,$78
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$12345678,$aabbccdd,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0010 ; E----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0E,$D0,$00,$81
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $AA,$BB,$CC,$DD
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$12345678,$aabbccdd,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0014 ; SR=E-Z--
+
+.assert_code
+ CAS.L D1,D2,(A0)
+
+;===========================================
+
+cas_l_not_equal
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cas_l_not_equal",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $12,$34,$56,$78
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$12345600,$aabbccdd,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0004 ; --Z--
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0E,$D0,$00,$81
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $12,$34,$56,$78
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$12345678,$aabbccdd,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ CAS.L D1,D2,(A0)
+
+;===========================================
+
+cas_b_equal_postincrement
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cas_b_equal_postincrement",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $05
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$11111105,$222222ee,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$D8,$00,$81
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $EE
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$11111105,$222222ee,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050001,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ CAS.B D1,D2,(A0)+
+
+;===========================================
+
+cas_w_not_equal_predecrement
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cas_w_not_equal_predecrement",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $12,$34
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$11111235,$2222eeee,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050002,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0010 ; E----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0C,$E0,$00,$81
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $12,$34
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$11111234,$2222eeee,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ CAS.W D1,D2,-(A0)
+
+;===========================================
+
+cas2_l_equal
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cas2_l_equal",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000008,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $11,$11,$11,$11,$22,$22,$22,$22
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $11111111,$22222222,$000000d2,$33333333,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$00050004,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000003,$00040000
+ dc.b $0E,$FC,$80,$C0,$90,$C1
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000008,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $33,$33,$33,$33,$33,$33,$33,$33
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $11111111,$22222222,$000000d2,$33333333,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$00050004,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040006 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ CAS2.L D0:D1,D3:D3,(A0):(A1)
+
+;===========================================
+
+cas2_w_second_not_equal
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cas2_w_second_not_equal",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $11,$11,$22,$22
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00001111,$00002223,$000000d2,$00003333,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$00050002,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000003,$00040000
+ dc.b $0C,$FC,$80,$C0,$90,$C1
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $11,$11,$22,$22
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00001111,$00002222,$000000d2,$00003333,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$00050002,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040006 ; PC
+ dc.w $0009 ; SR=-N--C
+
+.assert_code
+ CAS2.W D0:D1,D3:D3,(A0):(A1)
+
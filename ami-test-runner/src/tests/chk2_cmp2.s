@@ -0,0 +1,372 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\chk2_cmp2.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+cmp2_b_in_bounds
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cmp2_b_in_bounds",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $10,$20
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$ffffff15,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $00,$D0,$10,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $10,$20
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$ffffff15,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $001a ; SR=EN-O-
+
+.assert_code
+ CMP2.B (A0),D1
+
+;===========================================
+
+cmp2_b_equals_upper_bound
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cmp2_b_equals_upper_bound",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $10,$20
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $00,$D0,$10,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $10,$20
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ CMP2.B (A0),D1
+
+;===========================================
+
+cmp2_b_out_of_bounds
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cmp2_b_out_of_bounds",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $10,$20
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000025,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $00,$D0,$10,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $10,$20
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000025,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0001 ; SR=----C
+
+.assert_code
+ CMP2.B (A0),D1
+
+;===========================================
+
+cmp2_b_wrapped_bounds_out_of_bounds
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cmp2_b_wrapped_bounds_out_of_bounds",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $20,$10
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000018,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $00,$D0,$10,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $20,$10
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000018,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0001 ; SR=----C
+
+.assert_code
+ CMP2.B (A0),D1
+
+;===========================================
+
+cmp2_b_wrapped_bounds_in_bounds
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cmp2_b_wrapped_bounds_in_bounds",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $20,$10
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000030,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0001 ; ----C
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $00,$D0,$10,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $20,$10
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000030,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ CMP2.B (A0),D1
+
+;===========================================
+
+cmp2_w_address_register_sign_extended_bounds
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "cmp2_w_address_register_sign_extended_bounds",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050010,.arrange_mem_00050010
+ dc.l $00000000
+
+.arrange_mem_00050010
+ dc.b $FF,$F0,$00,$10
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$fffffff8,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000003,$00040000
+ dc.b $02,$E8,$90,$00,$00,$10
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050010,.assert_mem_00050010
+ dc.l $00000000
+
+.assert_mem_00050010
+ dc.b $FF,$F0,$00,$10
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$fffffff8,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040006 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ CMP2.W ($0010,A0),A1
+
+;===========================================
+
+chk2_l_equals_lower_bound
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "chk2_l_equals_lower_bound",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000008,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $00,$00,$01,$00,$00,$00,$02,$00
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000100,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $04,$D0,$18,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000008,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $00,$00,$01,$00,$00,$00,$02,$00
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000100,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ CHK2.L (A0),D1
+
@@ -0,0 +1,360 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\divl.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+divu_l_32_bit
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "divu_l_32_bit",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000007,$00000064,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0011 ; E---C
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $4C,$41,$20,$02
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000007,$0000000e,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0010 ; SR=E----
+
+.assert_code
+ DIVU.L D1,D2
+
+;===========================================
+
+divul_l_32_bit_remainder
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "divul_l_32_bit_remainder",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000007,$00000064,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $4C,$41,$20,$03
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000007,$0000000e,$00000002,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ DIVUL.L D1,D3:D2
+
+;===========================================
+
+divs_l_32_bit_negative
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "divs_l_32_bit_negative",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000007,$ffffff9c,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $4C,$41,$28,$02
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000007,$fffffff2,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ DIVS.L D1,D2
+
+;===========================================
+
+divsl_l_32_bit_remainder
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "divsl_l_32_bit_remainder",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000007,$ffffff9c,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $4C,$41,$28,$03
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000007,$fffffff2,$fffffffe,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ DIVSL.L D1,D3:D2
+
+;===========================================
+
+divu_l_64_bit
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "divu_l_64_bit",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000002,$00000000,$00000001,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $4C,$41,$24,$03
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000002,$80000000,$00000000,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ DIVU.L D1,D3:D2
+
+;===========================================
+
+divu_l_64_bit_overflow
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "divu_l_64_bit_overflow",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000002,$00000000,$00000002,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0001 ; ----C
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $4C,$41,$24,$03
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$00000002,$00000000,$00000002,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0002 ; SR=---O-
+
+.assert_code
+ DIVU.L D1,D3:D2
+
+;===========================================
+
+divs_l_32_bit_overflow
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "divs_l_32_bit_overflow",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$ffffffff,$80000000,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $4C,$41,$28,$02
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$ffffffff,$80000000,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0002 ; SR=---O-
+
+.assert_code
+ DIVS.L D1,D2
+
+;===========================================
+
+divu_l_32_bit_z_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "divu_l_32_bit_z_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$000000ff,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0008 ; -N---
+
+.arrange_code
+ ;length,address
+ dc.l $00000004,$00040000
+ dc.b $4C,$7C,$20,$02,$00,$00,$01,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000040,$000000d1,$00000000,$000000d3,$000000d4,$000000d5,$000000d6,$000000d7
+ dc.l $000000a0,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040008 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ DIVU.L #$00000100,D2
+
@@ -0,0 +1,562 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\eori.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+eori_b_immediate_to_data_register_direct_x_unaffected_still_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_b_immediate_to_data_register_direct_x_unaffected_still_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a5a55a,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$03,$00,$0F
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a5a555,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0010 ; SR=E----
+
+.assert_code
+ EORI.B #$0F,D3
+
+;===========================================
+
+eori_b_immediate_to_data_register_direct_n_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_b_immediate_to_data_register_direct_n_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a5a501,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$03,$00,$80
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a5a581,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ EORI.B #$80,D3
+
+;===========================================
+
+eori_b_immediate_to_data_register_direct_z_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_b_immediate_to_data_register_direct_z_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a5a533,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$03,$00,$33
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a5a500,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ EORI.B #$33,D3
+
+;===========================================
+
+eori_b_immediate_to_address_register_indirect
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_b_immediate_to_address_register_indirect",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $0F
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$10,$00,$FF
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $F0
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ EORI.B #$FF,(A0)
+
+;===========================================
+
+eori_w_immediate_to_data_register_direct_x_unaffected_still_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_w_immediate_to_data_register_direct_x_unaffected_still_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a5005a,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$43,$00,$0F
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a50055,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0010 ; SR=E----
+
+.assert_code
+ EORI.W #$000F,D3
+
+;===========================================
+
+eori_w_immediate_to_data_register_direct_n_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_w_immediate_to_data_register_direct_n_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a50001,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$43,$80,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a58001,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ EORI.W #$8000,D3
+
+;===========================================
+
+eori_w_immediate_to_data_register_direct_z_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_w_immediate_to_data_register_direct_z_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a50033,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$43,$00,$33
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$a5a50000,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ EORI.W #$0033,D3
+
+;===========================================
+
+eori_w_immediate_to_address_register_indirect
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_w_immediate_to_address_register_indirect",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $0F,$F0
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $0A,$50,$FF,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $F0,$F0
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ EORI.W #$FF00,(A0)
+
+;===========================================
+
+eori_l_immediate_to_data_register_direct_x_unaffected_still_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_l_immediate_to_data_register_direct_x_unaffected_still_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$0000005a,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000003,$00040000
+ dc.b $0A,$83,$00,$00,$00,$0F
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$00000055,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040006 ; PC
+ dc.w $0010 ; SR=E----
+
+.assert_code
+ EORI.L #$0000000F,D3
+
+;===========================================
+
+eori_l_immediate_to_data_register_direct_n_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_l_immediate_to_data_register_direct_n_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$00000001,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000003,$00040000
+ dc.b $0A,$83,$80,$00,$00,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$80000001,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040006 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ EORI.L #$80000000,D3
+
+;===========================================
+
+eori_l_immediate_to_data_register_direct_z_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_l_immediate_to_data_register_direct_z_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$00000033,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000003,$00040000
+ dc.b $0A,$83,$00,$00,$00,$33
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$00000000,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040006 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ EORI.L #$00000033,D3
+
+;===========================================
+
+eori_l_immediate_to_address_register_indirect
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "eori_l_immediate_to_address_register_indirect",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $12,$34,$56,$78
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000003,$00040000
+ dc.b $0A,$90,$FF,$FF,$00,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $ED,$CB,$56,$78
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040006 ; PC
+ dc.w $0008 ; SR=-N---
+
+.assert_code
+ EORI.L #$FFFF0000,(A0)
+
@@ -0,0 +1,236 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\move_to_ccr.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+move_to_ccr_w_data_register_direct_1f
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "move_to_ccr_w_data_register_direct_1f",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$a5a5ff1f,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $44,$C2
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$a5a5ff1f,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $001f ; SR=ENZOC
+
+.assert_code
+ MOVE.W D2,CCR
+
+;===========================================
+
+move_to_ccr_w_data_register_direct_00
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "move_to_ccr_w_data_register_direct_00",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$5a5a5ae0,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $44,$C2
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$5a5a5ae0,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ MOVE.W D2,CCR
+
+;===========================================
+
+move_to_ccr_w_data_register_direct_0a
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "move_to_ccr_w_data_register_direct_0a",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$0000000a,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0015 ; E-Z-C
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $44,$C2
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$0000000a,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $000a ; SR=-N-O-
+
+.assert_code
+ MOVE.W D2,CCR
+
+;===========================================
+
+move_to_ccr_w_immediate_data
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "move_to_ccr_w_immediate_data",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $000a ; -N-O-
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $44,$FC,$00,$15
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0015 ; SR=E-Z-C
+
+.assert_code
+ MOVE.W #$0015,CCR
+
+;===========================================
+
+move_to_ccr_w_address_register_indirect
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "move_to_ccr_w_address_register_indirect",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $FF,$04
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $44,$D0
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $FF,$04
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ MOVE.W (A0),CCR
+
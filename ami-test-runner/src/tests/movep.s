@@ -0,0 +1,220 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\movep.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+movep_w_memory_to_register
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "movep_w_memory_to_register",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000003,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $12,$FF,$34
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$a5a5a5a5,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $001f ; ENZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $03,$08,$00,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000003,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $12,$FF,$34
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$a5a51234,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $001f ; SR=ENZOC
+
+.assert_code
+ MOVEP.W ($0000,A0),D1
+
+;===========================================
+
+movep_l_memory_to_register
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "movep_l_memory_to_register",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000007,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $11,$FF,$22,$FF,$33,$FF,$44
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0000 ; -----
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $05,$48,$00,$02
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000007,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $11,$FF,$22,$FF,$33,$FF,$44
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$11223344,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ MOVEP.L ($0002,A0),D2
+
+;===========================================
+
+movep_w_register_to_memory
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "movep_w_register_to_memory",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.arrange_mem_00050000
+ dc.l $00000000
+
+.arrange_mem_00050000
+ dc.b $00,$00,$00,$00
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$5555abcd,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $000a ; -N-O-
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $07,$88,$00,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000004,$00050000,.assert_mem_00050000
+ dc.l $00000000
+
+.assert_mem_00050000
+ dc.b $AB,$00,$CD,$00
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$5555abcd,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $000a ; SR=-N-O-
+
+.assert_code
+ MOVEP.W D3,($0000,A0)
+
+;===========================================
+
+movep_l_register_to_memory
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "movep_l_register_to_memory",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000008,$00050004,.arrange_mem_00050004
+ dc.l $00000000
+
+.arrange_mem_00050004
+ dc.b $00,$00,$00,$00,$00,$00,$00,$00
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$89abcdef,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.w $0015 ; E-Z-C
+
+.arrange_code
+ ;length,address
+ dc.l $00000002,$00040000
+ dc.b $09,$C8,$00,$04
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000008,$00050004,.assert_mem_00050004
+ dc.l $00000000
+
+.assert_mem_00050004
+ dc.b $89,$00,$AB,$00,$CD,$00,$EF,$00
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$89abcdef,$000000d5,$000000d6,$00004344
+ dc.l $00050000,$000000a1,$000000a2,$000000a3,$000000a4,$000000a5,$000000a6,$000000a7
+ dc.l $00040004 ; PC
+ dc.w $0015 ; SR=E-Z-C
+
+.assert_code
+ MOVEP.L D4,($0004,A0)
+
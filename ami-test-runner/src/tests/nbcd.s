@@ -0,0 +1,498 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\nbcd.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+nbcd_byte_data_register_zero_with_extend_clear_leave_zero_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_data_register_zero_with_extend_clear_leave_zero_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000000,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000000,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ NBCD.B D0
+
+;===========================================
+
+nbcd_byte_data_register_zero_with_extend_clear_leave_zero_cleared
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_data_register_zero_with_extend_clear_leave_zero_cleared",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000000,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000000,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ NBCD.B D0
+
+;===========================================
+
+nbcd_byte_data_register_with_extend_clear
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_data_register_with_extend_clear",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000088,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ NBCD.B D0
+
+;===========================================
+
+nbcd_byte_data_register_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_data_register_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001e ; ENZO-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000087,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ NBCD.B D0
+
+;===========================================
+
+nbcd_byte_data_register_one_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_data_register_one_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000001,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001a ; EN-O-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000098,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ NBCD.B D0
+
+;===========================================
+
+nbcd_byte_address_register_predecrement_zero_with_extend_clear_leave_zero_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_address_register_predecrement_zero_with_extend_clear_leave_zero_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $00
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$23
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $00
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ NBCD.B -(A3)
+
+;===========================================
+
+nbcd_byte_address_register_predecrement_zero_with_extend_clear_leave_zero_cleared
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_address_register_predecrement_zero_with_extend_clear_leave_zero_cleared",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $00
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$23
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $00
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ NBCD.B -(A3)
+
+;===========================================
+
+nbcd_byte_address_register_predecrement_with_extend_clear
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_address_register_predecrement_with_extend_clear",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $12
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$23
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $88
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ NBCD.B -(A3)
+
+;===========================================
+
+nbcd_byte_address_register_predecrement_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_address_register_predecrement_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $12
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001e ; ENZO-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$23
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $87
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ NBCD.B -(A3)
+
+;===========================================
+
+nbcd_byte_address_register_predecrement_one_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "nbcd_byte_address_register_predecrement_one_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $01
+ even
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001a ; EN-O-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $48,$23
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000001,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $98
+ even
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ NBCD.B -(A3)
+
@@ -0,0 +1,680 @@
+; ----------------------T----------------------------------
+
+; Path: ..\ami-test-runner\src\tests\sbcd.s
+; This file is autogenerated
+
+ ;rts in case this source is run by mistake
+ rts
+
+;===========================================
+
+sbcd_byte_data_register_with_extend_clear
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_data_register_with_extend_clear",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000046,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $83,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000034,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ SBCD.B D0,D1
+
+;===========================================
+
+sbcd_byte_data_register_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_data_register_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000046,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001e ; ENZO-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $83,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000012,$00000033,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ SBCD.B D0,D1
+
+;===========================================
+
+sbcd_byte_data_register_decimal_borrow_from_low_digit
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_data_register_decimal_borrow_from_low_digit",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000009,$00000012,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $83,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000009,$00000003,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ SBCD.B D0,D1
+
+;===========================================
+
+sbcd_byte_data_register_zero_result_leave_zero_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_data_register_zero_result_leave_zero_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000046,$00000046,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $83,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000046,$00000000,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ SBCD.B D0,D1
+
+;===========================================
+
+sbcd_byte_data_register_zero_result_leave_zero_cleared
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_data_register_zero_result_leave_zero_cleared",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000046,$00000046,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $83,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000046,$00000000,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ SBCD.B D0,D1
+
+;===========================================
+
+sbcd_byte_data_register_borrow_negative
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_data_register_borrow_negative",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000001,$00000000,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $83,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000001,$00000099,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ SBCD.B D0,D1
+
+;===========================================
+
+sbcd_byte_data_register_borrow_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_data_register_borrow_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000023,$00000012,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001e ; ENZO-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $83,$00
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000000
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000023,$00000088,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ SBCD.B D0,D1
+
+;===========================================
+
+sbcd_byte_address_register_with_extend_clear
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_address_register_with_extend_clear",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $12,$46
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $89,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $12,$34
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ SBCD.B -(A3),-(A4)
+
+;===========================================
+
+sbcd_byte_address_register_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_address_register_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $12,$46
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001e ; ENZO-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $89,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $12,$33
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ SBCD.B -(A3),-(A4)
+
+;===========================================
+
+sbcd_byte_address_register_decimal_borrow_from_low_digit
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_address_register_decimal_borrow_from_low_digit",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $09,$12
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $89,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $09,$03
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ SBCD.B -(A3),-(A4)
+
+;===========================================
+
+sbcd_byte_address_register_zero_result_leave_zero_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_address_register_zero_result_leave_zero_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $46,$46
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $89,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $46,$00
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0004 ; SR=--Z--
+
+.assert_code
+ SBCD.B -(A3),-(A4)
+
+;===========================================
+
+sbcd_byte_address_register_zero_result_leave_zero_cleared
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_address_register_zero_result_leave_zero_cleared",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $46,$46
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000b ; -N-OC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $89,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $46,$00
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0000 ; SR=-----
+
+.assert_code
+ SBCD.B -(A3),-(A4)
+
+;===========================================
+
+sbcd_byte_address_register_borrow_negative
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_address_register_borrow_negative",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $01,$00
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $000f ; -NZOC
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $89,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $01,$99
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ SBCD.B -(A3),-(A4)
+
+;===========================================
+
+sbcd_byte_address_register_borrow_with_extend_set
+ dc.l .name	; $00
+ dc.l .arrange_mem	; $04
+ dc.l .arrange_regs	; $08
+ dc.l .arrange_code	; $0c
+ dc.l .assert_mem	; $10
+ dc.l .assert_regs	; $14
+ dc.l .assert_code	; $18
+
+.name
+ dc.b "sbcd_byte_address_register_borrow_with_extend_set",0
+ even
+
+.arrange_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.arrange_mem_00050002
+ dc.l $00000000
+
+.arrange_mem_00050002
+ dc.b $23,$12
+
+.arrange_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050003,$00050004,$000000a5,$000000a6,$000000a7
+ dc.w $001e ; ENZO-
+
+.arrange_code
+ ;length,address
+ dc.l $00000001,$00040000
+ dc.b $89,$0B
+
+.assert_mem
+ ;length,address,ptr
+ dc.l $00000002,$00050002,.assert_mem_00050002
+ dc.l $00000000
+
+.assert_mem_00050002
+ dc.b $23,$88
+
+.assert_regs
+ ;    D0/A0     D1/A1     D2/A2     D3/A3     D4/A4     D5/A5     D6/A6     D7/A7
+ dc.l $00000010,$00000020,$000000d2,$000000d3,$000000d4,$000000d5,$000000d6,$00004344
+ dc.l $a0a0a0a0,$000000a1,$000000a2,$00050002,$00050003,$000000a5,$000000a6,$000000a7
+ dc.l $00040002 ; PC
+ dc.w $0019 ; SR=EN--C
+
+.assert_code
+ SBCD.B -(A3),-(A4)
+
@@ -6,6 +6,20 @@
 	rts
 
 test_suite
+	dc.l	abcd_byte_data_register_with_extend_clear
+	dc.l	abcd_byte_data_register_with_extend_set
+	dc.l	abcd_byte_data_register_decimal_carry_from_low_digit
+	dc.l	abcd_byte_data_register_carry_leave_zero_set
+	dc.l	abcd_byte_data_register_carry_leave_zero_cleared
+	dc.l	abcd_byte_data_register_overflow_negative
+	dc.l	abcd_byte_data_register_carry_overflow_negative
+	dc.l	abcd_byte_address_register_with_extend_clear
+	dc.l	abcd_byte_address_register_with_extend_set
+	dc.l	abcd_byte_address_register_decimal_carry_from_low_digit
+	dc.l	abcd_byte_address_register_carry_leave_zero_set
+	dc.l	abcd_byte_address_register_carry_leave_zero_cleared
+	dc.l	abcd_byte_address_register_overflow_negative
+	dc.l	abcd_byte_address_register_carry_overflow_negative
 	dc.l	address_register_indirect_to_data_register_direct_byte
 	dc.l	address_register_indirect_to_data_register_direct_byte_overflow
 	dc.l	address_register_indirect_to_data_register_direct_byte_carry
@@ -60,6 +74,11 @@ test_suite
 	dc.l	address_register_word_with_extend_set
 	dc.l	address_register_long_with_extend_clear
 	dc.l	address_register_long_with_extend_set
+	dc.l	andi_to_ccr_b_00_ccr_1f
+	dc.l	andi_to_ccr_b_1f_ccr_00
+	dc.l	andi_to_ccr_b_15_ccr_1f
+	dc.l	andi_to_ccr_b_0a_ccr_05
+	dc.l	andi_to_ccr_b_e0_ccr_1f
 	dc.l	asl_b_register_by_immediate_cx_set
 	dc.l	asl_b_register_by_immediate_cx_clear
 	dc.l	asl_b_register_by_immediate_n_set
@@ -127,6 +146,40 @@ test_suite
 	dc.l	asl_w_memory_by_1_areg_indirect_with_displacement
 	dc.l	asr_w_memory_by_1_areg_indirect_with_postincrement
 	dc.l	asr_w_memory_by_1_areg_indirect
+	dc.l	bfextu_data_register
+	dc.l	bfextu_data_register_wraps_around
+	dc.l	bfexts_data_register_register_offset_and_width
+	dc.l	bftst_memory_z_set
+	dc.l	bfextu_memory_spans_bytes
+	dc.l	bfextu_memory_negative_offset
+	dc.l	bfchg_data_register
+	dc.l	bfclr_memory_full_width
+	dc.l	bfset_data_register
+	dc.l	bfffo_data_register
+	dc.l	bfffo_data_register_no_bit_set
+	dc.l	bfins_memory
+	dc.l	bfins_data_register_z_set
+	dc.l	cas_l_equal
+	dc.l	cas_l_not_equal
+	dc.l	cas_b_equal_postincrement
+	dc.l	cas_w_not_equal_predecrement
+	dc.l	cas2_l_equal
+	dc.l	cas2_w_second_not_equal
+	dc.l	cmp2_b_in_bounds
+	dc.l	cmp2_b_equals_upper_bound
+	dc.l	cmp2_b_out_of_bounds
+	dc.l	cmp2_b_wrapped_bounds_out_of_bounds
+	dc.l	cmp2_b_wrapped_bounds_in_bounds
+	dc.l	cmp2_w_address_register_sign_extended_bounds
+	dc.l	chk2_l_equals_lower_bound
+	dc.l	divu_l_32_bit
+	dc.l	divul_l_32_bit_remainder
+	dc.l	divs_l_32_bit_negative
+	dc.l	divsl_l_32_bit_remainder
+	dc.l	divu_l_64_bit
+	dc.l	divu_l_64_bit_overflow
+	dc.l	divs_l_32_bit_overflow
+	dc.l	divu_l_32_bit_z_set
 	dc.l	divs_w__data_register_by_ea__x_unaffected_still_set
 	dc.l	divs_w__data_register_by_ea__x_unaffected_still_clear
 	dc.l	divs_w__data_register_by_ea__n_set
@@ -165,6 +218,23 @@ test_suite
 	dc.l	eor_b__data_register_to_ea__z_set
 	dc.l	eor_b__data_register_to_ea__z_clear
 	dc.l	eor_b__data_register_to_ea__vc_clear
+	dc.l	eori_b_immediate_to_data_register_direct_x_unaffected_still_set
+	dc.l	eori_b_immediate_to_data_register_direct_n_set
+	dc.l	eori_b_immediate_to_data_register_direct_z_set
+	dc.l	eori_b_immediate_to_address_register_indirect
+	dc.l	eori_w_immediate_to_data_register_direct_x_unaffected_still_set
+	dc.l	eori_w_immediate_to_data_register_direct_n_set
+	dc.l	eori_w_immediate_to_data_register_direct_z_set
+	dc.l	eori_w_immediate_to_address_register_indirect
+	dc.l	eori_l_immediate_to_data_register_direct_x_unaffected_still_set
+	dc.l	eori_l_immediate_to_data_register_direct_n_set
+	dc.l	eori_l_immediate_to_data_register_direct_z_set
+	dc.l	eori_l_immediate_to_address_register_indirect
+	dc.l	eori_to_ccr_b_00_ccr_1f
+	dc.l	eori_to_ccr_b_1f_ccr_00
+	dc.l	eori_to_ccr_b_15_ccr_1f
+	dc.l	eori_to_ccr_b_0a_ccr_05
+	dc.l	eori_to_ccr_b_e0_ccr_1f
 	dc.l	ext_w_x_not_affected_still_set
 	dc.l	ext_w_x_not_affected_still_clear
 	dc.l	ext_w_n_set
@@ -179,21 +249,185 @@ test_suite
 	dc.l	ext_l_z_set
 	dc.l	ext_l_z_clear
 	dc.l	ext_l_vc_clear
+	dc.l	extb_l_n_set
+	dc.l	extb_l_vc_cleared
+	dc.l	extb_l_z_set
 	dc.l	lsl_b_register_by_immediate
 	dc.l	lsl_register_by_immediate_byte_negative
 	dc.l	lsl_register_by_immediate_byte_zero
 	dc.l	lsl_register_by_immediate_byte_extend_carry
 	dc.l	lsl_register_by_immediate_word
+	dc.l	move_to_ccr_w_data_register_direct_1f
+	dc.l	move_to_ccr_w_data_register_direct_00
+	dc.l	move_to_ccr_w_data_register_direct_0a
+	dc.l	move_to_ccr_w_immediate_data
+	dc.l	move_to_ccr_w_address_register_indirect
+	dc.l	movep_w_memory_to_register
+	dc.l	movep_l_memory_to_register
+	dc.l	movep_w_register_to_memory
+	dc.l	movep_l_register_to_memory
+	dc.l	mulu_l_32_bit
+	dc.l	mulu_l_32_bit_overflow
+	dc.l	muls_l_32_bit_negative
+	dc.l	muls_l_32_bit_overflow
+	dc.l	mulu_l_64_bit
+	dc.l	muls_l_64_bit_immediate
+	dc.l	mulu_l_memory
+	dc.l	muls_word_data_register_direct_positive
+	dc.l	muls_word_data_register_direct_negative
+	dc.l	muls_word_data_register_direct_both_negative
+	dc.l	muls_word_data_register_direct_zero
+	dc.l	muls_word_data_register_direct_ignore_upper_word
+	dc.l	muls_word_immediate_data_to_data_register
+	dc.l	nbcd_byte_data_register_zero_with_extend_clear_leave_zero_set
+	dc.l	nbcd_byte_data_register_zero_with_extend_clear_leave_zero_cleared
+	dc.l	nbcd_byte_data_register_with_extend_clear
+	dc.l	nbcd_byte_data_register_with_extend_set
+	dc.l	nbcd_byte_data_register_one_with_extend_set
+	dc.l	nbcd_byte_address_register_predecrement_zero_with_extend_clear_leave_zero_set
+	dc.l	nbcd_byte_address_register_predecrement_zero_with_extend_clear_leave_zero_cleared
+	dc.l	nbcd_byte_address_register_predecrement_with_extend_clear
+	dc.l	nbcd_byte_address_register_predecrement_with_extend_set
+	dc.l	nbcd_byte_address_register_predecrement_one_with_extend_set
+	dc.l	negx_b_data_register_direct_positive_extend_clear
+	dc.l	negx_b_data_register_direct_positive_extend_set
+	dc.l	negx_b_data_register_direct_zero_extend_clear_leave_zero_set
+	dc.l	negx_b_data_register_direct_zero_extend_clear_leave_zero_cleared
+	dc.l	negx_b_data_register_direct_zero_extend_set
+	dc.l	negx_b_data_register_direct_overflow
+	dc.l	negx_b_data_register_direct_negative_to_positive
+	dc.l	negx_w_data_register_direct_positive_extend_clear
+	dc.l	negx_w_data_register_direct_positive_extend_set
+	dc.l	negx_w_data_register_direct_zero_extend_clear_leave_zero_set
+	dc.l	negx_w_data_register_direct_zero_extend_clear_leave_zero_cleared
+	dc.l	negx_w_data_register_direct_zero_extend_set
+	dc.l	negx_w_data_register_direct_overflow
+	dc.l	negx_w_data_register_direct_negative_to_positive
+	dc.l	negx_l_data_register_direct_positive_extend_clear
+	dc.l	negx_l_data_register_direct_positive_extend_set
+	dc.l	negx_l_data_register_direct_zero_extend_clear_leave_zero_set
+	dc.l	negx_l_data_register_direct_zero_extend_clear_leave_zero_cleared
+	dc.l	negx_l_data_register_direct_zero_extend_set
+	dc.l	negx_l_data_register_direct_overflow
+	dc.l	negx_l_data_register_direct_negative_to_positive
+	dc.l	negx_w_address_register_indirect
+	dc.l	ori_to_ccr_b_00_ccr_1f
+	dc.l	ori_to_ccr_b_1f_ccr_00
+	dc.l	ori_to_ccr_b_15_ccr_1f
+	dc.l	ori_to_ccr_b_0a_ccr_05
+	dc.l	ori_to_ccr_b_e0_ccr_1f
+	dc.l	pack_data_register
+	dc.l	pack_data_register_adjustment
+	dc.l	pack_memory
+	dc.l	rol_b_immediate_msb_out_extend_clear
+	dc.l	ror_b_immediate_lsb_out_extend_clear
+	dc.l	rol_b_immediate_8_extend_set
+	dc.l	ror_b_immediate_3_extend_set
+	dc.l	rol_b_register_count_zero_extend_set
+	dc.l	ror_b_register_count_larger_than_size_extend_clear
+	dc.l	rol_b_register_zero_result_extend_set
+	dc.l	rol_w_immediate_msb_out_extend_clear
+	dc.l	ror_w_immediate_lsb_out_extend_clear
+	dc.l	rol_w_immediate_8_extend_set
+	dc.l	ror_w_immediate_3_extend_set
+	dc.l	rol_w_register_count_zero_extend_set
+	dc.l	ror_w_register_count_larger_than_size_extend_clear
+	dc.l	rol_w_register_zero_result_extend_set
+	dc.l	rol_l_immediate_msb_out_extend_clear
+	dc.l	ror_l_immediate_lsb_out_extend_clear
+	dc.l	rol_l_immediate_8_extend_set
+	dc.l	ror_l_immediate_3_extend_set
+	dc.l	rol_l_register_count_zero_extend_set
+	dc.l	ror_l_register_count_larger_than_size_extend_clear
+	dc.l	rol_l_register_zero_result_extend_set
+	dc.l	rol_w_memory_8001_extend_clear
+	dc.l	rol_w_memory_8001_extend_set
+	dc.l	rol_w_memory_4000_extend_clear
+	dc.l	ror_w_memory_8001_extend_clear
+	dc.l	ror_w_memory_8001_extend_set
+	dc.l	ror_w_memory_4000_extend_clear
+	dc.l	roxl_b_immediate_msb_out_extend_clear
+	dc.l	roxr_b_immediate_lsb_out_extend_clear
+	dc.l	roxl_b_immediate_8_extend_set
+	dc.l	roxr_b_immediate_3_extend_set
+	dc.l	roxl_b_register_count_zero_extend_set
+	dc.l	roxr_b_register_count_larger_than_size_extend_clear
+	dc.l	roxl_b_register_zero_result_extend_set
+	dc.l	roxl_w_immediate_msb_out_extend_clear
+	dc.l	roxr_w_immediate_lsb_out_extend_clear
+	dc.l	roxl_w_immediate_8_extend_set
+	dc.l	roxr_w_immediate_3_extend_set
+	dc.l	roxl_w_register_count_zero_extend_set
+	dc.l	roxr_w_register_count_larger_than_size_extend_clear
+	dc.l	roxl_w_register_zero_result_extend_set
+	dc.l	roxl_l_immediate_msb_out_extend_clear
+	dc.l	roxr_l_immediate_lsb_out_extend_clear
+	dc.l	roxl_l_immediate_8_extend_set
+	dc.l	roxr_l_immediate_3_extend_set
+	dc.l	roxl_l_register_count_zero_extend_set
+	dc.l	roxr_l_register_count_larger_than_size_extend_clear
+	dc.l	roxl_l_register_zero_result_extend_set
+	dc.l	roxl_w_memory_8001_extend_clear
+	dc.l	roxl_w_memory_8001_extend_set
+	dc.l	roxl_w_memory_4000_extend_clear
+	dc.l	roxr_w_memory_8001_extend_clear
+	dc.l	roxr_w_memory_8001_extend_set
+	dc.l	roxr_w_memory_4000_extend_clear
+	dc.l	sbcd_byte_data_register_with_extend_clear
+	dc.l	sbcd_byte_data_register_with_extend_set
+	dc.l	sbcd_byte_data_register_decimal_borrow_from_low_digit
+	dc.l	sbcd_byte_data_register_zero_result_leave_zero_set
+	dc.l	sbcd_byte_data_register_zero_result_leave_zero_cleared
+	dc.l	sbcd_byte_data_register_borrow_negative
+	dc.l	sbcd_byte_data_register_borrow_with_extend_set
+	dc.l	sbcd_byte_address_register_with_extend_clear
+	dc.l	sbcd_byte_address_register_with_extend_set
+	dc.l	sbcd_byte_address_register_decimal_borrow_from_low_digit
+	dc.l	sbcd_byte_address_register_zero_result_leave_zero_set
+	dc.l	sbcd_byte_address_register_zero_result_leave_zero_cleared
+	dc.l	sbcd_byte_address_register_borrow_negative
+	dc.l	sbcd_byte_address_register_borrow_with_extend_set
+	dc.l	tas_b_data_register_direct_zero
+	dc.l	tas_b_data_register_direct_negative
+	dc.l	tas_b_data_register_direct_positive
+	dc.l	trapeq_not_taken
+	dc.l	trapne_w_not_taken
+	dc.l	trapf_l_not_taken
+	dc.l	unpk_data_register_adjustment
+	dc.l	unpk_memory
 
 	dc.l	$0
 
+	include	"abcd.s"
 	include	"add.s"
 	include	"addi.s"
 	include	"addq.s"
 	include	"addx.s"
+	include	"andi_to_ccr.s"
 	include	"aslr.s"
+	include	"bitfield.s"
+	include	"cas.s"
+	include	"chk2_cmp2.s"
+	include	"divl.s"
 	include	"divs.s"
 	include	"divu.s"
 	include	"eor.s"
+	include	"eori.s"
+	include	"eori_to_ccr.s"
 	include	"ext.s"
+	include	"extb.s"
 	include	"lslr.s"
+	include	"move_to_ccr.s"
+	include	"movep.s"
+	include	"mull.s"
+	include	"muls.s"
+	include	"nbcd.s"
+	include	"negx.s"
+	include	"ori_to_ccr.s"
+	include	"pack.s"
+	include	"rolr.s"
+	include	"roxlr.s"
+	include	"sbcd.s"
+	include	"tas.s"
+	include	"trapcc.s"
+	include	"unpk.s"
@@ -10,6 +10,8 @@ pub trait AInt: Copy + PartialEq + Shl<u32, Output = Self> + Shr<u32, Output = S
     fn get_msb_mask(self) -> Self;
     // fn leading_zeros(self) -> u32;
     fn zero() -> Self;
+    fn lsb() -> Self;
+    fn msb() -> Self;
 
     fn get_hex_string(self) -> String;
 
@@ -48,6 +50,12 @@ impl AInt for u8 {
     fn zero() -> Self {
         0x00
     }
+    fn lsb() -> Self {
+        0x01
+    }
+    fn msb() -> Self {
+        0x80
+    }
 
     fn get_hex_string(self) -> String {
         format!("{:02x}", self)
@@ -96,6 +104,12 @@ impl AInt for u16 {
     fn zero() -> Self {
         0x0000
     }
+    fn lsb() -> Self {
+        0x0001
+    }
+    fn msb() -> Self {
+        0x8000
+    }
 
     fn get_hex_string(self) -> String {
         format!("{:04x}", self)
@@ -144,6 +158,12 @@ impl AInt for u32 {
     fn zero() -> Self {
         0x00000000
     }
+    fn lsb() -> Self {
+        0x00000001
+    }
+    fn msb() -> Self {
+        0x80000000
+    }
 
     fn get_hex_string(self) -> String {
         format!("{:08x}", self)
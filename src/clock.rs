@@ -0,0 +1,138 @@
+use std::time::{Duration, Instant};
+
+use crate::cpu::CpuSpeed;
+
+// All the chips in the Amiga are clocked from the same crystal, 28.37516 MHz on PAL and
+// 28.63636 MHz on NTSC machines. Everything is measured in ticks of that master clock, so the
+// devices can be advanced by the time actually used by the CPU, independent of the host.
+pub const MASTER_TICKS_PER_CPU_CYCLE: u64 = 4;
+pub const MASTER_TICKS_PER_COLOR_CLOCK: u64 = 8;
+// The CIAs are clocked by the E clock, which is the CPU clock divided by 10
+pub const MASTER_TICKS_PER_E_CLOCK: u64 = 40;
+
+// Derives a slower clock from the master clock, and keeps track of how many of its cycles
+// have elapsed
+pub struct ClockDivider {
+    master_ticks_per_cycle: u64,
+    next_cycle_master_ticks: u64,
+}
+
+impl ClockDivider {
+    pub fn new(master_ticks_per_cycle: u64) -> ClockDivider {
+        ClockDivider {
+            master_ticks_per_cycle,
+            next_cycle_master_ticks: master_ticks_per_cycle,
+        }
+    }
+
+    // Returns the number of cycles of this clock that completed up to the master clock time
+    pub fn advance_to(&mut self, master_ticks: u64) -> u64 {
+        if master_ticks < self.next_cycle_master_ticks {
+            return 0;
+        }
+        let cycles =
+            (master_ticks - self.next_cycle_master_ticks) / self.master_ticks_per_cycle + 1;
+        self.next_cycle_master_ticks += cycles * self.master_ticks_per_cycle;
+        cycles
+    }
+}
+
+pub struct MasterClock {
+    hz: u64,
+    ticks: u64,
+    // Only used when throttling to real time
+    real_time_start: Option<Instant>,
+}
+
+impl MasterClock {
+    pub fn new(cpu_speed: &CpuSpeed) -> MasterClock {
+        MasterClock {
+            hz: cpu_speed.get_hz() as u64 * MASTER_TICKS_PER_CPU_CYCLE,
+            ticks: 0,
+            real_time_start: None,
+        }
+    }
+
+    pub fn get_ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    pub fn add_cpu_cycles(&mut self, cpu_cycles: u32) {
+        self.ticks += cpu_cycles as u64 * MASTER_TICKS_PER_CPU_CYCLE;
+    }
+
+    pub fn get_emulated_time(&self) -> Duration {
+        Duration::from_nanos((self.ticks as u128 * 1_000_000_000 / self.hz as u128) as u64)
+    }
+
+    // Real time throttling is layered on top of the emulation. The emulation itself only ever
+    // looks at the master clock, so a throttled run behaves exactly like an unthrottled one.
+    pub fn set_real_time(&mut self, real_time: bool) {
+        self.real_time_start = match real_time {
+            true => Some(Instant::now() - self.get_emulated_time()),
+            false => None,
+        };
+    }
+
+    pub fn is_real_time(&self) -> bool {
+        self.real_time_start.is_some()
+    }
+
+    // Sleeps until the host has caught up with the emulated time. Short differences are
+    // ignored, to avoid a sleep call for each instruction.
+    pub fn throttle(&self) {
+        if let Some(real_time_start) = self.real_time_start {
+            let emulated_time = self.get_emulated_time();
+            let real_time = real_time_start.elapsed();
+            if emulated_time > real_time + Duration::from_millis(1) {
+                std::thread::sleep(emulated_time - real_time);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_divider_counts_completed_cycles() {
+        // arrange
+        let mut e_clock = ClockDivider::new(MASTER_TICKS_PER_E_CLOCK);
+        // act/assert
+        assert_eq!(0, e_clock.advance_to(39));
+        assert_eq!(1, e_clock.advance_to(40));
+        assert_eq!(0, e_clock.advance_to(79));
+        assert_eq!(3, e_clock.advance_to(199));
+        assert_eq!(1, e_clock.advance_to(200));
+    }
+
+    #[test]
+    fn master_clock_counts_cpu_cycles() {
+        // arrange
+        let mut master_clock = MasterClock::new(&CpuSpeed::PAL_7_093790_MHz);
+        // act
+        master_clock.add_cpu_cycles(7_093_790);
+        // assert
+        assert_eq!(28_375_160, master_clock.get_ticks());
+        assert_eq!(Duration::from_secs(1), master_clock.get_emulated_time());
+    }
+
+    #[test]
+    fn master_clock_advances_by_instruction_cost() {
+        // arrange
+        let code = [0x4e, 0x71, 0x70, 0x01, 0x4e, 0x75].to_vec(); // NOP, MOVEQ #1,D0, RTS
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.set_long_no_log(0x010003FC, 0x00C00000);
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x010003FC);
+        // act
+        mm.step();
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(
+            (4 + 4 + 16) * MASTER_TICKS_PER_CPU_CYCLE,
+            mm.get_master_clock_ticks()
+        );
+    }
+}
@@ -5,11 +5,18 @@ use core::panic;
 use std::convert::TryInto;
 use crate::aint::AInt;
 use self::ea::EffectiveAddressDebug;
-use self::step_log::StepLog;
+use self::fpu::{Fpu, FpuModel};
+use self::opcode_table::{OpcodeTable, OpcodeTableEntry};
+use self::step_log::{StepLog, StepLogEntry};
+use self::timing::*;
 
 pub mod ea;
+pub mod float80;
+pub mod fpu;
 pub mod instruction;
+pub mod opcode_table;
 pub mod step_log;
+pub mod timing;
 
 #[derive(Debug, PartialEq)]
 pub struct ResultWithStatusRegister<T> {
@@ -47,6 +54,7 @@ impl RotateDirection {
 }
 
 #[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
 pub enum CpuSpeed {
     PAL_7_093790_MHz,
     NTSC_7_159090_MHz,
@@ -61,11 +69,110 @@ impl CpuSpeed {
     }
 }
 
+// The CPU that is emulated, for the instructions and addressing modes that differ. Ordered so
+// that a later model includes everything of the earlier ones.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CpuModel {
+    M68000,
+    M68010,
+    M68020,
+    M68030,
+}
+
+pub const EXCEPTION_VECTOR_BUS_ERROR: u32 = 2;
+pub const EXCEPTION_VECTOR_ADDRESS_ERROR: u32 = 3;
+pub const EXCEPTION_VECTOR_ILLEGAL_INSTRUCTION: u32 = 4;
+pub const EXCEPTION_VECTOR_ZERO_DIVIDE: u32 = 5;
+pub const EXCEPTION_VECTOR_CHK_INSTRUCTION: u32 = 6;
+pub const EXCEPTION_VECTOR_TRAPV_INSTRUCTION: u32 = 7;
+pub const EXCEPTION_VECTOR_PRIVILEGE_VIOLATION: u32 = 8;
+pub const EXCEPTION_VECTOR_TRACE: u32 = 9;
+pub const EXCEPTION_VECTOR_LINE_1010_EMULATOR: u32 = 10;
+pub const EXCEPTION_VECTOR_LINE_1111_EMULATOR: u32 = 11;
+pub const EXCEPTION_VECTOR_FORMAT_ERROR: u32 = 14;
+pub const EXCEPTION_VECTOR_SPURIOUS_INTERRUPT: u32 = 24;
+pub const EXCEPTION_VECTOR_TRAP_0: u32 = 32;
+pub const EXCEPTION_VECTOR_FPU_BSUN: u32 = 48;
+pub const EXCEPTION_VECTOR_FPU_INEX: u32 = 49;
+pub const EXCEPTION_VECTOR_FPU_DZ: u32 = 50;
+pub const EXCEPTION_VECTOR_FPU_UNFL: u32 = 51;
+pub const EXCEPTION_VECTOR_FPU_OPERR: u32 = 52;
+pub const EXCEPTION_VECTOR_FPU_OVFL: u32 = 53;
+pub const EXCEPTION_VECTOR_FPU_SNAN: u32 = 54;
+pub const EXCEPTION_VECTOR_MMU_CONFIGURATION_ERROR: u32 = 56;
+
+// Details about the bus cycle that caused a bus error or an address error, stacked in the
+// group 0 exception frame
+#[derive(Copy, Clone, Debug, std::cmp::PartialEq)]
+pub struct BusCycleFault {
+    pub address: u32,
+    pub read: bool,
+    pub program_space: bool,
+}
+
+impl BusCycleFault {
+    // Special status word: R/W (bit 4), I/N (bit 3) and the function code (bits 2-0)
+    pub fn get_status_word(&self, supervisor: bool) -> u16 {
+        let read_write = match self.read {
+            true => 0x0010,
+            false => 0x0000,
+        };
+        read_write | self.get_function_code(supervisor)
+    }
+
+    // Special status word of the 68010 format $8 frame: IF (bit 13) or DF (bit 12) for the
+    // instruction or data fetch that failed, R/W (bit 8) and the function code (bits 2-0)
+    pub fn get_status_word_68010(&self, supervisor: bool) -> u16 {
+        let fetch = match (self.read, self.program_space) {
+            (true, true) => 0x2000,
+            (true, false) => 0x1000,
+            (false, _) => 0x0000,
+        };
+        let read_write = match self.read {
+            true => 0x0100,
+            false => 0x0000,
+        };
+        fetch | read_write | self.get_function_code(supervisor)
+    }
+
+    // Special status word of the 68020 format $A frame: FB/RB (bits 14 and 12) for a faulted
+    // instruction prefetch, DF (bit 8) for a faulted data cycle, R/W (bit 6) and the function
+    // code (bits 2-0)
+    pub fn get_status_word_68020(&self, supervisor: bool) -> u16 {
+        let fault = match self.program_space {
+            true => 0x5000,
+            false => 0x0100,
+        };
+        let read_write = match self.read {
+            true => 0x0040,
+            false => 0x0000,
+        };
+        fault | read_write | self.get_function_code(supervisor)
+    }
+
+    fn get_function_code(&self, supervisor: bool) -> u16 {
+        match (supervisor, self.program_space) {
+            (false, false) => 0x0001, // User data
+            (false, true) => 0x0002,  // User program
+            (true, false) => 0x0005,  // Supervisor data
+            (true, true) => 0x0006,   // Supervisor program
+        }
+    }
+}
+
 pub struct Cpu {
     pub cpu_speed: CpuSpeed,
     pub register: Register,
     pub stopped: bool,
+    pub halted: bool,
+    instruction_register: u16,
+    // Address of the instruction being executed, stacked in the 68020 format $2 frame
+    instruction_address: u32,
+    // Level on the IPL0-IPL2 pins, driven by Paula
+    pub interrupt_priority_level: u8,
+    sampled_interrupt_priority_level: u8,
     instructions: Vec<Instruction>,
+    opcode_table: OpcodeTable,
 }
 
 pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
@@ -282,8 +389,16 @@ pub fn match_check_ea_1011111__111_11_ea(
 }
 
 impl Cpu {
-    pub fn new(cpu_speed: CpuSpeed, ssp_address: u32,  pc_address: u32) -> Cpu {
-        let instructions = vec![
+    pub fn new(cpu_model: CpuModel, cpu_speed: CpuSpeed, ssp_address: u32, pc_address: u32) -> Cpu {
+        let instructions: Vec<Instruction> = vec![
+            Instruction::new(
+                String::from("ABCD"),
+                0xf1f0,
+                0xc100,
+                crate::cpu::match_check,
+                instruction::abcd::step,
+                instruction::abcd::get_disassembly,
+            ),
             Instruction::new(
                 String::from("ADD"),
                 0xf000,
@@ -332,6 +447,14 @@ impl Cpu {
                 instruction::andi::step,
                 instruction::andi::get_disassembly,
             ),
+            Instruction::new(
+                String::from("ANDI to CCR"),
+                0xffff,
+                0x023c,
+                crate::cpu::match_check,
+                instruction::andi_to_ccr::step,
+                instruction::andi_to_ccr::get_disassembly,
+            ),
             Instruction::new(
                 String::from("ANDI to SR"),
                 0xffff,
@@ -364,6 +487,22 @@ impl Cpu {
                 instruction::bcc::step,
                 instruction::bcc::get_disassembly,
             ),
+            Instruction::new(
+                String::from("BCHG"), // Bit Number Dynamic
+                0xf1c0,
+                0x0140,
+                instruction::bchg::match_check,
+                instruction::bchg::step_dynamic,
+                instruction::bchg::get_disassembly_dynamic,
+            ),
+            Instruction::new(
+                String::from("BCHG"), // Bit Number Static
+                0xffc0,
+                0x0840,
+                instruction::bchg::match_check,
+                instruction::bchg::step_static,
+                instruction::bchg::get_disassembly_static,
+            ),
             Instruction::new(
                 String::from("BCLR"), // Bit Number Dynamic
                 0xf1c0,
@@ -380,6 +519,87 @@ impl Cpu {
                 instruction::bclr::step_static,
                 instruction::bclr::get_disassembly_static,
             ),
+            Instruction::new(
+                String::from("BFCHG"),
+                0xffc0,
+                0xeac0,
+                instruction::bitfield::match_check_alterable,
+                instruction::bitfield::step,
+                instruction::bitfield::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("BFCLR"),
+                0xffc0,
+                0xecc0,
+                instruction::bitfield::match_check_alterable,
+                instruction::bitfield::step,
+                instruction::bitfield::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("BFEXTS"),
+                0xffc0,
+                0xebc0,
+                instruction::bitfield::match_check,
+                instruction::bitfield::step,
+                instruction::bitfield::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("BFEXTU"),
+                0xffc0,
+                0xe9c0,
+                instruction::bitfield::match_check,
+                instruction::bitfield::step,
+                instruction::bitfield::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("BFFFO"),
+                0xffc0,
+                0xedc0,
+                instruction::bitfield::match_check,
+                instruction::bitfield::step,
+                instruction::bitfield::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("BFINS"),
+                0xffc0,
+                0xefc0,
+                instruction::bitfield::match_check_alterable,
+                instruction::bitfield::step,
+                instruction::bitfield::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("BFSET"),
+                0xffc0,
+                0xeec0,
+                instruction::bitfield::match_check_alterable,
+                instruction::bitfield::step,
+                instruction::bitfield::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("BFTST"),
+                0xffc0,
+                0xe8c0,
+                instruction::bitfield::match_check,
+                instruction::bitfield::step,
+                instruction::bitfield::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("BKPT"),
+                0xfff8,
+                0x4848,
+                crate::cpu::match_check,
+                instruction::bkpt::step,
+                instruction::bkpt::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68010),
             Instruction::new(
                 String::from("BRA"),
                 0xff00,
@@ -428,6 +648,41 @@ impl Cpu {
                 instruction::btst::step_static,
                 instruction::btst::get_disassembly_static,
             ),
+            Instruction::new(
+                String::from("CAS"),
+                0xf9c0,
+                0x08c0,
+                instruction::cas::match_check,
+                instruction::cas::step,
+                instruction::cas::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("CAS2"),
+                0xfdff,
+                0x0cfc,
+                crate::cpu::match_check,
+                instruction::cas::step_cas2,
+                instruction::cas::get_disassembly_cas2,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("CHK"),
+                0xf1c0,
+                0x4180,
+                instruction::chk::match_check,
+                instruction::chk::step,
+                instruction::chk::get_disassembly,
+            ),
+            Instruction::new(
+                String::from("CHK2/CMP2"),
+                0xf9c0,
+                0x00c0,
+                instruction::chk2_cmp2::match_check,
+                instruction::chk2_cmp2::step,
+                instruction::chk2_cmp2::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
             Instruction::new(
                 String::from("CLR"),
                 0xff00,
@@ -446,7 +701,7 @@ impl Cpu {
             ),
             Instruction::new(
                 String::from("CMP"),
-                0xb000,
+                0xf000,
                 0xb000,
                 // TODO: match_check
                 instruction::cmp::match_check,
@@ -485,6 +740,15 @@ impl Cpu {
                 instruction::divu::step,
                 instruction::divu::get_disassembly,
             ),
+            Instruction::new(
+                String::from("DIVS.L/DIVU.L"),
+                0xffc0,
+                0x4c40,
+                instruction::divl::match_check,
+                instruction::divl::step,
+                instruction::divl::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
             Instruction::new(
                 String::from("EOR"),
                 0xf000,
@@ -493,6 +757,30 @@ impl Cpu {
                 instruction::eor::step,
                 instruction::eor::get_disassembly,
             ),
+            Instruction::new(
+                String::from("EORI"),
+                0xff00,
+                0x0a00,
+                instruction::eori::match_check,
+                instruction::eori::step,
+                instruction::eori::get_disassembly,
+            ),
+            Instruction::new(
+                String::from("EORI to CCR"),
+                0xffff,
+                0x0a3c,
+                crate::cpu::match_check,
+                instruction::eori_to_ccr::step,
+                instruction::eori_to_ccr::get_disassembly,
+            ),
+            Instruction::new(
+                String::from("EORI to SR"),
+                0xffff,
+                0x0a7c,
+                crate::cpu::match_check,
+                instruction::eori_to_sr::step,
+                instruction::eori_to_sr::get_disassembly,
+            ),
             Instruction::new(
                 String::from("EXG"),
                 0xf100,
@@ -509,6 +797,86 @@ impl Cpu {
                 instruction::ext::step,
                 instruction::ext::get_disassembly,
             ),
+            Instruction::new(
+                String::from("EXTB"),
+                0xfff8,
+                0x49c0,
+                crate::cpu::match_check,
+                instruction::extb::step,
+                instruction::extb::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("FBCC"),
+                0xff80,
+                0xf280,
+                instruction::fbcc::match_check,
+                instruction::fbcc::step,
+                instruction::fbcc::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("FDBCC"),
+                0xfff8,
+                0xf248,
+                crate::cpu::match_check,
+                instruction::fdbcc::step,
+                instruction::fdbcc::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("FGEN"),
+                0xffc0,
+                0xf200,
+                instruction::fgen::match_check,
+                instruction::fgen::step,
+                instruction::fgen::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("FRESTORE"),
+                0xffc0,
+                0xf340,
+                instruction::frestore::match_check,
+                instruction::frestore::step,
+                instruction::frestore::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("FSAVE"),
+                0xffc0,
+                0xf300,
+                instruction::fsave::match_check,
+                instruction::fsave::step,
+                instruction::fsave::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("FSCC"),
+                0xffc0,
+                0xf240,
+                instruction::fscc::match_check,
+                instruction::fscc::step,
+                instruction::fscc::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("FTRAPCC"),
+                0xfff8,
+                0xf278,
+                instruction::ftrapcc::match_check,
+                instruction::ftrapcc::step,
+                instruction::ftrapcc::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("ILLEGAL"),
+                0xffff,
+                0x4afc,
+                crate::cpu::match_check,
+                instruction::illegal::step,
+                instruction::illegal::get_disassembly,
+            ),
             Instruction::new(
                 String::from("JMP"),
                 0xffc0,
@@ -573,6 +941,15 @@ impl Cpu {
                 instruction::mov::step,
                 instruction::mov::get_disassembly,
             ),
+            Instruction::new(
+                String::from("MOVE from CCR"),
+                0xffc0,
+                0x42c0,
+                instruction::move_from_ccr::match_check,
+                instruction::move_from_ccr::step,
+                instruction::move_from_ccr::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68010),
             Instruction::new(
                 String::from("MOVE from SR"),
                 0xffc0,
@@ -588,7 +965,8 @@ impl Cpu {
                 crate::cpu::match_check,
                 instruction::movec::step,
                 instruction::movec::get_disassembly,
-            ),
+            )
+            .with_minimum_cpu_model(CpuModel::M68010),
             Instruction::new(
                 String::from("MOVEM"),
                 0xfb80,
@@ -597,6 +975,14 @@ impl Cpu {
                 instruction::movem::step,
                 instruction::movem::get_disassembly,
             ),
+            Instruction::new(
+                String::from("MOVEP"),
+                0xf138,
+                0x0108,
+                crate::cpu::match_check,
+                instruction::movep::step,
+                instruction::movep::get_disassembly,
+            ),
             Instruction::new(
                 String::from("MOVEQ"),
                 0xf100,
@@ -605,6 +991,23 @@ impl Cpu {
                 instruction::moveq::step,
                 instruction::moveq::get_disassembly,
             ),
+            Instruction::new(
+                String::from("MOVES"),
+                0xff00,
+                0x0e00,
+                instruction::moves::match_check,
+                instruction::moves::step,
+                instruction::moves::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68010),
+            Instruction::new(
+                String::from("MOVE to CCR"),
+                0xffc0,
+                0x44c0,
+                instruction::move_to_ccr::match_check,
+                instruction::move_to_ccr::step,
+                instruction::move_to_ccr::get_disassembly,
+            ),
             Instruction::new(
                 String::from("MOVE to SR"),
                 0xffc0,
@@ -621,6 +1024,14 @@ impl Cpu {
                 instruction::move_usp::step,
                 instruction::move_usp::get_disassembly,
             ),
+            Instruction::new(
+                String::from("MULS"),
+                0xf1c0,
+                0xc1c0,
+                instruction::muls::match_check,
+                instruction::muls::step,
+                instruction::muls::get_disassembly,
+            ),
             Instruction::new(
                 String::from("MULU"),
                 0xf1c0,
@@ -629,6 +1040,23 @@ impl Cpu {
                 instruction::mulu::step,
                 instruction::mulu::get_disassembly,
             ),
+            Instruction::new(
+                String::from("MULS.L/MULU.L"),
+                0xffc0,
+                0x4c00,
+                instruction::mull::match_check,
+                instruction::mull::step,
+                instruction::mull::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("NBCD"),
+                0xffc0,
+                0x4800,
+                instruction::nbcd::match_check,
+                instruction::nbcd::step,
+                instruction::nbcd::get_disassembly,
+            ),
             Instruction::new(
                 String::from("NEG"),
                 0xff00,
@@ -637,6 +1065,14 @@ impl Cpu {
                 instruction::neg::step,
                 instruction::neg::get_disassembly,
             ),
+            Instruction::new(
+                String::from("NEGX"),
+                0xff00,
+                0x4000,
+                instruction::negx::match_check,
+                instruction::negx::step,
+                instruction::negx::get_disassembly,
+            ),
             Instruction::new(
                 String::from("NOP"),
                 0xffff,
@@ -669,6 +1105,14 @@ impl Cpu {
                 instruction::ori::step,
                 instruction::ori::get_disassembly,
             ),
+            Instruction::new(
+                String::from("ORI to CCR"),
+                0xffff,
+                0x003c,
+                crate::cpu::match_check,
+                instruction::ori_to_ccr::step,
+                instruction::ori_to_ccr::get_disassembly,
+            ),
             Instruction::new(
                 String::from("ORI to SR"),
                 0xffff,
@@ -677,6 +1121,15 @@ impl Cpu {
                 instruction::ori_to_sr::step,
                 instruction::ori_to_sr::get_disassembly,
             ),
+            Instruction::new(
+                String::from("PACK"),
+                0xf1f0,
+                0x8140,
+                crate::cpu::match_check,
+                instruction::pack::step,
+                instruction::pack::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
             Instruction::new(
                 String::from("PEA"),
                 0xffc0,
@@ -685,6 +1138,15 @@ impl Cpu {
                 instruction::pea::step,
                 instruction::pea::get_disassembly,
             ),
+            Instruction::new(
+                String::from("PMMU"),
+                0xffc0,
+                0xf000,
+                instruction::pmmu::match_check,
+                instruction::pmmu::step,
+                instruction::pmmu::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68030),
             Instruction::new(
                 String::from("RESET"),
                 0xffff,
@@ -697,8 +1159,7 @@ impl Cpu {
                 String::from("ROLR"), // register
                 0xf018,
                 0xe018,
-                // TODO: match_check
-                crate::cpu::match_check,
+                instruction::rolrreg::match_check,
                 instruction::rolrreg::step,
                 instruction::rolrreg::get_disassembly,
             ),
@@ -706,11 +1167,26 @@ impl Cpu {
                 String::from("ROLR"), // memory
                 0xfec0,
                 0xe6c0,
-                // TODO: match_check
-                crate::cpu::match_check,
+                instruction::rolrmem::match_check,
                 instruction::rolrmem::step,
                 instruction::rolrmem::get_disassembly,
             ),
+            Instruction::new(
+                String::from("ROXLR"), // register
+                0xf018,
+                0xe010,
+                instruction::roxlrreg::match_check,
+                instruction::roxlrreg::step,
+                instruction::roxlrreg::get_disassembly,
+            ),
+            Instruction::new(
+                String::from("ROXLR"), // memory
+                0xfec0,
+                0xe4c0,
+                instruction::roxlrmem::match_check,
+                instruction::roxlrmem::step,
+                instruction::roxlrmem::get_disassembly,
+            ),
             Instruction::new(
                 String::from("RTS"),
                 0xffff,
@@ -719,6 +1195,15 @@ impl Cpu {
                 instruction::rts::step,
                 instruction::rts::get_disassembly,
             ),
+            Instruction::new(
+                String::from("RTD"),
+                0xffff,
+                0x4e74,
+                crate::cpu::match_check,
+                instruction::rtd::step,
+                instruction::rtd::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68010),
             Instruction::new(
                 String::from("RTE"),
                 0xffff,
@@ -727,6 +1212,22 @@ impl Cpu {
                 instruction::rte::step,
                 instruction::rte::get_disassembly,
             ),
+            Instruction::new(
+                String::from("RTR"),
+                0xffff,
+                0x4e77,
+                crate::cpu::match_check,
+                instruction::rtr::step,
+                instruction::rtr::get_disassembly,
+            ),
+            Instruction::new(
+                String::from("SBCD"),
+                0xf1f0,
+                0x8100,
+                crate::cpu::match_check,
+                instruction::sbcd::step,
+                instruction::sbcd::get_disassembly,
+            ),
             Instruction::new(
                 String::from("Scc"),
                 0xf0c0,
@@ -783,6 +1284,39 @@ impl Cpu {
                 instruction::swap::step,
                 instruction::swap::get_disassembly,
             ),
+            Instruction::new(
+                String::from("TAS"),
+                0xffc0,
+                0x4ac0,
+                instruction::tas::match_check,
+                instruction::tas::step,
+                instruction::tas::get_disassembly,
+            ),
+            Instruction::new(
+                String::from("TRAP"),
+                0xfff0,
+                0x4e40,
+                crate::cpu::match_check,
+                instruction::trap::step,
+                instruction::trap::get_disassembly,
+            ),
+            Instruction::new(
+                String::from("TRAPcc"),
+                0xf0f8,
+                0x50f8,
+                instruction::trapcc::match_check,
+                instruction::trapcc::step,
+                instruction::trapcc::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+            Instruction::new(
+                String::from("TRAPV"),
+                0xffff,
+                0x4e76,
+                crate::cpu::match_check,
+                instruction::trapv::step,
+                instruction::trapv::get_disassembly,
+            ),
             Instruction::new(
                 String::from("TST"),
                 0xff00,
@@ -799,20 +1333,56 @@ impl Cpu {
                 instruction::unlk::step,
                 instruction::unlk::get_disassembly,
             ),
-        ];
+            Instruction::new(
+                String::from("UNPK"),
+                0xf1f0,
+                0x8180,
+                crate::cpu::match_check,
+                instruction::unpk::step,
+                instruction::unpk::get_disassembly,
+            )
+            .with_minimum_cpu_model(CpuModel::M68020),
+        ]
+        .into_iter()
+        .filter(|instruction| cpu_model >= instruction.minimum_cpu_model)
+        .collect();
+        let opcode_table = match OpcodeTable::new(&instructions) {
+            Ok(opcode_table) => opcode_table,
+            Err(ambiguous_opcodes) => panic!(
+                "Ambiguous instruction decoding, {} opcodes match more than one instruction. First: {}",
+                ambiguous_opcodes.len(),
+                ambiguous_opcodes[0]
+            ),
+        };
         let reg_pc = ProgramCounter::from_address(pc_address);
         let mut register = Register::new();
+        register.cpu_model = cpu_model;
         register.set_ssp_reg(ssp_address);
         register.reg_pc = reg_pc;
         let cpu = Cpu {
             cpu_speed,
             register,
             stopped: false,
+            halted: false,
+            instruction_register: 0x0000,
+            instruction_address: 0x00000000,
+            interrupt_priority_level: 0,
+            sampled_interrupt_priority_level: 0,
             instructions,
+            opcode_table,
         };
         cpu
     }
 
+    // Attaches a 68881/68882 as coprocessor 1. The coprocessor interface only exists on the
+    // 68020, the earlier models have to emulate the F-line instructions in software.
+    pub fn with_fpu(mut self, fpu_model: FpuModel) -> Cpu {
+        if self.register.cpu_model >= CpuModel::M68020 {
+            self.register.fpu = Some(Fpu::new(fpu_model));
+        }
+        self
+    }
+
     pub fn sign_extend_byte(value: u8) -> u16 {
         // TODO: Any better way to do this?
         let address_bytes = value.to_be_bytes();
@@ -1121,6 +1691,65 @@ impl Cpu {
         }
     }
 
+    // The N and V flags are undefined according to the documentation, but the 68000 sets them
+    // deterministically from the decimal correction. See the "68000 undocumented behavior"
+    // notes by Flamewing for how they're derived.
+    pub fn add_bcd_bytes_with_extend(
+        source: u8,
+        dest: u8,
+        extend: bool,
+    ) -> ResultWithStatusRegister<u8> {
+        let source = source as u16;
+        let dest = dest as u16;
+        let sum = source + dest + extend as u16;
+        // Binary carries out of bit 3 and bit 7
+        let binary_carry = ((source & dest) | (!sum & dest) | (source & !sum)) & 0x88;
+        // Decimal carries out of the low and high digit
+        let decimal_carry = ((sum.wrapping_add(0x66) ^ sum) & 0x110) >> 1;
+        let carries = binary_carry | decimal_carry;
+        let correction = carries - (carries >> 2);
+        let result = sum.wrapping_add(correction);
+
+        let carry = (binary_carry | (sum & !result)) & 0x80 != 0;
+        let overflow = (!sum & result) & 0x80 != 0;
+
+        Cpu::get_bcd_result_with_status_register(result as u8, carry, overflow)
+    }
+
+    fn get_bcd_result_with_status_register(
+        result: u8,
+        carry: bool,
+        overflow: bool,
+    ) -> ResultWithStatusRegister<u8> {
+        let mut status_register_mask = STATUS_REGISTER_MASK_CARRY
+            | STATUS_REGISTER_MASK_EXTEND
+            | STATUS_REGISTER_MASK_OVERFLOW
+            | STATUS_REGISTER_MASK_ZERO
+            | STATUS_REGISTER_MASK_NEGATIVE;
+        let mut status_register = 0x0000;
+        if carry {
+            status_register |= STATUS_REGISTER_MASK_CARRY | STATUS_REGISTER_MASK_EXTEND;
+        }
+        if overflow {
+            status_register |= STATUS_REGISTER_MASK_OVERFLOW;
+        }
+        if result & 0x80 != 0 {
+            status_register |= STATUS_REGISTER_MASK_NEGATIVE;
+        }
+        // Zero is cleared if the result is nonzero, unchanged otherwise
+        if result == 0 {
+            status_register_mask &= !STATUS_REGISTER_MASK_ZERO;
+        }
+
+        ResultWithStatusRegister {
+            result,
+            status_register_result: StatusRegisterResult {
+                status_register,
+                status_register_mask,
+            },
+        }
+    }
+
     pub fn add_words(source: u16, dest: u16) -> ResultWithStatusRegister<u16> {
         let source_signed = Cpu::get_signed_word_from_word(source);
         let dest_signed = Cpu::get_signed_word_from_word(dest);
@@ -1584,6 +2213,28 @@ impl Cpu {
         }
     }
 
+    // dest - source - extend, with the same deterministic N and V flags as
+    // add_bcd_bytes_with_extend
+    pub fn sub_bcd_bytes_with_extend(
+        source: u8,
+        dest: u8,
+        extend: bool,
+    ) -> ResultWithStatusRegister<u8> {
+        let source = source as u16;
+        let dest = dest as u16;
+        let difference = dest.wrapping_sub(source).wrapping_sub(extend as u16);
+        // Binary borrows out of bit 3 and bit 7
+        let binary_borrow =
+            ((!dest & source) | (difference & !dest) | (difference & source)) & 0x88;
+        let correction = binary_borrow - (binary_borrow >> 2);
+        let result = difference.wrapping_sub(correction);
+
+        let carry = (binary_borrow | (!difference & result)) & 0x80 != 0;
+        let overflow = (difference & !result) & 0x80 != 0;
+
+        Cpu::get_bcd_result_with_status_register(result as u8, carry, overflow)
+    }
+
     pub fn sub_words(source: u16, dest: u16) -> ResultWithStatusRegister<u16> {
         let source_signed = Cpu::get_signed_word_from_word(source);
         let dest_signed = Cpu::get_signed_word_from_word(dest);
@@ -2035,42 +2686,173 @@ impl Cpu {
         (result, status_register_result)
     }
 
-    pub fn divs_long_by_word(source: u16, dest: u32) -> ResultWithStatusRegister<u32> {
-        let source = Cpu::sign_extend_word(source) as i32;
-        let dest_signed = dest as i32;
-        let quotient_i32 = (dest_signed / source) as i32;
-        let quotient_u16 = quotient_i32 as u16;
-        let remainder = (dest_signed % source) as u16;
+    pub fn rotate<T: AInt>(
+        value: T,
+        direction: RotateDirection,
+        rotate_count: u32,
+    ) -> (T, StatusRegisterResult) {
+        if rotate_count > 63 {
+            std::panic!("rotate: rotate count is larger than 63");
+        }
 
+        // X -- Not affected
+        // N -- Set if the most significant bit of the result is set; cleared otherwise
+        // Z -- Set if the result is zero; cleared otherwise
+        // V -- Always cleared
+        // C -- Set according to the last bit rotated out of the operand; cleared when the rotate count is zero
         let mut status_register = 0x0000;
-
-        let result;
-        if quotient_i32 < 0x00010000 {
-            match quotient_u16 {
-                0 => status_register |= STATUS_REGISTER_MASK_ZERO,
-                0x8000..=0xffff => status_register |= STATUS_REGISTER_MASK_NEGATIVE,
-                _ => (),
+        let status_register_mask = STATUS_REGISTER_MASK_NEGATIVE
+            | STATUS_REGISTER_MASK_ZERO
+            | STATUS_REGISTER_MASK_OVERFLOW
+            | STATUS_REGISTER_MASK_CARRY;
+        let mut result = value;
+        for _ in 0..rotate_count {
+            result = match direction {
+                RotateDirection::Left => {
+                    let bit_out = result.is_msb_set();
+                    let new_result = result.checked_shift_left(1).unwrap_or(T::zero());
+                    match bit_out {
+                        true => new_result.bit_or(T::lsb()),
+                        false => new_result,
+                    }
+                }
+                RotateDirection::Right => {
+                    let bit_out = result.is_lsb_set();
+                    let new_result = result.checked_shift_right(1).unwrap_or(T::zero());
+                    match bit_out {
+                        true => new_result.bit_or(T::msb()),
+                        false => new_result,
+                    }
+                }
+            };
+        }
+        if rotate_count > 0 {
+            // The last bit rotated out is the bit rotated back in at the other end
+            let carry = match direction {
+                RotateDirection::Left => result.is_lsb_set(),
+                RotateDirection::Right => result.is_msb_set(),
+            };
+            if carry {
+                status_register |= STATUS_REGISTER_MASK_CARRY;
             }
-            result = Self::join_words_to_long(remainder, quotient_u16);
-        } else {
-            status_register |= STATUS_REGISTER_MASK_OVERFLOW;
-            result = dest;
         }
-
-        ResultWithStatusRegister {
-            result,
-            status_register_result: StatusRegisterResult {
-                status_register,
-                status_register_mask: STATUS_REGISTER_MASK_CARRY
-                    | STATUS_REGISTER_MASK_OVERFLOW
-                    | STATUS_REGISTER_MASK_ZERO
-                    | STATUS_REGISTER_MASK_NEGATIVE,
-            },
+        if result.is_zero() {
+            status_register |= STATUS_REGISTER_MASK_ZERO;
+        } else if result.is_msb_set() {
+            status_register |= STATUS_REGISTER_MASK_NEGATIVE;
         }
+
+        let status_register_result = StatusRegisterResult {
+            status_register,
+            status_register_mask,
+        };
+        (result, status_register_result)
     }
 
-    pub fn divu_long_by_word(source: u16, dest: u32) -> ResultWithStatusRegister<u32> {
-        let source = source as u32;
+    pub fn rotate_with_extend<T: AInt>(
+        value: T,
+        direction: RotateDirection,
+        rotate_count: u32,
+        extend: bool,
+    ) -> (T, StatusRegisterResult) {
+        if rotate_count > 63 {
+            std::panic!("rotate_with_extend: rotate count is larger than 63");
+        }
+
+        // X -- Set to the value of the last bit rotated out of the operand; unaffected when the rotate count is zero
+        // N -- Set if the most significant bit of the result is set; cleared otherwise
+        // Z -- Set if the result is zero; cleared otherwise
+        // V -- Always cleared
+        // C -- Set according to the last bit rotated out of the operand; when the rotate count is zero, set to the value of the extend bit
+        let mut status_register = 0x0000;
+        let mut status_register_mask = STATUS_REGISTER_MASK_NEGATIVE
+            | STATUS_REGISTER_MASK_ZERO
+            | STATUS_REGISTER_MASK_OVERFLOW
+            | STATUS_REGISTER_MASK_CARRY;
+        let mut result = value;
+        let mut extend = extend;
+        for _ in 0..rotate_count {
+            result = match direction {
+                RotateDirection::Left => {
+                    let bit_out = result.is_msb_set();
+                    let new_result = result.checked_shift_left(1).unwrap_or(T::zero());
+                    let new_result = match extend {
+                        true => new_result.bit_or(T::lsb()),
+                        false => new_result,
+                    };
+                    extend = bit_out;
+                    new_result
+                }
+                RotateDirection::Right => {
+                    let bit_out = result.is_lsb_set();
+                    let new_result = result.checked_shift_right(1).unwrap_or(T::zero());
+                    let new_result = match extend {
+                        true => new_result.bit_or(T::msb()),
+                        false => new_result,
+                    };
+                    extend = bit_out;
+                    new_result
+                }
+            };
+        }
+        if rotate_count > 0 {
+            status_register_mask |= STATUS_REGISTER_MASK_EXTEND;
+            if extend {
+                status_register |= STATUS_REGISTER_MASK_EXTEND;
+            }
+        }
+        if extend {
+            status_register |= STATUS_REGISTER_MASK_CARRY;
+        }
+        if result.is_zero() {
+            status_register |= STATUS_REGISTER_MASK_ZERO;
+        } else if result.is_msb_set() {
+            status_register |= STATUS_REGISTER_MASK_NEGATIVE;
+        }
+
+        let status_register_result = StatusRegisterResult {
+            status_register,
+            status_register_mask,
+        };
+        (result, status_register_result)
+    }
+
+    pub fn divs_long_by_word(source: u16, dest: u32) -> ResultWithStatusRegister<u32> {
+        let source = Cpu::sign_extend_word(source) as i32;
+        let dest_signed = dest as i32;
+        let quotient_i32 = (dest_signed / source) as i32;
+        let quotient_u16 = quotient_i32 as u16;
+        let remainder = (dest_signed % source) as u16;
+
+        let mut status_register = 0x0000;
+
+        let result;
+        if quotient_i32 < 0x00010000 {
+            match quotient_u16 {
+                0 => status_register |= STATUS_REGISTER_MASK_ZERO,
+                0x8000..=0xffff => status_register |= STATUS_REGISTER_MASK_NEGATIVE,
+                _ => (),
+            }
+            result = Self::join_words_to_long(remainder, quotient_u16);
+        } else {
+            status_register |= STATUS_REGISTER_MASK_OVERFLOW;
+            result = dest;
+        }
+
+        ResultWithStatusRegister {
+            result,
+            status_register_result: StatusRegisterResult {
+                status_register,
+                status_register_mask: STATUS_REGISTER_MASK_CARRY
+                    | STATUS_REGISTER_MASK_OVERFLOW
+                    | STATUS_REGISTER_MASK_ZERO
+                    | STATUS_REGISTER_MASK_NEGATIVE,
+            },
+        }
+    }
+
+    pub fn divu_long_by_word(source: u16, dest: u32) -> ResultWithStatusRegister<u32> {
+        let source = source as u32;
         let quotient_u32 = dest / source;
         let quotient_u16 = quotient_u32 as u16;
         let remainder = (dest % source) as u16;
@@ -2102,6 +2884,32 @@ impl Cpu {
         }
     }
 
+    pub fn muls_words(source: u16, dest: u16) -> ResultWithStatusRegister<u32> {
+        let source = Cpu::sign_extend_word(source) as i32;
+        let dest = Cpu::sign_extend_word(dest) as i32;
+
+        let result = (source * dest) as u32;
+
+        let mut status_register = 0x0000;
+
+        match result {
+            0 => status_register |= STATUS_REGISTER_MASK_ZERO,
+            0x80000000..=0xffffffff => status_register |= STATUS_REGISTER_MASK_NEGATIVE,
+            _ => (),
+        }
+
+        ResultWithStatusRegister {
+            result,
+            status_register_result: StatusRegisterResult {
+                status_register,
+                status_register_mask: STATUS_REGISTER_MASK_CARRY
+                    | STATUS_REGISTER_MASK_OVERFLOW
+                    | STATUS_REGISTER_MASK_ZERO
+                    | STATUS_REGISTER_MASK_NEGATIVE,
+            },
+        }
+    }
+
     pub fn mulu_words(source: u16, dest: u16) -> ResultWithStatusRegister<u32> {
         let source = source as u32;
         let dest = dest as u32;
@@ -2274,6 +3082,98 @@ impl Cpu {
 
                 EffectiveAddressDebug { format }
             }
+            EffectiveAddressingMode::ARegIndirectWithIndexBaseDisplacement {
+                ea_register,
+                base_displacement,
+                index,
+                ..
+            } => {
+                // (bd,An,Xn.SIZE*SCALE)
+                let base = ea_register.map(|ea_register| format!("A{}", ea_register));
+                let format =
+                    Cpu::get_full_extension_word_format(base, base_displacement, index, None);
+                EffectiveAddressDebug { format }
+            }
+            EffectiveAddressingMode::MemoryIndirectPostIndexed {
+                ea_register,
+                base_displacement,
+                index,
+                outer_displacement,
+                ..
+            } => {
+                // ([bd,An],Xn.SIZE*SCALE,od)
+                let base = ea_register.map(|ea_register| format!("A{}", ea_register));
+                let format = Cpu::get_full_extension_word_format(
+                    base,
+                    base_displacement,
+                    index,
+                    Some((false, outer_displacement)),
+                );
+                EffectiveAddressDebug { format }
+            }
+            EffectiveAddressingMode::MemoryIndirectPreIndexed {
+                ea_register,
+                base_displacement,
+                index,
+                outer_displacement,
+                ..
+            } => {
+                // ([bd,An,Xn.SIZE*SCALE],od)
+                let base = ea_register.map(|ea_register| format!("A{}", ea_register));
+                let format = Cpu::get_full_extension_word_format(
+                    base,
+                    base_displacement,
+                    index,
+                    Some((true, outer_displacement)),
+                );
+                EffectiveAddressDebug { format }
+            }
+            EffectiveAddressingMode::PcIndirectWithIndexBaseDisplacement {
+                pc_suppressed,
+                base_displacement,
+                index,
+                ..
+            } => {
+                // (bd,PC,Xn.SIZE*SCALE)
+                let base = Some(Cpu::get_pc_format(pc_suppressed));
+                let format =
+                    Cpu::get_full_extension_word_format(base, base_displacement, index, None);
+                EffectiveAddressDebug { format }
+            }
+            EffectiveAddressingMode::PcMemoryIndirectPostIndexed {
+                pc_suppressed,
+                base_displacement,
+                index,
+                outer_displacement,
+                ..
+            } => {
+                // ([bd,PC],Xn.SIZE*SCALE,od)
+                let base = Some(Cpu::get_pc_format(pc_suppressed));
+                let format = Cpu::get_full_extension_word_format(
+                    base,
+                    base_displacement,
+                    index,
+                    Some((false, outer_displacement)),
+                );
+                EffectiveAddressDebug { format }
+            }
+            EffectiveAddressingMode::PcMemoryIndirectPreIndexed {
+                pc_suppressed,
+                base_displacement,
+                index,
+                outer_displacement,
+                ..
+            } => {
+                // ([bd,PC,Xn.SIZE*SCALE],od)
+                let base = Some(Cpu::get_pc_format(pc_suppressed));
+                let format = Cpu::get_full_extension_word_format(
+                    base,
+                    base_displacement,
+                    index,
+                    Some((true, outer_displacement)),
+                );
+                EffectiveAddressDebug { format }
+            }
             EffectiveAddressingMode::ImmediateDataByte { data } => {
                 // #<xxx>
                 EffectiveAddressDebug {
@@ -2289,78 +3189,555 @@ impl Cpu {
         }
     }
 
+    fn get_pc_format(pc_suppressed: bool) -> String {
+        match pc_suppressed {
+            true => String::from("ZPC"),
+            false => String::from("PC"),
+        }
+    }
+
+    // Suppressed parts and null displacements are left out. memory_indirect is whether the
+    // index is applied before the indirection, and the outer displacement.
+    fn get_full_extension_word_format(
+        base: Option<String>,
+        base_displacement: Displacement,
+        index: Option<IndexRegister>,
+        memory_indirect: Option<(bool, Displacement)>,
+    ) -> String {
+        let mut inner = Vec::new();
+        if base_displacement != Displacement::Null {
+            inner.push(base_displacement.to_string());
+        }
+        if let Some(base) = base {
+            inner.push(base);
+        }
+        match memory_indirect {
+            None => {
+                if let Some(index) = index {
+                    inner.push(index.to_string());
+                }
+                format!("({})", inner.join(","))
+            }
+            Some((pre_indexed, outer_displacement)) => {
+                let mut outer = Vec::new();
+                if let Some(index) = index {
+                    match pre_indexed {
+                        true => inner.push(index.to_string()),
+                        false => outer.push(index.to_string()),
+                    }
+                }
+                if outer_displacement != Displacement::Null {
+                    outer.push(outer_displacement.to_string());
+                }
+                let outer: String = outer.iter().map(|x| format!(",{}", x)).collect();
+                format!("([{}]{})", inner.join(","), outer)
+            }
+        }
+    }
+
+    fn begin_exception(&mut self, mem: &Mem) -> u16 {
+        let sr = self.register.reg_sr.get_value();
+        self.register.reg_sr.set_supervisor();
+        self.register.reg_sr.clear_trace();
+        mem.set_supervisor(true);
+        sr
+    }
+
+    // The 68010+ stacks a format/vector word below the PC, with the frame format in the upper
+    // 4 bits and the vector offset in the lower 12 bits
+    fn get_format_vector_word(format: u16, vector: u32) -> u16 {
+        (format << 12) | ((vector * 4) as u16 & 0x0fff)
+    }
+
+    fn push_short_exception_frame(
+        &mut self,
+        mem: &mut Mem,
+        step_log: &mut StepLog,
+        vector: u32,
+        stacked_pc: u32,
+        stacked_sr: u16,
+    ) -> Result<(), StepError> {
+        match self.register.cpu_model {
+            CpuModel::M68000 => (),
+            CpuModel::M68010 => {
+                let format_vector_word = Cpu::get_format_vector_word(0x0, vector);
+                self.register
+                    .stack_push_word(mem, step_log, format_vector_word)?;
+            }
+            CpuModel::M68020 | CpuModel::M68030 => match vector {
+                EXCEPTION_VECTOR_ZERO_DIVIDE
+                | EXCEPTION_VECTOR_CHK_INSTRUCTION
+                | EXCEPTION_VECTOR_TRAPV_INSTRUCTION
+                | EXCEPTION_VECTOR_TRACE
+                | EXCEPTION_VECTOR_MMU_CONFIGURATION_ERROR => {
+                    // Format $2 also has the address of the instruction that caused it
+                    let format_vector_word = Cpu::get_format_vector_word(0x2, vector);
+                    self.register
+                        .stack_push_long(mem, step_log, self.instruction_address)?;
+                    self.register
+                        .stack_push_word(mem, step_log, format_vector_word)?;
+                }
+                _ => {
+                    let format_vector_word = Cpu::get_format_vector_word(0x0, vector);
+                    self.register
+                        .stack_push_word(mem, step_log, format_vector_word)?;
+                }
+            },
+        }
+        self.register.stack_push_long(mem, step_log, stacked_pc)?;
+        self.register.stack_push_word(mem, step_log, stacked_sr)
+    }
+
+    // Words of the group 0 exception stack frame, from the lowest address up. The 68000 has the
+    // access address, instruction register and status word below the short frame. The 68010
+    // stacks format $8 and the 68020 format $A, both with the internal state that RTE uses to
+    // continue the faulted bus cycle. The internal state words are left zero here, RTE just
+    // discards them.
+    fn get_group_0_exception_frame(
+        &self,
+        vector: u32,
+        stacked_pc: u32,
+        stacked_sr: u16,
+        instruction_register: u16,
+        fault: BusCycleFault,
+    ) -> Vec<u16> {
+        let supervisor = stacked_sr & STATUS_REGISTER_MASK_SUPERVISOR_STATE != 0;
+        match self.register.cpu_model {
+            CpuModel::M68000 => vec![
+                fault.get_status_word(supervisor),
+                (fault.address >> 16) as u16,
+                fault.address as u16,
+                instruction_register,
+                stacked_sr,
+                (stacked_pc >> 16) as u16,
+                stacked_pc as u16,
+            ],
+            CpuModel::M68010 => {
+                let mut frame = vec![
+                    stacked_sr,
+                    (stacked_pc >> 16) as u16,
+                    stacked_pc as u16,
+                    Cpu::get_format_vector_word(0x8, vector),
+                    fault.get_status_word_68010(supervisor),
+                    (fault.address >> 16) as u16,
+                    fault.address as u16,
+                    // Unused and the data output/input buffers
+                    0x0000,
+                    0x0000,
+                    0x0000,
+                    0x0000,
+                    0x0000,
+                    // Instruction output buffer
+                    instruction_register,
+                ];
+                // Version number and internal information
+                frame.extend_from_slice(&[0x0000; 16]);
+                frame
+            }
+            CpuModel::M68020 | CpuModel::M68030 => vec![
+                stacked_sr,
+                (stacked_pc >> 16) as u16,
+                stacked_pc as u16,
+                Cpu::get_format_vector_word(0xa, vector),
+                // Internal register
+                0x0000,
+                fault.get_status_word_68020(supervisor),
+                // Instruction pipe stage C and B
+                instruction_register,
+                0x0000,
+                (fault.address >> 16) as u16,
+                fault.address as u16,
+                // Internal registers and the data output buffer
+                0x0000,
+                0x0000,
+                0x0000,
+                0x0000,
+                0x0000,
+                0x0000,
+            ],
+        }
+    }
+
+    // Pushes a whole stack frame, given from the lowest address up. Written a long at a time to
+    // keep the number of step log entries down.
+    fn push_exception_frame(
+        &mut self,
+        mem: &mut Mem,
+        step_log: &mut StepLog,
+        frame: &[u16],
+    ) -> Result<(), StepError> {
+        let sp = self
+            .register
+            .get_a_reg_long(7, step_log)
+            .wrapping_sub(frame.len() as u32 * 2);
+        self.register.set_a_reg_long(step_log, 7, sp);
+        let (first, longs) = frame.split_at(frame.len() % 2);
+        let mut address = sp;
+        for word in first {
+            mem.set_word(step_log, address, *word)?;
+            address = address.wrapping_add(2);
+        }
+        for words in longs.chunks(2) {
+            let value = ((words[0] as u32) << 16) | words[1] as u32;
+            mem.set_long(step_log, address, value)?;
+            address = address.wrapping_add(4);
+        }
+        Ok(())
+    }
+
+    // Size in bytes of a 68010+ exception stack frame, including the SR, PC and format/vector
+    // word, or None for a format that RTE doesn't accept on the CPU model
+    pub fn get_exception_frame_size(cpu_model: CpuModel, format: u16) -> Option<u32> {
+        match (cpu_model, format) {
+            (CpuModel::M68000, _) => None,
+            (_, 0x0) => Some(8),
+            (CpuModel::M68010, 0x8) => Some(58),
+            (CpuModel::M68020 | CpuModel::M68030, 0x2) => Some(12),
+            (CpuModel::M68020 | CpuModel::M68030, 0xa) => Some(32),
+            (CpuModel::M68020 | CpuModel::M68030, 0xb) => Some(92),
+            _ => None,
+        }
+    }
+
+    fn jump_to_exception_handler(
+        &mut self,
+        mem: &mut Mem,
+        step_log: &mut StepLog,
+        vector: u32,
+        stacked_pc: u32,
+        stacked_sr: u16,
+    ) -> Result<(), StepError> {
+        // The vector base register is always zero on the 68000
+        let vector_address = self.register.reg_vbr.wrapping_add(vector * 4);
+        let handler_address = mem.get_long(step_log, vector_address)?;
+        step_log.add_step_log_entry(StepLogEntry::Exception {
+            vector,
+            stacked_pc,
+            stacked_sr,
+            handler_address,
+        });
+        self.register.reg_pc = ProgramCounter::from_address(handler_address);
+        mem.flush_prefetch();
+        Ok(())
+    }
+
+    // Group 1 and 2 exceptions, and interrupts. The stacked PC differs between the exception
+    // classes:
+    //  - Illegal instruction, line 1010/1111 and privilege violation: the faulting instruction
+    //  - TRAP, TRAPV, CHK, divide by zero, trace and interrupts: the next instruction
     pub fn exception(
         &mut self,
-        pc: &mut ProgramCounter,
         mem: &mut Mem,
         step_log: &mut StepLog,
         vector: u32,
+        stacked_pc: u32,
     ) {
-        // TODO: This could probably be a StepLogEntry
-        println!("Exception occured!");
-        println!("vector: {} [${:02X}]", vector, vector);
-        let sr_to_push = self.register.reg_sr.get_value();
-        self.register.reg_sr.set_supervisor();
-        // TODO: T bit clear
-        // TODO: Do we push PC or PC+2?
-        self.register.stack_push_pc(mem, step_log);
-        self.register.stack_push_word(mem, step_log, sr_to_push);
+        let stacked_sr = self.begin_exception(mem);
+        let result = self
+            .push_short_exception_frame(mem, step_log, vector, stacked_pc, stacked_sr)
+            .and_then(|_| {
+                self.jump_to_exception_handler(mem, step_log, vector, stacked_pc, stacked_sr)
+            });
+        match result {
+            Ok(()) => (),
+            Err(StepError::AccessFault { fault }) => {
+                // Supervisor stack pointer or vector table in a bus error region
+                let instruction_register = self.instruction_register;
+                self.exception_group_0(
+                    mem,
+                    step_log,
+                    EXCEPTION_VECTOR_BUS_ERROR,
+                    stacked_pc,
+                    instruction_register,
+                    fault,
+                );
+            }
+            Err(StepError::AddressError { fault }) => {
+                // Odd supervisor stack pointer
+                let instruction_register = self.instruction_register;
+                self.exception_group_0(
+                    mem,
+                    step_log,
+                    EXCEPTION_VECTOR_ADDRESS_ERROR,
+                    stacked_pc,
+                    instruction_register,
+                    fault,
+                );
+            }
+            Err(step_error) => panic!("Error during exception processing: {}", step_error),
+        }
+    }
+
+    // Group 0 exceptions (bus error and address error) use the long stack frame. The 68000
+    // stacks a PC that is somewhere between 2 and 10 bytes past the start of the instruction,
+    // so it's up to the caller to provide a reasonable value.
+    pub fn exception_group_0(
+        &mut self,
+        mem: &mut Mem,
+        step_log: &mut StepLog,
+        vector: u32,
+        stacked_pc: u32,
+        instruction_register: u16,
+        fault: BusCycleFault,
+    ) {
+        let supervisor = self.register.reg_sr.is_sr_supervisor_set_no_log();
+        let status_word = fault.get_status_word(supervisor);
+        let stacked_sr = self.begin_exception(mem);
+        step_log.add_step_log_entry(StepLogEntry::GroupZeroException {
+            access_address: fault.address,
+            instruction_register,
+            status_word,
+        });
+        let frame = self.get_group_0_exception_frame(
+            vector,
+            stacked_pc,
+            stacked_sr,
+            instruction_register,
+            fault,
+        );
+        let result = self
+            .push_exception_frame(mem, step_log, &frame)
+            .and_then(|_| {
+                self.jump_to_exception_handler(mem, step_log, vector, stacked_pc, stacked_sr)
+            });
+        if let Err(step_error) = result {
+            // A bus error or address error during group 0 exception processing is a double
+            // bus fault. The CPU halts and only an external reset gets it going again.
+            step_log.add_log_string(format!(
+                "CPU: Double bus fault, halting CPU ({})",
+                step_error
+            ));
+            self.halted = true;
+        }
+    }
+
+    pub fn set_interrupt_priority_level(&mut self, level: u8) {
+        self.interrupt_priority_level = level & 0x07;
+    }
+
+    fn process_pending_interrupt(&mut self, mem: &mut Mem, step_log: &mut StepLog) -> bool {
+        let level = self.interrupt_priority_level;
+        // Level 7 is the non-maskable interrupt. It's edge triggered, so it's only taken when
+        // the level changes to 7, even if the mask already is 7.
+        let nmi_edge = level == 7 && self.sampled_interrupt_priority_level != 7;
+        self.sampled_interrupt_priority_level = level;
+        if level == 0 || (level <= self.register.reg_sr.get_interrupt_priority_mask() && !nmi_edge)
+        {
+            return false;
+        }
 
-        let vector_offset = (vector) * 4;
-        println!("vector_offset: ${:08X}", vector_offset);
-        let vector_address = mem.get_long(step_log, vector_offset);
-        println!("vector_address: ${:08X}", vector_address);
-        pc.set_long(vector_address);
+        // Autovectored interrupts use vector 25-31 (vector 24 is the spurious interrupt)
+        let stacked_pc = self.register.reg_pc.get_address();
+        self.exception(
+            mem,
+            step_log,
+            EXCEPTION_VECTOR_SPURIOUS_INTERRUPT + level as u32,
+            stacked_pc,
+        );
+        self.register.reg_sr.set_interrupt_priority_mask(level);
+        true
     }
 
-    pub fn execute_next_instruction(self: &mut Cpu, mem: &mut Mem) {
+    pub fn execute_next_instruction(self: &mut Cpu, mem: &mut Mem) -> u32 {
         self.execute_next_instruction_step_log(mem, &mut StepLog::none())
     }
 
+    // Executes the next instruction and returns the number of clock cycles it took, including
+    // any exception processing
     pub fn execute_next_instruction_step_log(
         self: &mut Cpu,
         mem: &mut Mem,
         step_log: &mut StepLog,
-    ) {
+    ) -> u32 {
+        if self.halted == true {
+            return IDLE_CYCLES;
+        }
         if self.stopped == true {
-            return;
+            // Only an interrupt (or reset) gets a stopped CPU going again. The stacked PC is the
+            // instruction following the STOP.
+            if self.process_pending_interrupt(mem, step_log) {
+                self.stopped = false;
+                return EXCEPTION_CYCLES_INTERRUPT;
+            }
+            return IDLE_CYCLES;
         }
         let mut pc = self.register.reg_pc.clone();
+        if pc.get_address() & 0x00000001 != 0 {
+            // Prefetch from an odd address, e.g. after jumping to or returning to an odd address
+            let address = pc.get_address();
+            let instruction_register = self.instruction_register;
+            self.exception_group_0(
+                mem,
+                step_log,
+                EXCEPTION_VECTOR_ADDRESS_ERROR,
+                address,
+                instruction_register,
+                BusCycleFault {
+                    address,
+                    read: true,
+                    program_space: true,
+                },
+            );
+            return EXCEPTION_CYCLES_ADDRESS_ERROR;
+        }
+        mem.set_supervisor(self.register.reg_sr.is_sr_supervisor_set_no_log());
+        let check_result = mem.check_instruction_address(pc.get_address());
+        if let Err(StepError::AccessFault { fault }) = check_result {
+            // The MMU faulted the prefetch of the instruction
+            let instruction_register = self.instruction_register;
+            self.exception_group_0(
+                mem,
+                step_log,
+                EXCEPTION_VECTOR_BUS_ERROR,
+                pc.get_address(),
+                instruction_register,
+                fault,
+            );
+            return EXCEPTION_CYCLES_BUS_ERROR;
+        }
+        self.instruction_address = pc.get_address();
+        mem.begin_instruction_prefetch(pc.get_address());
         let instr_word = pc.fetch_next_word(mem);
-
-        let instruction_pos = self
-            .instructions
-            .iter()
-            .position(|x| match_check(x, instr_word) && (x.match_check)(x, instr_word));
-        match instruction_pos {
-            None => {
-                panic!(
-                    "{:#010X} Unidentified instruction {:#06X}",
-                    pc.get_address(),
-                    instr_word
-                );
-                // self.exception(&mut pc, 4);
-                // self.register.reg_pc = pc.get_step_next_pc();
+        self.instruction_register = instr_word;
+        // The trace bit is sampled when the instruction begins executing, so an instruction
+        // that sets T (RTE, MOVE to SR) isn't traced, but one that clears it is
+        let mut trace = self.register.reg_sr.is_sr_trace_set();
+
+        let mut cycles = match self.opcode_table.get_entry(instr_word) {
+            OpcodeTableEntry::IllegalInstruction
+            | OpcodeTableEntry::Line1010Emulator
+            | OpcodeTableEntry::Line1111Emulator => {
+                // Unassigned opcodes, including the line 1010 and line 1111 emulator opcodes
+                trace = false;
+                let vector = Cpu::get_illegal_instruction_vector(instr_word);
+                self.exception(mem, step_log, vector, pc.get_address());
+                EXCEPTION_CYCLES_ILLEGAL_INSTRUCTION
             }
-            Some(instruction_pos) => {
+            OpcodeTableEntry::Instruction {
+                index: instruction_pos,
+            } => {
                 let instruction = &self.instructions[instruction_pos];
                 let step_result =
                     (instruction.step)(instr_word, &mut pc, &mut self.register, mem, step_log);
                 match step_result {
-                    Ok(step_result) => self.register.reg_pc = pc.get_step_next_pc(),
+                    Ok(step_result) => {
+                        if pc.is_jump() {
+                            // Branches, jumps and RTE refill the prefetch queue
+                            mem.flush_prefetch();
+                        }
+                        self.register.reg_pc = pc.get_step_next_pc();
+                        step_result.cycles
+                    }
                     Err(step_error) => match step_error {
                         StepError::IllegalInstruction => {
-                            self.exception(&mut pc, mem, step_log, 4);
-                            self.register.reg_pc = pc.get_step_next_pc();
+                            let vector = Cpu::get_illegal_instruction_vector(instr_word);
+                            trace = false;
+                            self.exception(mem, step_log, vector, pc.get_address());
+                            EXCEPTION_CYCLES_ILLEGAL_INSTRUCTION
                         }
                         StepError::PriviliegeViolation => {
-                            self.exception(&mut pc, mem, step_log, 8);
-                            self.register.reg_pc = pc.get_step_next_pc();
+                            trace = false;
+                            self.exception(
+                                mem,
+                                step_log,
+                                EXCEPTION_VECTOR_PRIVILEGE_VIOLATION,
+                                pc.get_address(),
+                            );
+                            EXCEPTION_CYCLES_PRIVILEGE_VIOLATION
+                        }
+                        StepError::FormatError => {
+                            trace = false;
+                            self.exception(
+                                mem,
+                                step_log,
+                                EXCEPTION_VECTOR_FORMAT_ERROR,
+                                pc.get_address(),
+                            );
+                            EXCEPTION_CYCLES_FORMAT_ERROR
+                        }
+                        StepError::IntegerDivideByZero => {
+                            let stacked_pc = pc.get_step_next_pc().get_address();
+                            self.exception(mem, step_log, EXCEPTION_VECTOR_ZERO_DIVIDE, stacked_pc);
+                            EXCEPTION_CYCLES_ZERO_DIVIDE
+                        }
+                        StepError::CHK_CHK2_Instruction => {
+                            let stacked_pc = pc.get_step_next_pc().get_address();
+                            self.exception(
+                                mem,
+                                step_log,
+                                EXCEPTION_VECTOR_CHK_INSTRUCTION,
+                                stacked_pc,
+                            );
+                            EXCEPTION_CYCLES_CHK_INSTRUCTION
+                        }
+                        StepError::FRAPcc_TRAPcc_TRAPV_Instruction => {
+                            let stacked_pc = pc.get_step_next_pc().get_address();
+                            self.exception(
+                                mem,
+                                step_log,
+                                EXCEPTION_VECTOR_TRAPV_INSTRUCTION,
+                                stacked_pc,
+                            );
+                            EXCEPTION_CYCLES_TRAPV_INSTRUCTION
+                        }
+                        StepError::FloatingPointException { vector } => {
+                            // Reported at the start of an FPU instruction, which is restarted
+                            // after the handler returns
+                            trace = false;
+                            self.exception(mem, step_log, vector, pc.get_address());
+                            EXCEPTION_CYCLES_FPU_PRE_INSTRUCTION
+                        }
+                        StepError::MmuConfigurationError => {
+                            let stacked_pc = pc.get_step_next_pc().get_address();
+                            self.exception(
+                                mem,
+                                step_log,
+                                EXCEPTION_VECTOR_MMU_CONFIGURATION_ERROR,
+                                stacked_pc,
+                            );
+                            EXCEPTION_CYCLES_MMU_CONFIGURATION_ERROR
+                        }
+                        StepError::TRAP_Instruction { trap_number } => {
+                            let stacked_pc = pc.get_step_next_pc().get_address();
+                            self.exception(
+                                mem,
+                                step_log,
+                                EXCEPTION_VECTOR_TRAP_0 + trap_number as u32,
+                                stacked_pc,
+                            );
+                            EXCEPTION_CYCLES_TRAP_INSTRUCTION
+                        }
+                        StepError::AccessFault { fault } => {
+                            trace = false;
+                            let stacked_pc = pc.get_address().wrapping_add(2);
+                            self.exception_group_0(
+                                mem,
+                                step_log,
+                                EXCEPTION_VECTOR_BUS_ERROR,
+                                stacked_pc,
+                                instr_word,
+                                fault,
+                            );
+                            EXCEPTION_CYCLES_BUS_ERROR
+                        }
+                        StepError::AddressError { fault } => {
+                            trace = false;
+                            let stacked_pc = pc.get_address().wrapping_add(2);
+                            self.exception_group_0(
+                                mem,
+                                step_log,
+                                EXCEPTION_VECTOR_ADDRESS_ERROR,
+                                stacked_pc,
+                                instr_word,
+                                fault,
+                            );
+                            EXCEPTION_CYCLES_ADDRESS_ERROR
                         }
                         StepError::Stop => {
                             println!("STOP:ing CPU instruction excecution");
                             self.stopped = true;
                             self.register.reg_pc = pc.get_step_next_pc();
+                            IDLE_CYCLES
                         }
                         _ => {
                             println!("Runtime error occured when running instruction.");
@@ -2378,6 +3755,30 @@ impl Cpu {
                 }
             }
         };
+
+        // Trace is taken after the instruction completes, and after any trap caused by it, in
+        // which case the trap handler address is stacked. A traced STOP doesn't stop the CPU.
+        if trace {
+            self.stopped = false;
+            let stacked_pc = self.register.reg_pc.get_address();
+            self.exception(mem, step_log, EXCEPTION_VECTOR_TRACE, stacked_pc);
+            cycles += EXCEPTION_CYCLES_TRACE;
+        }
+
+        // Interrupts are sampled between instructions
+        if self.process_pending_interrupt(mem, step_log) {
+            self.stopped = false;
+            cycles += EXCEPTION_CYCLES_INTERRUPT;
+        }
+        cycles
+    }
+
+    fn get_illegal_instruction_vector(instr_word: u16) -> u32 {
+        match instr_word & 0xf000 {
+            0xa000 => EXCEPTION_VECTOR_LINE_1010_EMULATOR,
+            0xf000 => EXCEPTION_VECTOR_LINE_1111_EMULATOR,
+            _ => EXCEPTION_VECTOR_ILLEGAL_INSTRUCTION,
+        }
     }
 
     pub fn get_next_disassembly_no_log(self: &mut Cpu, mem: &mut Mem) -> GetDisassemblyResult {
@@ -2402,13 +3803,10 @@ impl Cpu {
     ) -> GetDisassemblyResult {
         let instr_word = pc.fetch_next_word(mem);
 
-        let instruction_pos = self
-            .instructions
-            .iter()
-            .position(|x| match_check(x, instr_word) && (x.match_check)(x, instr_word));
-
-        let result = match instruction_pos {
-            Some(instruction_pos) => {
+        let result = match self.opcode_table.get_entry(instr_word) {
+            OpcodeTableEntry::Instruction {
+                index: instruction_pos,
+            } => {
                 let instruction = &self.instructions[instruction_pos];
 
                 let get_disassembly_result = (instruction.get_disassembly)(
@@ -2432,7 +3830,7 @@ impl Cpu {
                     ),
                 }
             }
-            None => GetDisassemblyResult::from_pc(
+            _ => GetDisassemblyResult::from_pc(
                 pc,
                 mem,
                 String::from("DC.W"),
@@ -3266,4 +4664,624 @@ mod tests {
             debug_result
         );
     }
+
+    #[test]
+    fn interrupt_taken_when_level_above_mask() {
+        // arrange
+        let code = [0x4e, 0x71].to_vec(); // NOP
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x0000006C, 0x00C01248); // Level 3 autovector
+        mm.cpu.set_interrupt_priority_level(3);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2300, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x2000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn interrupt_not_taken_when_level_not_above_mask() {
+        // arrange
+        let code = [0x4e, 0x71].to_vec(); // NOP
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2300);
+        mm.mem.set_long_no_log(0x0000006C, 0x00C01248); // Level 3 autovector
+        mm.cpu.set_interrupt_priority_level(3);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00002, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2300, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x01000400, mm.cpu.register.get_ssp_reg());
+    }
+
+    #[test]
+    fn interrupt_level_7_taken_when_mask_is_7() {
+        // arrange
+        let code = [0x4e, 0x71].to_vec(); // NOP
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2700);
+        mm.mem.set_long_no_log(0x0000007C, 0x00C01248); // Level 7 autovector
+        mm.cpu.set_interrupt_priority_level(7);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2700, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x2700, mm.mem.get_word_no_log(0x010003FA));
+    }
+
+    #[test]
+    fn exception_privilege_violation_stacks_faulting_instruction_and_clears_trace() {
+        // arrange
+        let code = [0x46, 0xfc, 0x27, 0x00].to_vec(); // MOVE.W #$2700,SR
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(STATUS_REGISTER_MASK_TRACE_1);
+        mm.cpu.register.set_ssp_reg(0x01000400);
+        mm.mem.set_long_no_log(0x00000020, 0x00C01248); // Privilege violation vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2000, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x8000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn exception_divide_by_zero_stacks_next_instruction() {
+        // arrange
+        let code = [0x80, 0xfc, 0x00, 0x00].to_vec(); // DIVU.W #$0000,D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x00000014, 0x00C01248); // Zero divide vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x2000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn exception_line_1010_emulator_stacks_faulting_instruction() {
+        // arrange
+        let code = [0xa1, 0x23].to_vec(); // DC.W $A123
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x00000028, 0x00C01248); // Line 1010 emulator vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x2000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn exception_line_1111_emulator_stacks_faulting_instruction() {
+        // arrange
+        let code = [0xf2, 0x00].to_vec(); // DC.W $F200
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x0000002C, 0x00C01248); // Line 1111 emulator vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn exception_unassigned_opcode_is_illegal_instruction() {
+        // arrange
+        let code = [0x4e, 0x7c].to_vec(); // DC.W $4E7C
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x00000010, 0x00C01248); // Illegal instruction vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn exception_group_0_pushes_long_frame() {
+        // arrange
+        let code = [0x4e, 0x71].to_vec(); // NOP
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x0000);
+        mm.mem.set_long_no_log(0x0000000C, 0x00C01248); // Address error vector
+        // act
+        mm.cpu.exception_group_0(
+            &mut mm.mem,
+            &mut StepLog::none(),
+            EXCEPTION_VECTOR_ADDRESS_ERROR,
+            0x00C00002,
+            0x3010,
+            BusCycleFault {
+                address: 0x00001001,
+                read: true,
+                program_space: false,
+            },
+        );
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2000, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x010003F2, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x0011, mm.mem.get_word_no_log(0x010003F2));
+        assert_eq!(0x00001001, mm.mem.get_long_no_log(0x010003F4));
+        assert_eq!(0x3010, mm.mem.get_word_no_log(0x010003F8));
+        assert_eq!(0x0000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn exception_group_0_pushes_format_8_frame_on_68010() {
+        // arrange
+        let code = [0x4e, 0x71].to_vec(); // NOP
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.cpu.register.reg_sr.set_value(0x0000);
+        mm.mem.set_long_no_log(0x00000008, 0x00C01248); // Bus error vector
+        // act
+        mm.cpu.exception_group_0(
+            &mut mm.mem,
+            &mut StepLog::none(),
+            EXCEPTION_VECTOR_BUS_ERROR,
+            0x00C00002,
+            0x3010,
+            BusCycleFault {
+                address: 0x00F00000,
+                read: true,
+                program_space: false,
+            },
+        );
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x01000400 - 58, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x0000, mm.mem.get_word_no_log(0x010003C6));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003C8));
+        assert_eq!(0x8008, mm.mem.get_word_no_log(0x010003CC));
+        assert_eq!(0x1101, mm.mem.get_word_no_log(0x010003CE));
+        assert_eq!(0x00F00000, mm.mem.get_long_no_log(0x010003D0));
+        assert_eq!(0x3010, mm.mem.get_word_no_log(0x010003DE));
+    }
+
+    #[test]
+    fn exception_group_0_pushes_format_a_frame_on_68020() {
+        // arrange
+        let code = [0x4e, 0x71].to_vec(); // NOP
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.cpu.register.reg_sr.set_value(0x0000);
+        mm.mem.set_long_no_log(0x00000008, 0x00C01248); // Bus error vector
+        // act
+        mm.cpu.exception_group_0(
+            &mut mm.mem,
+            &mut StepLog::none(),
+            EXCEPTION_VECTOR_BUS_ERROR,
+            0x00C00002,
+            0x3010,
+            BusCycleFault {
+                address: 0x00F00000,
+                read: false,
+                program_space: false,
+            },
+        );
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x01000400 - 32, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003E2));
+        assert_eq!(0xa008, mm.mem.get_word_no_log(0x010003E6));
+        assert_eq!(0x0101, mm.mem.get_word_no_log(0x010003EA));
+        assert_eq!(0x3010, mm.mem.get_word_no_log(0x010003EC));
+        assert_eq!(0x00F00000, mm.mem.get_long_no_log(0x010003F0));
+    }
+
+    #[test]
+    fn exception_zero_divide_pushes_format_2_frame_on_68020() {
+        // arrange
+        let code = [0x80, 0xc1].to_vec(); // DIVU.W D1,D0
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x00000014, 0x00C01248); // Zero divide vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003F4, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003F6));
+        assert_eq!(0x2014, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn address_error_on_odd_word_read() {
+        // arrange
+        let code = [0x30, 0x10].to_vec(); // MOVE.W (A0),D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00001001);
+        mm.mem.set_long_no_log(0x0000000C, 0x00C01248); // Address error vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003F2, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x0015, mm.mem.get_word_no_log(0x010003F2));
+        assert_eq!(0x00001001, mm.mem.get_long_no_log(0x010003F4));
+        assert_eq!(0x3010, mm.mem.get_word_no_log(0x010003F8));
+        assert_eq!(0x2000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn address_error_on_odd_program_counter() {
+        // arrange
+        let code = [0x4e, 0xd0].to_vec(); // JMP (A0)
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00C01001);
+        mm.mem.set_long_no_log(0x0000000C, 0x00C01248); // Address error vector
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003F2, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x0016, mm.mem.get_word_no_log(0x010003F2));
+        assert_eq!(0x00C01001, mm.mem.get_long_no_log(0x010003F4));
+        assert_eq!(0x00C01001, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn double_bus_fault_halts_cpu() {
+        // arrange
+        let code = [0x30, 0x10].to_vec(); // MOVE.W (A0),D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.cpu.register.set_ssp_reg(0x01000401);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00001001);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(true, mm.cpu.halted);
+    }
+
+    #[test]
+    fn bus_error_on_unmapped_access_with_bus_error_policy() {
+        // arrange
+        let code = [0x30, 0x10].to_vec(); // MOVE.W (A0),D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.add_unmapped_access_policy(
+            0x00200000,
+            0x009fffff,
+            crate::mem::unmappedmemory::UnmappedAccessPolicy::BusError,
+        );
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00200000);
+        mm.mem.set_long_no_log(0x00000008, 0x00C01248); // Bus error vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003F2, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x0015, mm.mem.get_word_no_log(0x010003F2));
+        assert_eq!(0x00200000, mm.mem.get_long_no_log(0x010003F4));
+        assert_eq!(0x3010, mm.mem.get_word_no_log(0x010003F8));
+    }
+
+    #[test]
+    fn open_bus_on_unmapped_access_with_open_bus_policy() {
+        // arrange
+        let code = [0x30, 0x10].to_vec(); // MOVE.W (A0),D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem
+            .set_unmapped_access_policy(crate::mem::unmappedmemory::UnmappedAccessPolicy::OpenBus);
+        mm.mem.set_word(&mut StepLog::none(), 0x01000000, 0xbeef).ok();
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00200000);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00002, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x3010, mm.cpu.register.get_d_reg_word_no_log(0));
+    }
+
+    #[test]
+    fn open_bus_on_unmapped_access_with_prefetch_queue_returns_prefetched_word() {
+        // arrange
+        let code = [0x30, 0x10, 0x4e, 0x71].to_vec(); // MOVE.W (A0),D0 ; NOP
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem
+            .set_unmapped_access_policy(crate::mem::unmappedmemory::UnmappedAccessPolicy::OpenBus);
+        mm.mem.set_prefetch_enabled(true);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00200000);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x4e71, mm.cpu.register.get_d_reg_word_no_log(0));
+    }
+
+    #[test]
+    fn trace_after_trap_stacks_trap_handler_address() {
+        // arrange
+        let code = [0x80, 0xfc, 0x00, 0x00].to_vec(); // DIVU.W #$0000,D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0xa000);
+        mm.mem.set_long_no_log(0x00000014, 0x00C01248); // Zero divide vector
+        mm.mem.set_long_no_log(0x00000024, 0x11223344); // Trace vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x11223344, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003F4, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x2000, mm.mem.get_word_no_log(0x010003F4));
+        assert_eq!(0x00C01248, mm.mem.get_long_no_log(0x010003F6));
+        assert_eq!(0xa000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn trace_not_taken_after_privilege_violation() {
+        // arrange
+        let code = [0x4e, 0x73].to_vec(); // RTE
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(STATUS_REGISTER_MASK_TRACE_1);
+        mm.mem.set_long_no_log(0x00000020, 0x00C01248); // Privilege violation vector
+        mm.mem.set_long_no_log(0x00000024, 0x11223344); // Trace vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+    }
+
+    #[test]
+    fn trace_after_stop_does_not_stop_cpu() {
+        // arrange
+        let code = [0x4e, 0x72, 0x27, 0x00].to_vec(); // STOP #$2700
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0xa000);
+        mm.mem.set_long_no_log(0x00000024, 0x11223344); // Trace vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(false, mm.cpu.stopped);
+        assert_eq!(0x11223344, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2700, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x2700, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn stop_loads_status_register_and_stays_stopped() {
+        // arrange
+        let code = [0x4e, 0x72, 0x25, 0x00].to_vec(); // STOP #$2500
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("STOP"),
+                String::from("#$2500"),
+                vec![0x4e72, 0x2500]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        mm.cpu.set_interrupt_priority_level(5);
+        mm.step();
+        // assert
+        assert_eq!(true, mm.cpu.stopped);
+        assert_eq!(0x2500, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x00C00004, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x01000400, mm.cpu.register.get_ssp_reg());
+    }
+
+    #[test]
+    fn stop_resumes_on_interrupt_above_mask() {
+        // arrange
+        let code = [0x4e, 0x72, 0x23, 0x00].to_vec(); // STOP #$2300
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.set_long_no_log(0x00000070, 0x00C01248); // Level 4 autovector
+        mm.step();
+        // act
+        mm.cpu.set_interrupt_priority_level(4);
+        mm.step();
+        // assert
+        assert_eq!(false, mm.cpu.stopped);
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2400, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x2300, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn stop_resumes_immediately_when_interrupt_already_pending() {
+        // arrange
+        let code = [0x4e, 0x72, 0x20, 0x00].to_vec(); // STOP #$2000
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2700);
+        mm.mem.set_long_no_log(0x00000068, 0x00C01248); // Level 2 autovector
+        mm.cpu.set_interrupt_priority_level(2);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(false, mm.cpu.stopped);
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2200, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn cycles_branch_taken_and_not_taken() {
+        // arrange
+        let code = [0x67, 0x02, 0x67, 0x00, 0x00, 0x04].to_vec(); // BEQ.B $02, BEQ.W $0004
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(0x0000);
+        // act
+        let cycles_byte_not_taken = mm.step();
+        let cycles_word_not_taken = mm.step();
+        mm.cpu.register.reg_pc = ProgramCounter::from_address(0xC00000);
+        mm.cpu
+            .register
+            .reg_sr
+            .set_sr_reg_flags_abcde(STATUS_REGISTER_MASK_ZERO);
+        let cycles_taken = mm.step();
+        // assert
+        assert_eq!(8, cycles_byte_not_taken);
+        assert_eq!(12, cycles_word_not_taken);
+        assert_eq!(10, cycles_taken);
+    }
+
+    #[test]
+    fn cycles_dbcc_loop_and_expire() {
+        // arrange
+        let code = [0x51, 0xc8, 0xff, 0xfe].to_vec(); // DBRA D0,$FFFE
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0x00000001);
+        // act
+        let cycles_loop = mm.step();
+        let cycles_expired = mm.step();
+        // assert
+        assert_eq!(10, cycles_loop);
+        assert_eq!(14, cycles_expired);
+        assert_eq!(0xC00004, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn cycles_include_exception_processing() {
+        // arrange
+        let code = [0x4e, 0x41].to_vec(); // TRAP #1
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.set_long_no_log(0x00000084, 0x00C01248); // TRAP #1 vector
+        // act
+        let cycles = mm.step();
+        // assert
+        assert_eq!(34, cycles);
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn cycles_stopped_cpu_idles_until_interrupt() {
+        // arrange
+        let code = [0x4e, 0x72, 0x23, 0x00].to_vec(); // STOP #$2300
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.set_long_no_log(0x00000070, 0x00C01248); // Level 4 autovector
+        mm.step();
+        // act
+        let cycles_stopped = mm.step();
+        mm.cpu.set_interrupt_priority_level(4);
+        let cycles_interrupt = mm.step();
+        // assert
+        assert_eq!(4, cycles_stopped);
+        assert_eq!(44, cycles_interrupt);
+    }
+
+    fn self_modifying_code_setup(
+        code: Vec<u8>,
+        prefetch_enabled: bool,
+    ) -> crate::modermodem::Modermodem {
+        let mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.set_prefetch_enabled(prefetch_enabled);
+        mm
+    }
+
+    #[test]
+    fn prefetch_queue_runs_stale_overwritten_instruction() {
+        // arrange
+        let code = [
+            0x33, 0xfc, 0x70, 0x05, 0x00, 0xc0, 0x00, 0x08, // MOVE.W #$7005,($00C00008).L
+            0x70, 0x01, // MOVEQ #1,D0
+        ]
+        .to_vec();
+        let mut mm = self_modifying_code_setup(code, true);
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x7005, mm.mem.get_word_no_log(0x00C00008));
+        assert_eq!(0x00000001, mm.cpu.register.get_d_reg_long_no_log(0));
+    }
+
+    #[test]
+    fn prefetch_queue_runs_overwritten_word_after_next_opcode() {
+        // arrange
+        let code = [
+            0x33, 0xfc, 0x00, 0x05, 0x00, 0xc0, 0x00, 0x0a, // MOVE.W #$0005,($00C0000A).L
+            0x30, 0x3c, 0x00, 0x01, // MOVE.W #$0001,D0
+        ]
+        .to_vec();
+        let mut mm = self_modifying_code_setup(code, true);
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x00000005, mm.cpu.register.get_d_reg_long_no_log(0));
+    }
+
+    #[test]
+    fn no_prefetch_queue_runs_overwritten_instruction() {
+        // arrange
+        let code = [
+            0x33, 0xfc, 0x70, 0x05, 0x00, 0xc0, 0x00, 0x08, // MOVE.W #$7005,($00C00008).L
+            0x70, 0x01, // MOVEQ #1,D0
+        ]
+        .to_vec();
+        let mut mm = self_modifying_code_setup(code, false);
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x00000005, mm.cpu.register.get_d_reg_long_no_log(0));
+    }
+
+    #[test]
+    fn prefetch_queue_is_refilled_by_branch() {
+        // arrange
+        let code = [
+            0x33, 0xfc, 0x70, 0x05, 0x00, 0xc0, 0x00, 0x0c, // MOVE.W #$7005,($00C0000C).L
+            0x60, 0x00, 0x00, 0x02, // BRA.W $00C0000C
+            0x70, 0x01, // MOVEQ #1,D0
+        ]
+        .to_vec();
+        let mut mm = self_modifying_code_setup(code, true);
+        // act
+        mm.step();
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x00000005, mm.cpu.register.get_d_reg_long_no_log(0));
+    }
+
+    #[test]
+    fn prefetch_queue_is_refilled_by_exception() {
+        // arrange
+        let code = [
+            0x33, 0xfc, 0x70, 0x05, 0x00, 0xc0, 0x00, 0x0a, // MOVE.W #$7005,($00C0000A).L
+            0x4e, 0x41, // TRAP #1
+            0x70, 0x01, // MOVEQ #1,D0
+        ]
+        .to_vec();
+        let mut mm = self_modifying_code_setup(code, true);
+        mm.mem.set_long_no_log(0x00000084, 0x00C0000A); // TRAP #1 vector
+        // act
+        mm.step();
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x00000005, mm.cpu.register.get_d_reg_long_no_log(0));
+    }
 }
@@ -9,7 +9,7 @@ use crate::{
 };
 
 use super::{
-    instruction::{EffectiveAddressingMode, OperationSize},
+    instruction::{EffectiveAddressingMode, OperationSize, StepError},
     step_log::StepLog,
     Cpu, StatusRegisterResult,
 };
@@ -47,6 +47,23 @@ impl EffectiveAddressingData {
         }
     }
 
+    pub fn get_calculation_cycles(&self) -> u32 {
+        self.ea_mode.get_calculation_cycles(self.operation_size)
+    }
+
+    // For read-modify-write instructions that end up not writing the operand back
+    pub fn apply_increment_decrement(&self, reg: &mut Register, step_log: &mut StepLog) {
+        match self.ea_mode {
+            EffectiveAddressingMode::ARegIndirectWithPostIncrement { ea_register, .. } => {
+                reg.increment_a_reg(ea_register, step_log, self.operation_size)
+            }
+            EffectiveAddressingMode::ARegIndirectWithPreDecrement { ea_register, .. } => {
+                reg.decrement_a_reg(ea_register, step_log, self.operation_size)
+            }
+            _ => (),
+        }
+    }
+
     pub fn get_address(
         &self,
         pc: &mut ProgramCounter,
@@ -149,6 +166,18 @@ impl EffectiveAddressingData {
                 // PcMemoryInderectPreIndexed          ([bd, PC, Xn.SIZE*SCALE],od)
                 ea_address
             }
+            EffectiveAddressingMode::ARegIndirectWithIndexBaseDisplacement {
+                ea_address, ..
+            }
+            | EffectiveAddressingMode::MemoryIndirectPostIndexed { ea_address, .. }
+            | EffectiveAddressingMode::MemoryIndirectPreIndexed { ea_address, .. }
+            | EffectiveAddressingMode::PcIndirectWithIndexBaseDisplacement { ea_address, .. }
+            | EffectiveAddressingMode::PcMemoryIndirectPostIndexed { ea_address, .. }
+            | EffectiveAddressingMode::PcMemoryIndirectPreIndexed { ea_address, .. } => {
+                // (bd,An,Xn.SIZE*SCALE), ([bd,An],Xn.SIZE*SCALE,od), ([bd,An,Xn.SIZE*SCALE],od)
+                // and the same with PC. Memory indirection was resolved when decoding.
+                ea_address
+            }
             EffectiveAddressingMode::ImmediateDataByte { .. }
             | EffectiveAddressingMode::ImmediateDataWord { .. }
             | EffectiveAddressingMode::ImmediateDataLong { .. } => {
@@ -165,23 +194,23 @@ impl EffectiveAddressingData {
         mem: &Mem,
         step_log: &mut StepLog,
         apply_increment_decrement: bool,
-    ) -> u8 {
+    ) -> Result<u8, StepError> {
         match self.ea_mode {
             EffectiveAddressingMode::DRegDirect { ea_register } => {
                 // Dn
-                reg.get_d_reg_byte(ea_register, step_log)
+                Ok(reg.get_d_reg_byte(ea_register, step_log))
             }
             EffectiveAddressingMode::ARegDirect { ea_register } => {
                 // An
-                reg.get_a_reg_byte(ea_register, step_log)
+                Ok(reg.get_a_reg_byte(ea_register, step_log))
             }
             EffectiveAddressingMode::ImmediateDataByte { data } => {
                 // #<xxx>
-                data
+                Ok(data)
             }
             _ => {
                 let ea = self.get_address(pc, reg, mem, step_log);
-                let result = mem.get_byte(step_log, ea);
+                let result = mem.get_byte(step_log, ea)?;
                 if apply_increment_decrement {
                     match self.ea_mode {
                         EffectiveAddressingMode::ARegIndirectWithPostIncrement {
@@ -195,7 +224,7 @@ impl EffectiveAddressingData {
                         _ => (),
                     }
                 }
-                result
+                Ok(result)
             }
         }
     }
@@ -207,23 +236,23 @@ impl EffectiveAddressingData {
         mem: &Mem,
         step_log: &mut StepLog,
         apply_increment_decrement: bool,
-    ) -> u16 {
+    ) -> Result<u16, StepError> {
         match self.ea_mode {
             EffectiveAddressingMode::DRegDirect { ea_register } => {
                 // Dn
-                reg.get_d_reg_word(ea_register, step_log)
+                Ok(reg.get_d_reg_word(ea_register, step_log))
             }
             EffectiveAddressingMode::ARegDirect { ea_register } => {
                 // An
-                reg.get_a_reg_word(ea_register, step_log)
+                Ok(reg.get_a_reg_word(ea_register, step_log))
             }
             EffectiveAddressingMode::ImmediateDataWord { data } => {
                 // #<xxx>
-                data
+                Ok(data)
             }
             _ => {
                 let ea = self.get_address(pc, reg, mem, step_log);
-                let result = mem.get_word(step_log, ea);
+                let result = mem.get_word(step_log, ea)?;
                 if apply_increment_decrement {
                     match self.ea_mode {
                         EffectiveAddressingMode::ARegIndirectWithPostIncrement {
@@ -239,7 +268,7 @@ impl EffectiveAddressingData {
                         _ => (),
                     }
                 }
-                result
+                Ok(result)
             }
         }
     }
@@ -251,23 +280,23 @@ impl EffectiveAddressingData {
         mem: &Mem,
         step_log: &mut StepLog,
         apply_increment_decrement: bool,
-    ) -> u32 {
+    ) -> Result<u32, StepError> {
         match self.ea_mode {
             EffectiveAddressingMode::DRegDirect { ea_register } => {
                 // Dn
-                reg.get_d_reg_long(ea_register, step_log)
+                Ok(reg.get_d_reg_long(ea_register, step_log))
             }
             EffectiveAddressingMode::ARegDirect { ea_register } => {
                 // An
-                reg.get_a_reg_long(ea_register, step_log)
+                Ok(reg.get_a_reg_long(ea_register, step_log))
             }
             EffectiveAddressingMode::ImmediateDataLong { data } => {
                 // #<xxx>
-                data
+                Ok(data)
             }
             _ => {
                 let ea = self.get_address(pc, reg, mem, step_log);
-                let result = mem.get_long(step_log, ea);
+                let result = mem.get_long(step_log, ea)?;
                 if apply_increment_decrement {
                     match self.ea_mode {
                         EffectiveAddressingMode::ARegIndirectWithPostIncrement {
@@ -283,7 +312,7 @@ impl EffectiveAddressingData {
                         _ => (),
                     }
                 }
-                result
+                Ok(result)
             }
         }
     }
@@ -296,7 +325,7 @@ impl EffectiveAddressingData {
         step_log: &mut StepLog,
         value: u8,
         apply_increment_decrement: bool,
-    ) -> SetEffectiveAddressValueResult {
+    ) -> Result<SetEffectiveAddressValueResult, StepError> {
         match self.ea_mode {
             EffectiveAddressingMode::DRegDirect {
                 ea_register: register,
@@ -318,7 +347,7 @@ impl EffectiveAddressingData {
             }
             _ => {
                 let ea = self.get_address(pc, reg, mem, step_log);
-                mem.set_byte(step_log, ea, value);
+                mem.set_byte(step_log, ea, value)?;
                 if apply_increment_decrement {
                     match self.ea_mode {
                         EffectiveAddressingMode::ARegIndirectWithPostIncrement {
@@ -346,7 +375,7 @@ impl EffectiveAddressingData {
             _ => (),
         }
 
-        SetEffectiveAddressValueResult {
+        Ok(SetEffectiveAddressValueResult {
             status_register_result: StatusRegisterResult {
                 status_register,
                 status_register_mask: STATUS_REGISTER_MASK_CARRY
@@ -354,7 +383,7 @@ impl EffectiveAddressingData {
                     | STATUS_REGISTER_MASK_ZERO
                     | STATUS_REGISTER_MASK_NEGATIVE,
             },
-        }
+        })
     }
 
     pub fn set_value_word(
@@ -365,7 +394,7 @@ impl EffectiveAddressingData {
         step_log: &mut StepLog,
         value: u16,
         apply_increment_decrement: bool,
-    ) -> SetEffectiveAddressValueResult {
+    ) -> Result<SetEffectiveAddressValueResult, StepError> {
         match self.ea_mode {
             EffectiveAddressingMode::DRegDirect {
                 ea_register: register,
@@ -387,7 +416,7 @@ impl EffectiveAddressingData {
             }
             _ => {
                 let ea = self.get_address(pc, reg, mem, step_log);
-                mem.set_word(step_log, ea, value);
+                mem.set_word(step_log, ea, value)?;
                 if apply_increment_decrement {
                     match self.ea_mode {
                         EffectiveAddressingMode::ARegIndirectWithPostIncrement {
@@ -415,7 +444,7 @@ impl EffectiveAddressingData {
             _ => (),
         }
 
-        SetEffectiveAddressValueResult {
+        Ok(SetEffectiveAddressValueResult {
             status_register_result: StatusRegisterResult {
                 status_register,
                 status_register_mask: STATUS_REGISTER_MASK_CARRY
@@ -423,7 +452,7 @@ impl EffectiveAddressingData {
                     | STATUS_REGISTER_MASK_ZERO
                     | STATUS_REGISTER_MASK_NEGATIVE,
             },
-        }
+        })
     }
 
     pub fn set_value_long(
@@ -434,7 +463,7 @@ impl EffectiveAddressingData {
         step_log: &mut StepLog,
         value: u32,
         apply_increment_decrement: bool,
-    ) -> SetEffectiveAddressValueResult {
+    ) -> Result<SetEffectiveAddressValueResult, StepError> {
         match self.ea_mode {
             EffectiveAddressingMode::DRegDirect {
                 ea_register: register,
@@ -456,7 +485,7 @@ impl EffectiveAddressingData {
             }
             _ => {
                 let ea = self.get_address(pc, reg, mem, step_log);
-                mem.set_long(step_log, ea, value);
+                mem.set_long(step_log, ea, value)?;
                 if apply_increment_decrement {
                     match self.ea_mode {
                         EffectiveAddressingMode::ARegIndirectWithPostIncrement {
@@ -487,7 +516,7 @@ impl EffectiveAddressingData {
             _ => (),
         }
 
-        SetEffectiveAddressValueResult {
+        Ok(SetEffectiveAddressValueResult {
             status_register_result: StatusRegisterResult {
                 status_register,
                 status_register_mask: STATUS_REGISTER_MASK_CARRY
@@ -495,6 +524,6 @@ impl EffectiveAddressingData {
                     | STATUS_REGISTER_MASK_ZERO
                     | STATUS_REGISTER_MASK_NEGATIVE,
             },
-        }
+        })
     }
 }
@@ -0,0 +1,1187 @@
+// Extended precision floating point, the format of the 68881/68882 data registers. The basic
+// operations work on the integer mantissa, so they are exact before the single rounding step
+// and give the same bits as the FPU. Bits below the kept precision are folded into the lowest
+// bit of the 128 bit working mantissa ("jamming"), which is enough to round correctly.
+
+pub const EXTENDED_EXPONENT_BIAS: i32 = 16383;
+pub const EXTENDED_EXPONENT_MAX: u16 = 0x7fff;
+
+// Exceptions raised by an operation, laid out as the FPSR exception byte
+pub const FLOAT_EXCEPTION_BSUN: u8 = 0b10000000;
+pub const FLOAT_EXCEPTION_SNAN: u8 = 0b01000000;
+pub const FLOAT_EXCEPTION_OPERR: u8 = 0b00100000;
+pub const FLOAT_EXCEPTION_OVFL: u8 = 0b00010000;
+pub const FLOAT_EXCEPTION_UNFL: u8 = 0b00001000;
+pub const FLOAT_EXCEPTION_DZ: u8 = 0b00000100;
+pub const FLOAT_EXCEPTION_INEX2: u8 = 0b00000010;
+pub const FLOAT_EXCEPTION_INEX1: u8 = 0b00000001;
+
+const MANTISSA_QUIET_NAN: u64 = 0x4000000000000000;
+const MANTISSA_INTEGER_BIT: u64 = 0x8000000000000000;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    Nearest,
+    Zero,
+    MinusInfinity,
+    PlusInfinity,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingPrecision {
+    Extended,
+    Single,
+    Double,
+}
+
+// A binary format a value can be rounded to. The rounding precision of the FPCR only rounds the
+// mantissa, the exponent keeps the extended range.
+struct FloatFormat {
+    mantissa_bits: u32,
+    exponent_bias: i32,
+    exponent_max: i32,
+}
+
+const FORMAT_EXTENDED: FloatFormat = FloatFormat {
+    mantissa_bits: 64,
+    exponent_bias: EXTENDED_EXPONENT_BIAS,
+    exponent_max: EXTENDED_EXPONENT_MAX as i32,
+};
+
+const FORMAT_EXTENDED_SINGLE_PRECISION: FloatFormat = FloatFormat {
+    mantissa_bits: 24,
+    exponent_bias: EXTENDED_EXPONENT_BIAS,
+    exponent_max: EXTENDED_EXPONENT_MAX as i32,
+};
+
+const FORMAT_EXTENDED_DOUBLE_PRECISION: FloatFormat = FloatFormat {
+    mantissa_bits: 53,
+    exponent_bias: EXTENDED_EXPONENT_BIAS,
+    exponent_max: EXTENDED_EXPONENT_MAX as i32,
+};
+
+const FORMAT_SINGLE: FloatFormat = FloatFormat {
+    mantissa_bits: 24,
+    exponent_bias: 127,
+    exponent_max: 0xff,
+};
+
+const FORMAT_DOUBLE: FloatFormat = FloatFormat {
+    mantissa_bits: 53,
+    exponent_bias: 1023,
+    exponent_max: 0x7ff,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Float80 {
+    pub sign: bool,
+    // Biased exponent, 15 bits
+    pub exponent: u16,
+    // Mantissa with the explicit integer bit in bit 63
+    pub mantissa: u64,
+}
+
+// A finite, non zero value as mantissa * 2^(exponent - 127), the mantissa isn't normalized
+struct Unpacked {
+    sign: bool,
+    exponent: i32,
+    mantissa: u128,
+}
+
+impl Float80 {
+    pub fn zero(sign: bool) -> Float80 {
+        Float80 {
+            sign,
+            exponent: 0,
+            mantissa: 0,
+        }
+    }
+
+    pub fn infinity(sign: bool) -> Float80 {
+        Float80 {
+            sign,
+            exponent: EXTENDED_EXPONENT_MAX,
+            mantissa: 0,
+        }
+    }
+
+    // The NaN the FPU returns for an invalid operation
+    pub fn default_nan() -> Float80 {
+        Float80 {
+            sign: false,
+            exponent: EXTENDED_EXPONENT_MAX,
+            mantissa: 0xffffffffffffffff,
+        }
+    }
+
+    pub fn from_words(sign_exponent: u16, mantissa: u64) -> Float80 {
+        Float80 {
+            sign: sign_exponent & 0x8000 != 0,
+            exponent: sign_exponent & 0x7fff,
+            mantissa,
+        }
+    }
+
+    pub fn get_sign_exponent_word(&self) -> u16 {
+        match self.sign {
+            true => 0x8000 | self.exponent,
+            false => self.exponent,
+        }
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.exponent == EXTENDED_EXPONENT_MAX && self.mantissa << 1 != 0
+    }
+
+    pub fn is_signaling_nan(&self) -> bool {
+        self.is_nan() && self.mantissa & MANTISSA_QUIET_NAN == 0
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.exponent == EXTENDED_EXPONENT_MAX && self.mantissa << 1 == 0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.exponent != EXTENDED_EXPONENT_MAX && self.mantissa == 0
+    }
+
+    fn quiet(&self) -> Float80 {
+        Float80 {
+            mantissa: self.mantissa | MANTISSA_QUIET_NAN,
+            ..*self
+        }
+    }
+
+    fn negate(&self) -> Float80 {
+        Float80 {
+            sign: !self.sign,
+            ..*self
+        }
+    }
+
+    fn unpack(&self) -> Unpacked {
+        // Denormals have the exponent of the smallest normal, unnormals are just normalized
+        let shift = self.mantissa.leading_zeros() as i32;
+        Unpacked {
+            sign: self.sign,
+            exponent: (self.exponent as i32).max(1) - EXTENDED_EXPONENT_BIAS - shift,
+            mantissa: ((self.mantissa << shift) as u128) << 64,
+        }
+    }
+
+    // Value of a finite, non zero integer, which always fits the extended mantissa
+    pub fn from_i64(value: i64) -> Float80 {
+        let magnitude = value.unsigned_abs();
+        match magnitude {
+            0 => Float80::zero(false),
+            _ => {
+                let shift = magnitude.leading_zeros();
+                Float80 {
+                    sign: value < 0,
+                    exponent: (EXTENDED_EXPONENT_BIAS + 63 - shift as i32) as u16,
+                    mantissa: magnitude << shift,
+                }
+            }
+        }
+    }
+
+    pub fn from_single(bits: u32) -> Float80 {
+        let sign = bits & 0x80000000 != 0;
+        let exponent = ((bits >> 23) & 0xff) as i32;
+        let fraction = (bits & 0x007fffff) as u64;
+        match exponent {
+            0xff => Float80 {
+                sign,
+                exponent: EXTENDED_EXPONENT_MAX,
+                mantissa: match fraction {
+                    0 => 0,
+                    _ => MANTISSA_INTEGER_BIT | (fraction << 40),
+                },
+            },
+            0 => Float80::from_exact(sign, -149, fraction),
+            _ => Float80::from_exact(sign, exponent - 127 - 23, fraction | 0x00800000),
+        }
+    }
+
+    pub fn from_double(bits: u64) -> Float80 {
+        let sign = bits & 0x8000000000000000 != 0;
+        let exponent = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = bits & 0x000fffffffffffff;
+        match exponent {
+            0x7ff => Float80 {
+                sign,
+                exponent: EXTENDED_EXPONENT_MAX,
+                mantissa: match fraction {
+                    0 => 0,
+                    _ => MANTISSA_INTEGER_BIT | (fraction << 11),
+                },
+            },
+            0 => Float80::from_exact(sign, -1074, fraction),
+            _ => Float80::from_exact(sign, exponent - 1023 - 52, fraction | 0x0010000000000000),
+        }
+    }
+
+    // sign * integer * 2^exponent, for values that are known to fit the extended format
+    fn from_exact(sign: bool, exponent: i32, integer: u64) -> Float80 {
+        match integer {
+            0 => Float80::zero(sign),
+            _ => {
+                let shift = integer.leading_zeros();
+                Float80 {
+                    sign,
+                    exponent: (exponent + EXTENDED_EXPONENT_BIAS + 63 - shift as i32) as u16,
+                    mantissa: integer << shift,
+                }
+            }
+        }
+    }
+
+    pub fn from_f64(value: f64) -> Float80 {
+        Float80::from_double(value.to_bits())
+    }
+
+    // Nearest double, used for the functions that are calculated with the host's library
+    pub fn to_f64(self) -> f64 {
+        let mut context = FloatContext::new(RoundingMode::Nearest, RoundingPrecision::Extended);
+        f64::from_bits(context.convert_to_double(self))
+    }
+}
+
+// Rounding mode and precision of an operation, collecting the exceptions it raises
+pub struct FloatContext {
+    pub rounding_mode: RoundingMode,
+    pub rounding_precision: RoundingPrecision,
+    pub exceptions: u8,
+}
+
+// Shifts right, folding the bits that are shifted out into the lowest bit
+fn shift_right_jamming(value: u128, shift: u32) -> u128 {
+    match shift {
+        0 => value,
+        1..=127 => (value >> shift) | ((value & ((1 << shift) - 1) != 0) as u128),
+        _ => (value != 0) as u128,
+    }
+}
+
+impl FloatContext {
+    pub fn new(rounding_mode: RoundingMode, rounding_precision: RoundingPrecision) -> FloatContext {
+        FloatContext {
+            rounding_mode,
+            rounding_precision,
+            exceptions: 0,
+        }
+    }
+
+    fn get_precision_format(&self) -> &'static FloatFormat {
+        match self.rounding_precision {
+            RoundingPrecision::Extended => &FORMAT_EXTENDED,
+            RoundingPrecision::Single => &FORMAT_EXTENDED_SINGLE_PRECISION,
+            RoundingPrecision::Double => &FORMAT_EXTENDED_DOUBLE_PRECISION,
+        }
+    }
+
+    // Rounds away the lowest bits of the value, returns the kept bits and if anything was lost
+    fn round_bits(&self, sign: bool, value: u128, shift: u32) -> (u128, bool) {
+        let (kept, remainder, half) = match shift {
+            0 => return (value, false),
+            1..=127 => (
+                value >> shift,
+                value & ((1 << shift) - 1),
+                1u128 << (shift - 1),
+            ),
+            128 => (0, value, 1u128 << 127),
+            // Less than half of the lowest kept bit
+            _ => (0, (value != 0) as u128, u128::MAX),
+        };
+        let inexact = remainder != 0;
+        let round_up = match self.rounding_mode {
+            RoundingMode::Nearest => remainder > half || (remainder == half && kept & 1 == 1),
+            RoundingMode::Zero => false,
+            RoundingMode::MinusInfinity => inexact && sign,
+            RoundingMode::PlusInfinity => inexact && !sign,
+        };
+        match round_up {
+            true => (kept + 1, inexact),
+            false => (kept, inexact),
+        }
+    }
+
+    // Rounds mantissa * 2^(exponent - 127) to the format. Returns the biased exponent and the
+    // mantissa with its top bit in bit 63.
+    fn round_pack(
+        &mut self,
+        sign: bool,
+        exponent: i32,
+        mantissa: u128,
+        format: &FloatFormat,
+    ) -> (i32, u64) {
+        let shift = mantissa.leading_zeros();
+        let mut mantissa = mantissa << shift;
+        let mut biased = exponent - shift as i32 + format.exponent_bias;
+        let tiny = biased <= 0;
+        if tiny {
+            mantissa = shift_right_jamming(mantissa, (1 - biased) as u32);
+            biased = 0;
+        }
+        let (mut kept, inexact) = self.round_bits(sign, mantissa, 128 - format.mantissa_bits);
+        if kept >> format.mantissa_bits != 0 {
+            kept >>= 1;
+            biased += 1;
+        } else if biased == 0 && kept >> (format.mantissa_bits - 1) != 0 {
+            // A denormal that was rounded up to the smallest normal
+            biased = 1;
+        }
+        if inexact {
+            self.exceptions |= FLOAT_EXCEPTION_INEX2;
+            if tiny {
+                self.exceptions |= FLOAT_EXCEPTION_UNFL;
+            }
+        }
+        if biased >= format.exponent_max {
+            self.exceptions |= FLOAT_EXCEPTION_OVFL | FLOAT_EXCEPTION_INEX2;
+            let infinity = match self.rounding_mode {
+                RoundingMode::Nearest => true,
+                RoundingMode::Zero => false,
+                RoundingMode::MinusInfinity => sign,
+                RoundingMode::PlusInfinity => !sign,
+            };
+            return match infinity {
+                true => (format.exponent_max, 0),
+                false => (
+                    format.exponent_max - 1,
+                    u64::MAX << (64 - format.mantissa_bits),
+                ),
+            };
+        }
+        (biased, (kept as u64) << (64 - format.mantissa_bits))
+    }
+
+    // Rounds the value to the rounding precision, any value (also zero) is accepted
+    fn round_pack_extended(&mut self, sign: bool, exponent: i32, mantissa: u128) -> Float80 {
+        match mantissa {
+            0 => Float80::zero(sign),
+            _ => {
+                let format = self.get_precision_format();
+                let (biased, mantissa) = self.round_pack(sign, exponent, mantissa, format);
+                let mantissa = match biased == EXTENDED_EXPONENT_MAX as i32 {
+                    true => 0,
+                    false => mantissa,
+                };
+                Float80 {
+                    sign,
+                    exponent: biased as u16,
+                    mantissa,
+                }
+            }
+        }
+    }
+
+    fn propagate_nan(&mut self, dest: Float80, source: Float80) -> Float80 {
+        if dest.is_signaling_nan() || source.is_signaling_nan() {
+            self.exceptions |= FLOAT_EXCEPTION_SNAN;
+        }
+        match dest.is_nan() {
+            true => dest.quiet(),
+            false => source.quiet(),
+        }
+    }
+
+    fn invalid_operation(&mut self) -> Float80 {
+        self.exceptions |= FLOAT_EXCEPTION_OPERR;
+        Float80::default_nan()
+    }
+
+    fn exact_zero_sign(&self) -> bool {
+        self.rounding_mode == RoundingMode::MinusInfinity
+    }
+
+    // Rounds a value to the rounding precision, which is what FMOVE to a data register does
+    pub fn round(&mut self, value: Float80) -> Float80 {
+        if value.is_nan() {
+            return self.propagate_nan(value, value);
+        }
+        if value.is_infinity() || value.is_zero() {
+            return value;
+        }
+        let unpacked = value.unpack();
+        self.round_pack_extended(unpacked.sign, unpacked.exponent, unpacked.mantissa)
+    }
+
+    // FTST, only a signaling NaN raises an exception
+    pub fn test(&mut self, source: Float80) -> Float80 {
+        match source.is_nan() {
+            true => self.propagate_nan(source, source),
+            false => source,
+        }
+    }
+
+    pub fn add(&mut self, dest: Float80, source: Float80) -> Float80 {
+        if dest.is_nan() || source.is_nan() {
+            return self.propagate_nan(dest, source);
+        }
+        match (dest.is_infinity(), source.is_infinity()) {
+            (true, true) if dest.sign != source.sign => return self.invalid_operation(),
+            (true, _) => return dest,
+            (_, true) => return source,
+            _ => (),
+        }
+        match (dest.is_zero(), source.is_zero()) {
+            (true, true) => {
+                return match dest.sign == source.sign {
+                    true => dest,
+                    false => Float80::zero(self.exact_zero_sign()),
+                }
+            }
+            (true, false) => return self.round(source),
+            (false, true) => return self.round(dest),
+            _ => (),
+        }
+        let (large, small) = {
+            let dest = dest.unpack();
+            let source = source.unpack();
+            match dest.exponent >= source.exponent {
+                true => (dest, source),
+                false => (source, dest),
+            }
+        };
+        // One bit of headroom for the carry of the addition
+        let exponent = large.exponent + 1;
+        let large_mantissa = large.mantissa >> 1;
+        let small_mantissa = shift_right_jamming(
+            small.mantissa >> 1,
+            (large.exponent - small.exponent) as u32,
+        );
+        match large.sign == small.sign {
+            true => self.round_pack_extended(large.sign, exponent, large_mantissa + small_mantissa),
+            false => match large_mantissa.cmp(&small_mantissa) {
+                std::cmp::Ordering::Equal => Float80::zero(self.exact_zero_sign()),
+                std::cmp::Ordering::Greater => {
+                    self.round_pack_extended(large.sign, exponent, large_mantissa - small_mantissa)
+                }
+                std::cmp::Ordering::Less => {
+                    self.round_pack_extended(small.sign, exponent, small_mantissa - large_mantissa)
+                }
+            },
+        }
+    }
+
+    pub fn sub(&mut self, dest: Float80, source: Float80) -> Float80 {
+        match source.is_nan() {
+            true => self.propagate_nan(dest, source),
+            false => self.add(dest, source.negate()),
+        }
+    }
+
+    pub fn mul(&mut self, dest: Float80, source: Float80) -> Float80 {
+        if dest.is_nan() || source.is_nan() {
+            return self.propagate_nan(dest, source);
+        }
+        let sign = dest.sign != source.sign;
+        if dest.is_infinity() || source.is_infinity() {
+            return match dest.is_zero() || source.is_zero() {
+                true => self.invalid_operation(),
+                false => Float80::infinity(sign),
+            };
+        }
+        if dest.is_zero() || source.is_zero() {
+            return Float80::zero(sign);
+        }
+        let dest = dest.unpack();
+        let source = source.unpack();
+        let product = (dest.mantissa >> 64) * (source.mantissa >> 64);
+        self.round_pack_extended(sign, dest.exponent + source.exponent + 1, product)
+    }
+
+    pub fn div(&mut self, dest: Float80, source: Float80) -> Float80 {
+        if dest.is_nan() || source.is_nan() {
+            return self.propagate_nan(dest, source);
+        }
+        let sign = dest.sign != source.sign;
+        match (dest.is_infinity(), source.is_infinity()) {
+            (true, true) => return self.invalid_operation(),
+            (true, false) => return Float80::infinity(sign),
+            (false, true) => return Float80::zero(sign),
+            _ => (),
+        }
+        match (dest.is_zero(), source.is_zero()) {
+            (true, true) => return self.invalid_operation(),
+            (true, false) => return Float80::zero(sign),
+            (false, true) => {
+                self.exceptions |= FLOAT_EXCEPTION_DZ;
+                return Float80::infinity(sign);
+            }
+            _ => (),
+        }
+        let dest = dest.unpack();
+        let source = source.unpack();
+        let divisor = source.mantissa >> 64;
+        let dividend_shift = match dest.mantissa >> 64 >= divisor {
+            true => 63,
+            false => 64,
+        };
+        // Two steps of long division give 128 quotient bits, the final remainder is jammed
+        let dividend = (dest.mantissa >> 64) << dividend_shift;
+        let quotient_high = dividend / divisor;
+        let remainder = dividend % divisor;
+        let quotient_low = (remainder << 64) / divisor;
+        let remainder = (remainder << 64) % divisor;
+        let quotient = (quotient_high << 64) | quotient_low | (remainder != 0) as u128;
+        self.round_pack_extended(
+            sign,
+            dest.exponent - source.exponent + 63 - dividend_shift,
+            quotient,
+        )
+    }
+
+    pub fn sqrt(&mut self, source: Float80) -> Float80 {
+        if source.is_nan() {
+            return self.propagate_nan(source, source);
+        }
+        if source.is_zero() {
+            return source;
+        }
+        if source.sign {
+            return self.invalid_operation();
+        }
+        if source.is_infinity() {
+            return source;
+        }
+        let source = source.unpack();
+        // Value is radicand * 2^exponent with an even exponent
+        let mut exponent = source.exponent - 63;
+        let mut radicand = source.mantissa >> 64;
+        if exponent & 1 != 0 {
+            radicand <<= 1;
+            exponent -= 1;
+        }
+        // Square root bit by bit, of the radicand followed by 128 zero bits
+        let mut root: u128 = 0;
+        let mut remainder: u128 = 0;
+        for i in (0..97).rev() {
+            let bit_pos = 2 * i;
+            let bits = match bit_pos >= 128 {
+                true => (radicand >> (bit_pos - 128)) & 0b11,
+                false => 0,
+            };
+            remainder = (remainder << 2) | bits;
+            let trial = (root << 2) | 1;
+            if remainder >= trial {
+                remainder -= trial;
+                root = (root << 1) | 1;
+            } else {
+                root <<= 1;
+            }
+        }
+        let root = root | (remainder != 0) as u128;
+        self.round_pack_extended(false, (exponent - 128) / 2 + 127, root)
+    }
+
+    // FMOD (quotient rounded toward zero) and FREM (quotient rounded to nearest). Returns the
+    // remainder and the sign and lowest 7 bits of the quotient, for the FPSR quotient byte.
+    pub fn remainder(&mut self, dest: Float80, source: Float80, nearest: bool) -> (Float80, u8) {
+        let quotient_sign = match dest.sign != source.sign {
+            true => 0x80,
+            false => 0x00,
+        };
+        if dest.is_nan() || source.is_nan() {
+            return (self.propagate_nan(dest, source), 0);
+        }
+        if dest.is_infinity() || source.is_zero() {
+            return (self.invalid_operation(), 0);
+        }
+        if dest.is_zero() || source.is_infinity() {
+            return (self.round(dest), quotient_sign);
+        }
+        let dest = dest.unpack();
+        let source = source.unpack();
+        let divisor = source.mantissa >> 64;
+        let mut remainder = dest.mantissa >> 64;
+        let mut sign = dest.sign;
+        let mut quotient: u64 = 0;
+        // The remainder is remainder * 2^(remainder_exponent - 63)
+        let mut remainder_exponent = dest.exponent;
+        if dest.exponent >= source.exponent {
+            for i in 0..=(dest.exponent - source.exponent) {
+                if i > 0 {
+                    remainder <<= 1;
+                    quotient <<= 1;
+                }
+                if remainder >= divisor {
+                    remainder -= divisor;
+                    quotient |= 1;
+                }
+            }
+            remainder_exponent = source.exponent;
+            if nearest
+                && (remainder << 1 > divisor || (remainder << 1 == divisor && quotient & 1 == 1))
+            {
+                remainder = divisor - remainder;
+                quotient = quotient.wrapping_add(1);
+                sign = !sign;
+            }
+        } else if nearest && dest.exponent == source.exponent - 1 && remainder > divisor {
+            // Just over half of the divisor, the remainder is in units of the dest exponent
+            remainder = (divisor << 1) - remainder;
+            quotient = 1;
+            sign = !sign;
+        }
+        let quotient_byte = quotient_sign | (quotient & 0x7f) as u8;
+        let result = match remainder {
+            0 => Float80::zero(dest.sign),
+            _ => self.round_pack_extended(sign, remainder_exponent + 127 - 63, remainder),
+        };
+        (result, quotient_byte)
+    }
+
+    // FINT with the rounding mode, FINTRZ with round to zero
+    pub fn round_to_integer(&mut self, source: Float80, rounding_mode: RoundingMode) -> Float80 {
+        if source.is_nan() {
+            return self.propagate_nan(source, source);
+        }
+        if source.is_infinity() || source.is_zero() {
+            return source;
+        }
+        let unpacked = source.unpack();
+        let fraction_bits = 63 - (unpacked.exponent.min(63));
+        if fraction_bits == 0 {
+            return self.round(source);
+        }
+        let context = FloatContext::new(rounding_mode, self.rounding_precision);
+        let (integer, inexact) =
+            context.round_bits(unpacked.sign, unpacked.mantissa >> 64, fraction_bits as u32);
+        if inexact {
+            self.exceptions |= FLOAT_EXCEPTION_INEX2;
+        }
+        match integer {
+            0 => Float80::zero(unpacked.sign),
+            _ => self.round_pack_extended(unpacked.sign, 127, integer),
+        }
+    }
+
+    // Conversion to a signed integer of the given number of bits. Values that don't fit are an
+    // operand error and give the largest integer of the same sign.
+    pub fn convert_to_integer(&mut self, source: Float80, bits: u32) -> i64 {
+        let max = i64::MAX >> (64 - bits);
+        let min = !max;
+        let saturated = match source.sign {
+            true => min,
+            false => max,
+        };
+        if source.is_nan() || source.is_infinity() {
+            self.exceptions |= FLOAT_EXCEPTION_OPERR;
+            return saturated;
+        }
+        if source.is_zero() {
+            return 0;
+        }
+        let unpacked = source.unpack();
+        if unpacked.exponent >= 63 {
+            self.exceptions |= FLOAT_EXCEPTION_OPERR;
+            return saturated;
+        }
+        let (integer, inexact) = self.round_bits(
+            unpacked.sign,
+            unpacked.mantissa >> 64,
+            (63 - unpacked.exponent) as u32,
+        );
+        let value = match unpacked.sign {
+            true => -(integer as i128),
+            false => integer as i128,
+        };
+        if value > max as i128 || value < min as i128 {
+            self.exceptions |= FLOAT_EXCEPTION_OPERR;
+            return saturated;
+        }
+        if inexact {
+            self.exceptions |= FLOAT_EXCEPTION_INEX2;
+        }
+        value as i64
+    }
+
+    fn convert_to_binary(&mut self, source: Float80, format: &FloatFormat) -> (bool, i32, u64) {
+        if source.is_nan() {
+            if source.is_signaling_nan() {
+                self.exceptions |= FLOAT_EXCEPTION_SNAN;
+            }
+            return (
+                source.sign,
+                format.exponent_max,
+                source.mantissa | MANTISSA_QUIET_NAN,
+            );
+        }
+        if source.is_infinity() {
+            return (source.sign, format.exponent_max, 0);
+        }
+        if source.is_zero() {
+            return (source.sign, 0, 0);
+        }
+        let unpacked = source.unpack();
+        let (biased, mantissa) =
+            self.round_pack(unpacked.sign, unpacked.exponent, unpacked.mantissa, format);
+        (unpacked.sign, biased, mantissa)
+    }
+
+    pub fn convert_to_single(&mut self, source: Float80) -> u32 {
+        let (sign, biased, mantissa) = self.convert_to_binary(source, &FORMAT_SINGLE);
+        let fraction = match biased == FORMAT_SINGLE.exponent_max && !source.is_nan() {
+            true => 0,
+            false => ((mantissa >> 40) & 0x007fffff) as u32,
+        };
+        ((sign as u32) << 31) | ((biased as u32) << 23) | fraction
+    }
+
+    pub fn convert_to_double(&mut self, source: Float80) -> u64 {
+        let (sign, biased, mantissa) = self.convert_to_binary(source, &FORMAT_DOUBLE);
+        let fraction = match biased == FORMAT_DOUBLE.exponent_max && !source.is_nan() {
+            true => 0,
+            false => (mantissa >> 11) & 0x000fffffffffffff,
+        };
+        ((sign as u64) << 63) | ((biased as u64) << 52) | fraction
+    }
+
+    // Condition code result of dest - source: a zero for equal values, otherwise a value with
+    // the sign of the difference
+    pub fn compare(&mut self, dest: Float80, source: Float80) -> Float80 {
+        if dest.is_nan() || source.is_nan() {
+            return self.propagate_nan(dest, source);
+        }
+        if dest.is_infinity() && source.is_infinity() && dest.sign == source.sign {
+            return Float80::zero(dest.sign);
+        }
+        if dest.is_zero() && source.is_zero() {
+            return Float80::zero(dest.sign && !source.sign);
+        }
+        let mut context = FloatContext::new(RoundingMode::Nearest, RoundingPrecision::Extended);
+        context.add(dest, source.negate())
+    }
+
+    pub fn abs(&mut self, source: Float80) -> Float80 {
+        match source.is_nan() {
+            true => self.propagate_nan(source, source),
+            false => self.round(Float80 {
+                sign: false,
+                ..source
+            }),
+        }
+    }
+
+    pub fn neg(&mut self, source: Float80) -> Float80 {
+        match source.is_nan() {
+            true => self.propagate_nan(source, source),
+            false => self.round(source.negate()),
+        }
+    }
+
+    pub fn get_exponent(&mut self, source: Float80) -> Float80 {
+        if source.is_nan() {
+            return self.propagate_nan(source, source);
+        }
+        if source.is_infinity() {
+            return self.invalid_operation();
+        }
+        if source.is_zero() {
+            return source;
+        }
+        let unpacked = source.unpack();
+        Float80::from_i64((unpacked.exponent) as i64)
+    }
+
+    pub fn get_mantissa(&mut self, source: Float80) -> Float80 {
+        if source.is_nan() {
+            return self.propagate_nan(source, source);
+        }
+        if source.is_infinity() {
+            return self.invalid_operation();
+        }
+        if source.is_zero() {
+            return source;
+        }
+        let unpacked = source.unpack();
+        self.round_pack_extended(unpacked.sign, 127, unpacked.mantissa >> 64 << 64)
+    }
+
+    // dest * 2^(source rounded toward zero)
+    pub fn scale(&mut self, dest: Float80, source: Float80) -> Float80 {
+        if dest.is_nan() || source.is_nan() {
+            return self.propagate_nan(dest, source);
+        }
+        if source.is_infinity() {
+            return self.invalid_operation();
+        }
+        if dest.is_infinity() || dest.is_zero() {
+            return dest;
+        }
+        let mut context = FloatContext::new(RoundingMode::Zero, RoundingPrecision::Extended);
+        let scale = context
+            .convert_to_integer(source, 32)
+            .clamp(-0x10000, 0x10000) as i32;
+        let unpacked = dest.unpack();
+        self.round_pack_extended(unpacked.sign, unpacked.exponent + scale, unpacked.mantissa)
+    }
+
+    // FSGLMUL and FSGLDIV round to single precision, whatever the rounding precision
+    pub fn single_mul(&mut self, dest: Float80, source: Float80) -> Float80 {
+        let rounding_precision = self.rounding_precision;
+        self.rounding_precision = RoundingPrecision::Single;
+        let result = self.mul(dest, source);
+        self.rounding_precision = rounding_precision;
+        result
+    }
+
+    pub fn single_div(&mut self, dest: Float80, source: Float80) -> Float80 {
+        let rounding_precision = self.rounding_precision;
+        self.rounding_precision = RoundingPrecision::Single;
+        let result = self.div(dest, source);
+        self.rounding_precision = rounding_precision;
+        result
+    }
+
+    // The transcendental functions are calculated in double precision by the host, so unlike
+    // the basic operations they aren't bit exact
+    pub fn calculate<F>(&mut self, source: Float80, function: F) -> Float80
+    where
+        F: Fn(f64) -> f64,
+    {
+        if source.is_nan() {
+            return self.propagate_nan(source, source);
+        }
+        let result = function(source.to_f64());
+        if result.is_nan() {
+            return self.invalid_operation();
+        }
+        if result.is_infinite() && !source.is_infinity() {
+            // A pole like log(0) or atanh(1) divides by zero, anything else overflowed
+            match source.is_zero() || source.to_f64().abs() == 1.0 {
+                true => self.exceptions |= FLOAT_EXCEPTION_DZ,
+                false => self.exceptions |= FLOAT_EXCEPTION_OVFL | FLOAT_EXCEPTION_INEX2,
+            }
+            return Float80::from_f64(result);
+        }
+        if result == 0.0 && !source.is_zero() && !source.is_infinity() {
+            self.exceptions |= FLOAT_EXCEPTION_UNFL | FLOAT_EXCEPTION_INEX2;
+        }
+        let result = Float80::from_f64(result);
+        if !result.is_zero() && !result.is_infinity() {
+            self.exceptions |= FLOAT_EXCEPTION_INEX2;
+        }
+        self.round(result)
+    }
+
+    pub fn pow10(&mut self, exponent: u32) -> Float80 {
+        let mut result = Float80::from_i64(1);
+        let mut power = Float80::from_i64(10);
+        let mut exponent = exponent;
+        while exponent != 0 {
+            if exponent & 1 != 0 {
+                result = self.mul(result, power);
+            }
+            power = self.mul(power, power);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    // Packed decimal real: sign of the mantissa and the exponent, three exponent digits, one
+    // integer digit and sixteen fraction digits, all BCD
+    pub fn convert_from_packed(&mut self, words: [u32; 3]) -> Float80 {
+        let sign = words[0] & 0x80000000 != 0;
+        if words[0] & 0x7fff0000 == 0x7fff0000 {
+            let mantissa = ((words[1] as u64) << 32) | words[2] as u64;
+            return Float80 {
+                sign,
+                exponent: EXTENDED_EXPONENT_MAX,
+                mantissa,
+            };
+        }
+        let mut digits: u64 = (words[0] & 0xf) as u64;
+        for word in &words[1..] {
+            for shift in (0..8).rev() {
+                digits = digits * 10 + ((word >> (shift * 4)) & 0xf) as u64;
+            }
+        }
+        if digits == 0 {
+            return Float80::zero(sign);
+        }
+        let mut exponent: i32 = 0;
+        for shift in [24, 20, 16] {
+            exponent = exponent * 10 + ((words[0] >> shift) & 0xf) as i32;
+        }
+        if words[0] & 0x40000000 != 0 {
+            exponent = -exponent;
+        }
+        // Digits is the mantissa with its sixteen fraction digits as an integer
+        let exponent = exponent - 16;
+        let mut context = FloatContext::new(self.rounding_mode, RoundingPrecision::Extended);
+        let integer = Float80::from_i64(digits as i64);
+        let power = context.pow10(exponent.unsigned_abs());
+        let result = match exponent < 0 {
+            true => context.div(integer, power),
+            false => context.mul(integer, power),
+        };
+        if context.exceptions & FLOAT_EXCEPTION_INEX2 != 0 {
+            self.exceptions |= FLOAT_EXCEPTION_INEX1;
+        }
+        Float80 { sign, ..result }
+    }
+
+    // Converts to packed decimal. A positive k-factor is the number of significant digits, zero
+    // or negative the number of digits right of the decimal point.
+    pub fn convert_to_packed(&mut self, source: Float80, k_factor: i32) -> [u32; 3] {
+        let sign_word = match source.sign {
+            true => 0x80000000,
+            false => 0x00000000,
+        };
+        if source.is_nan() || source.is_infinity() {
+            return [
+                sign_word | 0x7fff0000,
+                (source.mantissa >> 32) as u32,
+                source.mantissa as u32,
+            ];
+        }
+        if source.is_zero() {
+            return [sign_word, 0, 0];
+        }
+        if k_factor > 17 {
+            self.exceptions |= FLOAT_EXCEPTION_OPERR;
+        }
+        let unpacked = source.unpack();
+        let magnitude = Float80 {
+            sign: false,
+            ..source
+        };
+        let fraction = (unpacked.mantissa >> 64) as f64 / 9223372036854775808.0;
+        let mut decimal_exponent = ((unpacked.exponent as f64 + fraction.log2())
+            * std::f64::consts::LOG10_2)
+            .floor() as i32;
+        let mut context = FloatContext::new(self.rounding_mode, RoundingPrecision::Extended);
+        let (digits, digit_count) = loop {
+            let digit_count = match k_factor > 0 {
+                true => k_factor.min(17),
+                false => (decimal_exponent + 1 - k_factor).clamp(1, 17),
+            };
+            context.exceptions = 0;
+            let scale = decimal_exponent - (digit_count - 1);
+            let power = context.pow10(scale.unsigned_abs());
+            let scaled = match scale < 0 {
+                true => context.mul(magnitude, power),
+                false => context.div(magnitude, power),
+            };
+            let integer = context.round_to_integer(scaled, self.rounding_mode);
+            let digits = context.convert_to_integer(integer, 64) as u64;
+            // The estimate of the decimal exponent can be one off
+            if digits >= 10u64.pow(digit_count as u32) {
+                decimal_exponent += 1;
+            } else if digits < 10u64.pow(digit_count as u32 - 1) && digits != 0 {
+                decimal_exponent -= 1;
+            } else {
+                break (digits, digit_count);
+            }
+        };
+        self.exceptions |= context.exceptions & FLOAT_EXCEPTION_INEX2;
+        // Seventeen digits, the first one left of the decimal point
+        let digits = digits * 10u64.pow(17 - digit_count as u32);
+        let mut fraction: u64 = 0;
+        for i in 0..16 {
+            fraction |= ((digits / 10u64.pow(i)) % 10) << (i * 4);
+        }
+        let integer_digit = (digits / 10u64.pow(16)) as u32;
+        let exponent = decimal_exponent.unsigned_abs();
+        let exponent_sign = match decimal_exponent < 0 {
+            true => 0x40000000,
+            false => 0x00000000,
+        };
+        let exponent_digits = ((exponent / 100 % 10) << 24)
+            | ((exponent / 10 % 10) << 20)
+            | ((exponent % 10) << 16)
+            | ((exponent / 1000 % 10) << 12);
+        [
+            sign_word | exponent_sign | exponent_digits | integer_digit,
+            (fraction >> 32) as u32,
+            fraction as u32,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> FloatContext {
+        FloatContext::new(RoundingMode::Nearest, RoundingPrecision::Extended)
+    }
+
+    #[test]
+    fn add_one_and_one() {
+        // arrange
+        let mut context = context();
+        // act
+        let result = context.add(Float80::from_i64(1), Float80::from_i64(1));
+        // assert
+        assert_eq!(Float80::from_words(0x4000, 0x8000000000000000), result);
+        assert_eq!(0, context.exceptions);
+    }
+
+    #[test]
+    fn add_rounds_to_nearest_even() {
+        // arrange
+        let mut context = context();
+        // 2^63 + 0.5 is halfway between two extended values
+        let half = Float80::from_words(0x3ffe, 0x8000000000000000);
+        // act
+        let result = context.add(Float80::from_words(0x403e, 0x8000000000000000), half);
+        // assert
+        assert_eq!(Float80::from_words(0x403e, 0x8000000000000000), result);
+        assert_eq!(FLOAT_EXCEPTION_INEX2, context.exceptions);
+    }
+
+    #[test]
+    fn sub_equal_values_is_positive_zero() {
+        // arrange
+        let mut context = context();
+        // act
+        let result = context.sub(Float80::from_i64(3), Float80::from_i64(3));
+        // assert
+        assert_eq!(Float80::zero(false), result);
+    }
+
+    #[test]
+    fn mul_matches_double_arithmetic() {
+        // arrange
+        let mut context = FloatContext::new(RoundingMode::Nearest, RoundingPrecision::Double);
+        // act
+        let result = context.mul(Float80::from_f64(1.1), Float80::from_f64(3.3));
+        // assert
+        assert_eq!(1.1 * 3.3, result.to_f64());
+    }
+
+    #[test]
+    fn div_one_by_three_extended() {
+        // arrange
+        let mut context = context();
+        // act
+        let result = context.div(Float80::from_i64(1), Float80::from_i64(3));
+        // assert
+        assert_eq!(Float80::from_words(0x3ffd, 0xaaaaaaaaaaaaaaab), result);
+        assert_eq!(FLOAT_EXCEPTION_INEX2, context.exceptions);
+    }
+
+    #[test]
+    fn div_by_zero_is_infinity() {
+        // arrange
+        let mut context = context();
+        // act
+        let result = context.div(Float80::from_i64(-1), Float80::zero(false));
+        // assert
+        assert_eq!(Float80::infinity(true), result);
+        assert_eq!(FLOAT_EXCEPTION_DZ, context.exceptions);
+    }
+
+    #[test]
+    fn sqrt_of_two_extended() {
+        // arrange
+        let mut context = context();
+        // act
+        let result = context.sqrt(Float80::from_i64(2));
+        // assert
+        assert_eq!(Float80::from_words(0x3fff, 0xb504f333f9de6484), result);
+    }
+
+    #[test]
+    fn sqrt_of_negative_is_operand_error() {
+        // arrange
+        let mut context = context();
+        // act
+        let result = context.sqrt(Float80::from_i64(-4));
+        // assert
+        assert_eq!(Float80::default_nan(), result);
+        assert_eq!(FLOAT_EXCEPTION_OPERR, context.exceptions);
+    }
+
+    #[test]
+    fn remainder_mod_and_rem() {
+        // arrange
+        let mut context = context();
+        // act
+        let (fmod, fmod_quotient) =
+            context.remainder(Float80::from_i64(11), Float80::from_i64(4), false);
+        let (frem, frem_quotient) =
+            context.remainder(Float80::from_i64(11), Float80::from_i64(4), true);
+        // assert
+        assert_eq!(Float80::from_i64(3), fmod);
+        assert_eq!(2, fmod_quotient);
+        assert_eq!(Float80::from_i64(-1), frem);
+        assert_eq!(3, frem_quotient);
+    }
+
+    #[test]
+    fn to_integer_rounds_and_saturates() {
+        // arrange
+        let mut context = context();
+        // act assert
+        assert_eq!(2, context.convert_to_integer(Float80::from_f64(2.5), 32));
+        assert_eq!(-4, context.convert_to_integer(Float80::from_f64(-3.5), 32));
+        assert_eq!(127, context.convert_to_integer(Float80::from_i64(1000), 8));
+        assert_eq!(
+            FLOAT_EXCEPTION_INEX2 | FLOAT_EXCEPTION_OPERR,
+            context.exceptions
+        );
+    }
+
+    #[test]
+    fn single_and_double_round_trip() {
+        // arrange
+        let mut context = context();
+        // act assert
+        assert_eq!(
+            0x3fc00000,
+            context.convert_to_single(Float80::from_single(0x3fc00000))
+        );
+        assert_eq!(
+            0x00000001,
+            context.convert_to_single(Float80::from_single(0x00000001))
+        );
+        assert_eq!(
+            0x400921fb54442d18,
+            context.convert_to_double(Float80::from_double(0x400921fb54442d18))
+        );
+        assert_eq!(0, context.exceptions);
+    }
+
+    #[test]
+    fn to_single_overflows_to_infinity() {
+        // arrange
+        let mut context = context();
+        // act
+        let result = context.convert_to_single(Float80::from_double(0x47f0000000000000));
+        // assert
+        assert_eq!(0x7f800000, result);
+        assert_eq!(
+            FLOAT_EXCEPTION_OVFL | FLOAT_EXCEPTION_INEX2,
+            context.exceptions
+        );
+    }
+
+    #[test]
+    fn packed_decimal_round_trip() {
+        // arrange
+        let mut context = context();
+        // 1.25E+2
+        let packed = [0x00020001, 0x25000000, 0x00000000];
+        // act
+        let value = context.convert_from_packed(packed);
+        let result = context.convert_to_packed(value, 3);
+        // assert
+        assert_eq!(Float80::from_i64(125), value);
+        assert_eq!(packed, result);
+    }
+
+    #[test]
+    fn packed_decimal_negative_exponent() {
+        // arrange
+        let mut context = context();
+        // act
+        let result = context.convert_to_packed(Float80::from_f64(-0.5), 1);
+        // assert
+        assert_eq!([0xc0010005, 0x00000000, 0x00000000], result);
+    }
+}
@@ -0,0 +1,585 @@
+use super::float80::*;
+use crate::{
+    cpu::{
+        instruction::{OperationSize, StepError},
+        step_log::StepLog,
+        EXCEPTION_VECTOR_FPU_BSUN, EXCEPTION_VECTOR_FPU_DZ, EXCEPTION_VECTOR_FPU_INEX,
+        EXCEPTION_VECTOR_FPU_OPERR, EXCEPTION_VECTOR_FPU_OVFL, EXCEPTION_VECTOR_FPU_SNAN,
+        EXCEPTION_VECTOR_FPU_UNFL,
+    },
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// The 68881/68882 floating point coprocessor, attached to a 68020 as coprocessor 1. The CPU
+// and the FPU talk through the coprocessor interface, which isn't emulated bus cycle by bus
+// cycle. The F-line instructions just use the FPU state in the registers directly.
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FpuModel {
+    M68881,
+    M68882,
+}
+
+// FPSR: condition code byte, quotient byte, exception status byte and accrued exception byte
+pub const FPSR_CONDITION_CODE_N: u32 = 0x08000000;
+pub const FPSR_CONDITION_CODE_Z: u32 = 0x04000000;
+pub const FPSR_CONDITION_CODE_I: u32 = 0x02000000;
+pub const FPSR_CONDITION_CODE_NAN: u32 = 0x01000000;
+const FPSR_CONDITION_CODE_MASK: u32 = 0x0f000000;
+const FPSR_QUOTIENT_MASK: u32 = 0x00ff0000;
+const FPSR_EXCEPTION_MASK: u32 = 0x0000ff00;
+const FPSR_ACCRUED_IOP: u32 = 0x00000080;
+const FPSR_ACCRUED_OVFL: u32 = 0x00000040;
+const FPSR_ACCRUED_UNFL: u32 = 0x00000020;
+const FPSR_ACCRUED_DZ: u32 = 0x00000010;
+const FPSR_ACCRUED_INEX: u32 = 0x00000008;
+pub const FPSR_MASK: u32 = 0x0ffffff8;
+
+// FPCR: exception enable byte, rounding precision and rounding mode
+pub const FPCR_MASK: u32 = 0x0000fff0;
+
+// FSAVE state frames. The null frame is saved until the FPU has executed an instruction after
+// a reset, after that it's idle. Bit 27 of the last long word of the idle frame (the BIU flags)
+// is set while an exception is pending.
+const STATE_FRAME_NULL: u32 = 0x00000000;
+const STATE_FRAME_VERSION: u32 = 0x1f;
+const STATE_FRAME_IDLE_SIZE_68881: u32 = 0x18;
+const STATE_FRAME_IDLE_SIZE_68882: u32 = 0x38;
+const STATE_FRAME_BIU_EXCEPTION_PENDING: u32 = 0x08000000;
+
+// Constant ROM of FMOVECR, rounded to nearest. Offsets that aren't listed read as zero.
+pub fn get_constant(offset: u16) -> (Float80, bool) {
+    let (sign_exponent, mantissa, inexact) = match offset {
+        0x00 => (0x4000, 0xc90fdaa22168c235, true),  // pi
+        0x0b => (0x3ffd, 0x9a209a84fbcff799, true),  // log10(2)
+        0x0c => (0x4000, 0xadf85458a2bb4a9b, true),  // e
+        0x0d => (0x3fff, 0xb8aa3b295c17f0bc, true),  // log2(e)
+        0x0e => (0x3ffd, 0xde5bd8a937287195, true),  // log10(e)
+        0x30 => (0x3ffe, 0xb17217f7d1cf79ac, true),  // ln(2)
+        0x31 => (0x4000, 0x935d8dddaaa8ac17, true),  // ln(10)
+        0x32 => (0x3fff, 0x8000000000000000, false), // 1e0
+        0x33 => (0x4002, 0xa000000000000000, false), // 1e1
+        0x34 => (0x4005, 0xc800000000000000, false), // 1e2
+        0x35 => (0x400c, 0x9c40000000000000, false), // 1e4
+        0x36 => (0x4019, 0xbebc200000000000, false), // 1e8
+        0x37 => (0x4034, 0x8e1bc9bf04000000, false), // 1e16
+        0x38 => (0x4069, 0x9dc5ada82b70b59e, true),  // 1e32
+        0x39 => (0x40d3, 0xc2781f49ffcfa6d5, true),  // 1e64
+        0x3a => (0x41a8, 0x93ba47c980e98ce0, true),  // 1e128
+        0x3b => (0x4351, 0xaa7eebfb9df9de8e, true),  // 1e256
+        0x3c => (0x46a3, 0xe319a0aea60e91c7, true),  // 1e512
+        0x3d => (0x4d48, 0xc976758681750c17, true),  // 1e1024
+        0x3e => (0x5a92, 0x9e8b3b5dc53d5de5, true),  // 1e2048
+        0x3f => (0x7525, 0xc46052028a20979b, true),  // 1e4096
+        _ => (0x0000, 0x0000000000000000, false),
+    };
+    (Float80::from_words(sign_exponent, mantissa), inexact)
+}
+
+pub struct Fpu {
+    pub fpu_model: FpuModel,
+    pub reg_fp: [Float80; 8],
+    pub reg_fpcr: u32,
+    pub reg_fpsr: u32,
+    pub reg_fpiar: u32,
+    null_state: bool,
+    // An enabled exception of an arithmetic instruction isn't taken right away, it's reported
+    // when the next FPU instruction starts (a pre-instruction exception)
+    pending_exception_vector: Option<u32>,
+}
+
+impl Fpu {
+    pub fn new(fpu_model: FpuModel) -> Fpu {
+        Fpu {
+            fpu_model,
+            reg_fp: [Float80::default_nan(); 8],
+            reg_fpcr: 0x00000000,
+            reg_fpsr: 0x00000000,
+            reg_fpiar: 0x00000000,
+            null_state: true,
+            pending_exception_vector: None,
+        }
+    }
+
+    // Hardware reset, also what restoring a null state frame does
+    pub fn reset(&mut self) {
+        *self = Fpu::new(self.fpu_model);
+    }
+
+    pub fn get_context(&self) -> FloatContext {
+        let rounding_precision = match (self.reg_fpcr >> 6) & 0b11 {
+            0b01 => RoundingPrecision::Single,
+            0b10 => RoundingPrecision::Double,
+            _ => RoundingPrecision::Extended,
+        };
+        let rounding_mode = match (self.reg_fpcr >> 4) & 0b11 {
+            0b00 => RoundingMode::Nearest,
+            0b01 => RoundingMode::Zero,
+            0b10 => RoundingMode::MinusInfinity,
+            _ => RoundingMode::PlusInfinity,
+        };
+        FloatContext::new(rounding_mode, rounding_precision)
+    }
+
+    // Any FPU instruction leaves the null state
+    pub fn leave_null_state(&mut self) {
+        self.null_state = false;
+    }
+
+    pub fn take_pending_exception(&mut self) -> Result<(), StepError> {
+        match self.pending_exception_vector.take() {
+            Some(vector) => Err(StepError::FloatingPointException { vector }),
+            None => Ok(()),
+        }
+    }
+
+    // Start of an arithmetic instruction (or FMOVE out), which has the exception status byte
+    // cleared and its address in FPIAR
+    pub fn begin_operation(&mut self, instruction_address: u32) {
+        self.null_state = false;
+        self.reg_fpsr &= !FPSR_EXCEPTION_MASK;
+        self.reg_fpiar = instruction_address;
+    }
+
+    pub fn end_operation(&mut self, exceptions: u8) {
+        let exceptions = exceptions as u32;
+        self.reg_fpsr |= exceptions << 8;
+        let exception = |mask: u8| exceptions & mask as u32 != 0;
+        if exception(FLOAT_EXCEPTION_BSUN | FLOAT_EXCEPTION_SNAN | FLOAT_EXCEPTION_OPERR) {
+            self.reg_fpsr |= FPSR_ACCRUED_IOP;
+        }
+        if exception(FLOAT_EXCEPTION_OVFL) {
+            self.reg_fpsr |= FPSR_ACCRUED_OVFL;
+        }
+        if exception(FLOAT_EXCEPTION_UNFL) && exception(FLOAT_EXCEPTION_INEX2) {
+            self.reg_fpsr |= FPSR_ACCRUED_UNFL;
+        }
+        if exception(FLOAT_EXCEPTION_DZ) {
+            self.reg_fpsr |= FPSR_ACCRUED_DZ;
+        }
+        if exception(FLOAT_EXCEPTION_INEX1 | FLOAT_EXCEPTION_INEX2 | FLOAT_EXCEPTION_OVFL) {
+            self.reg_fpsr |= FPSR_ACCRUED_INEX;
+        }
+        let enabled = (exceptions & (self.reg_fpcr >> 8)) as u8;
+        self.pending_exception_vector = Fpu::get_exception_vector(enabled);
+    }
+
+    // The highest priority enabled exception
+    fn get_exception_vector(enabled: u8) -> Option<u32> {
+        [
+            (FLOAT_EXCEPTION_BSUN, EXCEPTION_VECTOR_FPU_BSUN),
+            (FLOAT_EXCEPTION_SNAN, EXCEPTION_VECTOR_FPU_SNAN),
+            (FLOAT_EXCEPTION_OPERR, EXCEPTION_VECTOR_FPU_OPERR),
+            (FLOAT_EXCEPTION_OVFL, EXCEPTION_VECTOR_FPU_OVFL),
+            (FLOAT_EXCEPTION_UNFL, EXCEPTION_VECTOR_FPU_UNFL),
+            (FLOAT_EXCEPTION_DZ, EXCEPTION_VECTOR_FPU_DZ),
+            (
+                FLOAT_EXCEPTION_INEX2 | FLOAT_EXCEPTION_INEX1,
+                EXCEPTION_VECTOR_FPU_INEX,
+            ),
+        ]
+        .iter()
+        .find(|(mask, _)| enabled & mask != 0)
+        .map(|(_, vector)| *vector)
+    }
+
+    pub fn set_condition_codes(&mut self, value: Float80) {
+        let mut condition_codes = 0;
+        if value.sign {
+            condition_codes |= FPSR_CONDITION_CODE_N;
+        }
+        if value.is_zero() {
+            condition_codes |= FPSR_CONDITION_CODE_Z;
+        }
+        if value.is_infinity() {
+            condition_codes |= FPSR_CONDITION_CODE_I;
+        }
+        if value.is_nan() {
+            condition_codes |= FPSR_CONDITION_CODE_NAN;
+        }
+        self.reg_fpsr = (self.reg_fpsr & !FPSR_CONDITION_CODE_MASK) | condition_codes;
+    }
+
+    pub fn set_quotient_byte(&mut self, quotient_byte: u8) {
+        self.reg_fpsr = (self.reg_fpsr & !FPSR_QUOTIENT_MASK) | ((quotient_byte as u32) << 16);
+    }
+
+    // The conditional predicates of FBcc, FDBcc, FScc and FTRAPcc. The upper 16 are the same
+    // as the lower 16, but signal a branch/set on unordered when the NAN condition code is
+    // set, which traps right away if it's enabled.
+    pub fn evaluate_condition(&mut self, condition: u16) -> Result<bool, StepError> {
+        let n = self.reg_fpsr & FPSR_CONDITION_CODE_N != 0;
+        let z = self.reg_fpsr & FPSR_CONDITION_CODE_Z != 0;
+        let nan = self.reg_fpsr & FPSR_CONDITION_CODE_NAN != 0;
+        if condition & 0x10 != 0 && nan {
+            self.reg_fpsr |= ((FLOAT_EXCEPTION_BSUN as u32) << 8) | FPSR_ACCRUED_IOP;
+            if self.reg_fpcr & ((FLOAT_EXCEPTION_BSUN as u32) << 8) != 0 {
+                return Err(StepError::FloatingPointException {
+                    vector: EXCEPTION_VECTOR_FPU_BSUN,
+                });
+            }
+        }
+        let result = match condition & 0x0f {
+            0x0 => false,
+            0x1 => z,
+            0x2 => !(nan || z || n),
+            0x3 => z || !(nan || n),
+            0x4 => n && !(nan || z),
+            0x5 => z || (n && !nan),
+            0x6 => !(nan || z),
+            0x7 => !nan,
+            0x8 => nan,
+            0x9 => nan || z,
+            0xa => nan || !(n || z),
+            0xb => nan || z || !n,
+            0xc => nan || (n && !z),
+            0xd => nan || z || n,
+            0xe => !z,
+            _ => true,
+        };
+        Ok(result)
+    }
+
+    pub fn get_state_frame(&self) -> Vec<u32> {
+        if self.null_state {
+            return vec![STATE_FRAME_NULL];
+        }
+        let size = match self.fpu_model {
+            FpuModel::M68881 => STATE_FRAME_IDLE_SIZE_68881,
+            FpuModel::M68882 => STATE_FRAME_IDLE_SIZE_68882,
+        };
+        let mut frame = vec![0x00000000; 1 + size as usize / 4];
+        frame[0] = (STATE_FRAME_VERSION << 24) | (size << 16);
+        if self.pending_exception_vector.is_some() {
+            frame[size as usize / 4] = STATE_FRAME_BIU_EXCEPTION_PENDING;
+        }
+        frame
+    }
+
+    // Size in bytes of the rest of the state frame, after the format word. An invalid format
+    // word is a format error.
+    pub fn get_state_frame_size(&self, format_word: u32) -> Result<u32, StepError> {
+        let size = (format_word >> 16) & 0xff;
+        let idle_size = match self.fpu_model {
+            FpuModel::M68881 => STATE_FRAME_IDLE_SIZE_68881,
+            FpuModel::M68882 => STATE_FRAME_IDLE_SIZE_68882,
+        };
+        match (format_word >> 24, size) {
+            (0x00, _) => Ok(0),
+            (STATE_FRAME_VERSION, size) if size == idle_size => Ok(size),
+            _ => Err(StepError::FormatError),
+        }
+    }
+
+    pub fn restore_state_frame(&mut self, frame: &[u32]) {
+        match frame[0] {
+            STATE_FRAME_NULL => self.reset(),
+            _ => {
+                self.null_state = false;
+                let biu_flags = frame[frame.len() - 1];
+                self.pending_exception_vector =
+                    match biu_flags & STATE_FRAME_BIU_EXCEPTION_PENDING != 0 {
+                        true => Fpu::get_exception_vector(
+                            ((self.reg_fpsr & (self.reg_fpcr & 0xff00)) >> 8) as u8,
+                        ),
+                        false => None,
+                    };
+            }
+        }
+    }
+}
+
+pub fn get_condition_name(condition: u16) -> &'static str {
+    [
+        "F", "EQ", "OGT", "OGE", "OLT", "OLE", "OGL", "OR", "UN", "UEQ", "UGT", "UGE", "ULT",
+        "ULE", "NE", "T", "SF", "SEQ", "GT", "GE", "LT", "LE", "GL", "GLE", "NGLE", "NGL", "NLE",
+        "NLT", "NGE", "NGT", "SNE", "ST",
+    ][(condition & 0x1f) as usize]
+}
+
+// Conditional predicates 0x20-0x3f are undefined
+pub fn is_valid_condition(condition: u16) -> bool {
+    condition & 0x3f < 0x20
+}
+
+pub fn get_fpu(reg: &mut Register) -> Result<&mut Fpu, StepError> {
+    // Without an FPU the F-line instructions trap to the line 1111 emulator vector
+    match reg.fpu.as_mut() {
+        Some(fpu) => Ok(fpu),
+        None => Err(StepError::IllegalInstruction),
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FpuDataFormat {
+    Long,
+    Single,
+    Extended,
+    Packed,
+    Word,
+    Double,
+    Byte,
+}
+
+impl FpuDataFormat {
+    pub fn from_bits(bits: u16) -> Option<FpuDataFormat> {
+        match bits & 0b111 {
+            0b000 => Some(FpuDataFormat::Long),
+            0b001 => Some(FpuDataFormat::Single),
+            0b010 => Some(FpuDataFormat::Extended),
+            0b011 => Some(FpuDataFormat::Packed),
+            0b100 => Some(FpuDataFormat::Word),
+            0b101 => Some(FpuDataFormat::Double),
+            0b110 => Some(FpuDataFormat::Byte),
+            _ => None,
+        }
+    }
+
+    pub fn size_in_bytes(&self) -> u32 {
+        match self {
+            FpuDataFormat::Byte => 1,
+            FpuDataFormat::Word => 2,
+            FpuDataFormat::Long | FpuDataFormat::Single => 4,
+            FpuDataFormat::Double => 8,
+            FpuDataFormat::Extended | FpuDataFormat::Packed => 12,
+        }
+    }
+
+    pub fn get_format(&self) -> char {
+        match self {
+            FpuDataFormat::Long => 'L',
+            FpuDataFormat::Single => 'S',
+            FpuDataFormat::Extended => 'X',
+            FpuDataFormat::Packed => 'P',
+            FpuDataFormat::Word => 'W',
+            FpuDataFormat::Double => 'D',
+            FpuDataFormat::Byte => 'B',
+        }
+    }
+
+    // Only the formats of up to a long word fit a data register
+    pub fn is_valid_for_data_register(&self) -> bool {
+        self.size_in_bytes() <= 4
+    }
+
+    // Operand as up to three long words, the way it's laid out in memory
+    pub fn get_value(&self, context: &mut FloatContext, words: [u32; 3]) -> Float80 {
+        match self {
+            FpuDataFormat::Long => Float80::from_i64(words[0] as i32 as i64),
+            FpuDataFormat::Word => Float80::from_i64(words[0] as u16 as i16 as i64),
+            FpuDataFormat::Byte => Float80::from_i64(words[0] as u8 as i8 as i64),
+            FpuDataFormat::Single => Float80::from_single(words[0]),
+            FpuDataFormat::Double => {
+                Float80::from_double(((words[0] as u64) << 32) | words[1] as u64)
+            }
+            FpuDataFormat::Extended => Float80::from_words(
+                (words[0] >> 16) as u16,
+                ((words[1] as u64) << 32) | words[2] as u64,
+            ),
+            FpuDataFormat::Packed => context.convert_from_packed(words),
+        }
+    }
+
+    pub fn get_words(&self, context: &mut FloatContext, value: Float80, k_factor: i32) -> [u32; 3] {
+        match self {
+            FpuDataFormat::Long => [context.convert_to_integer(value, 32) as u32, 0, 0],
+            FpuDataFormat::Word => [context.convert_to_integer(value, 16) as u32 & 0xffff, 0, 0],
+            FpuDataFormat::Byte => [context.convert_to_integer(value, 8) as u32 & 0xff, 0, 0],
+            FpuDataFormat::Single => [context.convert_to_single(value), 0, 0],
+            FpuDataFormat::Double => {
+                let bits = context.convert_to_double(value);
+                [(bits >> 32) as u32, bits as u32, 0]
+            }
+            FpuDataFormat::Extended => [
+                (value.get_sign_exponent_word() as u32) << 16,
+                (value.mantissa >> 32) as u32,
+                value.mantissa as u32,
+            ],
+            FpuDataFormat::Packed => context.convert_to_packed(value, k_factor),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FpuOperandLocation {
+    DataRegister(usize),
+    AddressRegister(usize),
+    Memory(u32),
+}
+
+// Resolves the <ea> of an F-line instruction. The operands can be 8 or 12 bytes, which the
+// integer addressing modes don't know about, so the postincrement, predecrement and immediate
+// modes are handled here.
+pub fn get_operand_location(
+    instr_word: u16,
+    size_in_bytes: u32,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<FpuOperandLocation, StepError> {
+    let ea_register = (instr_word & 0b111) as usize;
+    // A byte on the stack still takes a word
+    let step = match (size_in_bytes, ea_register) {
+        (1, 7) => 2,
+        _ => size_in_bytes,
+    };
+    match (instr_word >> 3) & 0b111 {
+        0b000 => Ok(FpuOperandLocation::DataRegister(ea_register)),
+        0b001 => Ok(FpuOperandLocation::AddressRegister(ea_register)),
+        0b011 => {
+            let address = reg.get_a_reg_long(ea_register, step_log);
+            reg.set_a_reg_long(step_log, ea_register, address.wrapping_add(step));
+            Ok(FpuOperandLocation::Memory(address))
+        }
+        0b100 => {
+            let address = reg.get_a_reg_long(ea_register, step_log).wrapping_sub(step);
+            reg.set_a_reg_long(step_log, ea_register, address);
+            Ok(FpuOperandLocation::Memory(address))
+        }
+        0b111 if ea_register == 0b100 => {
+            // Immediate data follows the extension words, a byte is in the low half of a word
+            let address = pc.get_address_next();
+            for _ in 0..size_in_bytes.div_ceil(2) {
+                pc.skip_word();
+            }
+            match size_in_bytes {
+                1 => Ok(FpuOperandLocation::Memory(address.wrapping_add(1))),
+                _ => Ok(FpuOperandLocation::Memory(address)),
+            }
+        }
+        _ => {
+            let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+                instr_word,
+                reg,
+                mem,
+                step_log,
+                |_| Ok(OperationSize::Long),
+            )?;
+            Ok(FpuOperandLocation::Memory(
+                ea_data.get_address(pc, reg, mem, step_log),
+            ))
+        }
+    }
+}
+
+pub fn read_operand(
+    location: FpuOperandLocation,
+    size_in_bytes: u32,
+    reg: &mut Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<[u32; 3], StepError> {
+    match location {
+        FpuOperandLocation::DataRegister(register) => {
+            Ok([reg.get_d_reg_long(register, step_log), 0, 0])
+        }
+        FpuOperandLocation::AddressRegister(register) => {
+            Ok([reg.get_a_reg_long(register, step_log), 0, 0])
+        }
+        FpuOperandLocation::Memory(address) => {
+            let mut words = [0; 3];
+            match size_in_bytes {
+                1 => words[0] = mem.get_byte(step_log, address)? as u32,
+                2 => words[0] = mem.get_word(step_log, address)? as u32,
+                _ => {
+                    for (i, word) in words
+                        .iter_mut()
+                        .take(size_in_bytes as usize / 4)
+                        .enumerate()
+                    {
+                        *word = mem.get_long(step_log, address.wrapping_add(i as u32 * 4))?;
+                    }
+                }
+            }
+            Ok(words)
+        }
+    }
+}
+
+pub fn write_operand(
+    location: FpuOperandLocation,
+    size_in_bytes: u32,
+    words: [u32; 3],
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<(), StepError> {
+    match location {
+        FpuOperandLocation::DataRegister(register) => {
+            match size_in_bytes {
+                1 => reg.set_d_reg_byte(step_log, register, words[0] as u8),
+                2 => reg.set_d_reg_word(step_log, register, words[0] as u16),
+                _ => reg.set_d_reg_long(step_log, register, words[0]),
+            };
+            Ok(())
+        }
+        FpuOperandLocation::AddressRegister(register) => {
+            reg.set_a_reg_long(step_log, register, words[0]);
+            Ok(())
+        }
+        FpuOperandLocation::Memory(address) => match size_in_bytes {
+            1 => mem.set_byte(step_log, address, words[0] as u8),
+            2 => mem.set_word(step_log, address, words[0] as u16),
+            _ => {
+                for (i, word) in words.iter().take(size_in_bytes as usize / 4).enumerate() {
+                    mem.set_long(step_log, address.wrapping_add(i as u32 * 4), *word)?;
+                }
+                Ok(())
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enabled_exception_is_pending_until_the_next_instruction() {
+        // arrange
+        let mut fpu = Fpu::new(FpuModel::M68881);
+        fpu.reg_fpcr = (FLOAT_EXCEPTION_DZ as u32) << 8;
+        // act
+        fpu.begin_operation(0x00C00000);
+        fpu.end_operation(FLOAT_EXCEPTION_DZ);
+        // assert
+        assert_eq!(0x00000410, fpu.reg_fpsr);
+        assert!(matches!(
+            fpu.take_pending_exception(),
+            Err(StepError::FloatingPointException {
+                vector: EXCEPTION_VECTOR_FPU_DZ
+            })
+        ));
+        assert!(fpu.take_pending_exception().is_ok());
+    }
+
+    #[test]
+    fn signaling_predicate_sets_bsun_on_nan() {
+        // arrange
+        let mut fpu = Fpu::new(FpuModel::M68881);
+        fpu.set_condition_codes(Float80::default_nan());
+        // act
+        let ordered_equal = fpu.evaluate_condition(0x01).ok().unwrap();
+        let unordered = fpu.evaluate_condition(0x18).ok().unwrap();
+        // assert
+        assert!(!ordered_equal);
+        assert!(unordered);
+        assert_eq!(0x01008080, fpu.reg_fpsr);
+    }
+
+    #[test]
+    fn state_frame_of_idle_68882() {
+        // arrange
+        let mut fpu = Fpu::new(FpuModel::M68882);
+        // act
+        let null_frame = fpu.get_state_frame();
+        fpu.leave_null_state();
+        let idle_frame = fpu.get_state_frame();
+        // assert
+        assert_eq!(vec![0x00000000], null_frame);
+        assert_eq!(15, idle_frame.len());
+        assert_eq!(0x1f380000, idle_frame[0]);
+    }
+}
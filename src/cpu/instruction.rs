@@ -1,61 +1,98 @@
 use std::fmt::{self, Display};
 
-use super::step_log::StepLog;
+use super::{step_log::StepLog, BusCycleFault, Cpu, CpuModel};
 use crate::{
     mem::Mem,
     register::{ProgramCounter, Register, RegisterType},
 };
 
+pub mod abcd;
 pub mod add;
 pub mod addi;
 pub mod addq;
 pub mod addx;
 pub mod and;
 pub mod andi;
+pub mod andi_to_ccr;
 pub mod andi_to_sr;
 pub mod aslr;
 pub mod bcc;
+pub mod bchg;
 pub mod bclr;
+pub mod bitfield;
+pub mod bkpt;
 pub mod bra;
 pub mod bset;
 pub mod bsr;
 pub mod btst;
+pub mod cas;
+pub mod chk2_cmp2;
+pub mod chk;
 pub mod clr;
 pub mod cmp;
 pub mod cmpi;
 pub mod cmpm;
 pub mod dbcc;
+pub mod divl;
 pub mod divs;
 pub mod divu;
 pub mod eor;
+pub mod eori;
+pub mod eori_to_ccr;
+pub mod eori_to_sr;
 pub mod exg;
 pub mod ext;
+pub mod extb;
+pub mod fbcc;
+pub mod fdbcc;
+pub mod fgen;
+pub mod frestore;
+pub mod fsave;
+pub mod fscc;
+pub mod ftrapcc;
 pub mod gen_tests;
+pub mod illegal;
 pub mod jmp;
 pub mod jsr;
 pub mod lea;
 pub mod link;
 pub mod lslr;
 pub mod mov;
+pub mod move_from_ccr;
 pub mod move_from_sr;
+pub mod move_to_ccr;
 pub mod move_to_sr;
 pub mod move_usp;
 pub mod movec;
 pub mod movem;
+pub mod movep;
 pub mod moveq;
+pub mod moves;
+pub mod mull;
+pub mod muls;
 pub mod mulu;
+pub mod nbcd;
 pub mod neg;
+pub mod negx;
 pub mod nop;
 pub mod not;
 pub mod or;
 pub mod ori;
+pub mod ori_to_ccr;
 pub mod ori_to_sr;
+pub mod pack;
 pub mod pea;
+pub mod pmmu;
 pub mod reset;
 pub mod rolrmem;
 pub mod rolrreg;
+pub mod roxlrmem;
+pub mod roxlrreg;
+pub mod rtd;
 pub mod rte;
+pub mod rtr;
 pub mod rts;
+pub mod sbcd;
 pub mod scc;
 pub mod stop;
 pub mod sub;
@@ -63,8 +100,13 @@ pub mod subi;
 pub mod subq;
 pub mod subx;
 pub mod swap;
+pub mod tas;
+pub mod trap;
+pub mod trapcc;
+pub mod trapv;
 pub mod tst;
 pub mod unlk;
+pub mod unpk;
 
 pub struct InstructionError {
     pub details: String,
@@ -72,13 +114,21 @@ pub struct InstructionError {
 
 #[allow(non_camel_case_types)]
 pub enum StepError {
-    AccessFault,
-    AddressError,
+    // Bus error on the 68000/68010
+    AccessFault { fault: BusCycleFault },
+    AddressError { fault: BusCycleFault },
     IllegalInstruction,
     IntegerDivideByZero,
     CHK_CHK2_Instruction,
     FRAPcc_TRAPcc_TRAPV_Instruction,
     PriviliegeViolation,
+    TRAP_Instruction { trap_number: u8 },
+    // RTE found an invalid format/vector word on a 68010+
+    FormatError,
+    // Enabled FPU exception, or a branch/set on unordered
+    FloatingPointException { vector: u32 },
+    // PMOVE of an invalid value to TC, CRP or SRP on the 68030
+    MmuConfigurationError,
     // InstructionError isn't an actual hardware error. This error
     // is probably the result of an unimplemented instruction or an
     // instruction that is incorrectly implemented. And if not, this
@@ -94,8 +144,12 @@ pub enum StepError {
 impl Display for StepError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            StepError::AccessFault => write!(f, "AccessFault"),
-            StepError::AddressError => write!(f, "AddressError"),
+            StepError::AccessFault { fault } => {
+                write!(f, "AccessFault at ${:08X}", fault.address)
+            }
+            StepError::AddressError { fault } => {
+                write!(f, "AddressError at ${:08X}", fault.address)
+            }
             StepError::IllegalInstruction => write!(f, "IllegalInstruction"),
             StepError::IntegerDivideByZero => write!(f, "IntegerDivideByZero"),
             StepError::CHK_CHK2_Instruction => write!(f, "CHK_CHK2_Instruction"),
@@ -103,6 +157,14 @@ impl Display for StepError {
                 write!(f, "FRAPcc_TRAPcc_TRAPV_Instruction")
             }
             StepError::PriviliegeViolation => write!(f, "PriviliegeViolation"),
+            StepError::TRAP_Instruction { trap_number } => {
+                write!(f, "TRAP_Instruction #{}", trap_number)
+            }
+            StepError::FormatError => write!(f, "FormatError"),
+            StepError::FloatingPointException { vector } => {
+                write!(f, "FloatingPointException vector {}", vector)
+            }
+            StepError::MmuConfigurationError => write!(f, "MmuConfigurationError"),
             StepError::InstructionError { details } => write!(f, "InstructionError: {}", details),
             StepError::Stop => write!(f, "Stop"),
         }
@@ -116,6 +178,13 @@ impl From<InstructionError> for StepError {
     }
 }
 
+// Result of a successfully executed instruction
+pub struct StepResult {
+    // Clock cycles used by the instruction on the 68000, including the effective address
+    // calculation time
+    pub cycles: u32,
+}
+
 pub struct GetDisassemblyResultError {
     pub details: String,
 }
@@ -128,6 +197,14 @@ impl From<InstructionError> for GetDisassemblyResultError {
     }
 }
 
+impl From<StepError> for GetDisassemblyResultError {
+    fn from(error: StepError) -> Self {
+        GetDisassemblyResultError {
+            details: error.to_string(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
 pub struct GetDisassemblyResult {
     pub address: u32,
@@ -238,11 +315,30 @@ pub enum EffectiveAddressingMode {
         index_size: OperationSize,
         scale_factor: ScaleFactor,
     },
-    // TODO: 020+ CPU's below
-    // ARegIndirectWithIndex8BitDisplacement{register: usize}, // 0b110       (d8, An, Xn.SIZE*SCALE)
-    // ARegIndirectWithIndexBaseDisplacement{register: usize}, // 0b110       (bd, An, Xn.SIZE*SCALE)
-    // MemoryIndirectPostIndexed{register: usize},             // 0b110       ([bd, An], Xn.SIZE*SCALE,od)
-    // MemoryIndirectPreIndexed{register: usize},              // 0b110       ([bd, An, Xn.SIZE*SCALE],od)
+    // The 020+ full extension word formats below. A suppressed base register is None.
+    ARegIndirectWithIndexBaseDisplacement {
+        // 0b110       (bd, An, Xn.SIZE*SCALE)
+        ea_register: Option<usize>,
+        ea_address: u32,
+        base_displacement: Displacement,
+        index: Option<IndexRegister>,
+    },
+    MemoryIndirectPostIndexed {
+        // 0b110       ([bd, An], Xn.SIZE*SCALE, od)
+        ea_register: Option<usize>,
+        ea_address: u32,
+        base_displacement: Displacement,
+        index: Option<IndexRegister>,
+        outer_displacement: Displacement,
+    },
+    MemoryIndirectPreIndexed {
+        // 0b110       ([bd, An, Xn.SIZE*SCALE], od)
+        ea_register: Option<usize>,
+        ea_address: u32,
+        base_displacement: Displacement,
+        index: Option<IndexRegister>,
+        outer_displacement: Displacement,
+    },
     PcIndirectWithDisplacement {
         //                                0b111 0b010 (d16, PC)
         ea_address: u32,
@@ -259,11 +355,30 @@ pub enum EffectiveAddressingMode {
         index_size: OperationSize,
         scale_factor: ScaleFactor,
     },
-    // TODO: 020+ CPU's below
-    // PcIndirectWithIndex8BitDisplacement{register: usize},   // 0b111 0b011 (d8, PC, Xn.SIZE*SCALE)
-    // PcIndirectWithIndexBaseDisplacement{register: usize},   // 0b111 0b011 (bd, PC, Xn.SIZE*SCALE)
-    // PcMemoryInderectPostIndexed{register: usize},           // 0b111 0b011 ([bd, PC], Xn.SIZE*SCALE,od)
-    // PcMemoryInderectPreIndexed{register: usize},            // 0b111 0b011 ([bd, PC, Xn.SIZE*SCALE],od)
+    // The 020+ full extension word formats below. pc_suppressed is shown as ZPC.
+    PcIndirectWithIndexBaseDisplacement {
+        // 0b111 0b011 (bd, PC, Xn.SIZE*SCALE)
+        pc_suppressed: bool,
+        ea_address: u32,
+        base_displacement: Displacement,
+        index: Option<IndexRegister>,
+    },
+    PcMemoryIndirectPostIndexed {
+        // 0b111 0b011 ([bd, PC], Xn.SIZE*SCALE, od)
+        pc_suppressed: bool,
+        ea_address: u32,
+        base_displacement: Displacement,
+        index: Option<IndexRegister>,
+        outer_displacement: Displacement,
+    },
+    PcMemoryIndirectPreIndexed {
+        // 0b111 0b011 ([bd, PC, Xn.SIZE*SCALE], od)
+        pc_suppressed: bool,
+        ea_address: u32,
+        base_displacement: Displacement,
+        index: Option<IndexRegister>,
+        outer_displacement: Displacement,
+    },
     AbsoluteShortAddressing {
         //                                   0b111 0b000 (xxx).W
         ea_address: u32,
@@ -287,6 +402,48 @@ pub enum EffectiveAddressingMode {
     },
 }
 
+impl EffectiveAddressingMode {
+    // Effective address calculation time on the 68000. The time includes fetching the operand,
+    // and is the same for reading and writing except for MOVE destinations.
+    pub fn get_calculation_cycles(&self, operation_size: OperationSize) -> u32 {
+        let (cycles_byte_word, cycles_long) = match self {
+            EffectiveAddressingMode::DRegDirect { .. } => (0, 0),
+            EffectiveAddressingMode::ARegDirect { .. } => (0, 0),
+            EffectiveAddressingMode::ARegIndirect { .. } => (4, 8),
+            EffectiveAddressingMode::ARegIndirectWithPostIncrement { .. } => (4, 8),
+            EffectiveAddressingMode::ARegIndirectWithPreDecrement { .. } => (6, 10),
+            EffectiveAddressingMode::ARegIndirectWithDisplacement { .. } => (8, 12),
+            EffectiveAddressingMode::ARegIndirectWithIndexOrMemoryIndirect { .. } => (10, 14),
+            EffectiveAddressingMode::PcIndirectWithDisplacement { .. } => (8, 12),
+            EffectiveAddressingMode::PcIndirectWithIndexOrPcMemoryIndirect { .. } => (10, 14),
+            // Only on the 68020+, where the timing isn't modeled
+            EffectiveAddressingMode::ARegIndirectWithIndexBaseDisplacement { .. }
+            | EffectiveAddressingMode::MemoryIndirectPostIndexed { .. }
+            | EffectiveAddressingMode::MemoryIndirectPreIndexed { .. }
+            | EffectiveAddressingMode::PcIndirectWithIndexBaseDisplacement { .. }
+            | EffectiveAddressingMode::PcMemoryIndirectPostIndexed { .. }
+            | EffectiveAddressingMode::PcMemoryIndirectPreIndexed { .. } => (10, 14),
+            EffectiveAddressingMode::AbsoluteShortAddressing { .. } => (8, 12),
+            EffectiveAddressingMode::AbsolutLongAddressing { .. } => (12, 16),
+            EffectiveAddressingMode::ImmediateDataByte { .. } => (4, 8),
+            EffectiveAddressingMode::ImmediateDataWord { .. } => (4, 8),
+            EffectiveAddressingMode::ImmediateDataLong { .. } => (4, 8),
+        };
+        match operation_size {
+            OperationSize::Long => cycles_long,
+            _ => cycles_byte_word,
+        }
+    }
+
+    pub fn is_register_direct(&self) -> bool {
+        match self {
+            EffectiveAddressingMode::DRegDirect { .. }
+            | EffectiveAddressingMode::ARegDirect { .. } => true,
+            _ => false,
+        }
+    }
+}
+
 impl Display for EffectiveAddressingMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
@@ -419,6 +576,50 @@ impl Display for EffectiveAddressingMode {
                     data
                 )
             }
+            EffectiveAddressingMode::ARegIndirectWithIndexBaseDisplacement {
+                ea_address, ..
+            } => {
+                write!(
+                    f,
+                    "EffectiveAddressingMode::ARegIndirectWithIndexBaseDisplacement [${:08X}]",
+                    ea_address
+                )
+            }
+            EffectiveAddressingMode::MemoryIndirectPostIndexed { ea_address, .. } => {
+                write!(
+                    f,
+                    "EffectiveAddressingMode::MemoryIndirectPostIndexed [${:08X}]",
+                    ea_address
+                )
+            }
+            EffectiveAddressingMode::MemoryIndirectPreIndexed { ea_address, .. } => {
+                write!(
+                    f,
+                    "EffectiveAddressingMode::MemoryIndirectPreIndexed [${:08X}]",
+                    ea_address
+                )
+            }
+            EffectiveAddressingMode::PcIndirectWithIndexBaseDisplacement { ea_address, .. } => {
+                write!(
+                    f,
+                    "EffectiveAddressingMode::PcIndirectWithIndexBaseDisplacement [${:08X}]",
+                    ea_address
+                )
+            }
+            EffectiveAddressingMode::PcMemoryIndirectPostIndexed { ea_address, .. } => {
+                write!(
+                    f,
+                    "EffectiveAddressingMode::PcMemoryIndirectPostIndexed [${:08X}]",
+                    ea_address
+                )
+            }
+            EffectiveAddressingMode::PcMemoryIndirectPreIndexed { ea_address, .. } => {
+                write!(
+                    f,
+                    "EffectiveAddressingMode::PcMemoryIndirectPreIndexed [${:08X}]",
+                    ea_address
+                )
+            }
         }
     }
 }
@@ -502,6 +703,56 @@ impl Display for ScaleFactor {
     }
 }
 
+// Index register of the 020+ full extension word formats
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct IndexRegister {
+    pub register_type: RegisterType,
+    pub register: usize,
+    pub index_size: OperationSize,
+    pub scale_factor: ScaleFactor,
+}
+
+impl Display for IndexRegister {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}{}.{}{}",
+            self.register_type.get_format(),
+            self.register,
+            self.index_size.get_format(),
+            self.scale_factor
+        )
+    }
+}
+
+// Base or outer displacement of the 020+ full extension word formats
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Displacement {
+    Null,
+    Word(u16),
+    Long(u32),
+}
+
+impl Displacement {
+    pub fn get_value(&self) -> u32 {
+        match self {
+            Displacement::Null => 0,
+            Displacement::Word(displacement) => Cpu::sign_extend_word(*displacement),
+            Displacement::Long(displacement) => *displacement,
+        }
+    }
+}
+
+impl Display for Displacement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Displacement::Null => write!(f, "0"),
+            Displacement::Word(displacement) => write!(f, "${:04X}.W", displacement),
+            Displacement::Long(displacement) => write!(f, "${:08X}.L", displacement),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ConditionalTest {
     /// True (1)
@@ -602,6 +853,8 @@ pub struct Instruction {
     //       selected, but has been replace by adding match_checks to instruction code
     pub ex_mask: u16,
     pub ex_code: Vec<u16>,
+    // The first CPU model that decodes the instruction, on earlier models the opcode is illegal
+    pub minimum_cpu_model: CpuModel,
     pub match_check: fn(instruction: &Instruction, instr_word: u16) -> bool,
     pub step: fn(
         instr_word: u16,
@@ -609,7 +862,7 @@ pub struct Instruction {
         reg: &mut Register,
         mem: &mut Mem,
         step_log: &mut StepLog,
-    ) -> Result<(), StepError>,
+    ) -> Result<StepResult, StepError>,
     pub get_disassembly: fn(
         instr_word: u16,
         pc: &mut ProgramCounter,
@@ -631,7 +884,7 @@ impl Instruction {
             reg: &mut Register,
             mem: &mut Mem,
             step_log: &mut StepLog,
-        ) -> Result<(), StepError>,
+        ) -> Result<StepResult, StepError>,
         get_disassembly: fn(
             instr_word: u16,
             pc: &mut ProgramCounter,
@@ -646,10 +899,16 @@ impl Instruction {
             opcode,
             ex_mask: 0x0000,
             ex_code: vec![],
+            minimum_cpu_model: CpuModel::M68000,
             match_check,
             step,
             get_disassembly,
         };
         instr
     }
+
+    pub fn with_minimum_cpu_model(mut self, minimum_cpu_model: CpuModel) -> Instruction {
+        self.minimum_cpu_model = minimum_cpu_model;
+        self
+    }
 }
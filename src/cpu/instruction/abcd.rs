@@ -0,0 +1,105 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, OperationSize, StepError, StepResult};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register, RegisterType},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let register_type = match instr_word & 0x0008 {
+        0x0008 => RegisterType::Address,
+        _ => RegisterType::Data,
+    };
+    let source_register_index = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    let destination_register_index = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
+    let status_register_result = match register_type {
+        RegisterType::Data => {
+            let value_1 = reg.get_d_reg_byte(source_register_index, step_log);
+            let value_2 = reg.get_d_reg_byte(destination_register_index, step_log);
+            let result =
+                Cpu::add_bcd_bytes_with_extend(value_1, value_2, reg.reg_sr.is_sr_extend_set());
+            reg.set_d_reg_byte(step_log, destination_register_index, result.result);
+            result.status_register_result
+        }
+        RegisterType::Address => {
+            reg.decrement_a_reg(source_register_index, step_log, OperationSize::Byte);
+            let areg_1 = reg.get_a_reg_long(source_register_index, step_log);
+            let value_1 = mem.get_byte(step_log, areg_1)?;
+
+            reg.decrement_a_reg(destination_register_index, step_log, OperationSize::Byte);
+            let areg_2 = reg.get_a_reg_long(destination_register_index, step_log);
+            let value_2 = mem.get_byte(step_log, areg_2)?;
+
+            let result =
+                Cpu::add_bcd_bytes_with_extend(value_1, value_2, reg.reg_sr.is_sr_extend_set());
+
+            mem.set_byte(step_log, areg_2, result.result)?;
+            result.status_register_result
+        }
+    };
+
+    let cycles = match register_type {
+        RegisterType::Data => 6,
+        RegisterType::Address => 18,
+    };
+
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let register_type = match instr_word & 0x0008 {
+        0x0008 => RegisterType::Address,
+        _ => RegisterType::Data,
+    };
+    let source_register_index = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    let destination_register_index = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
+    match register_type {
+        RegisterType::Data => Ok(GetDisassemblyResult::from_pc(
+            pc,
+            mem,
+            String::from("ABCD.B"),
+            format!(
+                "{}{},{}{}",
+                register_type.get_format(),
+                source_register_index,
+                register_type.get_format(),
+                destination_register_index,
+            ),
+        )),
+        RegisterType::Address => Ok(GetDisassemblyResult::from_pc(
+            pc,
+            mem,
+            String::from("ABCD.B"),
+            format!(
+                "-({}{}),-({}{})",
+                register_type.get_format(),
+                source_register_index,
+                register_type.get_format(),
+                destination_register_index,
+            ),
+        )),
+    }
+}
@@ -1,7 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
 use crate::cpu::{Cpu, StatusRegisterResult};
 use crate::mem::Mem;
 use crate::register::{ProgramCounter, Register};
@@ -54,7 +56,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let opmode = Cpu::extract_op_mode_from_bit_pos_6(instr_word);
     let operation_size = match opmode {
         ADD_BYTE_DN_AS_DEST => OperationSize::Byte,
@@ -80,7 +82,7 @@ pub fn step<'a>(
 
     let status_register_result = match opmode {
         ADD_BYTE_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_byte(register, step_log);
             let add_result = Cpu::add_bytes(ea_value, reg_value);
 
@@ -88,7 +90,7 @@ pub fn step<'a>(
             add_result.status_register_result
         }
         ADD_WORD_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_word(register, step_log);
             let add_result = Cpu::add_words(ea_value, reg_value);
 
@@ -96,7 +98,7 @@ pub fn step<'a>(
             add_result.status_register_result
         }
         ADD_LONG_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_long(register, step_log);
             let add_result = Cpu::add_longs(ea_value, reg_value);
 
@@ -104,28 +106,28 @@ pub fn step<'a>(
             add_result.status_register_result
         }
         ADD_BYTE_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_byte(register, step_log);
             let add_result = Cpu::add_bytes(ea_value, reg_value);
-            ea_data.set_value_byte(pc, reg, mem, step_log, add_result.result, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, add_result.result, true)?;
             add_result.status_register_result
         }
         ADD_WORD_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_word(register, step_log);
             let add_result = Cpu::add_words(ea_value, reg_value);
-            ea_data.set_value_word(pc, reg, mem, step_log, add_result.result, true);
+            ea_data.set_value_word(pc, reg, mem, step_log, add_result.result, true)?;
             add_result.status_register_result
         }
         ADD_LONG_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_long(register, step_log);
             let add_result = Cpu::add_longs(ea_value, reg_value);
-            ea_data.set_value_long(pc, reg, mem, step_log, add_result.result, true);
+            ea_data.set_value_long(pc, reg, mem, step_log, add_result.result, true)?;
             add_result.status_register_result
         }
         ADDA_WORD => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
             let ea_value = Cpu::sign_extend_word(ea_value);
             let reg_value = reg.get_a_reg_long(register, step_log);
             let add_result = Cpu::add_longs(ea_value, reg_value);
@@ -134,7 +136,7 @@ pub fn step<'a>(
             StatusRegisterResult::cleared()
         }
         ADDA_LONG => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_a_reg_long(register, step_log);
             let add_result = Cpu::add_longs(ea_value, reg_value);
 
@@ -144,10 +146,18 @@ pub fn step<'a>(
         _ => panic!("Unhandled ea_opmode"),
     };
 
+    let cycles = match opmode {
+        ADDA_WORD | ADDA_LONG => timing::get_ea_to_an_cycles(&ea_data),
+        ADD_BYTE_EA_AS_DEST | ADD_WORD_EA_AS_DEST | ADD_LONG_EA_AS_DEST => {
+            timing::get_dn_to_ea_cycles(&ea_data)
+        }
+        _ => timing::get_ea_to_dn_cycles(&ea_data),
+    };
+
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,9 +1,9 @@
 use super::{
     EffectiveAddressingMode, GetDisassemblyResult, GetDisassemblyResultError, Instruction,
-    OperationSize, StepError,
+    OperationSize, StepError, StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
+    cpu::{step_log::StepLog, timing, Cpu, StatusRegisterResult},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -33,7 +33,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -47,28 +47,28 @@ pub fn step<'a>(
     let data = Cpu::extract_3_bit_data_1_to_8_from_word_at_pos(ea_data.instr_word, 9);
     let status_register_result = match ea_data.operation_size {
         OperationSize::Byte => {
-            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
             let add_result = Cpu::add_bytes(data, ea_value);
-            ea_data.set_value_byte(pc, reg, mem, step_log, add_result.result, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, add_result.result, true)?;
             add_result.status_register_result
         }
         OperationSize::Word => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
             if let EffectiveAddressingMode::ARegDirect { ea_register } = ea_data.ea_mode {
                 let ea_value = Cpu::sign_extend_word(ea_value);
                 let add_result = Cpu::add_longs(data as u32, ea_value);
-                ea_data.set_value_long(pc, reg, mem, step_log, add_result.result, true);
+                ea_data.set_value_long(pc, reg, mem, step_log, add_result.result, true)?;
                 StatusRegisterResult::cleared()
             } else {
                 let add_result = Cpu::add_words(data as u16, ea_value);
-                ea_data.set_value_word(pc, reg, mem, step_log, add_result.result, true);
+                ea_data.set_value_word(pc, reg, mem, step_log, add_result.result, true)?;
                 add_result.status_register_result
             }
         }
         OperationSize::Long => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, false)?;
             let add_result = Cpu::add_longs(data as u32, ea_value);
-            ea_data.set_value_long(pc, reg, mem, step_log, add_result.result, true);
+            ea_data.set_value_long(pc, reg, mem, step_log, add_result.result, true)?;
             if let EffectiveAddressingMode::ARegDirect { ea_register } = ea_data.ea_mode {
                 StatusRegisterResult::cleared()
             } else {
@@ -80,7 +80,8 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = timing::get_quick_cycles(&ea_data);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,5 +1,6 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
     cpu::{step_log::StepLog, Cpu},
@@ -29,7 +30,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let register_type = match instr_word & 0x0008 {
         0x0008 => RegisterType::Address,
         _ => RegisterType::Data,
@@ -68,54 +69,61 @@ pub fn step<'a>(
             OperationSize::Byte => {
                 reg.decrement_a_reg(source_register_index, step_log, operation_size);
                 let areg_1 = reg.get_a_reg_long(source_register_index, step_log);
-                let value_1 = mem.get_byte(step_log, areg_1);
+                let value_1 = mem.get_byte(step_log, areg_1)?;
 
                 reg.decrement_a_reg(destination_register_index, step_log, operation_size);
                 let areg_2 = reg.get_a_reg_long(destination_register_index, step_log);
-                let value_2 = mem.get_byte(step_log, areg_2);
+                let value_2 = mem.get_byte(step_log, areg_2)?;
 
                 let result =
                     Cpu::add_bytes_with_extend(value_1, value_2, reg.reg_sr.is_sr_extend_set());
 
-                mem.set_byte(step_log, areg_2, result.result);
+                mem.set_byte(step_log, areg_2, result.result)?;
                 result.status_register_result
             }
             OperationSize::Word => {
                 reg.decrement_a_reg(source_register_index, step_log, operation_size);
                 let areg_1 = reg.get_a_reg_long(source_register_index, step_log);
-                let value_1 = mem.get_word(step_log, areg_1);
+                let value_1 = mem.get_word(step_log, areg_1)?;
 
                 reg.decrement_a_reg(destination_register_index, step_log, operation_size);
                 let areg_2 = reg.get_a_reg_long(destination_register_index, step_log);
-                let value_2 = mem.get_word(step_log, areg_2);
+                let value_2 = mem.get_word(step_log, areg_2)?;
 
                 let result =
                     Cpu::add_words_with_extend(value_1, value_2, reg.reg_sr.is_sr_extend_set());
 
-                mem.set_word(step_log, areg_2, result.result);
+                mem.set_word(step_log, areg_2, result.result)?;
                 result.status_register_result
             }
             OperationSize::Long => {
                 reg.decrement_a_reg(source_register_index, step_log, operation_size);
                 let areg_1 = reg.get_a_reg_long(source_register_index, step_log);
-                let value_1 = mem.get_long(step_log, areg_1);
+                let value_1 = mem.get_long(step_log, areg_1)?;
 
                 reg.decrement_a_reg(destination_register_index, step_log, operation_size);
                 let areg_2 = reg.get_a_reg_long(destination_register_index, step_log);
-                let value_2 = mem.get_long(step_log, areg_2);
+                let value_2 = mem.get_long(step_log, areg_2)?;
 
                 let result =
                     Cpu::add_longs_with_extend(value_1, value_2, reg.reg_sr.is_sr_extend_set());
 
-                mem.set_long(step_log, areg_2, result.result);
+                mem.set_long(step_log, areg_2, result.result)?;
                 result.status_register_result
             }
         },
     };
 
+    let cycles = match (register_type, operation_size) {
+        (RegisterType::Data, OperationSize::Long) => 8,
+        (RegisterType::Data, _) => 4,
+        (RegisterType::Address, OperationSize::Long) => 30,
+        (RegisterType::Address, _) => 18,
+    };
+
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
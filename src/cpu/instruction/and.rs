@@ -1,7 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
 use crate::cpu::Cpu;
 use crate::mem::Mem;
 use crate::register::{ProgramCounter, Register};
@@ -49,7 +51,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let opmode = Cpu::extract_op_mode_from_bit_pos_6(instr_word);
     let operation_size = match opmode {
         BYTE_WITH_DN_AS_DEST => OperationSize::Byte,
@@ -72,7 +74,7 @@ pub fn step<'a>(
 
     let status_register_result = match opmode {
         BYTE_WITH_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_byte(register, step_log);
             let add_result = Cpu::and_bytes(ea_value, reg_value);
 
@@ -80,7 +82,7 @@ pub fn step<'a>(
             add_result.status_register_result
         }
         WORD_WITH_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_word(register, step_log);
             let add_result = Cpu::and_words(ea_value, reg_value);
 
@@ -88,7 +90,7 @@ pub fn step<'a>(
             add_result.status_register_result
         }
         LONG_WITH_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_long(register, step_log);
             let add_result = Cpu::and_longs(ea_value, reg_value);
 
@@ -96,33 +98,40 @@ pub fn step<'a>(
             add_result.status_register_result
         }
         BYTE_WITH_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_byte(register, step_log);
             let add_result = Cpu::and_bytes(ea_value, reg_value);
-            ea_data.set_value_byte(pc, reg, mem, step_log, add_result.result, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, add_result.result, true)?;
             add_result.status_register_result
         }
         WORD_WITH_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_word(register, step_log);
             let add_result = Cpu::and_words(ea_value, reg_value);
-            ea_data.set_value_word(pc, reg, mem, step_log, add_result.result, true);
+            ea_data.set_value_word(pc, reg, mem, step_log, add_result.result, true)?;
             add_result.status_register_result
         }
         LONG_WITH_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_long(register, step_log);
             let add_result = Cpu::and_longs(ea_value, reg_value);
-            ea_data.set_value_long(pc, reg, mem, step_log, add_result.result, true);
+            ea_data.set_value_long(pc, reg, mem, step_log, add_result.result, true)?;
             add_result.status_register_result
         }
         _ => panic!("Unhandled ea_opmode"),
     };
 
+    let cycles = match opmode {
+        BYTE_WITH_EA_AS_DEST | WORD_WITH_EA_AS_DEST | LONG_WITH_EA_AS_DEST => {
+            timing::get_dn_to_ea_cycles(&ea_data)
+        }
+        _ => timing::get_ea_to_dn_cycles(&ea_data),
+    };
+
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::cpu::step_log::StepLog;
 use crate::cpu::Cpu;
 use crate::mem::Mem;
@@ -19,7 +19,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     match reg.reg_sr.is_sr_supervisor_set(step_log) {
         true => {
             let immediate_data = pc.fetch_next_word(mem);
@@ -30,7 +30,7 @@ pub fn step<'a>(
 
             reg.reg_sr.set_value(result.result);
 
-            Ok(())
+            Ok(StepResult { cycles: 20 })
         }
         false => Err(StepError::PriviliegeViolation),
     }
@@ -69,7 +69,7 @@ mod tests {
         // arrange
         let code = [0x02, 0x7c, 0x00, 0x00].to_vec(); // ANDI.W #$0000,SR
         let mut mm = crate::tests::instr_test_setup(code, None);
-        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(0xffff);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(0x7fff); // Trace off
         // act assert - debug
         let debug_result = mm.get_next_disassembly_no_log();
         assert_eq!(
@@ -99,7 +99,7 @@ mod tests {
         // arrange
         let code = [0x02, 0x7c, 0xff, 0xff].to_vec(); // ANDI.W #$FFFF,SR
         let mut mm = crate::tests::instr_test_setup(code, None);
-        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(0xffff);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(0x7fff); // Trace off
         // act assert - debug
         let debug_result = mm.get_next_disassembly_no_log();
         assert_eq!(
@@ -115,7 +115,7 @@ mod tests {
         // act
         mm.step();
         // assert
-        assert_eq!(0xffff, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x7fff, mm.cpu.register.reg_sr.get_value());
         assert_eq!(true, mm.cpu.register.reg_sr.is_sr_carry_set());
         assert_eq!(true, mm.cpu.register.reg_sr.is_sr_overflow_set());
         assert_eq!(true, mm.cpu.register.reg_sr.is_sr_zero_set());
@@ -1,8 +1,10 @@
 use crate::aint::AInt;
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
 use crate::cpu::{Cpu, RotateDirection, StatusRegisterResult};
 use crate::mem::Mem;
 use crate::register::{
@@ -46,7 +48,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let (direction, aslr_type, operation_size) = match (instr_word & 0x01c0) >> 6 {
         0b000 => (
             RotateDirection::Right,
@@ -70,7 +72,7 @@ pub fn step<'a>(
         _ => (RotateDirection::Left, AslrType::Memory, OperationSize::Word),
     };
 
-    let status_register_result = match aslr_type {
+    let (status_register_result, cycles) = match aslr_type {
         AslrType::Register => {
             let dest_register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
             let shift_count = match instr_word & 0x0020 {
@@ -98,11 +100,15 @@ pub fn step<'a>(
                 status_register_result
             }
 
-            match operation_size {
+            let status_register_result = match operation_size {
                 OperationSize::Byte => do_register_shift::<u8>(dest_register, direction, shift_count, reg, step_log),
                 OperationSize::Word => do_register_shift::<u16>(dest_register, direction, shift_count, reg, step_log),
                 OperationSize::Long => do_register_shift::<u32>(dest_register, direction, shift_count, reg, step_log),
-            }
+            };
+            (
+                status_register_result,
+                timing::get_shift_register_cycles(operation_size, shift_count),
+            )
         }
         AslrType::Memory => {
             let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
@@ -113,18 +119,21 @@ pub fn step<'a>(
                 |instr_word| Ok(operation_size),
             )?;
 
-            let value = ea_data.get_value_word(pc, reg, mem, step_log, false);
+            let value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
             let (result, status_register_result) =
                 Cpu::shift_arithmetic(value, direction, 1);
-            ea_data.set_value_word(pc, reg, mem, step_log, result, true);
-            status_register_result
+            ea_data.set_value_word(pc, reg, mem, step_log, result, true)?;
+            (
+                status_register_result,
+                timing::get_shift_memory_cycles(&ea_data),
+            )
         }
     };
 
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,5 +1,6 @@
 use super::{
     ConditionalTest, GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError,
+    StepResult,
 };
 use crate::{
     cpu::{step_log::StepLog, Cpu},
@@ -35,7 +36,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let conditional_test = Cpu::extract_conditional_test_pos_8(instr_word);
     let condition = reg.reg_sr.evaluate_condition(&conditional_test);
 
@@ -60,7 +61,12 @@ pub fn step<'a>(
             }
         }
     };
-    Ok(())
+    let cycles = match (condition, displacement) {
+        (true, _) => 10,
+        (false, 0x00) => 12,
+        (false, _) => 8,
+    };
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
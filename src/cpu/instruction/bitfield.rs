@@ -0,0 +1,379 @@
+use super::{
+    EffectiveAddressingMode, GetDisassemblyResult, GetDisassemblyResultError, Instruction,
+    OperationSize, StepError, StepResult,
+};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::{Cpu, StatusRegisterResult};
+use crate::mem::Mem;
+use crate::register::{
+    ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE,
+    STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// All eight bit field instructions share this module, bits 10-8 of the instruction word select
+// the operation. The field is counted from the most significant bit. In a data register the
+// field wraps around to bit 31, in memory the offset is signed and the field can span up to
+// five bytes.
+
+enum BitFieldOperation {
+    Tst,
+    Extu,
+    Chg,
+    Exts,
+    Clr,
+    Ffo,
+    Set,
+    Ins,
+}
+
+fn get_bit_field_operation(instr_word: u16) -> BitFieldOperation {
+    match (instr_word >> 8) & 0b111 {
+        0b000 => BitFieldOperation::Tst,
+        0b001 => BitFieldOperation::Extu,
+        0b010 => BitFieldOperation::Chg,
+        0b011 => BitFieldOperation::Exts,
+        0b100 => BitFieldOperation::Clr,
+        0b101 => BitFieldOperation::Ffo,
+        0b110 => BitFieldOperation::Set,
+        _ => BitFieldOperation::Ins,
+    }
+}
+
+impl BitFieldOperation {
+    fn get_name(&self) -> &'static str {
+        match self {
+            BitFieldOperation::Tst => "BFTST",
+            BitFieldOperation::Extu => "BFEXTU",
+            BitFieldOperation::Chg => "BFCHG",
+            BitFieldOperation::Exts => "BFEXTS",
+            BitFieldOperation::Clr => "BFCLR",
+            BitFieldOperation::Ffo => "BFFFO",
+            BitFieldOperation::Set => "BFSET",
+            BitFieldOperation::Ins => "BFINS",
+        }
+    }
+}
+
+struct ExtensionWord {
+    register: usize,
+    offset_register: Option<usize>,
+    offset: u16,
+    width_register: Option<usize>,
+    width: u16,
+}
+
+fn get_extension_word(extension_word: u16) -> ExtensionWord {
+    ExtensionWord {
+        register: ((extension_word >> 12) & 0x0007) as usize,
+        offset_register: match extension_word & 0x0800 {
+            0x0800 => Some(((extension_word >> 6) & 0x0007) as usize),
+            _ => None,
+        },
+        offset: (extension_word >> 6) & 0x001f,
+        width_register: match extension_word & 0x0020 {
+            0x0020 => Some((extension_word & 0x0007) as usize),
+            _ => None,
+        },
+        width: extension_word & 0x001f,
+    }
+}
+
+impl ExtensionWord {
+    fn get_offset(&self, reg: &mut Register, step_log: &mut StepLog) -> i32 {
+        match self.offset_register {
+            Some(register) => reg.get_d_reg_long(register, step_log) as i32,
+            None => self.offset as i32,
+        }
+    }
+
+    // A width of 0 means 32
+    fn get_width(&self, reg: &mut Register, step_log: &mut StepLog) -> u32 {
+        let width = match self.width_register {
+            Some(register) => reg.get_d_reg_long(register, step_log),
+            None => self.width as u32,
+        };
+        ((width.wrapping_sub(1)) & 0x1f) + 1
+    }
+
+    fn get_format(&self) -> String {
+        let offset = match self.offset_register {
+            Some(register) => format!("D{}", register),
+            None => format!("{}", self.offset),
+        };
+        let width = match (self.width_register, self.width) {
+            (Some(register), _) => format!("D{}", register),
+            (None, 0) => String::from("32"),
+            (None, width) => format!("{}", width),
+        };
+        format!("{{{}:{}}}", offset, width)
+    }
+}
+
+fn match_check_ea_data_register_or_control(instr_word: u16) -> bool {
+    match instr_word & 0b_111_000 {
+        0b_000_000 => true,
+        _ => crate::cpu::match_check_ea_only_control_addressing_modes_pos_0(instr_word),
+    }
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => match_check_ea_data_register_or_control(instr_word),
+        false => false,
+    }
+}
+
+pub fn match_check_alterable(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => match instr_word & 0b_111_111 {
+            0b_111_010 => false, // (d16,PC)
+            0b_111_011 => false, // (d8,PC,Xn)
+            _ => match_check_ea_data_register_or_control(instr_word),
+        },
+        false => false,
+    }
+}
+
+// Field location in a data register or memory, with the field value right aligned
+enum BitField {
+    DataRegister {
+        register: usize,
+        offset: u32,
+    },
+    Memory {
+        address: u32,
+        bit_offset: u32,
+        byte_count: u32,
+        bytes: u64,
+    },
+}
+
+fn get_field_mask(width: u32) -> u32 {
+    match width {
+        32 => 0xffffffff,
+        _ => (1 << width) - 1,
+    }
+}
+
+fn get_bit_field(
+    ea_mode: EffectiveAddressingMode,
+    address: Option<u32>,
+    offset: i32,
+    width: u32,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<(BitField, u32), StepError> {
+    match (ea_mode, address) {
+        (EffectiveAddressingMode::DRegDirect { ea_register }, _) => {
+            let offset = (offset as u32) & 0x1f;
+            let value = reg.get_d_reg_long(ea_register, step_log);
+            let field = (value.rotate_left(offset) >> (32 - width)) & get_field_mask(width);
+            Ok((
+                BitField::DataRegister {
+                    register: ea_register,
+                    offset,
+                },
+                field,
+            ))
+        }
+        (_, Some(address)) => {
+            let address = address.wrapping_add((offset >> 3) as u32);
+            let bit_offset = (offset & 0x07) as u32;
+            let byte_count = (bit_offset + width).div_ceil(8);
+            let mut bytes: u64 = 0;
+            for i in 0..byte_count {
+                bytes = (bytes << 8) | mem.get_byte(step_log, address.wrapping_add(i))? as u64;
+            }
+            let field =
+                (bytes >> (byte_count * 8 - bit_offset - width)) as u32 & get_field_mask(width);
+            Ok((
+                BitField::Memory {
+                    address,
+                    bit_offset,
+                    byte_count,
+                    bytes,
+                },
+                field,
+            ))
+        }
+        _ => panic!("Bit field needs an address for memory addressing modes"),
+    }
+}
+
+fn set_bit_field(
+    bit_field: &BitField,
+    width: u32,
+    field: u32,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<(), StepError> {
+    let mask = get_field_mask(width);
+    match *bit_field {
+        BitField::DataRegister { register, offset } => {
+            let value = reg.get_d_reg_long(register, step_log);
+            let shift = 32 - width;
+            let mask = (mask << shift).rotate_right(offset);
+            let field = ((field & get_field_mask(width)) << shift).rotate_right(offset);
+            reg.set_d_reg_long(step_log, register, (value & !mask) | field);
+        }
+        BitField::Memory {
+            address,
+            bit_offset,
+            byte_count,
+            bytes,
+        } => {
+            let shift = byte_count * 8 - bit_offset - width;
+            let mask = (mask as u64) << shift;
+            let bytes = (bytes & !mask) | (((field as u64) << shift) & mask);
+            for i in 0..byte_count {
+                let byte = (bytes >> ((byte_count - 1 - i) * 8)) as u8;
+                mem.set_byte(step_log, address.wrapping_add(i), byte)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_status_register_result(field: u32, width: u32) -> StatusRegisterResult {
+    let status_register = match (field, field & (1 << (width - 1))) {
+        (0, _) => STATUS_REGISTER_MASK_ZERO,
+        (_, 0) => 0x0000,
+        _ => STATUS_REGISTER_MASK_NEGATIVE,
+    };
+    StatusRegisterResult {
+        status_register,
+        status_register_mask: STATUS_REGISTER_MASK_CARRY
+            | STATUS_REGISTER_MASK_OVERFLOW
+            | STATUS_REGISTER_MASK_ZERO
+            | STATUS_REGISTER_MASK_NEGATIVE,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let operation = get_bit_field_operation(instr_word);
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Byte),
+    )?;
+    let offset = extension_word.get_offset(reg, step_log);
+    let width = extension_word.get_width(reg, step_log);
+    let address = match ea_data.ea_mode {
+        EffectiveAddressingMode::DRegDirect { .. } => None,
+        _ => Some(ea_data.get_address(pc, reg, mem, step_log)),
+    };
+    let (bit_field, field) =
+        get_bit_field(ea_data.ea_mode, address, offset, width, reg, mem, step_log)?;
+
+    let mut status_register_result = get_status_register_result(field, width);
+    let cycles = match operation {
+        BitFieldOperation::Tst => 6,
+        BitFieldOperation::Extu => {
+            reg.set_d_reg_long(step_log, extension_word.register, field);
+            8
+        }
+        BitFieldOperation::Exts => {
+            let field = match width {
+                32 => field,
+                _ => (((field << (32 - width)) as i32) >> (32 - width)) as u32,
+            };
+            reg.set_d_reg_long(step_log, extension_word.register, field);
+            8
+        }
+        BitFieldOperation::Ffo => {
+            let leading_zeros = (field << (32 - width)).leading_zeros().min(width);
+            let result = offset.wrapping_add(leading_zeros as i32) as u32;
+            reg.set_d_reg_long(step_log, extension_word.register, result);
+            18
+        }
+        BitFieldOperation::Chg => {
+            set_bit_field(&bit_field, width, !field, reg, mem, step_log)?;
+            12
+        }
+        BitFieldOperation::Clr => {
+            set_bit_field(&bit_field, width, 0x00000000, reg, mem, step_log)?;
+            12
+        }
+        BitFieldOperation::Set => {
+            set_bit_field(&bit_field, width, 0xffffffff, reg, mem, step_log)?;
+            12
+        }
+        BitFieldOperation::Ins => {
+            // The flags are set from the inserted value
+            let value =
+                reg.get_d_reg_long(extension_word.register, step_log) & get_field_mask(width);
+            set_bit_field(&bit_field, width, value, reg, mem, step_log)?;
+            status_register_result = get_status_register_result(value, width);
+            10
+        }
+    };
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+
+    let cycles = match address {
+        Some(_) => cycles + 4 + ea_data.get_calculation_cycles(),
+        None => cycles,
+    };
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let operation = get_bit_field_operation(instr_word);
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Byte),
+    )?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+
+    let operands = match operation {
+        BitFieldOperation::Extu | BitFieldOperation::Exts | BitFieldOperation::Ffo => format!(
+            "{}{},D{}",
+            ea_format,
+            extension_word.get_format(),
+            extension_word.register
+        ),
+        BitFieldOperation::Ins => format!(
+            "D{},{}{}",
+            extension_word.register,
+            ea_format,
+            extension_word.get_format()
+        ),
+        _ => format!("{}{}", ea_format, extension_word.get_format()),
+    };
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from(operation.get_name()),
+        operands,
+    ))
+}
@@ -0,0 +1,73 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::{
+    cpu::step_log::StepLog,
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// BKPT runs a breakpoint acknowledge bus cycle. Nothing on the Amiga answers it, so the CPU
+// takes the illegal instruction exception.
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    Err(StepError::IllegalInstruction)
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("BKPT"),
+        format!("#{}", instr_word & 0x0007),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::{instruction::GetDisassemblyResult, CpuModel};
+
+    #[test]
+    fn bkpt_takes_illegal_instruction_exception() {
+        // arrange
+        let code = [0x48, 0x4d].to_vec(); // BKPT #5
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.mem.set_long_no_log(0x00000010, 0x00C01248); // Illegal instruction vector
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("BKPT"),
+                String::from("#5"),
+                vec![0x484d]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FA));
+    }
+}
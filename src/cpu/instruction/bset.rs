@@ -1,8 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
+    cpu::{step_log::StepLog, timing, Cpu, StatusRegisterResult},
     mem::Mem,
     register::{ProgramCounter, Register, STATUS_REGISTER_MASK_ZERO},
 };
@@ -27,7 +28,7 @@ pub fn step_dynamic<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -57,18 +58,18 @@ pub fn step_dynamic<'a>(
     let bit_set = match ea_data.operation_size {
         OperationSize::Long => {
             let bit_number_mask = 1 << bit_number;
-            let value = ea_data.get_value_long(pc, reg, mem, step_log, false);
+            let value = ea_data.get_value_long(pc, reg, mem, step_log, false)?;
             let bit_set = (value & bit_number_mask) != 0;
             let value = value | bit_number_mask;
-            ea_data.set_value_long(pc, reg, mem, step_log, value, true);
+            ea_data.set_value_long(pc, reg, mem, step_log, value, true)?;
             bit_set
         }
         _ => {
             let bit_number_mask = 1 << bit_number;
-            let value = ea_data.get_value_byte(pc, reg, mem, step_log, false);
+            let value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
             let bit_set = (value & bit_number_mask) != 0;
             let value = value | bit_number_mask;
-            ea_data.set_value_byte(pc, reg, mem, step_log, value, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, value, true)?;
             bit_set
         }
     };
@@ -88,7 +89,8 @@ pub fn step_dynamic<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = timing::get_bit_modify_cycles(&ea_data, false, bit_number as u32, false);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly_dynamic<'a>(
@@ -135,7 +137,7 @@ pub fn step_static<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let operation_size = match instr_word & 0x0038 {
         0x0000 => {
             // DRegDirect
@@ -163,18 +165,18 @@ pub fn step_static<'a>(
     let bit_set = match ea_data.operation_size {
         OperationSize::Long => {
             let bit_number_mask = 1 << bit_number;
-            let value = ea_data.get_value_long(pc, reg, mem, step_log, false);
+            let value = ea_data.get_value_long(pc, reg, mem, step_log, false)?;
             let bit_set = (value & bit_number_mask) != 0;
             let value = value | bit_number_mask;
-            ea_data.set_value_long(pc, reg, mem, step_log, value, true);
+            ea_data.set_value_long(pc, reg, mem, step_log, value, true)?;
             bit_set
         }
         _ => {
             let bit_number_mask = 1 << bit_number;
-            let value = ea_data.get_value_byte(pc, reg, mem, step_log, false);
+            let value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
             let bit_set = (value & bit_number_mask) != 0;
             let value = value | bit_number_mask;
-            ea_data.set_value_byte(pc, reg, mem, step_log, value, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, value, true)?;
             bit_set
         }
     };
@@ -194,7 +196,8 @@ pub fn step_static<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = timing::get_bit_modify_cycles(&ea_data, true, bit_number as u32, false);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly_static<'a>(
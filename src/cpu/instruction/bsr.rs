@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::{step_log::StepLog, Cpu},
     mem::Mem,
@@ -20,7 +20,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let displacement = Cpu::get_byte_from_word(instr_word);
 
     let result = match displacement {
@@ -36,8 +36,8 @@ pub fn step<'a>(
             pc.branch_byte(displacement);
         }
     };
-    reg.stack_push_long(mem, step_log, pc.get_address_next());
-    Ok(())
+    reg.stack_push_long(mem, step_log, pc.get_address_next())?;
+    Ok(StepResult { cycles: 18 })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,8 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
+    cpu::{step_log::StepLog, timing, Cpu, StatusRegisterResult},
     mem::Mem,
     register::{ProgramCounter, Register, STATUS_REGISTER_MASK_ZERO},
 };
@@ -27,7 +28,7 @@ pub fn step_dynamic<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -57,12 +58,12 @@ pub fn step_dynamic<'a>(
     let bit_set = match ea_data.operation_size {
         OperationSize::Long => {
             let bit_number_mask = 1 << bit_number;
-            let value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             (value & bit_number_mask) != 0
         }
         _ => {
             let bit_number_mask = 1 << bit_number;
-            let value = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let value = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
             (value & bit_number_mask) != 0
         }
     };
@@ -82,7 +83,8 @@ pub fn step_dynamic<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = timing::get_bit_test_cycles(&ea_data, false);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly_dynamic<'a>(
@@ -129,7 +131,7 @@ pub fn step_static<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let operation_size = match instr_word & 0x0038 {
         0x0000 => {
             // DRegDirect
@@ -158,12 +160,12 @@ pub fn step_static<'a>(
     let bit_set = match ea_data.operation_size {
         OperationSize::Long => {
             let bit_number_mask = 1 << bit_number;
-            let value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             (value & bit_number_mask) != 0
         }
         _ => {
             let bit_number_mask = 1 << bit_number;
-            let value = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let value = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
             (value & bit_number_mask) != 0
         }
     };
@@ -183,7 +185,8 @@ pub fn step_static<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = timing::get_bit_test_cycles(&ea_data, true);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly_static<'a>(
@@ -0,0 +1,309 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::{Cpu, StatusRegisterResult};
+use crate::mem::Mem;
+use crate::register::{
+    ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE,
+    STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// CAS2 shares the opcode with the CAS.W and CAS.L #<data> encodings, which aren't valid for CAS
+
+fn get_operation_size(instr_word: u16) -> Option<OperationSize> {
+    match (instr_word >> 9) & 0b11 {
+        0b01 => Some(OperationSize::Byte),
+        0b10 => Some(OperationSize::Word),
+        0b11 => Some(OperationSize::Long),
+        _ => None,
+    }
+}
+
+struct ExtensionWord {
+    address_register: bool,
+    register: usize,
+    register_du: usize,
+    register_dc: usize,
+}
+
+fn get_extension_word(extension_word: u16) -> ExtensionWord {
+    ExtensionWord {
+        address_register: extension_word & 0x8000 != 0,
+        register: ((extension_word >> 12) & 0x0007) as usize,
+        register_du: ((extension_word >> 6) & 0x0007) as usize,
+        register_dc: (extension_word & 0x0007) as usize,
+    }
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => match get_operation_size(instr_word) {
+            Some(_) => {
+                crate::cpu::match_check_ea_only_memory_alterable_addressing_modes_pos_0(instr_word)
+            }
+            None => false,
+        },
+        false => false,
+    }
+}
+
+fn get_memory_value(
+    operation_size: OperationSize,
+    address: u32,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<u32, StepError> {
+    Ok(match operation_size {
+        OperationSize::Byte => mem.get_byte(step_log, address)? as u32,
+        OperationSize::Word => mem.get_word(step_log, address)? as u32,
+        OperationSize::Long => mem.get_long(step_log, address)?,
+    })
+}
+
+fn set_memory_value(
+    operation_size: OperationSize,
+    address: u32,
+    value: u32,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<(), StepError> {
+    match operation_size {
+        OperationSize::Byte => mem.set_byte(step_log, address, value as u8)?,
+        OperationSize::Word => mem.set_word(step_log, address, value as u16)?,
+        OperationSize::Long => mem.set_long(step_log, address, value)?,
+    };
+    Ok(())
+}
+
+fn get_d_reg_value(
+    reg: &mut Register,
+    operation_size: OperationSize,
+    register: usize,
+    step_log: &mut StepLog,
+) -> u32 {
+    match operation_size {
+        OperationSize::Byte => reg.get_d_reg_byte(register, step_log) as u32,
+        OperationSize::Word => reg.get_d_reg_word(register, step_log) as u32,
+        OperationSize::Long => reg.get_d_reg_long(register, step_log),
+    }
+}
+
+fn set_d_reg_value(
+    reg: &mut Register,
+    operation_size: OperationSize,
+    register: usize,
+    value: u32,
+    step_log: &mut StepLog,
+) {
+    match operation_size {
+        OperationSize::Byte => reg.set_d_reg_byte(step_log, register, value as u8),
+        OperationSize::Word => reg.set_d_reg_word(step_log, register, value as u16),
+        OperationSize::Long => reg.set_d_reg_long(step_log, register, value),
+    }
+}
+
+// Compares by subtracting the compare operand from the memory operand, like CMP, so X isn't
+// affected
+fn compare(operation_size: OperationSize, compare: u32, operand: u32) -> StatusRegisterResult {
+    let status_register = match operation_size {
+        OperationSize::Byte => {
+            Cpu::sub_bytes(compare as u8, operand as u8)
+                .status_register_result
+                .status_register
+        }
+        OperationSize::Word => {
+            Cpu::sub_words(compare as u16, operand as u16)
+                .status_register_result
+                .status_register
+        }
+        OperationSize::Long => {
+            Cpu::sub_longs(compare, operand)
+                .status_register_result
+                .status_register
+        }
+    };
+    StatusRegisterResult {
+        status_register,
+        status_register_mask: STATUS_REGISTER_MASK_CARRY
+            | STATUS_REGISTER_MASK_OVERFLOW
+            | STATUS_REGISTER_MASK_ZERO
+            | STATUS_REGISTER_MASK_NEGATIVE,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(get_operation_size(instr_word).unwrap()),
+    )?;
+    let operation_size = ea_data.operation_size;
+    let address = ea_data.get_address(pc, reg, mem, step_log);
+    let operand = get_memory_value(operation_size, address, mem, step_log)?;
+    let compare_operand =
+        get_d_reg_value(reg, operation_size, extension_word.register_dc, step_log);
+
+    let status_register_result = compare(operation_size, compare_operand, operand);
+    match operand == compare_operand {
+        true => {
+            let update_operand =
+                get_d_reg_value(reg, operation_size, extension_word.register_du, step_log);
+            set_memory_value(operation_size, address, update_operand, mem, step_log)?;
+        }
+        false => set_d_reg_value(
+            reg,
+            operation_size,
+            extension_word.register_dc,
+            operand,
+            step_log,
+        ),
+    };
+    ea_data.apply_increment_decrement(reg, step_log);
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+
+    Ok(StepResult {
+        cycles: 16 + ea_data.get_calculation_cycles(),
+    })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(get_operation_size(instr_word).unwrap()),
+    )?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        format!("CAS.{}", ea_data.operation_size.get_format()),
+        format!(
+            "D{},D{},{}",
+            extension_word.register_dc, extension_word.register_du, ea_format
+        ),
+    ))
+}
+
+pub fn step_cas2(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let operation_size = get_operation_size(instr_word).unwrap();
+    let extension_word_1 = get_extension_word(pc.fetch_next_word(mem));
+    let extension_word_2 = get_extension_word(pc.fetch_next_word(mem));
+    let address_1 = match extension_word_1.address_register {
+        true => reg.get_a_reg_long(extension_word_1.register, step_log),
+        false => reg.get_d_reg_long(extension_word_1.register, step_log),
+    };
+    let address_2 = match extension_word_2.address_register {
+        true => reg.get_a_reg_long(extension_word_2.register, step_log),
+        false => reg.get_d_reg_long(extension_word_2.register, step_log),
+    };
+    let operand_1 = get_memory_value(operation_size, address_1, mem, step_log)?;
+    let operand_2 = get_memory_value(operation_size, address_2, mem, step_log)?;
+    let compare_operand_1 =
+        get_d_reg_value(reg, operation_size, extension_word_1.register_dc, step_log);
+    let compare_operand_2 =
+        get_d_reg_value(reg, operation_size, extension_word_2.register_dc, step_log);
+
+    // The flags come from the second compare only when the first one was equal
+    let status_register_result = match operand_1 == compare_operand_1 {
+        true => compare(operation_size, compare_operand_2, operand_2),
+        false => compare(operation_size, compare_operand_1, operand_1),
+    };
+    match operand_1 == compare_operand_1 && operand_2 == compare_operand_2 {
+        true => {
+            let update_operand_1 =
+                get_d_reg_value(reg, operation_size, extension_word_1.register_du, step_log);
+            let update_operand_2 =
+                get_d_reg_value(reg, operation_size, extension_word_2.register_du, step_log);
+            set_memory_value(operation_size, address_1, update_operand_1, mem, step_log)?;
+            set_memory_value(operation_size, address_2, update_operand_2, mem, step_log)?;
+        }
+        false => {
+            set_d_reg_value(
+                reg,
+                operation_size,
+                extension_word_1.register_dc,
+                operand_1,
+                step_log,
+            );
+            set_d_reg_value(
+                reg,
+                operation_size,
+                extension_word_2.register_dc,
+                operand_2,
+                step_log,
+            );
+        }
+    };
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+
+    Ok(StepResult { cycles: 24 })
+}
+
+pub fn get_disassembly_cas2(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let operation_size = get_operation_size(instr_word).unwrap();
+    let extension_word_1 = get_extension_word(pc.fetch_next_word(mem));
+    let extension_word_2 = get_extension_word(pc.fetch_next_word(mem));
+    let get_register_name = |extension_word: &ExtensionWord| match extension_word.address_register {
+        true => format!("A{}", extension_word.register),
+        false => format!("D{}", extension_word.register),
+    };
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        format!("CAS2.{}", operation_size.get_format()),
+        format!(
+            "D{}:D{},D{}:D{},({}):({})",
+            extension_word_1.register_dc,
+            extension_word_2.register_dc,
+            extension_word_1.register_du,
+            extension_word_2.register_du,
+            get_register_name(&extension_word_1),
+            get_register_name(&extension_word_2)
+        ),
+    ))
+}
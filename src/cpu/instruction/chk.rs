@@ -0,0 +1,204 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::{
+    cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
+    mem::Mem,
+    register::{
+        ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE,
+        STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
+    },
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO (CHK.L)
+// 020+ get_disassembly: TODO (CHK.L)
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_data_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Word),
+    )?;
+    let register = Cpu::extract_register_index_from_bit_pos(ea_data.instr_word, 9)?;
+
+    let upper_bound = Cpu::get_signed_word_from_word(ea_data.get_value_word(
+        pc, reg, mem, step_log, true,
+    )?);
+    let value = Cpu::get_signed_word_from_word(reg.get_d_reg_word(register, step_log));
+
+    // Z, V and C are documented as undefined. Z is set from the register value and V and C
+    // are cleared, like the 68000 does. N is only affected when the trap is taken.
+    let mut status_register = 0x0000;
+    let mut status_register_mask =
+        STATUS_REGISTER_MASK_ZERO | STATUS_REGISTER_MASK_OVERFLOW | STATUS_REGISTER_MASK_CARRY;
+    if value == 0 {
+        status_register |= STATUS_REGISTER_MASK_ZERO;
+    }
+    let trap = value < 0 || value > upper_bound;
+    if trap {
+        status_register_mask |= STATUS_REGISTER_MASK_NEGATIVE;
+        if value < 0 {
+            status_register |= STATUS_REGISTER_MASK_NEGATIVE;
+        }
+    }
+
+    reg.reg_sr.merge_status_register(
+        step_log,
+        StatusRegisterResult {
+            status_register,
+            status_register_mask,
+        },
+    );
+
+    match trap {
+        true => Err(StepError::CHK_CHK2_Instruction),
+        false => Ok(StepResult {
+            cycles: 10 + ea_data.get_calculation_cycles(),
+        }),
+    }
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Word),
+    )?;
+    let register = Cpu::extract_register_index_from_bit_pos(ea_data.instr_word, 9)?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, Some(OperationSize::Word), mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("CHK.W"),
+        format!("{},D{}", ea_format, register),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cpu::instruction::GetDisassemblyResult,
+        register::{
+            STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_EXTEND, STATUS_REGISTER_MASK_NEGATIVE,
+            STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
+        },
+    };
+
+    #[test]
+    fn chk_word_within_bounds() {
+        // arrange
+        let code = [0x41, 0xbc, 0x00, 0x10].to_vec(); // CHK.W #$0010,D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0xffff0010);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+            STATUS_REGISTER_MASK_EXTEND
+                | STATUS_REGISTER_MASK_NEGATIVE
+                | STATUS_REGISTER_MASK_ZERO
+                | STATUS_REGISTER_MASK_OVERFLOW
+                | STATUS_REGISTER_MASK_CARRY,
+        );
+        mm.mem.set_long_no_log(0x00000018, 0x00C01248); // CHK vector
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("CHK.W"),
+                String::from("#$0010,D0"),
+                vec![0x41bc, 0x0010]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00004, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(false, mm.cpu.register.reg_sr.is_sr_carry_set());
+        assert_eq!(false, mm.cpu.register.reg_sr.is_sr_overflow_set());
+        assert_eq!(false, mm.cpu.register.reg_sr.is_sr_zero_set());
+        assert_eq!(true, mm.cpu.register.reg_sr.is_sr_negative_set());
+        assert_eq!(true, mm.cpu.register.reg_sr.is_sr_extend_set());
+    }
+
+    #[test]
+    fn chk_word_negative() {
+        // arrange
+        let code = [0x43, 0x80].to_vec(); // CHK.W D0,D1
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0x00000010);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0x0000ffff);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(0x0000);
+        mm.mem.set_long_no_log(0x00000018, 0x00C01248); // CHK vector
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("CHK.W"),
+                String::from("D0,D1"),
+                vec![0x4380]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x2008, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn chk_word_greater_than_upper_bound() {
+        // arrange
+        let code = [0x43, 0x80].to_vec(); // CHK.W D0,D1
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0x00000010);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0x00000011);
+        mm.cpu.register
+            .reg_sr
+            .set_sr_reg_flags_abcde(STATUS_REGISTER_MASK_NEGATIVE);
+        mm.mem.set_long_no_log(0x00000018, 0x00C01248); // CHK vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x2000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
+}
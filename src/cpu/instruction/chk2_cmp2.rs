@@ -0,0 +1,199 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::{Cpu, StatusRegisterResult};
+use crate::mem::Mem;
+use crate::register::{
+    ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_ZERO,
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (N and V are undefined and left unchanged)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// CHK2 and CMP2 share the opcode, bit 11 of the extension word selects CHK2. The lower bound is
+// followed by the upper bound in memory. Both bounds are sign extended, a data register is
+// compared at the operation size and an address register always as a long.
+
+fn get_operation_size(instr_word: u16) -> Option<OperationSize> {
+    match (instr_word >> 9) & 0b11 {
+        0b00 => Some(OperationSize::Byte),
+        0b01 => Some(OperationSize::Word),
+        0b10 => Some(OperationSize::Long),
+        _ => None, // CALLM
+    }
+}
+
+struct ExtensionWord {
+    address_register: bool,
+    register: usize,
+    chk2: bool,
+}
+
+fn get_extension_word(extension_word: u16) -> ExtensionWord {
+    ExtensionWord {
+        address_register: extension_word & 0x8000 != 0,
+        register: ((extension_word >> 12) & 0x0007) as usize,
+        chk2: extension_word & 0x0800 != 0,
+    }
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => match get_operation_size(instr_word) {
+            Some(_) => crate::cpu::match_check_ea_only_control_addressing_modes_pos_0(instr_word),
+            None => false,
+        },
+        false => false,
+    }
+}
+
+fn get_sign_extended_value(operation_size: OperationSize, value: u32) -> i32 {
+    match operation_size {
+        OperationSize::Byte => value as u8 as i8 as i32,
+        OperationSize::Word => value as u16 as i16 as i32,
+        OperationSize::Long => value as i32,
+    }
+}
+
+fn get_bound(
+    operation_size: OperationSize,
+    address: u32,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<i32, StepError> {
+    let value = match operation_size {
+        OperationSize::Byte => mem.get_byte(step_log, address)? as u32,
+        OperationSize::Word => mem.get_word(step_log, address)? as u32,
+        OperationSize::Long => mem.get_long(step_log, address)?,
+    };
+    Ok(get_sign_extended_value(operation_size, value))
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(get_operation_size(instr_word).unwrap()),
+    )?;
+    let operation_size = ea_data.operation_size;
+    let address = ea_data.get_address(pc, reg, mem, step_log);
+    let lower_bound = get_bound(operation_size, address, mem, step_log)?;
+    let upper_bound = get_bound(
+        operation_size,
+        address.wrapping_add(operation_size.size_in_bytes()),
+        mem,
+        step_log,
+    )?;
+    let value = match extension_word.address_register {
+        true => reg.get_a_reg_long(extension_word.register, step_log) as i32,
+        false => get_sign_extended_value(
+            operation_size,
+            reg.get_d_reg_long(extension_word.register, step_log),
+        ),
+    };
+
+    let out_of_bounds = match lower_bound <= upper_bound {
+        true => value < lower_bound || value > upper_bound,
+        false => value > upper_bound && value < lower_bound,
+    };
+    let mut status_register = 0x0000;
+    if value == lower_bound || value == upper_bound {
+        status_register |= STATUS_REGISTER_MASK_ZERO;
+    }
+    if out_of_bounds {
+        status_register |= STATUS_REGISTER_MASK_CARRY;
+    }
+    reg.reg_sr.merge_status_register(
+        step_log,
+        StatusRegisterResult {
+            status_register,
+            status_register_mask: STATUS_REGISTER_MASK_CARRY | STATUS_REGISTER_MASK_ZERO,
+        },
+    );
+
+    match extension_word.chk2 && out_of_bounds {
+        true => Err(StepError::CHK_CHK2_Instruction),
+        false => Ok(StepResult {
+            cycles: 22 + ea_data.get_calculation_cycles(),
+        }),
+    }
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(get_operation_size(instr_word).unwrap()),
+    )?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+
+    let instruction_name = match extension_word.chk2 {
+        true => format!("CHK2.{}", ea_data.operation_size.get_format()),
+        false => format!("CMP2.{}", ea_data.operation_size.get_format()),
+    };
+    let register_name = match extension_word.address_register {
+        true => format!("A{}", extension_word.register),
+        false => format!("D{}", extension_word.register),
+    };
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        instruction_name,
+        format!("{},{}", ea_format, register_name),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cpu::CpuModel, register::STATUS_REGISTER_MASK_CARRY};
+
+    #[test]
+    fn chk2_w_out_of_bounds_traps() {
+        // arrange
+        let code = [0x02, 0xd0, 0x18, 0x00].to_vec(); // CHK2.W (A0),D1
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00000200);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0x00000300);
+        mm.mem.set_long_no_log(0x00000200, 0x01000200); // lower $0100, upper $0200
+        mm.mem.set_long_no_log(0x00000018, 0x00C01248); // CHK vector
+                                                        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(
+            STATUS_REGISTER_MASK_CARRY,
+            mm.mem.get_word_no_log(0x010003F4) & 0x001f
+        );
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003F6));
+        // format $2, vector offset $018
+        assert_eq!(0x2018, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+}
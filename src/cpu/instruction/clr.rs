@@ -1,8 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
+    cpu::{step_log::StepLog, timing, Cpu, StatusRegisterResult},
     mem::Mem,
     register::{
         ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE,
@@ -35,7 +36,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -45,9 +46,9 @@ pub fn step<'a>(
     )?;
 
     match ea_data.operation_size {
-        OperationSize::Byte => ea_data.set_value_byte(pc, reg, mem, step_log, 0x00, true),
-        OperationSize::Word => ea_data.set_value_word(pc, reg, mem, step_log, 0x0000, true),
-        OperationSize::Long => ea_data.set_value_long(pc, reg, mem, step_log, 0x00000000, true),
+        OperationSize::Byte => ea_data.set_value_byte(pc, reg, mem, step_log, 0x00, true)?,
+        OperationSize::Word => ea_data.set_value_word(pc, reg, mem, step_log, 0x0000, true)?,
+        OperationSize::Long => ea_data.set_value_long(pc, reg, mem, step_log, 0x00000000, true)?,
     };
 
     let status_register_result = StatusRegisterResult {
@@ -60,7 +61,8 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = timing::get_single_operand_cycles(&ea_data);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
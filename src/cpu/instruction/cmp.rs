@@ -1,5 +1,6 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
     cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
@@ -67,7 +68,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let operation_mode = CmpOpMode::from_u16(instr_word).unwrap();
     let operation_size = match operation_mode {
         CmpOpMode::CmpByte => OperationSize::Byte,
@@ -87,7 +88,7 @@ pub fn step<'a>(
 
     let status_register = match operation_mode {
         CmpOpMode::CmpByte => {
-            let source = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let source = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
             let dest = reg.get_d_reg_byte(register, step_log);
 
             let add_result = Cpu::sub_bytes(source, dest);
@@ -95,7 +96,7 @@ pub fn step<'a>(
             add_result.status_register_result.status_register
         }
         CmpOpMode::CmpWord => {
-            let source = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let source = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
             let dest = reg.get_d_reg_word(register, step_log);
 
             let add_result = Cpu::sub_words(source, dest);
@@ -103,7 +104,7 @@ pub fn step<'a>(
             add_result.status_register_result.status_register
         }
         CmpOpMode::CmpLong => {
-            let source = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let source = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             let dest = reg.get_d_reg_long(register, step_log);
 
             let add_result = Cpu::sub_longs(source, dest);
@@ -112,7 +113,7 @@ pub fn step<'a>(
         }
         CmpOpMode::CmpaWord => {
             let source =
-                Cpu::sign_extend_word(ea_data.get_value_word(pc, reg, mem, step_log, true));
+                Cpu::sign_extend_word(ea_data.get_value_word(pc, reg, mem, step_log, true)?);
             let dest = reg.get_a_reg_long(register, step_log);
 
             let add_result = Cpu::sub_longs(source, dest);
@@ -120,7 +121,7 @@ pub fn step<'a>(
             add_result.status_register_result.status_register
         }
         CmpOpMode::CmpaLong => {
-            let source = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let source = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             let dest = reg.get_a_reg_long(register, step_log);
 
             let add_result = Cpu::sub_longs(source, dest);
@@ -140,7 +141,11 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = match operation_mode {
+        CmpOpMode::CmpByte | CmpOpMode::CmpWord => 4 + ea_data.get_calculation_cycles(),
+        _ => 6 + ea_data.get_calculation_cycles(),
+    };
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
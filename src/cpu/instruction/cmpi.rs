@@ -1,6 +1,6 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
 use crate::{
-    cpu::{instruction::OperationSize, step_log::StepLog, Cpu, StatusRegisterResult},
+    cpu::{instruction::OperationSize, step_log::StepLog, timing, Cpu, StatusRegisterResult},
     mem::Mem,
     register::{
         ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE,
@@ -33,9 +33,9 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
-    let status_register = match operation_size {
+    let (status_register, cycles) = match operation_size {
         OperationSize::Byte => {
             pc.skip_byte();
             let source = pc.fetch_next_byte(mem);
@@ -49,11 +49,14 @@ pub fn step<'a>(
                 3,
                 0,
             )?;
-            let dest = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let dest = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
 
             let add_result = Cpu::sub_bytes(source, dest);
 
-            add_result.status_register_result.status_register
+            (
+                add_result.status_register_result.status_register,
+                timing::get_compare_immediate_cycles(&ea_data),
+            )
         }
         OperationSize::Word => {
             let source = pc.fetch_next_word(mem);
@@ -67,11 +70,14 @@ pub fn step<'a>(
                 3,
                 0,
             )?;
-            let dest = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let dest = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
 
             let add_result = Cpu::sub_words(source, dest);
 
-            add_result.status_register_result.status_register
+            (
+                add_result.status_register_result.status_register,
+                timing::get_compare_immediate_cycles(&ea_data),
+            )
         }
         OperationSize::Long => {
             let source = pc.fetch_next_long(mem);
@@ -85,11 +91,14 @@ pub fn step<'a>(
                 3,
                 0,
             )?;
-            let dest = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let dest = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
 
             let add_result = Cpu::sub_longs(source, dest);
 
-            add_result.status_register_result.status_register
+            (
+                add_result.status_register_result.status_register,
+                timing::get_compare_immediate_cycles(&ea_data),
+            )
         }
     };
 
@@ -104,7 +113,7 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,6 +1,6 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, InstructionError, OperationSize,
-    StepError,
+    StepError, StepResult,
 };
 use crate::{
     cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
@@ -58,7 +58,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let source_register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
     let dest_register = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
     let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
@@ -66,12 +66,12 @@ pub fn step<'a>(
     let status_register = match operation_size {
         OperationSize::Byte => {
             let mut source_address = reg.get_a_reg_long(source_register, step_log);
-            let source = mem.get_byte(step_log, source_address);
+            let source = mem.get_byte(step_log, source_address)?;
             source_address += operation_size.size_in_bytes();
             reg.set_a_reg_long(step_log, source_register, source_address);
 
             let mut dest_address = reg.get_a_reg_long(dest_register, step_log);
-            let dest = mem.get_byte(step_log, dest_address);
+            let dest = mem.get_byte(step_log, dest_address)?;
             dest_address += operation_size.size_in_bytes();
             reg.set_a_reg_long(step_log, dest_register, dest_address);
 
@@ -80,12 +80,12 @@ pub fn step<'a>(
         }
         OperationSize::Word => {
             let mut source_address = reg.get_a_reg_long(source_register, step_log);
-            let source = mem.get_word(step_log, source_address);
+            let source = mem.get_word(step_log, source_address)?;
             source_address += operation_size.size_in_bytes();
             reg.set_a_reg_long(step_log, source_register, source_address);
 
             let mut dest_address = reg.get_a_reg_long(dest_register, step_log);
-            let dest = mem.get_word(step_log, dest_address);
+            let dest = mem.get_word(step_log, dest_address)?;
             dest_address += operation_size.size_in_bytes();
             reg.set_a_reg_long(step_log, dest_register, dest_address);
 
@@ -94,12 +94,12 @@ pub fn step<'a>(
         }
         OperationSize::Long => {
             let mut source_address = reg.get_a_reg_long(source_register, step_log);
-            let source = mem.get_long(step_log, source_address);
+            let source = mem.get_long(step_log, source_address)?;
             source_address += operation_size.size_in_bytes();
             reg.set_a_reg_long(step_log, source_register, source_address);
 
             let mut dest_address = reg.get_a_reg_long(dest_register, step_log);
-            let dest = mem.get_long(step_log, dest_address);
+            let dest = mem.get_long(step_log, dest_address)?;
             dest_address += operation_size.size_in_bytes();
             reg.set_a_reg_long(step_log, dest_register, dest_address);
 
@@ -119,7 +119,11 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = match operation_size {
+        OperationSize::Long => 20,
+        _ => 12,
+    };
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
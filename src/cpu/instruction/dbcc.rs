@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::{instruction::GetDisassemblyResult, step_log::StepLog, Cpu},
     mem::Mem,
@@ -20,12 +20,12 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let conditional_test = Cpu::extract_conditional_test_pos_8(instr_word);
     let condition_result = reg.reg_sr.evaluate_condition(&conditional_test);
     let displacement_16bit = pc.fetch_next_word(mem);
 
-    match condition_result {
+    let cycles = match condition_result {
         false => {
             let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
             let reg_word = reg.get_d_reg_word(register, step_log);
@@ -35,17 +35,19 @@ pub fn step<'a>(
             match reg_word {
                 0xffff => {
                     // == -1 => loop done, next instruction
+                    14
                 }
                 _ => {
                     // != -1 => loop not done, branch
                     pc.branch_word(displacement_16bit);
+                    10
                 }
             }
         }
-        true => (),
+        true => 12,
     };
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
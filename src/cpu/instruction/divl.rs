@@ -0,0 +1,215 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::{Cpu, StatusRegisterResult};
+use crate::mem::Mem;
+use crate::register::{
+    ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE,
+    STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// DIVU.L, DIVS.L, DIVUL.L and DIVSL.L share the opcode. The extension word selects signed and
+// the 64 bit dividend in Dr:Dq. With a 32 bit dividend the remainder is only stored when Dr
+// and Dq are different registers.
+
+struct ExtensionWord {
+    register_dq: usize,
+    register_dr: usize,
+    signed: bool,
+    quad_dividend: bool,
+}
+
+fn get_extension_word(extension_word: u16) -> ExtensionWord {
+    ExtensionWord {
+        register_dq: ((extension_word >> 12) & 0x0007) as usize,
+        register_dr: (extension_word & 0x0007) as usize,
+        signed: extension_word & 0x0800 != 0,
+        quad_dividend: extension_word & 0x0400 != 0,
+    }
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_data_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+// Returns the quotient and remainder, or None when the quotient doesn't fit in 32 bits
+fn divide(extension_word: &ExtensionWord, dividend: u64, divisor: u32) -> Option<(u32, u32)> {
+    match extension_word.signed {
+        true => {
+            let dividend = match extension_word.quad_dividend {
+                true => dividend as i64 as i128,
+                false => dividend as u32 as i32 as i128,
+            };
+            let divisor = divisor as i32 as i128;
+            let quotient = dividend / divisor;
+            let remainder = dividend % divisor;
+            match quotient < i32::MIN as i128 || quotient > i32::MAX as i128 {
+                true => None,
+                false => Some((quotient as u32, remainder as u32)),
+            }
+        }
+        false => {
+            let quotient = dividend / divisor as u64;
+            let remainder = dividend % divisor as u64;
+            match quotient > 0xffffffff {
+                true => None,
+                false => Some((quotient as u32, remainder as u32)),
+            }
+        }
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Long),
+    )?;
+
+    let divisor = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
+    if divisor == 0 {
+        // division by zero
+        return Err(StepError::IntegerDivideByZero);
+    }
+    let dividend_low = reg.get_d_reg_long(extension_word.register_dq, step_log);
+    let dividend = match extension_word.quad_dividend {
+        true => {
+            let dividend_high = reg.get_d_reg_long(extension_word.register_dr, step_log);
+            ((dividend_high as u64) << 32) | dividend_low as u64
+        }
+        false => dividend_low as u64,
+    };
+
+    let status_register = match divide(&extension_word, dividend, divisor) {
+        Some((quotient, remainder)) => {
+            if extension_word.register_dr != extension_word.register_dq {
+                reg.set_d_reg_long(step_log, extension_word.register_dr, remainder);
+            }
+            reg.set_d_reg_long(step_log, extension_word.register_dq, quotient);
+            match quotient {
+                0 => STATUS_REGISTER_MASK_ZERO,
+                0x80000000..=0xffffffff => STATUS_REGISTER_MASK_NEGATIVE,
+                _ => 0x0000,
+            }
+        }
+        // On overflow the registers are left unchanged
+        None => STATUS_REGISTER_MASK_OVERFLOW,
+    };
+    reg.reg_sr.merge_status_register(
+        step_log,
+        StatusRegisterResult {
+            status_register,
+            status_register_mask: STATUS_REGISTER_MASK_CARRY
+                | STATUS_REGISTER_MASK_OVERFLOW
+                | STATUS_REGISTER_MASK_ZERO
+                | STATUS_REGISTER_MASK_NEGATIVE,
+        },
+    );
+
+    let cycles = match extension_word.signed {
+        true => 90,
+        false => 78,
+    } + ea_data.get_calculation_cycles();
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Long),
+    )?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+
+    let sign = match extension_word.signed {
+        true => "S",
+        false => "U",
+    };
+    let (instruction_name, operands) = match (
+        extension_word.quad_dividend,
+        extension_word.register_dr == extension_word.register_dq,
+    ) {
+        (false, true) => (
+            format!("DIV{}.L", sign),
+            format!("{},D{}", ea_format, extension_word.register_dq),
+        ),
+        (false, false) => (
+            format!("DIV{}L.L", sign),
+            format!(
+                "{},D{}:D{}",
+                ea_format, extension_word.register_dr, extension_word.register_dq
+            ),
+        ),
+        (true, _) => (
+            format!("DIV{}.L", sign),
+            format!(
+                "{},D{}:D{}",
+                ea_format, extension_word.register_dr, extension_word.register_dq
+            ),
+        ),
+    };
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        instruction_name,
+        operands,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::CpuModel;
+
+    #[test]
+    fn divul_l_by_zero() {
+        // arrange
+        let code = [0x4c, 0x41, 0x20, 0x03].to_vec(); // DIVUL.L D1,D3:D2
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0x00000000);
+        mm.cpu.register.set_d_reg_long_no_log(2, 0x12345678);
+        mm.cpu.register.set_d_reg_long_no_log(3, 0x9abcdef0);
+        mm.mem.set_long_no_log(0x00000014, 0x00C01248); // Zero divide vector
+                                                        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x12345678, mm.cpu.register.get_d_reg_long_no_log(2));
+        assert_eq!(0x9abcdef0, mm.cpu.register.get_d_reg_long_no_log(3));
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003F6));
+        // format $2, vector offset $014
+        assert_eq!(0x2014, mm.mem.get_word_no_log(0x010003FA));
+    }
+}
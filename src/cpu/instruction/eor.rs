@@ -1,8 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu},
+    cpu::{step_log::StepLog, timing, Cpu},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -37,7 +38,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let size = match (instr_word >> 6) & 0x07 {
         0b100 => OperationSize::Byte,
         0b101 => OperationSize::Word,
@@ -57,27 +58,27 @@ pub fn step<'a>(
     let status_register_result = match dst_ea_data.operation_size {
         OperationSize::Byte => {
             let source = reg.get_d_reg_byte(register, step_log);
-            let dest = dst_ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let dest = dst_ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
             let result = Cpu::eor_bytes(source, dest);
 
-            dst_ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true);
+            dst_ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
         OperationSize::Word => {
             let source = reg.get_d_reg_word(register, step_log);
-            let dest = dst_ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let dest = dst_ea_data.get_value_word(pc, reg, mem, step_log, true)?;
 
             let result = Cpu::eor_words(source, dest);
 
-            dst_ea_data.set_value_word(pc, reg, mem, step_log, result.result, true);
+            dst_ea_data.set_value_word(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
         OperationSize::Long => {
             let source = reg.get_d_reg_long(register, step_log);
-            let dest = dst_ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let dest = dst_ea_data.get_value_long(pc, reg, mem, step_log, true)?;
 
             let result = Cpu::eor_longs(source, dest);
-            dst_ea_data.set_value_long(pc, reg, mem, step_log, result.result, true);
+            dst_ea_data.set_value_long(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
     };
@@ -85,7 +86,8 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = timing::get_dn_to_ea_cycles(&dst_ea_data);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
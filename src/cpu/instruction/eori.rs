@@ -0,0 +1,148 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
+use crate::cpu::Cpu;
+use crate::mem::Mem;
+use crate::register::{ProgramCounter, Register};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => match crate::cpu::match_check_size000110_from_bit_pos_6(instr_word) {
+            true => {
+                crate::cpu::match_check_ea_only_data_alterable_addressing_modes_pos_0(instr_word)
+            }
+            false => false,
+        },
+        false => false,
+    }
+}
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
+    let (status_register_result, cycles) = match operation_size {
+        OperationSize::Byte => {
+            pc.skip_byte();
+            let source = pc.fetch_next_byte(mem);
+
+            let ea_data = pc.get_effective_addressing_data_from_bit_pos(
+                instr_word,
+                reg,
+                mem,
+                step_log,
+                |instr_word| Ok(operation_size),
+                3,
+                0,
+            )?;
+            let dest = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
+
+            let result = Cpu::eor_bytes(source, dest);
+            ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true)?;
+            (
+                result.status_register_result,
+                timing::get_immediate_cycles(&ea_data),
+            )
+        }
+        OperationSize::Word => {
+            let source = pc.fetch_next_word(mem);
+
+            let ea_data = pc.get_effective_addressing_data_from_bit_pos(
+                instr_word,
+                reg,
+                mem,
+                step_log,
+                |instr_word| Ok(operation_size),
+                3,
+                0,
+            )?;
+            let dest = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
+
+            let result = Cpu::eor_words(source, dest);
+            ea_data.set_value_word(pc, reg, mem, step_log, result.result, true)?;
+            (
+                result.status_register_result,
+                timing::get_immediate_cycles(&ea_data),
+            )
+        }
+        OperationSize::Long => {
+            let source = pc.fetch_next_long(mem);
+
+            let ea_data = pc.get_effective_addressing_data_from_bit_pos(
+                instr_word,
+                reg,
+                mem,
+                step_log,
+                |instr_word| Ok(operation_size),
+                3,
+                0,
+            )?;
+            let dest = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
+
+            let result = Cpu::eor_longs(source, dest);
+            ea_data.set_value_long(pc, reg, mem, step_log, result.result, true)?;
+            (
+                result.status_register_result,
+                timing::get_immediate_cycles(&ea_data),
+            )
+        }
+    };
+
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
+    let immediate_data = match operation_size {
+        OperationSize::Byte => {
+            pc.skip_byte();
+            format!("#${:02X}", pc.fetch_next_byte(mem))
+        }
+        OperationSize::Word => format!("#${:04X}", pc.fetch_next_word(mem)),
+        OperationSize::Long => format!("#${:08X}", pc.fetch_next_long(mem)),
+    };
+
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(operation_size),
+        3,
+        0,
+    )?;
+
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        format!("EORI.{}", ea_data.operation_size.get_format()),
+        format!("{},{}", immediate_data, ea_format),
+    ))
+}
@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
 use crate::{
     cpu::{step_log::StepLog, Cpu},
     mem::Mem,
@@ -46,7 +46,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let exgmode = get_exg_mode(instr_word).unwrap();
     let register_x = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
     let register_y = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
@@ -71,7 +71,7 @@ pub fn step<'a>(
             reg.set_d_reg_long(step_log, register_x, tmp_y);
         }
     }
-    Ok(())
+    Ok(StepResult { cycles: 6 })
 }
 
 pub fn get_disassembly<'a>(
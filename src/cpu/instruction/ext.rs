@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
 use crate::cpu::StatusRegisterResult;
 use crate::register::{STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE, STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO};
 use crate::{
@@ -10,13 +10,12 @@ use crate::{
 // step cc: DONE (not affected)
 // get_disassembly: DONE
 
-// 020+ step: TODO
-// 020+ get_disassembly: TODO
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
 
 enum ExtMode {
     ByteToWord,
     WordToLong,
-    // ByteToLong, // 020+
 }
 
 fn get_ext_mode(instr_word: u16) -> Option<ExtMode> {
@@ -24,8 +23,7 @@ fn get_ext_mode(instr_word: u16) -> Option<ExtMode> {
     match opmode {
         0b010 => Some(ExtMode::ByteToWord),
         0b011 => Some(ExtMode::WordToLong),
-        // 020+
-        // 0b111 => Some(ExgMode::ByteToLong),
+        // 0b111 is EXTB.L (020+), see extb.rs
         _ => None,
     }
 }
@@ -49,7 +47,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     // todo!();
 
     let extmode = get_ext_mode(instr_word).unwrap();
@@ -76,9 +74,6 @@ pub fn step<'a>(
                 _ => 0x0000,
             }
         }
-        // 020+
-        // ExtMode::ByteToLong => {
-        // }
     };
     let status_register_result = StatusRegisterResult {
         status_register,
@@ -89,7 +84,7 @@ pub fn step<'a>(
     };
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
-    Ok(())
+    Ok(StepResult { cycles: 4 })
 }
 
 pub fn get_disassembly<'a>(
@@ -115,12 +110,6 @@ pub fn get_disassembly<'a>(
             String::from("EXT.L"),
             format!("D{}", register),
         )),
-        // 020+
-        // ExtMode::ByteToLong => Ok(GetDisassemblyResult::from_pc(
-        //     pc,
-        //     String::from("EXTB.L"),
-        //     format!("D{}", register),
-        // )),
     }
 }
 
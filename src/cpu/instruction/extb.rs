@@ -0,0 +1,83 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::cpu::StatusRegisterResult;
+use crate::register::{
+    STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE, STATUS_REGISTER_MASK_OVERFLOW,
+    STATUS_REGISTER_MASK_ZERO,
+};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+
+    let value = reg.get_d_reg_byte(register, step_log);
+    let value_long = Cpu::sign_extend_byte_to_long(value);
+    reg.set_d_reg_long(step_log, register, value_long);
+    let status_register = match value_long {
+        0x00000000 => STATUS_REGISTER_MASK_ZERO,
+        0x80000000..=0xffffffff => STATUS_REGISTER_MASK_NEGATIVE,
+        _ => 0x0000,
+    };
+    let status_register_result = StatusRegisterResult {
+        status_register,
+        status_register_mask: STATUS_REGISTER_MASK_CARRY
+            | STATUS_REGISTER_MASK_OVERFLOW
+            | STATUS_REGISTER_MASK_ZERO
+            | STATUS_REGISTER_MASK_NEGATIVE,
+    };
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+    Ok(StepResult { cycles: 4 })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("EXTB.L"),
+        format!("D{}", register),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn extb_is_illegal_on_68000() {
+        // arrange
+        let code = [0x49, 0xc0].to_vec(); // EXTB.L D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0x00000080);
+        mm.mem.set_long_no_log(0x00000010, 0x00C00100);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00100, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x00000080, mm.cpu.register.get_d_reg_long_no_log(0));
+    }
+}
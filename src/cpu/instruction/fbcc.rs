@@ -0,0 +1,159 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
+use crate::{
+    cpu::{fpu, step_log::StepLog},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// FBcc branches on the FPU condition codes. FBF.W with a zero displacement is the FNOP, which
+// only synchronizes with the FPU.
+
+fn is_long_displacement(instr_word: u16) -> bool {
+    instr_word & 0x0040 != 0
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => fpu::is_valid_condition(instr_word),
+        false => false,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let fpu = fpu::get_fpu(reg)?;
+    fpu.take_pending_exception()?;
+    fpu.leave_null_state();
+    let condition = fpu.evaluate_condition(instr_word & 0x3f)?;
+    match is_long_displacement(instr_word) {
+        false => {
+            let displacement = pc.fetch_next_word(mem);
+            if condition {
+                pc.branch_word(displacement);
+            }
+        }
+        true => {
+            let displacement = pc.fetch_next_long(mem);
+            if condition {
+                pc.branch_long(displacement);
+            }
+        }
+    }
+    let cycles = match condition {
+        true => 10,
+        false => 12,
+    };
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let condition_name = fpu::get_condition_name(instr_word & 0x3f);
+    match is_long_displacement(instr_word) {
+        false => {
+            let displacement = pc.fetch_next_word(mem);
+            if instr_word & 0x3f == 0 && displacement == 0 {
+                return Ok(GetDisassemblyResult::from_pc(
+                    pc,
+                    mem,
+                    String::from("FNOP"),
+                    String::from(""),
+                ));
+            }
+            Ok(GetDisassemblyResult::from_pc(
+                pc,
+                mem,
+                format!("FB{}.W", condition_name),
+                format!(
+                    "${:04X} [${:08X}]",
+                    displacement,
+                    pc.get_branch_word_address(displacement)
+                ),
+            ))
+        }
+        true => {
+            let displacement = pc.fetch_next_long(mem);
+            Ok(GetDisassemblyResult::from_pc(
+                pc,
+                mem,
+                format!("FB{}.L", condition_name),
+                format!(
+                    "${:08X} [${:08X}]",
+                    displacement,
+                    pc.get_branch_long_address(displacement)
+                ),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::{fpu::FPSR_CONDITION_CODE_Z, instruction::GetDisassemblyResult};
+
+    #[test]
+    fn fbeq_w_taken_when_zero() {
+        // arrange
+        let code = [0xf2, 0x81, 0x00, 0x10].to_vec(); // FBEQ.W $0010
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.fpu.as_mut().unwrap().reg_fpsr = FPSR_CONDITION_CODE_Z;
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FBEQ.W"),
+                String::from("$0010 [$00C00012]"),
+                vec![0xf281, 0x0010]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xC00012, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn fnop_does_not_branch() {
+        // arrange
+        let code = [0xf2, 0x80, 0x00, 0x00].to_vec(); // FNOP
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FNOP"),
+                String::from(""),
+                vec![0xf280, 0x0000]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xC00004, mm.cpu.register.reg_pc.get_address());
+    }
+}
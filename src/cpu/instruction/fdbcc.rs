@@ -0,0 +1,132 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::{
+    cpu::{fpu, step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// The displacement follows the condition word, but is relative to the condition word like
+// DBcc is relative to its displacement word
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    fpu::get_fpu(reg)?.take_pending_exception()?;
+    let condition = pc.fetch_next_word(mem);
+    if !fpu::is_valid_condition(condition) {
+        return Err(StepError::IllegalInstruction);
+    }
+    let displacement_16bit = pc.fetch_next_word(mem);
+    let fpu = fpu::get_fpu(reg)?;
+    fpu.leave_null_state();
+    let condition_result = fpu.evaluate_condition(condition)?;
+
+    let cycles = match condition_result {
+        false => {
+            let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+            let reg_word = reg.get_d_reg_word(register, step_log).wrapping_sub(1);
+            reg.set_d_reg_word(step_log, register, reg_word);
+
+            match reg_word {
+                // == -1 => loop done, next instruction
+                0xffff => 24,
+                // != -1 => loop not done, branch
+                _ => {
+                    pc.jump_long(Cpu::get_address_with_word_displacement_sign_extended(
+                        pc.get_address().wrapping_add(4),
+                        displacement_16bit,
+                    ));
+                    20
+                }
+            }
+        }
+        true => 22,
+    };
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let condition = pc.fetch_next_word(mem);
+    if !fpu::is_valid_condition(condition) {
+        return Err(GetDisassemblyResultError::from(
+            StepError::IllegalInstruction,
+        ));
+    }
+    let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    let displacement_16bit = pc.fetch_next_word(mem);
+    let branch_to = Cpu::get_address_with_word_displacement_sign_extended(
+        pc.get_address().wrapping_add(4),
+        displacement_16bit,
+    );
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        format!("FDB{}", fpu::get_condition_name(condition)),
+        format!(
+            "D{},${:04X} [${:08X}]",
+            register, displacement_16bit, branch_to
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::instruction::GetDisassemblyResult;
+
+    #[test]
+    fn fdbf_decrease_reg_and_branch() {
+        // arrange
+        let code = [0xf2, 0x49, 0x00, 0x00, 0xff, 0xfc].to_vec(); // FDBF D1,$FFFC
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0xffff0001);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00006,
+                String::from("FDBF"),
+                String::from("D1,$FFFC [$00C00000]"),
+                vec![0xf249, 0x0000, 0xfffc]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xffff0000, mm.cpu.register.get_d_reg_long_no_log(1));
+        assert_eq!(0xC00000, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn fdbt_does_nothing() {
+        // arrange
+        let code = [0xf2, 0x49, 0x00, 0x0f, 0xff, 0xfc].to_vec(); // FDBT D1,$FFFC
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0xffff0001);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xffff0001, mm.cpu.register.get_d_reg_long_no_log(1));
+        assert_eq!(0xC00006, mm.cpu.register.reg_pc.get_address());
+    }
+}
@@ -0,0 +1,909 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
+use crate::{
+    cpu::{
+        float80::{Float80, RoundingMode},
+        fpu::{self, FpuDataFormat, FpuOperandLocation, FPCR_MASK, FPSR_MASK},
+        instruction::OperationSize,
+        step_log::StepLog,
+        Cpu,
+    },
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected, the FPU has its own condition codes in FPSR)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// The general FPU instructions (cpGEN). The command word after the opcode selects the
+// operation class: an arithmetic operation between FPU registers or with a memory/data register
+// source, FMOVE out to memory, FMOVECR, and FMOVE/FMOVEM of the control and data registers.
+
+const OPCLASS_REGISTER_TO_REGISTER: u16 = 0b000;
+const OPCLASS_MEMORY_TO_REGISTER: u16 = 0b010;
+const OPCLASS_REGISTER_TO_MEMORY: u16 = 0b011;
+const OPCLASS_MEMORY_TO_CONTROL: u16 = 0b100;
+const OPCLASS_CONTROL_TO_MEMORY: u16 = 0b101;
+const OPCLASS_MEMORY_TO_DATA_REGISTERS: u16 = 0b110;
+const OPCLASS_DATA_REGISTERS_TO_MEMORY: u16 = 0b111;
+
+// Source specifier of FMOVECR, in place of the memory data format
+const SOURCE_SPECIFIER_CONSTANT_ROM: u16 = 0b111;
+
+// Control register list, in the order they are moved to or from memory
+const CONTROL_REGISTERS: [(u16, &str); 3] = [(0b100, "FPCR"), (0b010, "FPSR"), (0b001, "FPIAR")];
+
+const EXTENDED_SIZE_IN_BYTES: u32 = 12;
+
+// Mnemonic and approximate 68881 execution time (register to register) of the operations
+fn get_operation(opmode: u16) -> Option<(&'static str, u32)> {
+    match opmode {
+        0x00 => Some(("FMOVE", 33)),
+        0x01 => Some(("FINT", 55)),
+        0x02 => Some(("FSINH", 687)),
+        0x03 => Some(("FINTRZ", 55)),
+        0x04 => Some(("FSQRT", 107)),
+        0x06 => Some(("FLOGNP1", 571)),
+        0x08 => Some(("FETOXM1", 545)),
+        0x09 => Some(("FTANH", 661)),
+        0x0a => Some(("FATAN", 403)),
+        0x0c => Some(("FASIN", 581)),
+        0x0d => Some(("FATANH", 693)),
+        0x0e => Some(("FSIN", 391)),
+        0x0f => Some(("FTAN", 473)),
+        0x10 => Some(("FETOX", 497)),
+        0x11 => Some(("FTWOTOX", 567)),
+        0x12 => Some(("FTENTOX", 567)),
+        0x14 => Some(("FLOGN", 525)),
+        0x15 => Some(("FLOG10", 581)),
+        0x16 => Some(("FLOG2", 581)),
+        0x18 => Some(("FABS", 35)),
+        0x19 => Some(("FCOSH", 607)),
+        0x1a => Some(("FNEG", 35)),
+        0x1c => Some(("FACOS", 625)),
+        0x1d => Some(("FCOS", 391)),
+        0x1e => Some(("FGETEXP", 45)),
+        0x1f => Some(("FGETMAN", 31)),
+        0x20 => Some(("FDIV", 103)),
+        0x21 => Some(("FMOD", 70)),
+        0x22 => Some(("FADD", 51)),
+        0x23 => Some(("FMUL", 71)),
+        0x24 => Some(("FSGLDIV", 69)),
+        0x25 => Some(("FREM", 100)),
+        0x26 => Some(("FSCALE", 41)),
+        0x27 => Some(("FSGLMUL", 59)),
+        0x28 => Some(("FSUB", 51)),
+        0x30..=0x37 => Some(("FSINCOS", 451)),
+        0x38 => Some(("FCMP", 33)),
+        0x3a => Some(("FTST", 33)),
+        _ => None,
+    }
+}
+
+// Sign extended 7 bit k-factor of FMOVE.P, static in the command word or dynamic in a data
+// register
+fn get_k_factor(value: u16) -> i32 {
+    ((value as i32) << 25) >> 25
+}
+
+fn is_ea_immediate(instr_word: u16) -> bool {
+    instr_word & 0b111_111 == 0b111_100
+}
+
+fn is_ea_pc_relative_or_immediate(instr_word: u16) -> bool {
+    matches!(instr_word & 0b111_111, 0b111_010..=0b111_100)
+}
+
+fn get_ea_mode(instr_word: u16) -> u16 {
+    (instr_word >> 3) & 0b111
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_all_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    fpu::get_fpu(reg)?;
+    let command_word = pc.fetch_next_word(mem);
+    match command_word >> 13 {
+        OPCLASS_REGISTER_TO_REGISTER | OPCLASS_MEMORY_TO_REGISTER => {
+            step_operation(instr_word, command_word, pc, reg, mem, step_log)
+        }
+        OPCLASS_REGISTER_TO_MEMORY => {
+            step_move_out(instr_word, command_word, pc, reg, mem, step_log)
+        }
+        OPCLASS_MEMORY_TO_CONTROL | OPCLASS_CONTROL_TO_MEMORY => {
+            step_move_control(instr_word, command_word, pc, reg, mem, step_log)
+        }
+        OPCLASS_MEMORY_TO_DATA_REGISTERS | OPCLASS_DATA_REGISTERS_TO_MEMORY => {
+            step_move_multiple(instr_word, command_word, pc, reg, mem, step_log)
+        }
+        _ => Err(StepError::IllegalInstruction),
+    }
+}
+
+fn step_operation(
+    instr_word: u16,
+    command_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let opclass = command_word >> 13;
+    let source_specifier = (command_word >> 10) & 0b111;
+    let destination = ((command_word >> 7) & 0b111) as usize;
+    let opmode = command_word & 0x7f;
+    if opclass == OPCLASS_MEMORY_TO_REGISTER && source_specifier == SOURCE_SPECIFIER_CONSTANT_ROM {
+        return step_move_constant(command_word, pc, reg);
+    }
+    let (_, cycles) = get_operation(opmode).ok_or(StepError::IllegalInstruction)?;
+    let instruction_address = pc.get_address();
+    fpu::get_fpu(reg)?.take_pending_exception()?;
+
+    let mut context = fpu::get_fpu(reg)?.get_context();
+    let (source, cycles) = match opclass {
+        OPCLASS_REGISTER_TO_REGISTER => {
+            (fpu::get_fpu(reg)?.reg_fp[source_specifier as usize], cycles)
+        }
+        _ => {
+            let format = FpuDataFormat::from_bits(source_specifier).unwrap();
+            match get_ea_mode(instr_word) {
+                0b000 if format.is_valid_for_data_register() => (),
+                0b000 | 0b001 => return Err(StepError::IllegalInstruction),
+                _ => (),
+            }
+            let size_in_bytes = format.size_in_bytes();
+            let location =
+                fpu::get_operand_location(instr_word, size_in_bytes, pc, reg, mem, step_log)?;
+            let words = fpu::read_operand(location, size_in_bytes, reg, mem, step_log)?;
+            (format.get_value(&mut context, words), cycles + 12)
+        }
+    };
+
+    let fpu = fpu::get_fpu(reg)?;
+    fpu.begin_operation(instruction_address);
+    let dest = fpu.reg_fp[destination];
+    let result = match opmode {
+        0x00 => Some(context.round(source)),
+        0x01 => Some(context.round_to_integer(source, context.rounding_mode)),
+        0x02 => Some(context.calculate(source, f64::sinh)),
+        0x03 => Some(context.round_to_integer(source, RoundingMode::Zero)),
+        0x04 => Some(context.sqrt(source)),
+        0x06 => Some(context.calculate(source, f64::ln_1p)),
+        0x08 => Some(context.calculate(source, f64::exp_m1)),
+        0x09 => Some(context.calculate(source, f64::tanh)),
+        0x0a => Some(context.calculate(source, f64::atan)),
+        0x0c => Some(context.calculate(source, f64::asin)),
+        0x0d => Some(context.calculate(source, f64::atanh)),
+        0x0e => Some(context.calculate(source, f64::sin)),
+        0x0f => Some(context.calculate(source, f64::tan)),
+        0x10 => Some(context.calculate(source, f64::exp)),
+        0x11 => Some(context.calculate(source, f64::exp2)),
+        0x12 => Some(context.calculate(source, |x| 10f64.powf(x))),
+        0x14 => Some(context.calculate(source, f64::ln)),
+        0x15 => Some(context.calculate(source, f64::log10)),
+        0x16 => Some(context.calculate(source, f64::log2)),
+        0x18 => Some(context.abs(source)),
+        0x19 => Some(context.calculate(source, f64::cosh)),
+        0x1a => Some(context.neg(source)),
+        0x1c => Some(context.calculate(source, f64::acos)),
+        0x1d => Some(context.calculate(source, f64::cos)),
+        0x1e => Some(context.get_exponent(source)),
+        0x1f => Some(context.get_mantissa(source)),
+        0x20 => Some(context.div(dest, source)),
+        0x21 | 0x25 => {
+            let (result, quotient_byte) = context.remainder(dest, source, opmode == 0x25);
+            fpu.set_quotient_byte(quotient_byte);
+            Some(result)
+        }
+        0x22 => Some(context.add(dest, source)),
+        0x23 => Some(context.mul(dest, source)),
+        0x24 => Some(context.single_div(dest, source)),
+        0x26 => Some(context.scale(dest, source)),
+        0x27 => Some(context.single_mul(dest, source)),
+        0x28 => Some(context.sub(dest, source)),
+        0x30..=0x37 => {
+            // The cosine goes to the register in the low bits of the opmode
+            fpu.reg_fp[(opmode & 0b111) as usize] = context.calculate(source, f64::cos);
+            Some(context.calculate(source, f64::sin))
+        }
+        0x38 => {
+            let result = context.compare(dest, source);
+            fpu.set_condition_codes(result);
+            None
+        }
+        _ => {
+            let result = context.test(source);
+            fpu.set_condition_codes(result);
+            None
+        }
+    };
+    if let Some(result) = result {
+        fpu.reg_fp[destination] = result;
+        fpu.set_condition_codes(result);
+    }
+    fpu.end_operation(context.exceptions);
+    Ok(StepResult { cycles })
+}
+
+fn step_move_constant(
+    command_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+) -> Result<StepResult, StepError> {
+    let destination = ((command_word >> 7) & 0b111) as usize;
+    let instruction_address = pc.get_address();
+    let fpu = fpu::get_fpu(reg)?;
+    fpu.take_pending_exception()?;
+    fpu.begin_operation(instruction_address);
+    let mut context = fpu.get_context();
+    let (constant, inexact) = fpu::get_constant(command_word & 0x7f);
+    let result = context.round(constant);
+    if inexact {
+        context.exceptions |= crate::cpu::float80::FLOAT_EXCEPTION_INEX2;
+    }
+    fpu.reg_fp[destination] = result;
+    fpu.set_condition_codes(result);
+    fpu.end_operation(context.exceptions);
+    Ok(StepResult { cycles: 29 })
+}
+
+fn step_move_out(
+    instr_word: u16,
+    command_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let source = ((command_word >> 7) & 0b111) as usize;
+    let (format, k_factor) = match (command_word >> 10) & 0b111 {
+        0b011 => (FpuDataFormat::Packed, get_k_factor(command_word)),
+        0b111 => {
+            let register = ((command_word >> 4) & 0b111) as usize;
+            let k_factor = reg.get_d_reg_long(register, step_log) as u16;
+            (FpuDataFormat::Packed, get_k_factor(k_factor))
+        }
+        bits => (FpuDataFormat::from_bits(bits).unwrap(), 0),
+    };
+    match get_ea_mode(instr_word) {
+        0b000 if format.is_valid_for_data_register() => (),
+        0b000 | 0b001 => return Err(StepError::IllegalInstruction),
+        _ if is_ea_pc_relative_or_immediate(instr_word) => {
+            return Err(StepError::IllegalInstruction)
+        }
+        _ => (),
+    }
+    let instruction_address = pc.get_address();
+    let fpu = fpu::get_fpu(reg)?;
+    fpu.take_pending_exception()?;
+    fpu.begin_operation(instruction_address);
+    let mut context = fpu.get_context();
+    let words = format.get_words(&mut context, fpu.reg_fp[source], k_factor);
+    fpu.end_operation(context.exceptions);
+
+    let size_in_bytes = format.size_in_bytes();
+    let location = fpu::get_operand_location(instr_word, size_in_bytes, pc, reg, mem, step_log)?;
+    fpu::write_operand(location, size_in_bytes, words, reg, mem, step_log)?;
+    Ok(StepResult { cycles: 60 })
+}
+
+fn get_control_register(reg: &mut Register, register: u16) -> Result<u32, StepError> {
+    let fpu = fpu::get_fpu(reg)?;
+    Ok(match register {
+        0b100 => fpu.reg_fpcr,
+        0b010 => fpu.reg_fpsr,
+        _ => fpu.reg_fpiar,
+    })
+}
+
+fn set_control_register(reg: &mut Register, register: u16, value: u32) -> Result<(), StepError> {
+    let fpu = fpu::get_fpu(reg)?;
+    match register {
+        0b100 => fpu.reg_fpcr = value & FPCR_MASK,
+        0b010 => fpu.reg_fpsr = value & FPSR_MASK,
+        _ => fpu.reg_fpiar = value,
+    }
+    Ok(())
+}
+
+fn step_move_control(
+    instr_word: u16,
+    command_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let to_memory = command_word >> 13 == OPCLASS_CONTROL_TO_MEMORY;
+    let register_list = (command_word >> 10) & 0b111;
+    let registers: Vec<u16> = CONTROL_REGISTERS
+        .iter()
+        .map(|(register, _)| *register)
+        .filter(|register| register_list & register != 0)
+        .collect();
+    // A data register holds one control register, an address register only FPIAR
+    let valid = match get_ea_mode(instr_word) {
+        _ if registers.is_empty() => false,
+        0b000 => registers.len() == 1,
+        0b001 => register_list == 0b001,
+        _ if to_memory => !is_ea_pc_relative_or_immediate(instr_word),
+        _ => true,
+    };
+    if !valid {
+        return Err(StepError::IllegalInstruction);
+    }
+    fpu::get_fpu(reg)?.leave_null_state();
+
+    let location = fpu::get_operand_location(
+        instr_word,
+        registers.len() as u32 * 4,
+        pc,
+        reg,
+        mem,
+        step_log,
+    )?;
+    for (i, register) in registers.iter().enumerate() {
+        let location = match location {
+            FpuOperandLocation::Memory(address) => {
+                FpuOperandLocation::Memory(address.wrapping_add(i as u32 * 4))
+            }
+            _ => location,
+        };
+        match to_memory {
+            true => {
+                let value = get_control_register(reg, *register)?;
+                fpu::write_operand(location, 4, [value, 0, 0], reg, mem, step_log)?;
+            }
+            false => {
+                let words = fpu::read_operand(location, 4, reg, mem, step_log)?;
+                set_control_register(reg, *register, words[0])?;
+            }
+        }
+    }
+    Ok(StepResult {
+        cycles: 20 + 10 * registers.len() as u32,
+    })
+}
+
+// Data registers of FMOVEM. The list has FP0 in bit 7 for the control and postincrement modes,
+// and FP0 in bit 0 for the predecrement mode. Either way FP0 is at the lowest address.
+fn get_data_register_list(command_word: u16, register_list_mask: u16) -> Vec<usize> {
+    let predecrement = command_word & 0x1000 == 0;
+    (0..8)
+        .filter(|register| match predecrement {
+            true => register_list_mask & (1 << register) != 0,
+            false => register_list_mask & (0x80 >> register) != 0,
+        })
+        .collect()
+}
+
+fn step_move_multiple(
+    instr_word: u16,
+    command_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let to_memory = command_word >> 13 == OPCLASS_DATA_REGISTERS_TO_MEMORY;
+    let valid = match get_ea_mode(instr_word) {
+        0b000 | 0b001 => false,
+        0b011 => !to_memory,
+        0b100 => to_memory,
+        _ if to_memory => !is_ea_pc_relative_or_immediate(instr_word),
+        _ => !is_ea_immediate(instr_word),
+    };
+    if !valid {
+        return Err(StepError::IllegalInstruction);
+    }
+    let register_list_mask = match command_word & 0x0800 {
+        0x0800 => reg.get_d_reg_long(((command_word >> 4) & 0b111) as usize, step_log) as u16,
+        _ => command_word,
+    } & 0xff;
+    let registers = get_data_register_list(command_word, register_list_mask);
+    fpu::get_fpu(reg)?.leave_null_state();
+
+    let location = fpu::get_operand_location(
+        instr_word,
+        registers.len() as u32 * EXTENDED_SIZE_IN_BYTES,
+        pc,
+        reg,
+        mem,
+        step_log,
+    )?;
+    let address = match location {
+        FpuOperandLocation::Memory(address) => address,
+        _ => return Err(StepError::IllegalInstruction),
+    };
+    for (i, register) in registers.iter().enumerate() {
+        let location =
+            FpuOperandLocation::Memory(address.wrapping_add(i as u32 * EXTENDED_SIZE_IN_BYTES));
+        match to_memory {
+            true => {
+                let value = fpu::get_fpu(reg)?.reg_fp[*register];
+                let words = [
+                    (value.get_sign_exponent_word() as u32) << 16,
+                    (value.mantissa >> 32) as u32,
+                    value.mantissa as u32,
+                ];
+                fpu::write_operand(location, EXTENDED_SIZE_IN_BYTES, words, reg, mem, step_log)?;
+            }
+            false => {
+                let words =
+                    fpu::read_operand(location, EXTENDED_SIZE_IN_BYTES, reg, mem, step_log)?;
+                fpu::get_fpu(reg)?.reg_fp[*register] = Float80::from_words(
+                    (words[0] >> 16) as u16,
+                    ((words[1] as u64) << 32) | words[2] as u64,
+                );
+            }
+        }
+    }
+    Ok(StepResult {
+        cycles: 20 + 25 * registers.len() as u32,
+    })
+}
+
+// Immediate operands can be longer than a long word, so they are formatted here
+fn get_ea_format(
+    instr_word: u16,
+    size_in_bytes: u32,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<String, GetDisassemblyResultError> {
+    match is_ea_immediate(instr_word) {
+        true => match size_in_bytes {
+            1 => Ok(format!("#${:02X}", pc.fetch_next_word(mem) & 0xff)),
+            2 => Ok(format!("#${:04X}", pc.fetch_next_word(mem))),
+            _ => {
+                let mut format = String::from("#$");
+                for _ in 0..size_in_bytes / 4 {
+                    format.push_str(&format!("{:08X}", pc.fetch_next_long(mem)));
+                }
+                Ok(format)
+            }
+        },
+        false => {
+            let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+                instr_word,
+                reg,
+                mem,
+                step_log,
+                |_| Ok(OperationSize::Long),
+            )?;
+            Ok(Cpu::get_ea_format(ea_data.ea_mode, pc, Some(OperationSize::Long), mem).format)
+        }
+    }
+}
+
+fn get_register_list_format(registers: &[usize]) -> String {
+    let mut groups: Vec<(usize, usize)> = vec![];
+    for register in registers {
+        match groups.last_mut() {
+            Some((_, end)) if *end + 1 == *register => *end = *register,
+            _ => groups.push((*register, *register)),
+        }
+    }
+    groups
+        .iter()
+        .map(|(start, end)| match start == end {
+            true => format!("FP{}", start),
+            false => format!("FP{}-FP{}", start, end),
+        })
+        .collect::<Vec<String>>()
+        .join("/")
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let command_word = pc.fetch_next_word(mem);
+    let opclass = command_word >> 13;
+    let source_specifier = (command_word >> 10) & 0b111;
+    let register = (command_word >> 7) & 0b111;
+    let opmode = command_word & 0x7f;
+    let (name, operands) = match opclass {
+        OPCLASS_MEMORY_TO_REGISTER if source_specifier == SOURCE_SPECIFIER_CONSTANT_ROM => (
+            String::from("FMOVECR.X"),
+            format!("#${:02X},FP{}", opmode, register),
+        ),
+        OPCLASS_REGISTER_TO_REGISTER | OPCLASS_MEMORY_TO_REGISTER => {
+            let (operation, _) = get_operation(opmode).ok_or(StepError::IllegalInstruction)?;
+            let (format, source) = match opclass {
+                OPCLASS_REGISTER_TO_REGISTER => ('X', format!("FP{}", source_specifier)),
+                _ => {
+                    let format = FpuDataFormat::from_bits(source_specifier).unwrap();
+                    let source =
+                        get_ea_format(instr_word, format.size_in_bytes(), pc, reg, mem, step_log)?;
+                    (format.get_format(), source)
+                }
+            };
+            let operands = match opmode {
+                0x30..=0x37 => format!("{},FP{}:FP{}", source, opmode & 0b111, register),
+                0x3a => source,
+                _ => format!("{},FP{}", source, register),
+            };
+            (format!("{}.{}", operation, format), operands)
+        }
+        OPCLASS_REGISTER_TO_MEMORY => {
+            let (format, k_factor) = match source_specifier {
+                0b011 => (
+                    FpuDataFormat::Packed,
+                    format!("{{#{}}}", get_k_factor(command_word)),
+                ),
+                0b111 => (
+                    FpuDataFormat::Packed,
+                    format!("{{D{}}}", (command_word >> 4) & 0b111),
+                ),
+                bits => (FpuDataFormat::from_bits(bits).unwrap(), String::new()),
+            };
+            let destination =
+                get_ea_format(instr_word, format.size_in_bytes(), pc, reg, mem, step_log)?;
+            (
+                format!("FMOVE.{}", format.get_format()),
+                format!("FP{},{}{}", register, destination, k_factor),
+            )
+        }
+        OPCLASS_MEMORY_TO_CONTROL | OPCLASS_CONTROL_TO_MEMORY => {
+            let registers: Vec<&str> = CONTROL_REGISTERS
+                .iter()
+                .filter(|(register, _)| source_specifier & register != 0)
+                .map(|(_, name)| *name)
+                .collect();
+            let ea_format = get_ea_format(
+                instr_word,
+                registers.len() as u32 * 4,
+                pc,
+                reg,
+                mem,
+                step_log,
+            )?;
+            let name = match registers.len() {
+                1 => String::from("FMOVE.L"),
+                _ => String::from("FMOVEM.L"),
+            };
+            let registers = registers.join("/");
+            match opclass {
+                OPCLASS_MEMORY_TO_CONTROL => (name, format!("{},{}", ea_format, registers)),
+                _ => (name, format!("{},{}", registers, ea_format)),
+            }
+        }
+        _ => {
+            let registers = match command_word & 0x0800 {
+                0x0800 => format!("D{}", (command_word >> 4) & 0b111),
+                _ => get_register_list_format(&get_data_register_list(
+                    command_word,
+                    command_word & 0xff,
+                )),
+            };
+            let ea_format = get_ea_format(instr_word, 0, pc, reg, mem, step_log)?;
+            match opclass {
+                OPCLASS_MEMORY_TO_DATA_REGISTERS => (
+                    String::from("FMOVEM.X"),
+                    format!("{},{}", ea_format, registers),
+                ),
+                _ => (
+                    String::from("FMOVEM.X"),
+                    format!("{},{}", registers, ea_format),
+                ),
+            }
+        }
+    };
+    Ok(GetDisassemblyResult::from_pc(pc, mem, name, operands))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::{
+        float80::Float80,
+        fpu::{FPSR_CONDITION_CODE_N, FPSR_CONDITION_CODE_Z},
+        instruction::GetDisassemblyResult,
+        CpuModel,
+    };
+
+    #[test]
+    fn fmove_l_immediate_to_fp0() {
+        // arrange
+        let code = [0xf2, 0x3c, 0x40, 0x00, 0xff, 0xff, 0xff, 0xfd].to_vec(); // FMOVE.L #$FFFFFFFD,FP0
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00008,
+                String::from("FMOVE.L"),
+                String::from("#$FFFFFFFD,FP0"),
+                vec![0xf23c, 0x4000, 0xffff, 0xfffd]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        let fpu = mm.cpu.register.fpu.as_ref().unwrap();
+        assert_eq!(Float80::from_i64(-3), fpu.reg_fp[0]);
+        assert_eq!(FPSR_CONDITION_CODE_N, fpu.reg_fpsr);
+        assert_eq!(0x00C00000, fpu.reg_fpiar);
+        assert_eq!(0xC00008, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn fadd_x_fp1_to_fp0() {
+        // arrange
+        let code = [0xf2, 0x00, 0x04, 0x22].to_vec(); // FADD.X FP1,FP0
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        let fpu = mm.cpu.register.fpu.as_mut().unwrap();
+        fpu.reg_fp[0] = Float80::from_f64(1.5);
+        fpu.reg_fp[1] = Float80::from_f64(-1.5);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FADD.X"),
+                String::from("FP1,FP0"),
+                vec![0xf200, 0x0422]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        let fpu = mm.cpu.register.fpu.as_ref().unwrap();
+        assert_eq!(Float80::zero(false), fpu.reg_fp[0]);
+        assert_eq!(FPSR_CONDITION_CODE_Z, fpu.reg_fpsr);
+    }
+
+    #[test]
+    fn fsqrt_d_from_memory() {
+        // arrange
+        let code = [0xf2, 0x10, 0x55, 0x04].to_vec(); // FSQRT.D (A0),FP2
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x01000100);
+        mm.mem.set_long_no_log(0x01000100, 0x40220000); // 9.0
+        mm.mem.set_long_no_log(0x01000104, 0x00000000);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FSQRT.D"),
+                String::from("(A0),FP2"),
+                vec![0xf210, 0x5504]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        let fpu = mm.cpu.register.fpu.as_ref().unwrap();
+        assert_eq!(Float80::from_i64(3), fpu.reg_fp[2]);
+    }
+
+    #[test]
+    fn fmove_d_fp0_to_memory() {
+        // arrange
+        let code = [0xf2, 0x10, 0x74, 0x00].to_vec(); // FMOVE.D FP0,(A0)
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x01000100);
+        mm.cpu.register.fpu.as_mut().unwrap().reg_fp[0] = Float80::from_f64(-2.5);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FMOVE.D"),
+                String::from("FP0,(A0)"),
+                vec![0xf210, 0x7400]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xc0040000, mm.mem.get_long_no_log(0x01000100));
+        assert_eq!(0x00000000, mm.mem.get_long_no_log(0x01000104));
+    }
+
+    #[test]
+    fn fmove_p_fp0_to_memory_with_static_k_factor() {
+        // arrange
+        let code = [0xf2, 0x10, 0x6c, 0x02].to_vec(); // FMOVE.P FP0,(A0){#2}
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x01000100);
+        mm.cpu.register.fpu.as_mut().unwrap().reg_fp[0] = Float80::from_i64(125);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FMOVE.P"),
+                String::from("FP0,(A0){#2}"),
+                vec![0xf210, 0x6c02]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        // 1.3E2, the two significant digits are rounded to nearest
+        assert_eq!(0x00020001, mm.mem.get_long_no_log(0x01000100));
+        assert_eq!(0x20000000, mm.mem.get_long_no_log(0x01000104));
+        assert_eq!(0x00000000, mm.mem.get_long_no_log(0x01000108));
+    }
+
+    #[test]
+    fn fmovecr_one_to_fp3() {
+        // arrange
+        let code = [0xf2, 0x00, 0x5d, 0xb2].to_vec(); // FMOVECR.X #$32,FP3
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FMOVECR.X"),
+                String::from("#$32,FP3"),
+                vec![0xf200, 0x5db2]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        let fpu = mm.cpu.register.fpu.as_ref().unwrap();
+        assert_eq!(Float80::from_i64(1), fpu.reg_fp[3]);
+    }
+
+    #[test]
+    fn fdiv_by_zero_with_dz_enabled_traps_on_next_instruction() {
+        // arrange
+        let code = [
+            0xf2, 0x00, 0x04, 0x20, // FDIV.X FP1,FP0
+            0xf2, 0x00, 0x00, 0x3a, // FTST.X FP0
+        ]
+        .to_vec();
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        let fpu = mm.cpu.register.fpu.as_mut().unwrap();
+        fpu.reg_fp[0] = Float80::from_i64(1);
+        fpu.reg_fp[1] = Float80::zero(false);
+        fpu.reg_fpcr = 0x00000400;
+        mm.mem.set_long_no_log(0x000000C8, 0x00C01000); // divide by zero vector
+                                                        // act
+        mm.step();
+        mm.step();
+        // assert
+        let fpu = mm.cpu.register.fpu.as_ref().unwrap();
+        assert_eq!(Float80::infinity(false), fpu.reg_fp[0]);
+        assert_eq!(0x00C01000, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003F8, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003FA));
+        // format $0, vector offset $0C8
+        assert_eq!(0x00C8, mm.mem.get_word_no_log(0x010003FE));
+    }
+
+    #[test]
+    fn fmove_l_fpsr_to_d0() {
+        // arrange
+        let code = [0xf2, 0x00, 0xa8, 0x00].to_vec(); // FMOVE.L FPSR,D0
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.fpu.as_mut().unwrap().reg_fpsr = 0x08000010;
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FMOVE.L"),
+                String::from("FPSR,D0"),
+                vec![0xf200, 0xa800]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x08000010, mm.cpu.register.get_d_reg_long_no_log(0));
+    }
+
+    #[test]
+    fn fmovem_l_control_registers_from_memory() {
+        // arrange
+        let code = [0xf2, 0x18, 0x9c, 0x00].to_vec(); // FMOVEM.L (A0)+,FPCR/FPSR/FPIAR
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x01000100);
+        mm.mem.set_long_no_log(0x01000100, 0xffffffff);
+        mm.mem.set_long_no_log(0x01000104, 0xffffffff);
+        mm.mem.set_long_no_log(0x01000108, 0x00c01234);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FMOVEM.L"),
+                String::from("(A0)+,FPCR/FPSR/FPIAR"),
+                vec![0xf218, 0x9c00]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        let fpu = mm.cpu.register.fpu.as_ref().unwrap();
+        assert_eq!(0x0000fff0, fpu.reg_fpcr);
+        assert_eq!(0x0ffffff8, fpu.reg_fpsr);
+        assert_eq!(0x00c01234, fpu.reg_fpiar);
+        assert_eq!(0x0100010c, mm.cpu.register.get_a_reg_long_no_log(0));
+    }
+
+    #[test]
+    fn fmovem_x_predecrement_stores_fp0_at_lowest_address() {
+        // arrange
+        let code = [0xf2, 0x27, 0xe0, 0x05].to_vec(); // FMOVEM.X FP0/FP2,-(A7)
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        let fpu = mm.cpu.register.fpu.as_mut().unwrap();
+        fpu.reg_fp[0] = Float80::from_i64(1);
+        fpu.reg_fp[2] = Float80::from_i64(-2);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("FMOVEM.X"),
+                String::from("FP0/FP2,-(A7)"),
+                vec![0xf227, 0xe005]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x010003E8, mm.cpu.register.get_a_reg_long_no_log(7));
+        assert_eq!(0x3fff0000, mm.mem.get_long_no_log(0x010003E8));
+        assert_eq!(0x80000000, mm.mem.get_long_no_log(0x010003EC));
+        assert_eq!(0xc0000000, mm.mem.get_long_no_log(0x010003F4));
+        assert_eq!(0x80000000, mm.mem.get_long_no_log(0x010003F8));
+    }
+
+    #[test]
+    fn fpu_instruction_without_fpu_is_line_1111_emulator() {
+        // arrange
+        let code = [0xf2, 0x00, 0xa8, 0x00].to_vec(); // FMOVE.L FPSR,D0
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.mem.set_long_no_log(0x0000002C, 0x00C01000); // line 1111 emulator vector
+                                                        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01000, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FA));
+    }
+}
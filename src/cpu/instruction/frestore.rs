@@ -0,0 +1,135 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
+use crate::{
+    cpu::{
+        fpu::{self, FpuOperandLocation},
+        instruction::OperationSize,
+        step_log::StepLog,
+        Cpu,
+    },
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// The size of the state frame is in its format word, so (An)+ is incremented by the rest of
+// the frame after that has been read
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_control_or_postincrement_addressing_modes_pos_0(
+            instr_word,
+        ),
+        false => false,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    fpu::get_fpu(reg)?;
+    if !reg.reg_sr.is_sr_supervisor_set(step_log) {
+        return Err(StepError::PriviliegeViolation);
+    }
+    let address = match fpu::get_operand_location(instr_word, 4, pc, reg, mem, step_log)? {
+        FpuOperandLocation::Memory(address) => address,
+        _ => return Err(StepError::IllegalInstruction),
+    };
+    let format_word = mem.get_long(step_log, address)?;
+    let size_in_bytes = fpu::get_fpu(reg)?.get_state_frame_size(format_word)?;
+    let mut frame = vec![format_word];
+    for i in 0..size_in_bytes / 4 {
+        frame.push(mem.get_long(step_log, address.wrapping_add(4 + i * 4))?);
+    }
+    if (instr_word >> 3) & 0b111 == 0b011 {
+        let register = (instr_word & 0b111) as usize;
+        let value = reg.get_a_reg_long(register, step_log);
+        reg.set_a_reg_long(step_log, register, value.wrapping_add(size_in_bytes));
+    }
+    fpu::get_fpu(reg)?.restore_state_frame(&frame);
+    Ok(StepResult {
+        cycles: 10 + 4 * frame.len() as u32,
+    })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |_| Ok(OperationSize::Long),
+    )?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("FRESTORE"),
+        format!("{}", ea_format),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::instruction::GetDisassemblyResult;
+
+    #[test]
+    fn frestore_idle_state_frame() {
+        // arrange
+        let code = [0xf3, 0x5f].to_vec(); // FRESTORE (A7)+
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x010003E0);
+        mm.mem.set_long_no_log(0x010003E0, 0x1f180000);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("FRESTORE"),
+                String::from("(A7)+"),
+                vec![0xf35f]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x010003FC, mm.cpu.register.get_a_reg_long_no_log(7));
+        assert_eq!(
+            vec![0x1f180000, 0, 0, 0, 0, 0, 0],
+            mm.cpu.register.fpu.as_ref().unwrap().get_state_frame()
+        );
+    }
+
+    #[test]
+    fn frestore_invalid_frame_is_format_error() {
+        // arrange
+        let code = [0xf3, 0x50].to_vec(); // FRESTORE (A0)
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x01000100);
+        mm.mem.set_long_no_log(0x01000100, 0x1f440000);
+        mm.mem.set_long_no_log(0x00000038, 0x00C01000); // format error vector
+                                                        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01000, mm.cpu.register.reg_pc.get_address());
+    }
+}
@@ -0,0 +1,139 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
+use crate::{
+    cpu::{
+        fpu::{self, FpuOperandLocation},
+        instruction::OperationSize,
+        step_log::StepLog,
+        Cpu,
+    },
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// The state frame is stored with its format word at the lowest address, so -(An) is
+// decremented by the whole frame first
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => {
+            crate::cpu::match_check_ea_only_control_alterable_or_predecrement_addressing_modes_pos_0(
+                instr_word,
+            )
+        }
+        false => false,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    fpu::get_fpu(reg)?;
+    if !reg.reg_sr.is_sr_supervisor_set(step_log) {
+        return Err(StepError::PriviliegeViolation);
+    }
+    let frame = fpu::get_fpu(reg)?.get_state_frame();
+    let size_in_bytes = frame.len() as u32 * 4;
+    let address =
+        match fpu::get_operand_location(instr_word, size_in_bytes, pc, reg, mem, step_log)? {
+            FpuOperandLocation::Memory(address) => address,
+            _ => return Err(StepError::IllegalInstruction),
+        };
+    for (i, long) in frame.iter().enumerate() {
+        mem.set_long(step_log, address.wrapping_add(i as u32 * 4), *long)?;
+    }
+    Ok(StepResult {
+        cycles: 10 + 4 * frame.len() as u32,
+    })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |_| Ok(OperationSize::Long),
+    )?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("FSAVE"),
+        format!("{}", ea_format),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::instruction::GetDisassemblyResult;
+
+    #[test]
+    fn fsave_null_state_frame() {
+        // arrange
+        let code = [0xf3, 0x27].to_vec(); // FSAVE -(A7)
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.mem.set_long_no_log(0x010003FC, 0xffffffff);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("FSAVE"),
+                String::from("-(A7)"),
+                vec![0xf327]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x010003FC, mm.cpu.register.get_a_reg_long_no_log(7));
+        assert_eq!(0x00000000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn fsave_idle_state_frame_after_instruction() {
+        // arrange
+        let code = [0xf2, 0x00, 0x00, 0x3a, 0xf3, 0x27].to_vec(); // FTST.X FP0, FSAVE -(A7)
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x010003E4, mm.cpu.register.get_a_reg_long_no_log(7));
+        assert_eq!(0x1f180000, mm.mem.get_long_no_log(0x010003E4));
+    }
+
+    #[test]
+    fn fsave_in_user_mode_is_privilege_violation() {
+        // arrange
+        let code = [0xf3, 0x10].to_vec(); // FSAVE (A0)
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.reg_sr.set_value(0x0000);
+        mm.mem.set_long_no_log(0x00000020, 0x00C01000); // privilege violation vector
+                                                        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01000, mm.cpu.register.reg_pc.get_address());
+    }
+}
@@ -0,0 +1,138 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
+use crate::{
+    cpu::{fpu, step_log::StepLog},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// Like TRAPcc the optional operand is only there for the trap handler to read
+
+enum FtrapccOperand {
+    None,
+    Word,
+    Long,
+}
+
+fn get_ftrapcc_operand(instr_word: u16) -> Option<FtrapccOperand> {
+    match instr_word & 0b111 {
+        0b100 => Some(FtrapccOperand::None),
+        0b010 => Some(FtrapccOperand::Word),
+        0b011 => Some(FtrapccOperand::Long),
+        _ => None,
+    }
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => get_ftrapcc_operand(instr_word).is_some(),
+        false => false,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    fpu::get_fpu(reg)?.take_pending_exception()?;
+    let condition = pc.fetch_next_word(mem);
+    if !fpu::is_valid_condition(condition) {
+        return Err(StepError::IllegalInstruction);
+    }
+    let cycles = match get_ftrapcc_operand(instr_word).unwrap() {
+        FtrapccOperand::None => 20,
+        FtrapccOperand::Word => {
+            pc.fetch_next_word(mem);
+            22
+        }
+        FtrapccOperand::Long => {
+            pc.fetch_next_long(mem);
+            24
+        }
+    };
+    let fpu = fpu::get_fpu(reg)?;
+    fpu.leave_null_state();
+    match fpu.evaluate_condition(condition)? {
+        true => Err(StepError::FRAPcc_TRAPcc_TRAPV_Instruction),
+        false => Ok(StepResult { cycles }),
+    }
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let condition = pc.fetch_next_word(mem);
+    if !fpu::is_valid_condition(condition) {
+        return Err(GetDisassemblyResultError::from(
+            StepError::IllegalInstruction,
+        ));
+    }
+    let condition_name = fpu::get_condition_name(condition);
+    let (instruction_name, operands) = match get_ftrapcc_operand(instr_word).unwrap() {
+        FtrapccOperand::None => (format!("FTRAP{}", condition_name), String::from("")),
+        FtrapccOperand::Word => (
+            format!("FTRAP{}.W", condition_name),
+            format!("#${:04X}", pc.fetch_next_word(mem)),
+        ),
+        FtrapccOperand::Long => (
+            format!("FTRAP{}.L", condition_name),
+            format!("#${:08X}", pc.fetch_next_long(mem)),
+        ),
+    };
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        instruction_name,
+        operands,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::instruction::GetDisassemblyResult;
+
+    #[test]
+    fn ftrapt_w_stacks_pc_after_operand() {
+        // arrange
+        let code = [0xf2, 0x7a, 0x00, 0x0f, 0x12, 0x34].to_vec(); // FTRAPT.W #$1234
+        let mut mm = crate::tests::instr_test_setup_with_fpu(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x0000001C, 0x00C01248); // TRAPcc vector
+                                                        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00006,
+                String::from("FTRAPT.W"),
+                String::from("#$1234"),
+                vec![0xf27a, 0x000f, 0x1234]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003F4, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x00C00006, mm.mem.get_long_no_log(0x010003F6));
+        // format $2, vector offset $01C
+        assert_eq!(0x201C, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+}
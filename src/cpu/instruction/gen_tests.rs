@@ -1,14 +1,37 @@
 // Path: ..\src\cpu\instruction\gen_tests.rs
 // This file is autogenerated
 
+pub mod abcd;
 pub mod add;
 pub mod addi;
 pub mod addq;
 pub mod addx;
+pub mod andi_to_ccr;
 pub mod aslr;
+pub mod bitfield;
+pub mod cas;
+pub mod chk2_cmp2;
+pub mod divl;
 pub mod divs;
 pub mod divu;
 pub mod eor;
+pub mod eori;
+pub mod eori_to_ccr;
 pub mod ext;
+pub mod extb;
 pub mod lslr;
+pub mod move_to_ccr;
+pub mod movep;
+pub mod mull;
+pub mod muls;
+pub mod nbcd;
+pub mod negx;
+pub mod ori_to_ccr;
+pub mod pack;
+pub mod rolr;
+pub mod roxlr;
+pub mod sbcd;
+pub mod tas;
+pub mod trapcc;
+pub mod unpk;
 
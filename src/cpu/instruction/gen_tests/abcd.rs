@@ -0,0 +1,867 @@
+// Path: ..\src\cpu\instruction\gen_tests\abcd.rs
+// This file is autogenerated from tests\abcd.tests
+
+#![allow(unused_imports)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::register::ProgramCounter;
+use crate::mem::rammemory::RamMemory;
+use crate::cpu::instruction::GetDisassemblyResult;
+use crate::mem::memory::Memory;
+use crate::mem::ciamemory::CiaMemory;
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
+use crate::mem::Mem;
+use crate::modermodem::Modermodem;
+use crate::register::STATUS_REGISTER_MASK_CARRY;
+use crate::register::STATUS_REGISTER_MASK_EXTEND;
+use crate::register::STATUS_REGISTER_MASK_NEGATIVE;
+use crate::register::STATUS_REGISTER_MASK_OVERFLOW;
+use crate::register::STATUS_REGISTER_MASK_ZERO;
+
+
+#[test]
+fn abcd_byte_data_register_with_extend_clear() {
+    // arrange - code
+    // ABCD.B D0,D1
+    let code = [0xC3, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000012, 0x00000034, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("D0,D1"),
+            vec![0xC300]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    let cycles = modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000012, 0x00000046, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+    assert_eq!(6, cycles);
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn abcd_byte_data_register_with_extend_set() {
+    // arrange - code
+    // ABCD.B D0,D1
+    let code = [0xC3, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000012, 0x00000034, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("D0,D1"),
+            vec![0xC300]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    let cycles = modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000012, 0x00000047, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+    assert_eq!(6, cycles);
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn abcd_byte_data_register_decimal_carry_from_low_digit() {
+    // arrange - code
+    // ABCD.B D0,D1
+    let code = [0xC3, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000008, 0x00000009, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("D0,D1"),
+            vec![0xC300]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    let cycles = modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000008, 0x00000017, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+    assert_eq!(6, cycles);
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn abcd_byte_data_register_carry_leave_zero_set() {
+    // arrange - code
+    // ABCD.B D0,D1
+    let code = [0xC3, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000099, 0x00000001, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("D0,D1"),
+            vec![0xC300]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    let cycles = modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000099, 0x00000000, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_CARRY
+    );
+    assert_eq!(6, cycles);
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn abcd_byte_data_register_carry_leave_zero_cleared() {
+    // arrange - code
+    // ABCD.B D0,D1
+    let code = [0xC3, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000099, 0x00000001, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("D0,D1"),
+            vec![0xC300]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    let cycles = modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000099, 0x00000000, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_CARRY
+    );
+    assert_eq!(6, cycles);
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn abcd_byte_data_register_overflow_negative() {
+    // arrange - code
+    // ABCD.B D0,D1
+    let code = [0xC3, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000045, 0x00000038, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("D0,D1"),
+            vec![0xC300]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    let cycles = modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000045, 0x00000084, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+    assert_eq!(6, cycles);
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn abcd_byte_data_register_carry_overflow_negative() {
+    // arrange - code
+    // ABCD.B D0,D1
+    let code = [0xC3, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000099, 0x00000099, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("D0,D1"),
+            vec![0xC300]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    let cycles = modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000099, 0x00000099, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+    assert_eq!(6, cycles);
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn abcd_byte_address_register_with_extend_clear() {
+    // arrange - code
+    // ABCD.B -(A3),-(A4)
+    let code = [0xC9, 0x0B].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050002 = [0x12, 0x34].to_vec();
+    let arrange_mem_00050002 = RamMemory::from_bytes(0x00050002, arrange_mem_bytes_00050002);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("-(A3),-(A4)"),
+            vec![0xC90B]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050002, 0x00050003, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    assert_eq!(0x12, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x46, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn abcd_byte_address_register_with_extend_set() {
+    // arrange - code
+    // ABCD.B -(A3),-(A4)
+    let code = [0xC9, 0x0B].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050002 = [0x12, 0x34].to_vec();
+    let arrange_mem_00050002 = RamMemory::from_bytes(0x00050002, arrange_mem_bytes_00050002);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("-(A3),-(A4)"),
+            vec![0xC90B]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050002, 0x00050003, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    assert_eq!(0x12, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x47, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn abcd_byte_address_register_decimal_carry_from_low_digit() {
+    // arrange - code
+    // ABCD.B -(A3),-(A4)
+    let code = [0xC9, 0x0B].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050002 = [0x08, 0x09].to_vec();
+    let arrange_mem_00050002 = RamMemory::from_bytes(0x00050002, arrange_mem_bytes_00050002);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("-(A3),-(A4)"),
+            vec![0xC90B]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050002, 0x00050003, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    assert_eq!(0x08, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x17, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn abcd_byte_address_register_carry_leave_zero_set() {
+    // arrange - code
+    // ABCD.B -(A3),-(A4)
+    let code = [0xC9, 0x0B].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050002 = [0x99, 0x01].to_vec();
+    let arrange_mem_00050002 = RamMemory::from_bytes(0x00050002, arrange_mem_bytes_00050002);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("-(A3),-(A4)"),
+            vec![0xC90B]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050002, 0x00050003, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // assert - mem
+    assert_eq!(0x99, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn abcd_byte_address_register_carry_leave_zero_cleared() {
+    // arrange - code
+    // ABCD.B -(A3),-(A4)
+    let code = [0xC9, 0x0B].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050002 = [0x99, 0x01].to_vec();
+    let arrange_mem_00050002 = RamMemory::from_bytes(0x00050002, arrange_mem_bytes_00050002);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("-(A3),-(A4)"),
+            vec![0xC90B]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050002, 0x00050003, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // assert - mem
+    assert_eq!(0x99, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn abcd_byte_address_register_overflow_negative() {
+    // arrange - code
+    // ABCD.B -(A3),-(A4)
+    let code = [0xC9, 0x0B].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050002 = [0x45, 0x38].to_vec();
+    let arrange_mem_00050002 = RamMemory::from_bytes(0x00050002, arrange_mem_bytes_00050002);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("-(A3),-(A4)"),
+            vec![0xC90B]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050002, 0x00050003, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+
+    // assert - mem
+    assert_eq!(0x45, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x84, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn abcd_byte_address_register_carry_overflow_negative() {
+    // arrange - code
+    // ABCD.B -(A3),-(A4)
+    let code = [0xC9, 0x0B].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050002 = [0x99, 0x99].to_vec();
+    let arrange_mem_00050002 = RamMemory::from_bytes(0x00050002, arrange_mem_bytes_00050002);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050003, 0x00050004, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040002,
+            String::from("ABCD.B"),
+            String::from("-(A3),-(A4)"),
+            vec![0xC90B]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0xa0a0a0a0, 0x000000a1, 0x000000a2, 0x00050002, 0x00050003, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // assert - mem
+    assert_eq!(0x99, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x99, modermodem.mem.get_byte_no_log(0x00050003));
+}
@@ -10,7 +10,7 @@ use crate::mem::rammemory::RamMemory;
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::mem::memory::Memory;
 use crate::mem::ciamemory::CiaMemory;
-use crate::cpu::{Cpu, CpuSpeed};
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
 use crate::mem::Mem;
 use crate::modermodem::Modermodem;
 use crate::register::STATUS_REGISTER_MASK_CARRY;
@@ -39,7 +39,7 @@ fn address_register_indirect_to_data_register_direct_byte() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -68,7 +68,7 @@ fn address_register_indirect_to_data_register_direct_byte() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000002, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x0000d7d7);
@@ -76,6 +76,7 @@ fn address_register_indirect_to_data_register_direct_byte() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     assert_eq!(0x01, modermodem.mem.get_byte_no_log(0x00050002));
@@ -100,7 +101,7 @@ fn address_register_indirect_to_data_register_direct_byte_overflow() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -129,7 +130,7 @@ fn address_register_indirect_to_data_register_direct_byte_overflow() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000080, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x0000d7d7);
@@ -138,6 +139,7 @@ fn address_register_indirect_to_data_register_direct_byte_overflow() {
        STATUS_REGISTER_MASK_NEGATIVE
        | STATUS_REGISTER_MASK_OVERFLOW
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     assert_eq!(0x01, modermodem.mem.get_byte_no_log(0x00050002));
@@ -162,7 +164,7 @@ fn address_register_indirect_to_data_register_direct_byte_carry() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -191,7 +193,7 @@ fn address_register_indirect_to_data_register_direct_byte_carry() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000000, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x0000d7d7);
@@ -201,6 +203,7 @@ fn address_register_indirect_to_data_register_direct_byte_carry() {
        | STATUS_REGISTER_MASK_ZERO
        | STATUS_REGISTER_MASK_CARRY
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     assert_eq!(0x01, modermodem.mem.get_byte_no_log(0x00050002));
@@ -225,7 +228,7 @@ fn address_register_indirect_to_data_register_direct_word() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00060002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -254,7 +257,7 @@ fn address_register_indirect_to_data_register_direct_word() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000002, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x0000d7d7);
@@ -262,6 +265,7 @@ fn address_register_indirect_to_data_register_direct_word() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00060002));
@@ -287,7 +291,7 @@ fn address_register_indirect_to_data_register_direct_word_overflow() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00060002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -316,7 +320,7 @@ fn address_register_indirect_to_data_register_direct_word_overflow() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00008000, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x0000d7d7);
@@ -325,6 +329,7 @@ fn address_register_indirect_to_data_register_direct_word_overflow() {
        STATUS_REGISTER_MASK_NEGATIVE
        | STATUS_REGISTER_MASK_OVERFLOW
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00060002));
@@ -350,7 +355,7 @@ fn address_register_indirect_to_data_register_direct_word_carry() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00060002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -379,7 +384,7 @@ fn address_register_indirect_to_data_register_direct_word_carry() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000000, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x0000d7d7);
@@ -389,6 +394,7 @@ fn address_register_indirect_to_data_register_direct_word_carry() {
        | STATUS_REGISTER_MASK_ZERO
        | STATUS_REGISTER_MASK_CARRY
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00060002));
@@ -412,7 +418,7 @@ fn data_register_direct_to_data_register_direct_long() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -473,7 +479,7 @@ fn data_register_direct_to_address_register_indirect_long() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00060002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -537,7 +543,7 @@ fn data_register_direct_to_address_register_indirect_word() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00060002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -599,7 +605,7 @@ fn data_register_direct_to_address_register_indirect_byte() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00060002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -659,7 +665,7 @@ fn immediate_data_to_address_register_direct_word() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -714,7 +720,7 @@ fn immediate_data_to_address_register_direct_long() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
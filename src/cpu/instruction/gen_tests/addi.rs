@@ -10,7 +10,7 @@ use crate::mem::rammemory::RamMemory;
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::mem::memory::Memory;
 use crate::mem::ciamemory::CiaMemory;
-use crate::cpu::{Cpu, CpuSpeed};
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
 use crate::mem::Mem;
 use crate::modermodem::Modermodem;
 use crate::register::STATUS_REGISTER_MASK_CARRY;
@@ -37,7 +37,7 @@ fn addi_byte_immediate_data_to_data_register_direct() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -98,7 +98,7 @@ fn addi_byte_immediate_data_to_absolute_short() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00004000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -158,7 +158,7 @@ fn addi_word_immediate_data_to_data_register_direct() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -219,7 +219,7 @@ fn addi_word_immediate_data_to_absolute_long() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00040000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00030000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00030000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -279,7 +279,7 @@ fn addi_long_immediate_data_to_data_register_direct() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00030000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00030000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -341,7 +341,7 @@ fn addi_long_immediate_data_to_absolute_long() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00040000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00030000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00030000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
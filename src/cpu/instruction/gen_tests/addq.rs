@@ -10,7 +10,7 @@ use crate::mem::rammemory::RamMemory;
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::mem::memory::Memory;
 use crate::mem::ciamemory::CiaMemory;
-use crate::cpu::{Cpu, CpuSpeed};
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
 use crate::mem::Mem;
 use crate::modermodem::Modermodem;
 use crate::register::STATUS_REGISTER_MASK_CARRY;
@@ -39,7 +39,7 @@ fn addq_data_to_data_register_direct_byte() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00004000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -68,7 +68,7 @@ fn addq_data_to_data_register_direct_byte() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x000000d0, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
@@ -76,6 +76,7 @@ fn addq_data_to_data_register_direct_byte() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(12, cycles);
 
     // assert - mem
     assert_eq!(0x15, modermodem.mem.get_byte_no_log(0x00004000));
@@ -100,7 +101,7 @@ fn addq_data_to_data_register_direct_byte_overflow() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00004000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -129,7 +130,7 @@ fn addq_data_to_data_register_direct_byte_overflow() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x000000d0, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
@@ -138,6 +139,7 @@ fn addq_data_to_data_register_direct_byte_overflow() {
        STATUS_REGISTER_MASK_NEGATIVE
        | STATUS_REGISTER_MASK_OVERFLOW
     );
+    assert_eq!(12, cycles);
 
     // assert - mem
     assert_eq!(0x83, modermodem.mem.get_byte_no_log(0x00004000));
@@ -162,7 +164,7 @@ fn addq_data_to_data_register_direct_word() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00004000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -191,7 +193,7 @@ fn addq_data_to_data_register_direct_word() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x000000d0, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
@@ -199,6 +201,7 @@ fn addq_data_to_data_register_direct_word() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(12, cycles);
 
     // assert - mem
     assert_eq!(0x60, modermodem.mem.get_byte_no_log(0x00004000));
@@ -224,7 +227,7 @@ fn addq_data_to_data_register_direct_word_carry() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00004000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -253,7 +256,7 @@ fn addq_data_to_data_register_direct_word_carry() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x000000d0, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
@@ -262,6 +265,7 @@ fn addq_data_to_data_register_direct_word_carry() {
        STATUS_REGISTER_MASK_EXTEND
        | STATUS_REGISTER_MASK_CARRY
     );
+    assert_eq!(12, cycles);
 
     // assert - mem
     assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00004000));
@@ -287,7 +291,7 @@ fn addq_data_to_data_register_direct_word_negative() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00004000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -315,7 +319,7 @@ fn addq_data_to_data_register_direct_word_negative() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x000000d0, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
@@ -323,6 +327,7 @@ fn addq_data_to_data_register_direct_word_negative() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        STATUS_REGISTER_MASK_NEGATIVE
     );
+    assert_eq!(12, cycles);
 
     // assert - mem
     assert_eq!(0xff, modermodem.mem.get_byte_no_log(0x00004000));
@@ -348,7 +353,7 @@ fn addq_data_to_data_register_direct_long() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00004000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -377,7 +382,7 @@ fn addq_data_to_data_register_direct_long() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x000000d0, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
@@ -385,6 +390,7 @@ fn addq_data_to_data_register_direct_long() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(20, cycles);
 
     // assert - mem
     assert_eq!(0x60, modermodem.mem.get_byte_no_log(0x00004000));
@@ -412,7 +418,7 @@ fn addq_data_to_data_register_direct_long_zero() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00004000)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -476,7 +482,7 @@ fn addq_data_to_address_register_direct_word() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -531,7 +537,7 @@ fn addq_data_to_address_register_direct_long() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
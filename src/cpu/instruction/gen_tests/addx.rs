@@ -10,7 +10,7 @@ use crate::mem::rammemory::RamMemory;
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::mem::memory::Memory;
 use crate::mem::ciamemory::CiaMemory;
-use crate::cpu::{Cpu, CpuSpeed};
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
 use crate::mem::Mem;
 use crate::modermodem::Modermodem;
 use crate::register::STATUS_REGISTER_MASK_CARRY;
@@ -37,7 +37,7 @@ fn data_register_byte_with_extend_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -95,7 +95,7 @@ fn data_register_byte_with_extend_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -150,7 +150,7 @@ fn data_register_byte_with_extend_set_set_carry_extend() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -206,7 +206,7 @@ fn data_register_byte_with_extend_set_set_carry_extend_leave_zero_cleared_test_b
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -262,7 +262,7 @@ fn data_register_byte_with_extend_set_set_carry_extend_leave_zero_set_test_both_
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -320,7 +320,7 @@ fn data_register_byte_with_extend_set_set_overflow_negative() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -376,7 +376,7 @@ fn data_register_byte_with_extend_set_set_overflow_negative_test_both_overflow()
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -432,7 +432,7 @@ fn data_register_word_with_extend_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -490,7 +490,7 @@ fn data_register_word_with_extend_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -545,7 +545,7 @@ fn data_register_word_with_extend_set_set_carry_extend() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -601,7 +601,7 @@ fn data_register_word_with_extend_set_set_carry_extend_leave_zero_cleared_test_b
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -657,7 +657,7 @@ fn data_register_word_with_extend_set_set_carry_extend_leave_zero_set_test_both_
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -715,7 +715,7 @@ fn data_register_word_with_extend_set_set_overflow_negative() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -771,7 +771,7 @@ fn data_register_word_with_extend_set_set_overflow_negative_zero_test_both_overf
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -827,7 +827,7 @@ fn data_register_long_with_extend_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -885,7 +885,7 @@ fn data_register_long_with_extend_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -940,7 +940,7 @@ fn data_register_long_with_extend_set_set_carry_extend() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -996,7 +996,7 @@ fn data_register_long_with_extend_set_set_carry_extend_leave_zero_cleared_test_b
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1052,7 +1052,7 @@ fn data_register_long_with_extend_set_set_carry_extend_leave_zero_cleared_set_bo
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1110,7 +1110,7 @@ fn data_register_long_with_extend_set_set_overflow_negative() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1166,7 +1166,7 @@ fn data_register_long_with_extend_set_set_overflow_negative_zero_test_both_overf
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1224,7 +1224,7 @@ fn address_register_byte_with_extend_clear() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1285,7 +1285,7 @@ fn address_register_byte_with_extend_set() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050002)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1343,7 +1343,7 @@ fn address_register_word_with_extend_clear() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00040110)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1406,7 +1406,7 @@ fn address_register_word_with_extend_set() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00040110)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1470,7 +1470,7 @@ fn address_register_long_with_extend_clear() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00040110)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1536,7 +1536,7 @@ fn address_register_long_with_extend_set() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00040110)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -0,0 +1,311 @@
+// Path: ..\src\cpu\instruction\gen_tests\andi_to_ccr.rs
+// This file is autogenerated from tests\andi_to_ccr.tests
+
+#![allow(unused_imports)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::register::ProgramCounter;
+use crate::mem::rammemory::RamMemory;
+use crate::cpu::instruction::GetDisassemblyResult;
+use crate::mem::memory::Memory;
+use crate::mem::ciamemory::CiaMemory;
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
+use crate::mem::Mem;
+use crate::modermodem::Modermodem;
+use crate::register::STATUS_REGISTER_MASK_CARRY;
+use crate::register::STATUS_REGISTER_MASK_EXTEND;
+use crate::register::STATUS_REGISTER_MASK_NEGATIVE;
+use crate::register::STATUS_REGISTER_MASK_OVERFLOW;
+use crate::register::STATUS_REGISTER_MASK_ZERO;
+
+
+#[test]
+fn andi_to_ccr_b_00_ccr_1f() {
+    // arrange - code
+    // ANDI.B #$00,CCR
+    let code = [0x02, 0x3C, 0x00, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("ANDI.B"),
+            String::from("#$00,CCR"),
+            vec![0x023C,0x0000]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn andi_to_ccr_b_1f_ccr_00() {
+    // arrange - code
+    // ANDI.B #$1F,CCR
+    let code = [0x02, 0x3C, 0x00, 0x1F].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("ANDI.B"),
+            String::from("#$1F,CCR"),
+            vec![0x023C,0x001F]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn andi_to_ccr_b_15_ccr_1f() {
+    // arrange - code
+    // ANDI.B #$15,CCR
+    let code = [0x02, 0x3C, 0x00, 0x15].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("ANDI.B"),
+            String::from("#$15,CCR"),
+            vec![0x023C,0x0015]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn andi_to_ccr_b_0a_ccr_05() {
+    // arrange - code
+    // ANDI.B #$0A,CCR
+    let code = [0x02, 0x3C, 0x00, 0x0A].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("ANDI.B"),
+            String::from("#$0A,CCR"),
+            vec![0x023C,0x000A]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn andi_to_ccr_b_e0_ccr_1f() {
+    // arrange - code
+    // ANDI.B #$E0,CCR
+    let code = [0x02, 0x3C, 0x00, 0xE0].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("ANDI.B"),
+            String::from("#$E0,CCR"),
+            vec![0x023C,0x00E0]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000010, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00004344);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    // -nothing-
+}
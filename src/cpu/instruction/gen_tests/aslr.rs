@@ -10,7 +10,7 @@ use crate::mem::rammemory::RamMemory;
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::mem::memory::Memory;
 use crate::mem::ciamemory::CiaMemory;
-use crate::cpu::{Cpu, CpuSpeed};
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
 use crate::mem::Mem;
 use crate::modermodem::Modermodem;
 use crate::register::STATUS_REGISTER_MASK_CARRY;
@@ -37,7 +37,7 @@ fn asl_b_register_by_immediate_cx_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -63,7 +63,7 @@ fn asl_b_register_by_immediate_cx_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
@@ -73,6 +73,7 @@ fn asl_b_register_by_immediate_cx_set() {
        | STATUS_REGISTER_MASK_OVERFLOW
        | STATUS_REGISTER_MASK_CARRY
     );
+    assert_eq!(18, cycles);
 
     // assert - mem
     // -nothing-
@@ -95,7 +96,7 @@ fn asl_b_register_by_immediate_cx_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -124,7 +125,7 @@ fn asl_b_register_by_immediate_cx_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
@@ -132,6 +133,7 @@ fn asl_b_register_by_immediate_cx_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(18, cycles);
 
     // assert - mem
     // -nothing-
@@ -154,7 +156,7 @@ fn asl_b_register_by_immediate_n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -181,7 +183,7 @@ fn asl_b_register_by_immediate_n_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000080, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
@@ -190,6 +192,7 @@ fn asl_b_register_by_immediate_n_set() {
        STATUS_REGISTER_MASK_NEGATIVE
        | STATUS_REGISTER_MASK_OVERFLOW
     );
+    assert_eq!(18, cycles);
 
     // assert - mem
     // -nothing-
@@ -212,7 +215,7 @@ fn asl_b_register_by_immediate_n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -241,7 +244,7 @@ fn asl_b_register_by_immediate_n_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000080, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00000014);
@@ -249,6 +252,7 @@ fn asl_b_register_by_immediate_n_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(10, cycles);
 
     // assert - mem
     // -nothing-
@@ -271,7 +275,7 @@ fn asl_b_register_by_immediate_z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -296,7 +300,7 @@ fn asl_b_register_by_immediate_z_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000080, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00000000);
@@ -307,6 +311,7 @@ fn asl_b_register_by_immediate_z_set() {
        | STATUS_REGISTER_MASK_OVERFLOW
        | STATUS_REGISTER_MASK_CARRY
     );
+    assert_eq!(10, cycles);
 
     // assert - mem
     // -nothing-
@@ -329,7 +334,7 @@ fn asl_b_register_by_immediate_z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -358,7 +363,7 @@ fn asl_b_register_by_immediate_z_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000080, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x00000004);
@@ -366,6 +371,7 @@ fn asl_b_register_by_immediate_z_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(10, cycles);
 
     // assert - mem
     // -nothing-
@@ -388,7 +394,7 @@ fn asl_b_register_by_immediate_v_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -446,7 +452,7 @@ fn asl_b_register_by_immediate_v_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -505,7 +511,7 @@ fn asr_b_register_by_immediate_cx_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -563,7 +569,7 @@ fn asr_b_register_by_immediate_cx_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -622,7 +628,7 @@ fn asr_b_register_by_immediate_n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -680,7 +686,7 @@ fn asr_b_register_by_immediate_n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -739,7 +745,7 @@ fn asr_b_register_by_immediate_z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -797,7 +803,7 @@ fn asr_b_register_by_immediate_z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -856,7 +862,7 @@ fn asr_b_register_by_immediate_v_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -915,7 +921,7 @@ fn asl_w_register_by_immediate_cx_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -973,7 +979,7 @@ fn asl_w_register_by_immediate_cx_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1032,7 +1038,7 @@ fn asl_w_register_by_immediate_n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1090,7 +1096,7 @@ fn asl_w_register_by_immediate_n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1149,7 +1155,7 @@ fn asl_w_register_by_immediate_z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1207,7 +1213,7 @@ fn asl_w_register_by_immediate_z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1266,7 +1272,7 @@ fn asl_w_register_by_immediate_v_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1324,7 +1330,7 @@ fn asl_w_register_by_immediate_v_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1383,7 +1389,7 @@ fn asr_w_register_by_immediate_cx_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1441,7 +1447,7 @@ fn asr_w_register_by_immediate_cx_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1500,7 +1506,7 @@ fn asr_w_register_by_immediate_n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1558,7 +1564,7 @@ fn asr_w_register_by_immediate_n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1617,7 +1623,7 @@ fn asr_w_register_by_immediate_z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1675,7 +1681,7 @@ fn asr_w_register_by_immediate_z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1734,7 +1740,7 @@ fn asr_w_register_by_immediate_v_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1793,7 +1799,7 @@ fn asl_l_register_by_immediate_cx_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1851,7 +1857,7 @@ fn asl_l_register_by_immediate_cx_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1910,7 +1916,7 @@ fn asl_l_register_by_immediate_n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1968,7 +1974,7 @@ fn asl_l_register_by_immediate_n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2027,7 +2033,7 @@ fn asl_l_register_by_immediate_z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2085,7 +2091,7 @@ fn asl_l_register_by_immediate_z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2144,7 +2150,7 @@ fn asl_l_register_by_immediate_v_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2202,7 +2208,7 @@ fn asl_l_register_by_immediate_v_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2261,7 +2267,7 @@ fn asr_l_register_by_immediate_cx_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2319,7 +2325,7 @@ fn asr_l_register_by_immediate_cx_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2378,7 +2384,7 @@ fn asr_l_register_by_immediate_n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2436,7 +2442,7 @@ fn asr_l_register_by_immediate_n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2495,7 +2501,7 @@ fn asr_l_register_by_immediate_z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2553,7 +2559,7 @@ fn asr_l_register_by_immediate_z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2612,7 +2618,7 @@ fn asr_l_register_by_immediate_v_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2671,7 +2677,7 @@ fn asl_b_register_by_register_cx_shift_0_c_clear_x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2730,7 +2736,7 @@ fn asl_b_register_by_register_cx_shift_0_c_clear_x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2787,7 +2793,7 @@ fn asr_b_register_by_register_cx_shift_0_c_clear_x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2846,7 +2852,7 @@ fn asr_b_register_by_register_cx_shift_0_c_clear_x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2904,7 +2910,7 @@ fn asl_w_register_by_register_cx_shift_0_c_clear_x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -2963,7 +2969,7 @@ fn asl_w_register_by_register_cx_shift_0_c_clear_x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3020,7 +3026,7 @@ fn asr_w_register_by_register_cx_shift_0_c_clear_x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3079,7 +3085,7 @@ fn asr_w_register_by_register_cx_shift_0_c_clear_x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3137,7 +3143,7 @@ fn asl_l_register_by_register_cx_shift_0_c_clear_x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3196,7 +3202,7 @@ fn asl_l_register_by_register_cx_shift_0_c_clear_x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3253,7 +3259,7 @@ fn asr_l_register_by_register_cx_shift_0_c_clear_x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3312,7 +3318,7 @@ fn asr_l_register_by_register_cx_shift_0_c_clear_x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3370,7 +3376,7 @@ fn asl_b_register_by_register_big_shift() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3428,7 +3434,7 @@ fn asr_b_register_by_register_big_shift() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3486,7 +3492,7 @@ fn asl_w_register_by_register_big_shift() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3544,7 +3550,7 @@ fn asr_w_register_by_register_big_shift() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3602,7 +3608,7 @@ fn asl_l_register_by_register_big_shift() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3660,7 +3666,7 @@ fn asr_l_register_by_register_big_shift() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3720,7 +3726,7 @@ fn asl_w_memory_by_1_areg_indirect_with_predecrement() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050110)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3782,7 +3788,7 @@ fn asl_w_memory_by_1_areg_indirect_with_displacement() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050110)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3843,7 +3849,7 @@ fn asr_w_memory_by_1_areg_indirect_with_postincrement() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050110)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -3905,7 +3911,7 @@ fn asr_w_memory_by_1_areg_indirect() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00050110)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -0,0 +1,758 @@
+// Path: ..\src\cpu\instruction\gen_tests\bitfield.rs
+// This file is autogenerated from tests\bitfield.tests
+
+#![allow(unused_imports)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::register::ProgramCounter;
+use crate::mem::rammemory::RamMemory;
+use crate::cpu::instruction::GetDisassemblyResult;
+use crate::mem::memory::Memory;
+use crate::mem::ciamemory::CiaMemory;
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
+use crate::mem::Mem;
+use crate::modermodem::Modermodem;
+use crate::register::STATUS_REGISTER_MASK_CARRY;
+use crate::register::STATUS_REGISTER_MASK_EXTEND;
+use crate::register::STATUS_REGISTER_MASK_NEGATIVE;
+use crate::register::STATUS_REGISTER_MASK_OVERFLOW;
+use crate::register::STATUS_REGISTER_MASK_ZERO;
+
+
+#[test]
+fn bfextu_data_register() {
+    // arrange - code
+    // BFEXTU D0{8:8},D1
+    let code = [0xE9, 0xC0, 0x12, 0x08].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x12345678, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFEXTU"),
+            String::from("D0{8:8},D1"),
+            vec![0xE9C0,0x1208]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12345678, 0x00000034, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn bfextu_data_register_wraps_around() {
+    // arrange - code
+    // BFEXTU D0{28:8},D1
+    let code = [0xE9, 0xC0, 0x17, 0x08].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x12345678, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFEXTU"),
+            String::from("D0{28:8},D1"),
+            vec![0xE9C0,0x1708]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12345678, 0x00000081, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn bfexts_data_register_register_offset_and_width() {
+    // arrange - code
+    // BFEXTS D0{D2:D3},D1
+    let code = [0xEB, 0xC0, 0x18, 0xA3].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0xf0000000, 0x000000d1, 0x00000000, 0x00000004, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFEXTS"),
+            String::from("D0{D2:D3},D1"),
+            vec![0xEBC0,0x18A3]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0xf0000000, 0xffffffff, 0x00000000, 0x00000004, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn bftst_memory_z_set() {
+    // arrange - code
+    // BFTST (A0){4:4}
+    let code = [0xE8, 0xD0, 0x01, 0x04].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0xF0].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFTST"),
+            String::from("(A0){4:4}"),
+            vec![0xE8D0,0x0104]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    assert_eq!(0xf0, modermodem.mem.get_byte_no_log(0x00050000));
+}
+
+#[test]
+fn bfextu_memory_spans_bytes() {
+    // arrange - code
+    // BFEXTU (A0){6:16},D1
+    let code = [0xE9, 0xD0, 0x11, 0x90].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x03, 0x4B, 0xBF, 0xFF].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFEXTU"),
+            String::from("(A0){6:16},D1"),
+            vec![0xE9D0,0x1190]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x0000d2ef, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+    );
+
+    // assert - mem
+    assert_eq!(0x03, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x4b, modermodem.mem.get_byte_no_log(0x00050001));
+    assert_eq!(0xbf, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0xff, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn bfextu_memory_negative_offset() {
+    // arrange - code
+    // BFEXTU (A0){D2:D3},D1
+    let code = [0xE9, 0xD0, 0x18, 0xA3].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0xA5, 0xA5].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0xfffffffc, 0x00000008, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050001, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFEXTU"),
+            String::from("(A0){D2:D3},D1"),
+            vec![0xE9D0,0x18A3]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x0000005a, 0xfffffffc, 0x00000008, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050001, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    assert_eq!(0xa5, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0xa5, modermodem.mem.get_byte_no_log(0x00050001));
+}
+
+#[test]
+fn bfchg_data_register() {
+    // arrange - code
+    // BFCHG D0{4:4}
+    let code = [0xEA, 0xC0, 0x01, 0x04].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x0f000000, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFCHG"),
+            String::from("D0{4:4}"),
+            vec![0xEAC0,0x0104]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000000, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn bfclr_memory_full_width() {
+    // arrange - code
+    // BFCLR (A0){4:32}
+    let code = [0xEC, 0xD0, 0x01, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFCLR"),
+            String::from("(A0){4:32}"),
+            vec![0xECD0,0x0100]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+    );
+
+    // assert - mem
+    assert_eq!(0xf0, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050001));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050003));
+    assert_eq!(0x0f, modermodem.mem.get_byte_no_log(0x00050004));
+}
+
+#[test]
+fn bfset_data_register() {
+    // arrange - code
+    // BFSET D0{28:2}
+    let code = [0xEE, 0xC0, 0x07, 0x02].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000000, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFSET"),
+            String::from("D0{28:2}"),
+            vec![0xEEC0,0x0702]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x0000000c, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn bfffo_data_register() {
+    // arrange - code
+    // BFFFO D0{2:8},D1
+    let code = [0xED, 0xC0, 0x10, 0x88].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x04000000, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFFFO"),
+            String::from("D0{2:8},D1"),
+            vec![0xEDC0,0x1088]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x04000000, 0x00000005, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn bfffo_data_register_no_bit_set() {
+    // arrange - code
+    // BFFFO D0{2:8},D1
+    let code = [0xED, 0xC0, 0x10, 0x88].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0xc0000000, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFFFO"),
+            String::from("D0{2:8},D1"),
+            vec![0xEDC0,0x1088]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0xc0000000, 0x0000000a, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    // -nothing-
+}
+
+#[test]
+fn bfins_memory() {
+    // arrange - code
+    // BFINS D1,(A0){4:12}
+    let code = [0xEF, 0xD0, 0x11, 0x0C].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0xFF, 0xFF, 0xFF].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x00000abc, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFINS"),
+            String::from("D1,(A0){4:12}"),
+            vec![0xEFD0,0x110C]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x00000abc, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+    );
+
+    // assert - mem
+    assert_eq!(0xfa, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0xbc, modermodem.mem.get_byte_no_log(0x00050001));
+    assert_eq!(0xff, modermodem.mem.get_byte_no_log(0x00050002));
+}
+
+#[test]
+fn bfins_data_register_z_set() {
+    // arrange - code
+    // BFINS D1,D0{0:4}
+    let code = [0xEF, 0xC0, 0x10, 0x04].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    // -nothing-
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0xffffffff, 0xfffffff0, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("BFINS"),
+            String::from("D1,D0{0:4}"),
+            vec![0xEFC0,0x1004]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x0fffffff, 0xfffffff0, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x000000a0, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    // -nothing-
+}
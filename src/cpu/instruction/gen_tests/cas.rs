@@ -0,0 +1,384 @@
+// Path: ..\src\cpu\instruction\gen_tests\cas.rs
+// This file is autogenerated from tests\cas.tests
+
+#![allow(unused_imports)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::register::ProgramCounter;
+use crate::mem::rammemory::RamMemory;
+use crate::cpu::instruction::GetDisassemblyResult;
+use crate::mem::memory::Memory;
+use crate::mem::ciamemory::CiaMemory;
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
+use crate::mem::Mem;
+use crate::modermodem::Modermodem;
+use crate::register::STATUS_REGISTER_MASK_CARRY;
+use crate::register::STATUS_REGISTER_MASK_EXTEND;
+use crate::register::STATUS_REGISTER_MASK_NEGATIVE;
+use crate::register::STATUS_REGISTER_MASK_OVERFLOW;
+use crate::register::STATUS_REGISTER_MASK_ZERO;
+
+
+#[test]
+fn cas_l_equal() {
+    // arrange - code
+    // CAS.L D1,D2,(A0)
+    let code = [0x0E, 0xD0, 0x00, 0x81].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x12, 0x34, 0x56, 0x78].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x12345678, 0xaabbccdd, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CAS.L"),
+            String::from("D1,D2,(A0)"),
+            vec![0x0ED0,0x0081]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x12345678, 0xaabbccdd, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    assert_eq!(0xaa, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0xbb, modermodem.mem.get_byte_no_log(0x00050001));
+    assert_eq!(0xcc, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0xdd, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn cas_l_not_equal() {
+    // arrange - code
+    // CAS.L D1,D2,(A0)
+    let code = [0x0E, 0xD0, 0x00, 0x81].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x12, 0x34, 0x56, 0x78].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x12345600, 0xaabbccdd, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CAS.L"),
+            String::from("D1,D2,(A0)"),
+            vec![0x0ED0,0x0081]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x12345678, 0xaabbccdd, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    assert_eq!(0x12, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x34, modermodem.mem.get_byte_no_log(0x00050001));
+    assert_eq!(0x56, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x78, modermodem.mem.get_byte_no_log(0x00050003));
+}
+
+#[test]
+fn cas_b_equal_postincrement() {
+    // arrange - code
+    // CAS.B D1,D2,(A0)+
+    let code = [0x0A, 0xD8, 0x00, 0x81].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x05].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x11111105, 0x222222ee, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CAS.B"),
+            String::from("D1,D2,(A0)+"),
+            vec![0x0AD8,0x0081]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x11111105, 0x222222ee, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050001, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    assert_eq!(0xee, modermodem.mem.get_byte_no_log(0x00050000));
+}
+
+#[test]
+fn cas_w_not_equal_predecrement() {
+    // arrange - code
+    // CAS.W D1,D2,-(A0)
+    let code = [0x0C, 0xE0, 0x00, 0x81].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x12, 0x34].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x11111235, 0x2222eeee, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050002, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CAS.W"),
+            String::from("D1,D2,-(A0)"),
+            vec![0x0CE0,0x0081]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x11111234, 0x2222eeee, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // assert - mem
+    assert_eq!(0x12, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x34, modermodem.mem.get_byte_no_log(0x00050001));
+}
+
+#[test]
+fn cas2_l_equal() {
+    // arrange - code
+    // CAS2.L D0:D1,D3:D3,(A0):(A1)
+    let code = [0x0E, 0xFC, 0x80, 0xC0, 0x90, 0xC1].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x11, 0x11, 0x11, 0x11, 0x22, 0x22, 0x22, 0x22].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x11111111, 0x22222222, 0x000000d2, 0x33333333, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x00050004, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040006,
+            String::from("CAS2.L"),
+            String::from("D0:D1,D3:D3,(A0):(A1)"),
+            vec![0x0EFC,0x80C0,0x90C1]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x11111111, 0x22222222, 0x000000d2, 0x33333333, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x00050004, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    assert_eq!(0x33, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x33, modermodem.mem.get_byte_no_log(0x00050001));
+    assert_eq!(0x33, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x33, modermodem.mem.get_byte_no_log(0x00050003));
+    assert_eq!(0x33, modermodem.mem.get_byte_no_log(0x00050004));
+    assert_eq!(0x33, modermodem.mem.get_byte_no_log(0x00050005));
+    assert_eq!(0x33, modermodem.mem.get_byte_no_log(0x00050006));
+    assert_eq!(0x33, modermodem.mem.get_byte_no_log(0x00050007));
+}
+
+#[test]
+fn cas2_w_second_not_equal() {
+    // arrange - code
+    // CAS2.W D0:D1,D3:D3,(A0):(A1)
+    let code = [0x0C, 0xFC, 0x80, 0xC0, 0x90, 0xC1].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x11, 0x11, 0x22, 0x22].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00001111, 0x00002223, 0x000000d2, 0x00003333, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x00050002, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040006,
+            String::from("CAS2.W"),
+            String::from("D0:D1,D3:D3,(A0):(A1)"),
+            vec![0x0CFC,0x80C0,0x90C1]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00001111, 0x00002222, 0x000000d2, 0x00003333, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x00050002, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // assert - mem
+    assert_eq!(0x11, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x11, modermodem.mem.get_byte_no_log(0x00050001));
+    assert_eq!(0x22, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x22, modermodem.mem.get_byte_no_log(0x00050003));
+}
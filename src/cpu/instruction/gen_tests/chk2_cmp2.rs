@@ -0,0 +1,441 @@
+// Path: ..\src\cpu\instruction\gen_tests\chk2_cmp2.rs
+// This file is autogenerated from tests\chk2_cmp2.tests
+
+#![allow(unused_imports)]
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use crate::register::ProgramCounter;
+use crate::mem::rammemory::RamMemory;
+use crate::cpu::instruction::GetDisassemblyResult;
+use crate::mem::memory::Memory;
+use crate::mem::ciamemory::CiaMemory;
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
+use crate::mem::Mem;
+use crate::modermodem::Modermodem;
+use crate::register::STATUS_REGISTER_MASK_CARRY;
+use crate::register::STATUS_REGISTER_MASK_EXTEND;
+use crate::register::STATUS_REGISTER_MASK_NEGATIVE;
+use crate::register::STATUS_REGISTER_MASK_OVERFLOW;
+use crate::register::STATUS_REGISTER_MASK_ZERO;
+
+
+#[test]
+fn cmp2_b_in_bounds() {
+    // arrange - code
+    // CMP2.B (A0),D1
+    let code = [0x00, 0xD0, 0x10, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x10, 0x20].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0xffffff15, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_ZERO
+       | STATUS_REGISTER_MASK_OVERFLOW
+       | STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CMP2.B"),
+            String::from("(A0),D1"),
+            vec![0x00D0,0x1000]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0xffffff15, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_EXTEND
+       | STATUS_REGISTER_MASK_NEGATIVE
+       | STATUS_REGISTER_MASK_OVERFLOW
+    );
+
+    // assert - mem
+    assert_eq!(0x10, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x20, modermodem.mem.get_byte_no_log(0x00050001));
+}
+
+#[test]
+fn cmp2_b_equals_upper_bound() {
+    // arrange - code
+    // CMP2.B (A0),D1
+    let code = [0x00, 0xD0, 0x10, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x10, 0x20].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CMP2.B"),
+            String::from("(A0),D1"),
+            vec![0x00D0,0x1000]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x00000020, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    assert_eq!(0x10, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x20, modermodem.mem.get_byte_no_log(0x00050001));
+}
+
+#[test]
+fn cmp2_b_out_of_bounds() {
+    // arrange - code
+    // CMP2.B (A0),D1
+    let code = [0x00, 0xD0, 0x10, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x10, 0x20].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x00000025, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CMP2.B"),
+            String::from("(A0),D1"),
+            vec![0x00D0,0x1000]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x00000025, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_CARRY
+    );
+
+    // assert - mem
+    assert_eq!(0x10, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x20, modermodem.mem.get_byte_no_log(0x00050001));
+}
+
+#[test]
+fn cmp2_b_wrapped_bounds_out_of_bounds() {
+    // arrange - code
+    // CMP2.B (A0),D1
+    let code = [0x00, 0xD0, 0x10, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x20, 0x10].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x00000018, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CMP2.B"),
+            String::from("(A0),D1"),
+            vec![0x00D0,0x1000]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x00000018, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_CARRY
+    );
+
+    // assert - mem
+    assert_eq!(0x20, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x10, modermodem.mem.get_byte_no_log(0x00050001));
+}
+
+#[test]
+fn cmp2_b_wrapped_bounds_in_bounds() {
+    // arrange - code
+    // CMP2.B (A0),D1
+    let code = [0x00, 0xD0, 0x10, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x20, 0x10].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x00000030, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_CARRY
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CMP2.B"),
+            String::from("(A0),D1"),
+            vec![0x00D0,0x1000]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x00000030, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    assert_eq!(0x20, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x10, modermodem.mem.get_byte_no_log(0x00050001));
+}
+
+#[test]
+fn cmp2_w_address_register_sign_extended_bounds() {
+    // arrange - code
+    // CMP2.W ($0010,A0),A1
+    let code = [0x02, 0xE8, 0x90, 0x00, 0x00, 0x10].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050010 = [0xFF, 0xF0, 0x00, 0x10].to_vec();
+    let arrange_mem_00050010 = RamMemory::from_bytes(0x00050010, arrange_mem_bytes_00050010);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050010)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0xfffffff8, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040006,
+            String::from("CMP2.W"),
+            String::from("($0010,A0),A1"),
+            vec![0x02E8,0x9000,0x0010]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0xfffffff8, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // assert - mem
+    assert_eq!(0xff, modermodem.mem.get_byte_no_log(0x00050010));
+    assert_eq!(0xf0, modermodem.mem.get_byte_no_log(0x00050011));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050012));
+    assert_eq!(0x10, modermodem.mem.get_byte_no_log(0x00050013));
+}
+
+#[test]
+fn chk2_l_equals_lower_bound() {
+    // arrange - code
+    // CHK2.L (A0),D1
+    let code = [0x04, 0xD0, 0x18, 0x00].to_vec();
+    let code_memory = RamMemory::from_bytes(0x00040000, code);
+
+    // arrange - mem
+    let arrange_mem_bytes_00050000 = [0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00].to_vec();
+    let arrange_mem_00050000 = RamMemory::from_bytes(0x00050000, arrange_mem_bytes_00050000);
+
+    // arrange - common
+    let mut mem = Mem::new(None, None);
+    let vectors = RamMemory::from_range(0x00000000, 0x000003ff);
+    let cia_memory = CiaMemory::new();
+    mem.add_range(Rc::new(RefCell::new(code_memory)));
+    mem.add_range(Rc::new(RefCell::new(vectors)));
+    mem.add_range(Rc::new(RefCell::new(cia_memory)));
+    mem.add_range(Rc::new(RefCell::new(arrange_mem_00050000)));
+    let cpu = Cpu::new(CpuModel::M68020, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let mut modermodem = Modermodem::bare(cpu, mem);
+
+    // arrange - regs
+    modermodem.cpu.register.set_all_d_reg_long_no_log(0x00000040, 0x00000100, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.set_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_pc = ProgramCounter::from_address(0x00040000);
+    modermodem.cpu.register.reg_sr.set_sr_reg_flags_abcde(
+       0x0000
+    );
+
+    // act/assert - disassembly
+    let get_disassembly_result = modermodem.get_next_disassembly_no_log();
+    assert_eq!(
+        GetDisassemblyResult::from_address_and_address_next(
+            0x00040000,
+            0x00040004,
+            String::from("CHK2.L"),
+            String::from("(A0),D1"),
+            vec![0x04D0,0x1800]
+            ),
+            get_disassembly_result
+        );
+
+    // act
+    modermodem.step();
+
+    // assert - regs
+    modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x00000100, 0x000000d2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
+    modermodem.cpu.register.assert_all_a_reg_long_no_log(0x00050000, 0x000000a1, 0x000000a2, 0x000000a3, 0x000000a4, 0x000000a5, 0x000000a6, 0x000000a7);
+    modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
+       STATUS_REGISTER_MASK_ZERO
+    );
+
+    // assert - mem
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050000));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050001));
+    assert_eq!(0x01, modermodem.mem.get_byte_no_log(0x00050002));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050003));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050004));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050005));
+    assert_eq!(0x02, modermodem.mem.get_byte_no_log(0x00050006));
+    assert_eq!(0x00, modermodem.mem.get_byte_no_log(0x00050007));
+}
@@ -10,7 +10,7 @@ use crate::mem::rammemory::RamMemory;
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::mem::memory::Memory;
 use crate::mem::ciamemory::CiaMemory;
-use crate::cpu::{Cpu, CpuSpeed};
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
 use crate::mem::Mem;
 use crate::modermodem::Modermodem;
 use crate::register::STATUS_REGISTER_MASK_CARRY;
@@ -39,7 +39,7 @@ fn eor_l__data_register_to_ea__x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00034440)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -66,7 +66,7 @@ fn eor_l__data_register_to_ea__x_unaffected_still_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12121212, 0x34343434, 0x56565656, 0x78787878, 0x90909090, 0xabababab, 0xcdcdcdcd, 0xefefefef);
@@ -74,6 +74,7 @@ fn eor_l__data_register_to_ea__x_unaffected_still_set() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        STATUS_REGISTER_MASK_EXTEND
     );
+    assert_eq!(24, cycles);
 
     // assert - mem
     assert_eq!(0x25, modermodem.mem.get_byte_no_log(0x00034440));
@@ -101,7 +102,7 @@ fn eor_l__data_register_to_ea__x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
     mem.add_range(Rc::new(RefCell::new(arrange_mem_00034440)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -127,7 +128,7 @@ fn eor_l__data_register_to_ea__x_unaffected_still_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12121212, 0x34343434, 0x56565656, 0x78787878, 0x90909090, 0xabababab, 0xcdcdcdcd, 0xefefefef);
@@ -135,6 +136,7 @@ fn eor_l__data_register_to_ea__x_unaffected_still_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(24, cycles);
 
     // assert - mem
     assert_eq!(0x25, modermodem.mem.get_byte_no_log(0x00034440));
@@ -160,7 +162,7 @@ fn eor_l__data_register_to_ea__n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -185,7 +187,7 @@ fn eor_l__data_register_to_ea__n_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12121212, 0x34343434, 0x56565656, 0x78787878, 0x90909090, 0x80808080, 0xc0c0c0c0, 0xefefefef);
@@ -193,6 +195,7 @@ fn eor_l__data_register_to_ea__n_set() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        STATUS_REGISTER_MASK_NEGATIVE
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     // -nothing-
@@ -215,7 +218,7 @@ fn eor_l__data_register_to_ea__n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -240,7 +243,7 @@ fn eor_l__data_register_to_ea__n_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12121212, 0x34343434, 0x56565656, 0x78787878, 0x90909090, 0x03030303, 0x41414141, 0xefefefef);
@@ -248,6 +251,7 @@ fn eor_l__data_register_to_ea__n_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     // -nothing-
@@ -270,7 +274,7 @@ fn eor_l__data_register_to_ea__z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -297,7 +301,7 @@ fn eor_l__data_register_to_ea__z_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12121212, 0x00000000, 0x56565656, 0x78787878, 0x90909090, 0x03030303, 0x42424242, 0xefefefef);
@@ -305,6 +309,7 @@ fn eor_l__data_register_to_ea__z_set() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        STATUS_REGISTER_MASK_ZERO
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     // -nothing-
@@ -327,7 +332,7 @@ fn eor_l__data_register_to_ea__z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -355,7 +360,7 @@ fn eor_l__data_register_to_ea__z_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12121212, 0x01000000, 0x56565656, 0x78787878, 0x90909090, 0x03030303, 0x42424242, 0xefefefef);
@@ -363,6 +368,7 @@ fn eor_l__data_register_to_ea__z_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     // -nothing-
@@ -385,7 +391,7 @@ fn eor_l__data_register_to_ea__vc_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -411,7 +417,7 @@ fn eor_l__data_register_to_ea__vc_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x12121212, 0x01900091, 0x56565656, 0x78787878, 0x90009001, 0x03030303, 0x42424242, 0xefefefef);
@@ -419,6 +425,7 @@ fn eor_l__data_register_to_ea__vc_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(8, cycles);
 
     // assert - mem
     // -nothing-
@@ -441,7 +448,7 @@ fn eor_w__data_register_to_ea__x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -500,7 +507,7 @@ fn eor_w__data_register_to_ea__x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -558,7 +565,7 @@ fn eor_w__data_register_to_ea__n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -615,7 +622,7 @@ fn eor_w__data_register_to_ea__n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -673,7 +680,7 @@ fn eor_w__data_register_to_ea__z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -728,7 +735,7 @@ fn eor_w__data_register_to_ea__z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -786,7 +793,7 @@ fn eor_w__data_register_to_ea__vc_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -844,7 +851,7 @@ fn eor_b__data_register_to_ea__x_unaffected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -899,7 +906,7 @@ fn eor_b__data_register_to_ea__x_unaffected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -954,7 +961,7 @@ fn eor_b__data_register_to_ea__n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1009,7 +1016,7 @@ fn eor_b__data_register_to_ea__n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1064,7 +1071,7 @@ fn eor_b__data_register_to_ea__z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1119,7 +1126,7 @@ fn eor_b__data_register_to_ea__z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -1174,7 +1181,7 @@ fn eor_b__data_register_to_ea__vc_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -10,7 +10,7 @@ use crate::mem::rammemory::RamMemory;
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::mem::memory::Memory;
 use crate::mem::ciamemory::CiaMemory;
-use crate::cpu::{Cpu, CpuSpeed};
+use crate::cpu::{Cpu, CpuModel, CpuSpeed};
 use crate::mem::Mem;
 use crate::modermodem::Modermodem;
 use crate::register::STATUS_REGISTER_MASK_CARRY;
@@ -37,7 +37,7 @@ fn ext_w_x_not_affected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -62,7 +62,7 @@ fn ext_w_x_not_affected_still_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x5555ffd2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
@@ -71,6 +71,7 @@ fn ext_w_x_not_affected_still_set() {
        STATUS_REGISTER_MASK_EXTEND
        | STATUS_REGISTER_MASK_NEGATIVE
     );
+    assert_eq!(4, cycles);
 
     // assert - mem
     // -nothing-
@@ -93,7 +94,7 @@ fn ext_w_x_not_affected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -118,7 +119,7 @@ fn ext_w_x_not_affected_still_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x5555ffd2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
@@ -126,6 +127,7 @@ fn ext_w_x_not_affected_still_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        STATUS_REGISTER_MASK_NEGATIVE
     );
+    assert_eq!(4, cycles);
 
     // assert - mem
     // -nothing-
@@ -148,7 +150,7 @@ fn ext_w_n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -173,7 +175,7 @@ fn ext_w_n_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x5555fff2, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
@@ -181,6 +183,7 @@ fn ext_w_n_set() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        STATUS_REGISTER_MASK_NEGATIVE
     );
+    assert_eq!(4, cycles);
 
     // assert - mem
     // -nothing-
@@ -203,7 +206,7 @@ fn ext_w_n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -228,7 +231,7 @@ fn ext_w_n_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x55550072, 0x000000d3, 0x000000d4, 0x000000d5, 0x000000d6, 0x000000d7);
@@ -236,6 +239,7 @@ fn ext_w_n_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(4, cycles);
 
     // assert - mem
     // -nothing-
@@ -258,7 +262,7 @@ fn ext_w_z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -283,7 +287,7 @@ fn ext_w_z_set() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x555555f2, 0x000000d3, 0x000000d4, 0x55550000, 0x000000d6, 0x000000d7);
@@ -291,6 +295,7 @@ fn ext_w_z_set() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        STATUS_REGISTER_MASK_ZERO
     );
+    assert_eq!(4, cycles);
 
     // assert - mem
     // -nothing-
@@ -313,7 +318,7 @@ fn ext_w_z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -338,7 +343,7 @@ fn ext_w_z_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x55555572, 0x000000d3, 0x000000d4, 0x55550001, 0x000000d6, 0x000000d7);
@@ -346,6 +351,7 @@ fn ext_w_z_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(4, cycles);
 
     // assert - mem
     // -nothing-
@@ -368,7 +374,7 @@ fn ext_w_vc_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -394,7 +400,7 @@ fn ext_w_vc_clear() {
         );
 
     // act
-    modermodem.step();
+    let cycles = modermodem.step();
 
     // assert - regs
     modermodem.cpu.register.assert_all_d_reg_long_no_log(0x00000040, 0x000000d1, 0x555555f2, 0x000000d3, 0x000000d4, 0x55550011, 0x000000d6, 0x000000d7);
@@ -402,6 +408,7 @@ fn ext_w_vc_clear() {
     modermodem.cpu.register.reg_sr.assert_sr_reg_flags_abcde(
        0x0000
     );
+    assert_eq!(4, cycles);
 
     // assert - mem
     // -nothing-
@@ -424,7 +431,7 @@ fn ext_l_x_not_affected_still_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -480,7 +487,7 @@ fn ext_l_x_not_affected_still_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -535,7 +542,7 @@ fn ext_l_n_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -590,7 +597,7 @@ fn ext_l_n_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -645,7 +652,7 @@ fn ext_l_z_set() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -700,7 +707,7 @@ fn ext_l_z_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -755,7 +762,7 @@ fn ext_l_vc_clear() {
     mem.add_range(Rc::new(RefCell::new(code_memory)));
     mem.add_range(Rc::new(RefCell::new(vectors)));
     mem.add_range(Rc::new(RefCell::new(cia_memory)));
-    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
+    let cpu = Cpu::new(CpuModel::M68000, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, 00040000);
     let mut modermodem = Modermodem::bare(cpu, mem);
 
     // arrange - regs
@@ -0,0 +1,75 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::{
+    cpu::step_log::StepLog,
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    Err(StepError::IllegalInstruction)
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("ILLEGAL"),
+        String::from(""),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::instruction::GetDisassemblyResult;
+
+    #[test]
+    fn illegal() {
+        // arrange
+        let code = [0x4a, 0xfc].to_vec(); // ILLEGAL
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x001f);
+        mm.cpu.register.set_ssp_reg(0x01000400);
+        mm.mem.set_long_no_log(0x00000010, 0x00C01248); // Illegal instruction vector
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("ILLEGAL"),
+                String::from(""),
+                vec![0x4afc]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x201f, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x001f, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+}
@@ -1,8 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu},
+    cpu::{step_log::StepLog, timing, Cpu},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -29,7 +30,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -42,8 +43,9 @@ pub fn step<'a>(
     // println!("${:08X}", address);
 
     pc.jump_long(address);
-    reg.stack_push_long(mem, step_log, pc.get_address_next());
-    Ok(())
+    reg.stack_push_long(mem, step_log, pc.get_address_next())?;
+    let cycles = 16 + timing::get_jump_address_cycles(&ea_data.ea_mode);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
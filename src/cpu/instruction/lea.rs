@@ -1,8 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu},
+    cpu::{step_log::StepLog, timing, Cpu},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -29,7 +30,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -41,7 +42,8 @@ pub fn step<'a>(
     let ea_address = ea_data.get_address(pc, reg, mem, step_log);
 
     reg.set_a_reg_long(step_log, register, ea_address);
-    Ok(())
+    let cycles = 4 + timing::get_control_address_cycles(&ea_data.ea_mode);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -73,7 +75,7 @@ pub fn get_disassembly<'a>(
 #[cfg(test)]
 mod tests {
     use crate::{
-        cpu::instruction::GetDisassemblyResult,
+        cpu::{instruction::GetDisassemblyResult, CpuModel},
         mem::rammemory::RamMemory,
         register::{
             STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_EXTEND, STATUS_REGISTER_MASK_NEGATIVE,
@@ -149,4 +151,105 @@ mod tests {
         assert_eq!(false, mm.cpu.register.reg_sr.is_sr_negative_set());
         assert_eq!(false, mm.cpu.register.reg_sr.is_sr_extend_set());
     }
+
+    #[test]
+    fn lea_full_extension_word_is_illegal_on_68000() {
+        // arrange
+        let code = [0x43, 0xf0, 0x1d, 0x30, 0x00, 0x00, 0x10, 0x00].to_vec(); // LEA ($00001000.L,A0,D1.L*4),A1
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.set_long_no_log(0x00000010, 0x00C00100);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("DC.W"),
+                String::from(
+                    "#$43F0 ; Error when getting disassembly from instruction: IllegalInstruction"
+                ),
+                vec![0x43f0, 0x1d30]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00100, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn lea_base_displacement_with_scaled_index_on_68020() {
+        // arrange
+        let code = [0x43, 0xf0, 0x1d, 0x30, 0x00, 0x00, 0x10, 0x00].to_vec(); // LEA ($00001000.L,A0,D1.L*4),A1
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00001000);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0x00000010);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00008,
+                String::from("LEA"),
+                String::from("($00001000.L,A0,D1.L*4),A1"),
+                vec![0x43f0, 0x1d30, 0x0000, 0x1000]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00002040, mm.cpu.register.get_a_reg_long_no_log(1));
+        assert_eq!(0x00C00008, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn lea_memory_indirect_post_indexed_on_68020() {
+        // arrange
+        let code = [0x45, 0xf0, 0x11, 0x26, 0x00, 0x10, 0x00, 0x04].to_vec(); // LEA ([$0010.W,A0],D1.W,$0004.W),A2
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x01000000);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0x0000fffe);
+        mm.mem.set_long_no_log(0x01000010, 0x00C00200);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00008,
+                String::from("LEA"),
+                String::from("([$0010.W,A0],D1.W,$0004.W),A2"),
+                vec![0x45f0, 0x1126, 0x0010, 0x0004]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00202, mm.cpu.register.get_a_reg_long_no_log(2));
+    }
+
+    #[test]
+    fn lea_pc_with_suppressed_index_on_68020() {
+        // arrange
+        let code = [0x47, 0xfb, 0x01, 0x60, 0x00, 0x10].to_vec(); // LEA ($0010.W,PC),A3
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00006,
+                String::from("LEA"),
+                String::from("($0010.W,PC),A3"),
+                vec![0x47fb, 0x0160, 0x0010]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00012, mm.cpu.register.get_a_reg_long_no_log(3));
+    }
 }
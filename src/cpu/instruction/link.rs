@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::{step_log::StepLog, Cpu},
     mem::Mem,
@@ -20,7 +20,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
     let register_value = reg.get_a_reg_long(register, step_log);
     // println!(
@@ -30,7 +30,7 @@ pub fn step<'a>(
     //     reg.get_a_reg_long(7)
     // );
 
-    reg.stack_push_long(mem, step_log, register_value);
+    reg.stack_push_long(mem, step_log, register_value)?;
     let sp = reg.get_a_reg_long(7, step_log);
     reg.set_a_reg_long(step_log, register, sp);
 
@@ -39,7 +39,7 @@ pub fn step<'a>(
     let new_sp = reg.get_a_reg_long(7, step_log).wrapping_add(displacement);
     reg.set_a_reg_long(step_log, 7, new_sp);
 
-    Ok(())
+    Ok(StepResult { cycles: 16 })
 }
 
 pub fn step_long<'a>(
@@ -48,11 +48,11 @@ pub fn step_long<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
     let register_value = reg.get_a_reg_long(register, step_log);
 
-    reg.stack_push_long(mem, step_log, register_value);
+    reg.stack_push_long(mem, step_log, register_value)?;
     let sp = reg.get_a_reg_long(7, step_log);
     reg.set_a_reg_long(step_log, register, sp);
 
@@ -61,7 +61,7 @@ pub fn step_long<'a>(
     let new_sp = reg.get_a_reg_long(7, step_log).wrapping_add(displacement);
     reg.set_a_reg_long(step_log, 7, new_sp);
 
-    Ok(())
+    Ok(StepResult { cycles: 16 })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,7 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
 use crate::cpu::{Cpu, StatusRegisterResult};
 use crate::mem::Mem;
 use crate::register::{
@@ -59,7 +61,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let (lslr_direction, lslr_type, operation_size) = match (instr_word & 0x01c0) >> 6 {
         0b000 => (
             LslrDirection::Right,
@@ -83,7 +85,7 @@ pub fn step<'a>(
         _ => (LslrDirection::Left, LslrType::Memory, OperationSize::Word),
     };
 
-    let status_register_result = match lslr_type {
+    let (status_register_result, cycles) = match lslr_type {
         LslrType::Register => {
             let dest_register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
             let shift_count = match instr_word & 0x0020 {
@@ -101,7 +103,7 @@ pub fn step<'a>(
                     shift_count
                 }
             };
-            match operation_size {
+            let status_register_result = match operation_size {
                 OperationSize::Byte => {
                     let value = reg.get_d_reg_byte(dest_register, step_log) as u16;
                     let (result, overflow) = match lslr_direction {
@@ -184,7 +186,11 @@ pub fn step<'a>(
 
                     get_status_register(shift_count, overflow, is_zero, is_negative)
                 }
-            }
+            };
+            (
+                status_register_result,
+                timing::get_shift_register_cycles(operation_size, shift_count),
+            )
         }
         LslrType::Memory => {
             let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
@@ -195,7 +201,7 @@ pub fn step<'a>(
                 |instr_word| Ok(operation_size),
             )?;
 
-            let value = ea_data.get_value_word(pc, reg, mem, step_log, false) as u32;
+            let value = ea_data.get_value_word(pc, reg, mem, step_log, false)? as u32;
             let (result, overflow) = match lslr_direction {
                 LslrDirection::Left => {
                     let result = value.checked_shl(1).unwrap_or(0);
@@ -211,7 +217,7 @@ pub fn step<'a>(
                 }
             };
 
-            ea_data.set_value_word(pc, reg, mem, step_log, result, true);
+            ea_data.set_value_word(pc, reg, mem, step_log, result, true)?;
 
             let (is_zero, is_negative) = match result {
                 0 => (true, false),
@@ -219,7 +225,10 @@ pub fn step<'a>(
                 _ => (false, false),
             };
 
-            get_status_register(1, overflow, is_zero, is_negative)
+            (
+                get_status_register(1, overflow, is_zero, is_negative),
+                timing::get_shift_memory_cycles(&ea_data),
+            )
         }
     };
 
@@ -230,7 +239,7 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 fn get_status_register(shift_count: u32, overflow: bool, is_zero: bool, is_negative: bool) -> StatusRegisterResult {
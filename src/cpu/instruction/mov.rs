@@ -1,9 +1,9 @@
 use super::{
     EffectiveAddressingMode, GetDisassemblyResult, GetDisassemblyResultError, Instruction,
-    OperationSize, StepError,
+    OperationSize, StepError, StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu},
+    cpu::{step_log::StepLog, timing, Cpu},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -37,7 +37,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let src_ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -59,16 +59,16 @@ pub fn step<'a>(
 
     let set_result = match src_ea_data.operation_size {
         OperationSize::Byte => {
-            let ea_value = src_ea_data.get_value_byte(pc, reg, mem, step_log, true);
-            dst_ea_data.set_value_byte(pc, reg, mem, step_log, ea_value, true)
+            let ea_value = src_ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
+            dst_ea_data.set_value_byte(pc, reg, mem, step_log, ea_value, true)?
         }
         OperationSize::Word => {
-            let ea_value = src_ea_data.get_value_word(pc, reg, mem, step_log, true);
-            dst_ea_data.set_value_word(pc, reg, mem, step_log, ea_value, true)
+            let ea_value = src_ea_data.get_value_word(pc, reg, mem, step_log, true)?;
+            dst_ea_data.set_value_word(pc, reg, mem, step_log, ea_value, true)?
         }
         OperationSize::Long => {
-            let ea_value = src_ea_data.get_value_long(pc, reg, mem, step_log, true);
-            dst_ea_data.set_value_long(pc, reg, mem, step_log, ea_value, true)
+            let ea_value = src_ea_data.get_value_long(pc, reg, mem, step_log, true)?;
+            dst_ea_data.set_value_long(pc, reg, mem, step_log, ea_value, true)?
         }
     };
 
@@ -82,7 +82,10 @@ pub fn step<'a>(
         }
     };
 
-    Ok(())
+    let cycles = 4
+        + src_ea_data.get_calculation_cycles()
+        + timing::get_move_destination_cycles(&dst_ea_data);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
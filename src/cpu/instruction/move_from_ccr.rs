@@ -0,0 +1,112 @@
+use super::{
+    EffectiveAddressingMode, GetDisassemblyResult, GetDisassemblyResultError, Instruction,
+    OperationSize, StepError, StepResult,
+};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register, STATUS_REGISTER_MASK_CONDITION_CODES},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_data_alterable_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Word),
+    )?;
+
+    let ccr = reg.reg_sr.get_value() & STATUS_REGISTER_MASK_CONDITION_CODES;
+    ea_data.set_value_word(pc, reg, mem, step_log, ccr, true)?;
+
+    let cycles = match ea_data.ea_mode {
+        EffectiveAddressingMode::DRegDirect { .. } => 4,
+        _ => 8 + ea_data.get_calculation_cycles(),
+    };
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Word),
+    )?;
+
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("MOVE.W"),
+        format!("CCR,{}", ea_format.format),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cpu::{instruction::GetDisassemblyResult, CpuModel},
+        register::{STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE},
+    };
+
+    #[test]
+    fn move_from_ccr_in_user_mode() {
+        // arrange
+        let code = [0x42, 0xc2].to_vec(); // MOVE.W CCR,D2
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.cpu.register.set_d_reg_long_no_log(2, 0xffffffff);
+        mm.cpu
+            .register
+            .reg_sr
+            .set_value(0x0700 | STATUS_REGISTER_MASK_CARRY | STATUS_REGISTER_MASK_NEGATIVE);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("MOVE.W"),
+                String::from("CCR,D2"),
+                vec![0x42c2]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xffff_0009, mm.cpu.register.get_d_reg_long_no_log(2));
+        assert_eq!(0x00C00002, mm.cpu.register.reg_pc.get_address());
+    }
+}
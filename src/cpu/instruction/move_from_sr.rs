@@ -1,8 +1,9 @@
 use super::{
-    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    EffectiveAddressingMode, GetDisassemblyResult, GetDisassemblyResultError, Instruction,
+    OperationSize, StepError, StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu},
+    cpu::{step_log::StepLog, Cpu, CpuModel},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -29,8 +30,10 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
-    match reg.reg_sr.is_sr_supervisor_set(step_log) {
+) -> Result<StepResult, StepError> {
+    // MOVE from SR is only privileged on the 68010+, which added MOVE from CCR for user code
+    let privileged = reg.cpu_model != CpuModel::M68000;
+    match !privileged || reg.reg_sr.is_sr_supervisor_set(step_log) {
         true => {
             let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
                 instr_word,
@@ -41,9 +44,13 @@ pub fn step<'a>(
             )?;
 
             let sr = reg.reg_sr.get_value();
-            let data = ea_data.set_value_word(pc, reg, mem, step_log, sr, true);
+            let data = ea_data.set_value_word(pc, reg, mem, step_log, sr, true)?;
 
-            Ok(())
+            let cycles = match ea_data.ea_mode {
+                EffectiveAddressingMode::DRegDirect { .. } => 6,
+                _ => 8 + ea_data.get_calculation_cycles(),
+            };
+            Ok(StepResult { cycles })
         }
         false => Err(StepError::PriviliegeViolation),
     }
@@ -77,7 +84,7 @@ pub fn get_disassembly<'a>(
 #[cfg(test)]
 mod tests {
     use crate::{
-        cpu::instruction::GetDisassemblyResult,
+        cpu::{instruction::GetDisassemblyResult, CpuModel},
         register::{
             STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_EXTEND, STATUS_REGISTER_MASK_NEGATIVE,
             STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
@@ -145,10 +152,24 @@ mod tests {
     }
 
     #[test]
-    fn move_from_sr_privilege_violation() {
+    fn move_from_sr_is_not_privileged_on_68000() {
         // arrange
         let code = [0x40, 0xc7].to_vec(); // MOVE.W SR,D7
         let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(STATUS_REGISTER_MASK_CARRY);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x0000_0001, mm.cpu.register.get_d_reg_long_no_log(7));
+        assert_eq!(0x00C00002, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(false, mm.cpu.register.reg_sr.is_sr_supervisor_set_no_log());
+    }
+
+    #[test]
+    fn move_from_sr_privilege_violation() {
+        // arrange
+        let code = [0x40, 0xc7].to_vec(); // MOVE.W SR,D7
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
         mm.cpu.register.reg_sr.set_value(
             STATUS_REGISTER_MASK_CARRY
                 | STATUS_REGISTER_MASK_EXTEND
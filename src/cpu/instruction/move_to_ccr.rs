@@ -0,0 +1,79 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::{
+    cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
+    mem::Mem,
+    register::{ProgramCounter, Register, STATUS_REGISTER_MASK_CONDITION_CODES},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_data_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Word),
+    )?;
+
+    // The source operand is a word, only the low order byte is used
+    let value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
+    reg.reg_sr.merge_status_register(
+        step_log,
+        StatusRegisterResult {
+            status_register: value,
+            status_register_mask: STATUS_REGISTER_MASK_CONDITION_CODES,
+        },
+    );
+
+    let cycles = 12 + ea_data.get_calculation_cycles();
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Word),
+    )?;
+
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, Some(OperationSize::Word), mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("MOVE.W"),
+        format!("{},CCR", ea_format.format),
+    ))
+}
@@ -1,5 +1,6 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
     cpu::{step_log::StepLog, Cpu},
@@ -29,7 +30,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     match reg.reg_sr.is_sr_supervisor_set(step_log) {
         true => {
             let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
@@ -40,10 +41,12 @@ pub fn step<'a>(
                 |instr_word| Ok(OperationSize::Word),
             )?;
 
-            let value = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
             reg.reg_sr.set_value(value);
 
-            Ok(())
+            Ok(StepResult {
+                cycles: 12 + ea_data.get_calculation_cycles(),
+            })
         }
         false => Err(StepError::PriviliegeViolation),
     }
@@ -181,4 +184,44 @@ mod tests {
         assert_eq!(true, mm.cpu.register.reg_sr.is_sr_extend_set());
         assert_eq!(true, mm.cpu.register.reg_sr.is_sr_supervisor_set_no_log());
     }
+
+    #[test]
+    fn move_to_sr_setting_trace_traces_next_instruction() {
+        // arrange
+        let code = [0x46, 0xc0, 0x4e, 0x71].to_vec(); // MOVE.W D0,SR + NOP
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0x0000a000);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x00000024, 0x11223344);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00002, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0xa000, mm.cpu.register.reg_sr.get_value());
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x11223344, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2000, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0xa000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn move_to_sr_clearing_trace_is_traced() {
+        // arrange
+        let code = [0x46, 0xc0].to_vec(); // MOVE.W D0,SR
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0x00002004);
+        mm.cpu.register.reg_sr.set_value(0xa000);
+        mm.mem.set_long_no_log(0x00000024, 0x11223344);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x11223344, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2004, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x2004, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
 }
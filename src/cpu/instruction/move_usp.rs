@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::{step_log::StepLog, Cpu},
     mem::Mem,
@@ -20,7 +20,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     match reg.reg_sr.is_sr_supervisor_set(step_log) {
         true => {
             let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
@@ -36,7 +36,7 @@ pub fn step<'a>(
                 }
             };
 
-            Ok(())
+            Ok(StepResult { cycles: 4 })
         }
         false => Err(StepError::PriviliegeViolation),
     }
@@ -1,44 +1,293 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
-    cpu::step_log::StepLog,
+    cpu::{step_log::StepLog, CpuModel},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
 
 // Instruction State
 // =================
-// step: TODO
-// step cc: TODO
-// get_disassembly: TODO
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
 
-// 020+ step: TODO
-// 020+ get_disassembly: TODO
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
 
-pub fn step<'a>(
+// Amiga OS executes MOVEC to check if the CPU is a 68010 or better. On the 68000 the
+// instruction isn't in the opcode table, so it's an illegal instruction there.
+
+const CONTROL_REGISTER_SFC: u16 = 0x000;
+const CONTROL_REGISTER_DFC: u16 = 0x001;
+const CONTROL_REGISTER_CACR: u16 = 0x002;
+const CONTROL_REGISTER_USP: u16 = 0x800;
+const CONTROL_REGISTER_VBR: u16 = 0x801;
+const CONTROL_REGISTER_CAAR: u16 = 0x802;
+const CONTROL_REGISTER_MSP: u16 = 0x803;
+const CONTROL_REGISTER_ISP: u16 = 0x804;
+
+// Only the enable (bit 0) and freeze (bit 1) bits of the 68020 CACR are stored, the clear bits
+// always read as zero. The 68030 adds the data cache bits, burst enable and write allocate.
+const CACR_MASK_68020: u32 = 0x00000003;
+const CACR_MASK_68030: u32 = 0x00003313;
+// The bits the 68020 passes on to its instruction cache
+const CACR_CONTROL_MASK_68020: u32 = 0x0000000f;
+
+struct ExtensionWord {
+    register_type: RegisterType,
+    register: usize,
+    control_register: u16,
+}
+
+enum RegisterType {
+    Data,
+    Address,
+}
+
+fn get_extension_word(extension_word: u16) -> ExtensionWord {
+    let register_type = match extension_word & 0x8000 {
+        0x8000 => RegisterType::Address,
+        _ => RegisterType::Data,
+    };
+    ExtensionWord {
+        register_type,
+        register: ((extension_word >> 12) & 0x0007) as usize,
+        control_register: extension_word & 0x0fff,
+    }
+}
+
+fn get_control_register_name(
+    control_register: u16,
+    cpu_model: CpuModel,
+) -> Result<&'static str, StepError> {
+    match (control_register, cpu_model) {
+        (CONTROL_REGISTER_SFC, _) => Ok("SFC"),
+        (CONTROL_REGISTER_DFC, _) => Ok("DFC"),
+        (CONTROL_REGISTER_USP, _) => Ok("USP"),
+        (CONTROL_REGISTER_VBR, _) => Ok("VBR"),
+        (CONTROL_REGISTER_CACR, model) if model >= CpuModel::M68020 => Ok("CACR"),
+        (CONTROL_REGISTER_CAAR, model) if model >= CpuModel::M68020 => Ok("CAAR"),
+        (CONTROL_REGISTER_MSP, model) if model >= CpuModel::M68020 => Ok("MSP"),
+        (CONTROL_REGISTER_ISP, model) if model >= CpuModel::M68020 => Ok("ISP"),
+        _ => Err(StepError::IllegalInstruction),
+    }
+}
+
+fn get_control_register(reg: &Register, control_register: u16) -> u32 {
+    match control_register {
+        CONTROL_REGISTER_SFC => reg.reg_sfc,
+        CONTROL_REGISTER_DFC => reg.reg_dfc,
+        CONTROL_REGISTER_CACR => reg.reg_cacr,
+        CONTROL_REGISTER_USP => reg.get_usp_reg(),
+        CONTROL_REGISTER_VBR => reg.reg_vbr,
+        CONTROL_REGISTER_CAAR => reg.reg_caar,
+        CONTROL_REGISTER_MSP => reg.reg_msp,
+        _ => reg.get_ssp_reg(),
+    }
+}
+
+fn set_control_register(reg: &mut Register, mem: &Mem, control_register: u16, value: u32) {
+    match control_register {
+        CONTROL_REGISTER_SFC => reg.reg_sfc = value & 0x00000007,
+        CONTROL_REGISTER_DFC => reg.reg_dfc = value & 0x00000007,
+        CONTROL_REGISTER_CACR => {
+            let (mask, control_mask) = match reg.cpu_model {
+                CpuModel::M68030 => (CACR_MASK_68030, 0xffffffff),
+                _ => (CACR_MASK_68020, CACR_CONTROL_MASK_68020),
+            };
+            reg.reg_cacr = value & mask;
+            mem.set_cache_control(value & control_mask, reg.reg_caar);
+        }
+        CONTROL_REGISTER_USP => reg.set_usp_reg(value),
+        CONTROL_REGISTER_VBR => reg.reg_vbr = value,
+        CONTROL_REGISTER_CAAR => reg.reg_caar = value,
+        CONTROL_REGISTER_MSP => reg.reg_msp = value,
+        _ => reg.set_ssp_reg(value),
+    }
+}
+
+pub fn step(
     instr_word: u16,
     pc: &mut ProgramCounter,
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
-    let _ = pc.fetch_next_word(mem);
+) -> Result<StepResult, StepError> {
+    match reg.reg_sr.is_sr_supervisor_set(step_log) {
+        true => {
+            let extension_word = get_extension_word(pc.fetch_next_word(mem));
+            get_control_register_name(extension_word.control_register, reg.cpu_model)?;
 
-    // This instruction is called by Amiga OS to check if the MC68010 instruction set is available.
-    // We imlement it is as IllegalInstruction to enable MC68000 emulation only.
-    Err(StepError::IllegalInstruction)
+            match instr_word & 0x0001 {
+                0x0001 => {
+                    let value = match extension_word.register_type {
+                        RegisterType::Data => reg.get_d_reg_long(extension_word.register, step_log),
+                        RegisterType::Address => {
+                            reg.get_a_reg_long(extension_word.register, step_log)
+                        }
+                    };
+                    set_control_register(reg, mem, extension_word.control_register, value);
+                    Ok(StepResult { cycles: 10 })
+                }
+                _ => {
+                    let value = get_control_register(reg, extension_word.control_register);
+                    match extension_word.register_type {
+                        RegisterType::Data => {
+                            reg.set_d_reg_long(step_log, extension_word.register, value)
+                        }
+                        RegisterType::Address => {
+                            reg.set_a_reg_long(step_log, extension_word.register, value)
+                        }
+                    };
+                    Ok(StepResult { cycles: 12 })
+                }
+            }
+        }
+        false => Err(StepError::PriviliegeViolation),
+    }
 }
 
-pub fn get_disassembly<'a>(
+pub fn get_disassembly(
     instr_word: u16,
     pc: &mut ProgramCounter,
     reg: &Register,
     mem: &Mem,
     step_log: &mut StepLog,
 ) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let control_register_name =
+        get_control_register_name(extension_word.control_register, reg.cpu_model)?;
+    let register_name = match extension_word.register_type {
+        RegisterType::Data => format!("D{}", extension_word.register),
+        RegisterType::Address => format!("A{}", extension_word.register),
+    };
+
+    let operands = match instr_word & 0x0001 {
+        0x0001 => format!("{},{}", register_name, control_register_name),
+        _ => format!("{},{}", control_register_name, register_name),
+    };
     Ok(GetDisassemblyResult::from_pc(
         pc,
         mem,
         String::from("MOVEC"),
-        format!("TODO"),
+        operands,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cpu::{instruction::GetDisassemblyResult, CpuModel},
+        register::STATUS_REGISTER_MASK_SUPERVISOR_STATE,
+    };
+
+    #[test]
+    fn movec_is_illegal_on_68000() {
+        // arrange
+        let code = [0x4e, 0x7a, 0x08, 0x01].to_vec(); // MOVEC VBR,D0
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.set_long_no_log(0x00000010, 0x00C00100);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00100, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn movec_vbr_to_data_register() {
+        // arrange
+        let code = [0x4e, 0x7a, 0x38, 0x01].to_vec(); // MOVEC VBR,D3
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.cpu.register.reg_vbr = 0x00F80000;
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("MOVEC"),
+                String::from("VBR,D3"),
+                vec![0x4e7a, 0x3801]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00F80000, mm.cpu.register.get_d_reg_long_no_log(3));
+        assert_eq!(0x00C00004, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn movec_address_register_to_vbr_moves_vector_table() {
+        // arrange
+        let code = [0x4e, 0x7b, 0x98, 0x01, 0x4a, 0xfc].to_vec(); // MOVEC A1,VBR + ILLEGAL
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.cpu.register.set_a_reg_long_no_log(1, 0x00000100);
+        mm.mem.set_long_no_log(0x00000110, 0x00C00200);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("MOVEC"),
+                String::from("A1,VBR"),
+                vec![0x4e7b, 0x9801]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x00000100, mm.cpu.register.reg_vbr);
+        assert_eq!(0x00C00200, mm.cpu.register.reg_pc.get_address());
+        // format $0, vector offset $010
+        assert_eq!(0x0010, mm.mem.get_word_no_log(0x010003FE));
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003FA));
+        assert_eq!(
+            STATUS_REGISTER_MASK_SUPERVISOR_STATE,
+            mm.mem.get_word_no_log(0x010003F8)
+        );
+    }
+
+    #[test]
+    fn movec_cacr_is_illegal_on_68010() {
+        // arrange
+        let code = [0x4e, 0x7a, 0x00, 0x02].to_vec(); // MOVEC CACR,D0
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.mem.set_long_no_log(0x00000010, 0x00C00100);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00100, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn movec_cacr_on_68020() {
+        // arrange
+        let code = [0x4e, 0x7b, 0x00, 0x02].to_vec(); // MOVEC D0,CACR
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0x0000000f);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00000003, mm.cpu.register.reg_cacr);
+        assert_eq!(0x00C00004, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn movec_cacr_on_68030_keeps_data_cache_bits() {
+        // arrange
+        let code = [0x4e, 0x7b, 0x00, 0x02, 0x4e, 0x7a, 0x10, 0x02].to_vec(); // MOVEC D0,CACR + MOVEC CACR,D1
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68030, code, None);
+        mm.cpu.register.set_d_reg_long_no_log(0, 0xffffffff);
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x00003313, mm.cpu.register.get_d_reg_long_no_log(1));
+    }
+}
@@ -1,9 +1,9 @@
 use super::{
     EffectiveAddressingMode, GetDisassemblyResult, GetDisassemblyResultError, Instruction,
-    OperationSize, StepError,
+    OperationSize, StepError, StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu},
+    cpu::{step_log::StepLog, timing, Cpu},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -40,7 +40,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let mut register_list_mask = pc.fetch_next_word(mem);
 
     let ea_data = pc.get_effective_addressing_data_from_bit_pos(
@@ -60,6 +60,11 @@ pub fn step<'a>(
         0x0400 => MovemDirection::MemoryToRegister,
         _ => MovemDirection::RegisterToMemory,
     };
+    let cycles = timing::get_movem_cycles(
+        &ea_data,
+        matches!(direction, MovemDirection::MemoryToRegister),
+        register_list_mask.count_ones(),
+    );
 
     match ea_data.ea_mode {
         EffectiveAddressingMode::ARegIndirectWithPreDecrement {
@@ -74,11 +79,11 @@ pub fn step<'a>(
                 match ea_data.operation_size {
                     OperationSize::Word => {
                         let value = reg.get_a_reg_word(a, step_log);
-                        ea_data.set_value_word(pc, reg, mem, step_log, value, true);
+                        ea_data.set_value_word(pc, reg, mem, step_log, value, true)?;
                     }
                     OperationSize::Long => {
                         let value = reg.get_a_reg_long(a, step_log);
-                        ea_data.set_value_long(pc, reg, mem, step_log, value, true);
+                        ea_data.set_value_long(pc, reg, mem, step_log, value, true)?;
                     }
                     _ => panic!(),
                 }
@@ -87,11 +92,11 @@ pub fn step<'a>(
                 match ea_data.operation_size {
                     OperationSize::Word => {
                         let value = reg.get_d_reg_word(d, step_log);
-                        ea_data.set_value_word(pc, reg, mem, step_log, value, true);
+                        ea_data.set_value_word(pc, reg, mem, step_log, value, true)?;
                     }
                     OperationSize::Long => {
                         let value = reg.get_d_reg_long(d, step_log);
-                        ea_data.set_value_long(pc, reg, mem, step_log, value, true);
+                        ea_data.set_value_long(pc, reg, mem, step_log, value, true)?;
                     }
                     _ => panic!(),
                 }
@@ -109,13 +114,13 @@ pub fn step<'a>(
                 match ea_data.operation_size {
                     OperationSize::Word => {
                         let value = Cpu::sign_extend_word(
-                            ea_data.get_value_word(pc, reg, mem, step_log, true),
+                            ea_data.get_value_word(pc, reg, mem, step_log, true)?,
                         );
                         // println!("d{}=${:08X}", reg.reg_d[d], value);
                         reg.set_d_reg_long(step_log, d, value);
                     }
                     OperationSize::Long => {
-                        let value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+                        let value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
                         reg.set_d_reg_long(step_log, d, value);
                     }
                     _ => panic!(),
@@ -125,13 +130,13 @@ pub fn step<'a>(
                 match ea_data.operation_size {
                     OperationSize::Word => {
                         let value = Cpu::sign_extend_word(
-                            ea_data.get_value_word(pc, reg, mem, step_log, true),
+                            ea_data.get_value_word(pc, reg, mem, step_log, true)?,
                         );
                         // println!("a{}=${:08X}", reg.reg_a[a], value);
                         reg.set_a_reg_long(step_log, a, value);
                     }
                     OperationSize::Long => {
-                        let value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+                        let value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
                         reg.set_a_reg_long(step_log, a, value);
                     }
                     _ => panic!(),
@@ -150,13 +155,13 @@ pub fn step<'a>(
                         match ea_data.operation_size {
                             OperationSize::Word => {
                                 let value = reg.get_d_reg_word(d, step_log);
-                                mem.set_word(step_log, address, value);
+                                mem.set_word(step_log, address, value)?;
                                 (address, _) =
                                     address.overflowing_add(ea_data.operation_size.size_in_bytes());
                             }
                             OperationSize::Long => {
                                 let value = reg.get_d_reg_long(d, step_log);
-                                mem.set_long(step_log, address, value);
+                                mem.set_long(step_log, address, value)?;
                                 (address, _) =
                                     address.overflowing_add(ea_data.operation_size.size_in_bytes());
                             }
@@ -167,13 +172,13 @@ pub fn step<'a>(
                         match ea_data.operation_size {
                             OperationSize::Word => {
                                 let value = reg.get_a_reg_word(a, step_log);
-                                mem.set_word(step_log, address, value);
+                                mem.set_word(step_log, address, value)?;
                                 (address, _) =
                                     address.overflowing_add(ea_data.operation_size.size_in_bytes());
                             }
                             OperationSize::Long => {
                                 let value = reg.get_a_reg_long(a, step_log);
-                                mem.set_long(step_log, address, value);
+                                mem.set_long(step_log, address, value)?;
                                 (address, _) =
                                     address.overflowing_add(ea_data.operation_size.size_in_bytes());
                             }
@@ -186,14 +191,14 @@ pub fn step<'a>(
                     for d in d_regs {
                         match ea_data.operation_size {
                             OperationSize::Word => {
-                                let value = Cpu::sign_extend_word(mem.get_word(step_log, address));
+                                let value = Cpu::sign_extend_word(mem.get_word(step_log, address)?);
                                 // println!("d{}=${:08X}", reg.reg_d[d], value);
                                 reg.set_d_reg_long(step_log, d, value);
                                 (address, _) =
                                     address.overflowing_add(ea_data.operation_size.size_in_bytes());
                             }
                             OperationSize::Long => {
-                                let value = mem.get_long(step_log, address);
+                                let value = mem.get_long(step_log, address)?;
                                 reg.set_d_reg_long(step_log, d, value);
                                 (address, _) =
                                     address.overflowing_add(ea_data.operation_size.size_in_bytes());
@@ -204,14 +209,14 @@ pub fn step<'a>(
                     for a in a_regs {
                         match ea_data.operation_size {
                             OperationSize::Word => {
-                                let value = Cpu::sign_extend_word(mem.get_word(step_log, address));
+                                let value = Cpu::sign_extend_word(mem.get_word(step_log, address)?);
                                 // println!("a{}=${:08X}", reg.reg_a[a], value);
                                 reg.set_a_reg_long(step_log, a, value);
                                 (address, _) =
                                     address.overflowing_add(ea_data.operation_size.size_in_bytes());
                             }
                             OperationSize::Long => {
-                                let value = mem.get_long(step_log, address);
+                                let value = mem.get_long(step_log, address)?;
                                 reg.set_a_reg_long(step_log, a, value);
                                 (address, _) =
                                     address.overflowing_add(ea_data.operation_size.size_in_bytes());
@@ -224,7 +229,7 @@ pub fn step<'a>(
         }
     };
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -0,0 +1,94 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+// MOVEP transfers data between a data register and alternate bytes of memory, starting at the
+// address and incrementing by two, which suits 8-bit peripherals on one half of the data bus.
+// The high order byte of the register is transferred first.
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let dreg = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
+    let areg = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    let displacement = Cpu::sign_extend_word(pc.fetch_next_word(mem));
+    let address = reg.get_a_reg_long(areg, step_log).wrapping_add(displacement);
+
+    let byte_count = match instr_word & 0x0040 {
+        0x0000 => 2, // Word
+        _ => 4,      // Long
+    };
+
+    match instr_word & 0x0080 {
+        0x0000 => {
+            // Memory to register
+            let mut value = 0;
+            for i in 0..byte_count {
+                let byte = mem.get_byte(step_log, address.wrapping_add(i * 2))?;
+                value = (value << 8) | byte as u32;
+            }
+            match byte_count {
+                2 => reg.set_d_reg_word(step_log, dreg, value as u16),
+                _ => reg.set_d_reg_long(step_log, dreg, value),
+            }
+        }
+        _ => {
+            // Register to memory
+            let value = reg.get_d_reg_long(dreg, step_log);
+            for i in 0..byte_count {
+                let byte = (value >> ((byte_count - 1 - i) * 8)) as u8;
+                mem.set_byte(step_log, address.wrapping_add(i * 2), byte)?;
+            }
+        }
+    }
+
+    let cycles = match byte_count {
+        2 => 16,
+        _ => 24,
+    };
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let dreg = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
+    let areg = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    let displacement = pc.fetch_next_word(mem);
+
+    let size = match instr_word & 0x0040 {
+        0x0000 => "W",
+        _ => "L",
+    };
+    let operands = match instr_word & 0x0080 {
+        0x0000 => format!("(${:04X},A{}),D{}", displacement, areg, dreg),
+        _ => format!("D{},(${:04X},A{})", dreg, displacement, areg),
+    };
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        format!("MOVEP.{}", size),
+        operands,
+    ))
+}
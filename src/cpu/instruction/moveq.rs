@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResultError, StepError, StepResult};
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::cpu::step_log::StepLog;
 use crate::cpu::StatusRegisterResult;
@@ -24,7 +24,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let register = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
     let data = Cpu::get_byte_from_word(instr_word);
     let mut status_register = 0x0000;
@@ -48,7 +48,7 @@ pub fn step<'a>(
 
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
-    Ok(())
+    Ok(StepResult { cycles: 4 })
 }
 
 pub fn get_disassembly<'a>(
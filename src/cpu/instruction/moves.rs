@@ -0,0 +1,226 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// MOVES accesses the address space in SFC (memory to register) or DFC (register to memory).
+// The Amiga doesn't decode the function codes, so it's a plain memory access here.
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => match crate::cpu::match_check_size000110_from_bit_pos_6(instr_word) {
+            false => false,
+            true => {
+                crate::cpu::match_check_ea_only_memory_alterable_addressing_modes_pos_0(instr_word)
+            }
+        },
+        false => false,
+    }
+}
+
+fn get_register_name(extension_word: u16) -> String {
+    let register = (extension_word >> 12) & 0x0007;
+    match extension_word & 0x8000 {
+        0x8000 => format!("A{}", register),
+        _ => format!("D{}", register),
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    match reg.reg_sr.is_sr_supervisor_set(step_log) {
+        true => {
+            let extension_word = pc.fetch_next_word(mem);
+            let register = ((extension_word >> 12) & 0x0007) as usize;
+            let address_register = extension_word & 0x8000 != 0;
+            let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+                instr_word,
+                reg,
+                mem,
+                step_log,
+                |instr_word| Ok(Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap()),
+            )?;
+
+            match extension_word & 0x0800 {
+                0x0800 => {
+                    // Register to memory
+                    let value = match address_register {
+                        true => reg.get_a_reg_long(register, step_log),
+                        false => reg.get_d_reg_long(register, step_log),
+                    };
+                    match ea_data.operation_size {
+                        OperationSize::Byte => {
+                            ea_data.set_value_byte(pc, reg, mem, step_log, value as u8, true)?
+                        }
+                        OperationSize::Word => {
+                            ea_data.set_value_word(pc, reg, mem, step_log, value as u16, true)?
+                        }
+                        OperationSize::Long => {
+                            ea_data.set_value_long(pc, reg, mem, step_log, value, true)?
+                        }
+                    };
+                }
+                _ => {
+                    // Memory to register, loading an address register always sign extends
+                    // to the full register
+                    match (ea_data.operation_size, address_register) {
+                        (OperationSize::Byte, false) => {
+                            let value = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
+                            reg.set_d_reg_byte(step_log, register, value);
+                        }
+                        (OperationSize::Word, false) => {
+                            let value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
+                            reg.set_d_reg_word(step_log, register, value);
+                        }
+                        (OperationSize::Long, false) => {
+                            let value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
+                            reg.set_d_reg_long(step_log, register, value);
+                        }
+                        (OperationSize::Byte, true) => {
+                            let value = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
+                            let value = Cpu::sign_extend_word(Cpu::sign_extend_byte(value));
+                            reg.set_a_reg_long(step_log, register, value);
+                        }
+                        (OperationSize::Word, true) => {
+                            let value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
+                            reg.set_a_reg_long(step_log, register, Cpu::sign_extend_word(value));
+                        }
+                        (OperationSize::Long, true) => {
+                            let value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
+                            reg.set_a_reg_long(step_log, register, value);
+                        }
+                    };
+                }
+            };
+
+            let cycles = match ea_data.operation_size {
+                OperationSize::Long => 22,
+                _ => 18,
+            } + ea_data.get_calculation_cycles();
+            Ok(StepResult { cycles })
+        }
+        false => Err(StepError::PriviliegeViolation),
+    }
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let extension_word = pc.fetch_next_word(mem);
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap()),
+    )?;
+
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, Some(ea_data.operation_size), mem);
+    let register_name = get_register_name(extension_word);
+    let operands = match extension_word & 0x0800 {
+        0x0800 => format!("{},{}", register_name, ea_format.format),
+        _ => format!("{},{}", ea_format.format, register_name),
+    };
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        format!("MOVES.{}", ea_data.operation_size.get_format()),
+        operands,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::{instruction::GetDisassemblyResult, CpuModel};
+
+    #[test]
+    fn moves_word_to_address_register_sign_extends() {
+        // arrange
+        let code = [0x0e, 0x51, 0xa0, 0x00].to_vec(); // MOVES.W (A1),A2
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.cpu.register.set_a_reg_long_no_log(1, 0x01000100);
+        mm.mem.set_word_no_log(0x01000100, 0x8001);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("MOVES.W"),
+                String::from("(A1),A2"),
+                vec![0x0e51, 0xa000]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xffff8001, mm.cpu.register.get_a_reg_long_no_log(2));
+        assert_eq!(0x00C00004, mm.cpu.register.reg_pc.get_address());
+    }
+
+    #[test]
+    fn moves_byte_data_register_to_memory() {
+        // arrange
+        let code = [0x0e, 0x19, 0x38, 0x00].to_vec(); // MOVES.B D3,(A1)+
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.cpu.register.set_a_reg_long_no_log(1, 0x01000100);
+        mm.cpu.register.set_d_reg_long_no_log(3, 0x123456ab);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("MOVES.B"),
+                String::from("D3,(A1)+"),
+                vec![0x0e19, 0x3800]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xab, mm.mem.get_byte_no_log(0x01000100));
+        assert_eq!(0x01000101, mm.cpu.register.get_a_reg_long_no_log(1));
+    }
+
+    #[test]
+    fn moves_privilege_violation() {
+        // arrange
+        let code = [0x0e, 0x51, 0xa0, 0x00].to_vec(); // MOVES.W (A1),A2
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.cpu.register.reg_sr.set_value(0x0000);
+        mm.mem.set_long_no_log(0x00000020, 0x00C00100);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00100, mm.cpu.register.reg_pc.get_address());
+        // format $0, vector offset $020
+        assert_eq!(0x0020, mm.mem.get_word_no_log(0x010003FE));
+    }
+}
@@ -0,0 +1,149 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::{Cpu, StatusRegisterResult};
+use crate::mem::Mem;
+use crate::register::{
+    ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE,
+    STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// MULU.L and MULS.L share the opcode, the extension word selects signed and the 64 bit result
+
+struct ExtensionWord {
+    register_dl: usize,
+    register_dh: usize,
+    signed: bool,
+    quad_result: bool,
+}
+
+fn get_extension_word(extension_word: u16) -> ExtensionWord {
+    ExtensionWord {
+        register_dl: ((extension_word >> 12) & 0x0007) as usize,
+        register_dh: (extension_word & 0x0007) as usize,
+        signed: extension_word & 0x0800 != 0,
+        quad_result: extension_word & 0x0400 != 0,
+    }
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_data_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Long),
+    )?;
+
+    let source = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
+    let dest = reg.get_d_reg_long(extension_word.register_dl, step_log);
+    let result = match extension_word.signed {
+        true => ((source as i32 as i64) * (dest as i32 as i64)) as u64,
+        false => (source as u64) * (dest as u64),
+    };
+
+    let status_register = match extension_word.quad_result {
+        true => {
+            reg.set_d_reg_long(step_log, extension_word.register_dl, result as u32);
+            reg.set_d_reg_long(step_log, extension_word.register_dh, (result >> 32) as u32);
+            match result {
+                0 => STATUS_REGISTER_MASK_ZERO,
+                0x8000000000000000..=0xffffffffffffffff => STATUS_REGISTER_MASK_NEGATIVE,
+                _ => 0x0000,
+            }
+        }
+        false => {
+            reg.set_d_reg_long(step_log, extension_word.register_dl, result as u32);
+            let overflow = match extension_word.signed {
+                true => result as i64 != result as u32 as i32 as i64,
+                false => result > 0xffffffff,
+            };
+            let status_register = match result as u32 {
+                0 => STATUS_REGISTER_MASK_ZERO,
+                0x80000000..=0xffffffff => STATUS_REGISTER_MASK_NEGATIVE,
+                _ => 0x0000,
+            };
+            match overflow {
+                true => status_register | STATUS_REGISTER_MASK_OVERFLOW,
+                false => status_register,
+            }
+        }
+    };
+    reg.reg_sr.merge_status_register(
+        step_log,
+        StatusRegisterResult {
+            status_register,
+            status_register_mask: STATUS_REGISTER_MASK_CARRY
+                | STATUS_REGISTER_MASK_OVERFLOW
+                | STATUS_REGISTER_MASK_ZERO
+                | STATUS_REGISTER_MASK_NEGATIVE,
+        },
+    );
+
+    let cycles = match extension_word.quad_result {
+        true => 45,
+        false => 43,
+    } + ea_data.get_calculation_cycles();
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let extension_word = get_extension_word(pc.fetch_next_word(mem));
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Long),
+    )?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+
+    let instruction_name = match extension_word.signed {
+        true => String::from("MULS.L"),
+        false => String::from("MULU.L"),
+    };
+    let operands = match extension_word.quad_result {
+        true => format!(
+            "{},D{}:D{}",
+            ea_format, extension_word.register_dh, extension_word.register_dl
+        ),
+        false => format!("{},D{}", ea_format, extension_word.register_dl),
+    };
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        instruction_name,
+        operands,
+    ))
+}
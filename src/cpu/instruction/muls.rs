@@ -0,0 +1,86 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
+use crate::cpu::Cpu;
+use crate::mem::Mem;
+use crate::register::{ProgramCounter, Register};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_data_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| {
+            // Muls for 68000 is always word*word => long. MULS.L for 020+ is in mull.rs.
+            Ok(OperationSize::Word)
+        },
+    )?;
+    let register = Cpu::extract_register_index_from_bit_pos(ea_data.instr_word, 9)?;
+
+    let source = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
+
+    let dest = reg.get_d_reg_word(register, step_log);
+    let result = Cpu::muls_words(source, dest);
+
+    reg.set_d_reg_long(step_log, register, result.result);
+
+    reg.reg_sr
+        .merge_status_register(step_log, result.status_register_result);
+
+    let cycles = timing::get_muls_cycles(source) + ea_data.get_calculation_cycles();
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| {
+            // Muls for 68000 is always word*word => long. MULS.L for 020+ is in mull.rs.
+            Ok(OperationSize::Word)
+        },
+    )?;
+    let register = Cpu::extract_register_index_from_bit_pos(ea_data.instr_word, 9)?;
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("MULS.W"),
+        format!("{},D{}", ea_format, register),
+    ))
+}
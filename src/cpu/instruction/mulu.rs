@@ -1,7 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
 use crate::cpu::Cpu;
 use crate::mem::Mem;
 use crate::register::{ProgramCounter, Register};
@@ -28,20 +30,20 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
         mem,
         step_log,
         |instr_word| {
-            // Mulu for 68000 is always word*word => long. MULU.L for 020+ in own get_disassembly_long function.
+            // Mulu for 68000 is always word*word => long. MULU.L for 020+ is in mull.rs.
             Ok(OperationSize::Word)
         },
     )?;
     let register = Cpu::extract_register_index_from_bit_pos(ea_data.instr_word, 9)?;
 
-    let source = ea_data.get_value_word(pc, reg, mem, step_log, true);
+    let source = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
 
     let dest = reg.get_d_reg_word(register, step_log);
     let result = Cpu::mulu_words(source, dest);
@@ -51,7 +53,8 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, result.status_register_result);
 
-    Ok(())
+    let cycles = timing::get_mulu_cycles(source) + ea_data.get_calculation_cycles();
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -67,7 +70,7 @@ pub fn get_disassembly<'a>(
         mem,
         step_log,
         |instr_word| {
-            // Mulu for 68000 is always word*word => long. MULU.L for 020+ in own get_disassembly_long function.
+            // Mulu for 68000 is always word*word => long. MULU.L for 020+ is in mull.rs.
             Ok(OperationSize::Word)
         },
     )?;
@@ -1,8 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
-    cpu::{step_log::StepLog, Cpu},
+    cpu::{step_log::StepLog, timing, Cpu},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -32,7 +33,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -43,24 +44,24 @@ pub fn step<'a>(
 
     let status_register_result = match ea_data.operation_size {
         OperationSize::Byte => {
-            let value = ea_data.get_value_byte(pc, reg, mem, step_log, false);
+            let value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
 
             let result = Cpu::neg_byte(value);
-            ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
         OperationSize::Word => {
-            let value = ea_data.get_value_word(pc, reg, mem, step_log, false);
+            let value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
 
             let result = Cpu::neg_word(value);
-            ea_data.set_value_word(pc, reg, mem, step_log, result.result, true);
+            ea_data.set_value_word(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
         OperationSize::Long => {
-            let value = ea_data.get_value_long(pc, reg, mem, step_log, false);
+            let value = ea_data.get_value_long(pc, reg, mem, step_log, false)?;
 
             let result = Cpu::neg_long(value);
-            ea_data.set_value_long(pc, reg, mem, step_log, result.result, true);
+            ea_data.set_value_long(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
     };
@@ -68,7 +69,8 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = timing::get_single_operand_cycles(&ea_data);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -0,0 +1,102 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::{
+    cpu::{step_log::StepLog, timing, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => match crate::cpu::match_check_size000110_from_bit_pos_6(instr_word) {
+            false => false,
+            true => {
+                crate::cpu::match_check_ea_only_data_alterable_addressing_modes_pos_0(instr_word)
+            }
+        },
+        false => false,
+    }
+}
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap()),
+    )?;
+
+    let status_register_result = match ea_data.operation_size {
+        OperationSize::Byte => {
+            let value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
+
+            let result =
+                Cpu::sub_bytes_with_extend(value, 0x00, reg.reg_sr.is_sr_extend_set());
+            ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true)?;
+            result.status_register_result
+        }
+        OperationSize::Word => {
+            let value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
+
+            let result =
+                Cpu::sub_words_with_extend(value, 0x0000, reg.reg_sr.is_sr_extend_set());
+            ea_data.set_value_word(pc, reg, mem, step_log, result.result, true)?;
+            result.status_register_result
+        }
+        OperationSize::Long => {
+            let value = ea_data.get_value_long(pc, reg, mem, step_log, false)?;
+
+            let result =
+                Cpu::sub_longs_with_extend(value, 0x00000000, reg.reg_sr.is_sr_extend_set());
+            ea_data.set_value_long(pc, reg, mem, step_log, result.result, true)?;
+            result.status_register_result
+        }
+    };
+
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+
+    let cycles = timing::get_single_operand_cycles(&ea_data);
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap()),
+    )?;
+
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, Some(ea_data.operation_size), mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from(format!("NEGX.{}", ea_data.operation_size.get_format())),
+        ea_format.format,
+    ))
+}
@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::step_log::StepLog,
     mem::Mem,
@@ -20,8 +20,8 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
-    Ok(())
+) -> Result<StepResult, StepError> {
+    Ok(StepResult { cycles: 4 })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,7 +1,9 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
 use crate::cpu::Cpu;
 use crate::mem::Mem;
 use crate::register::{ProgramCounter, Register};
@@ -33,9 +35,9 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
-    let status_register_result = match operation_size {
+    let (status_register_result, cycles) = match operation_size {
         OperationSize::Byte => {
             pc.skip_byte();
             let source = pc.fetch_next_byte(mem);
@@ -49,11 +51,14 @@ pub fn step<'a>(
                 3,
                 0,
             )?;
-            let dest = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let dest = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
 
             let result = Cpu::or_bytes(source, dest);
-            ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true);
-            result.status_register_result
+            ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true)?;
+            (
+                result.status_register_result,
+                timing::get_immediate_cycles(&ea_data),
+            )
         }
         OperationSize::Word => {
             let source = pc.fetch_next_word(mem);
@@ -67,11 +72,14 @@ pub fn step<'a>(
                 3,
                 0,
             )?;
-            let dest = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let dest = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
 
             let result = Cpu::or_words(source, dest);
-            ea_data.set_value_word(pc, reg, mem, step_log, result.result, true);
-            result.status_register_result
+            ea_data.set_value_word(pc, reg, mem, step_log, result.result, true)?;
+            (
+                result.status_register_result,
+                timing::get_immediate_cycles(&ea_data),
+            )
         }
         OperationSize::Long => {
             let source = pc.fetch_next_long(mem);
@@ -85,18 +93,21 @@ pub fn step<'a>(
                 3,
                 0,
             )?;
-            let dest = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let dest = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
 
             let result = Cpu::or_longs(source, dest);
-            ea_data.set_value_long(pc, reg, mem, step_log, result.result, true);
-            result.status_register_result
+            ea_data.set_value_long(pc, reg, mem, step_log, result.result, true)?;
+            (
+                result.status_register_result,
+                timing::get_immediate_cycles(&ea_data),
+            )
         }
     };
 
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
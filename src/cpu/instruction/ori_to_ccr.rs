@@ -0,0 +1,57 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::{Cpu, StatusRegisterResult};
+use crate::mem::Mem;
+use crate::register::{ProgramCounter, Register, STATUS_REGISTER_MASK_CONDITION_CODES};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    pc.skip_byte();
+    let immediate_data = pc.fetch_next_byte(mem);
+
+    let dest = reg.reg_sr.get_sr_reg_flags_abcde() as u8;
+
+    let result = Cpu::or_bytes(immediate_data, dest);
+
+    reg.reg_sr.merge_status_register(
+        step_log,
+        StatusRegisterResult {
+            status_register: result.result as u16,
+            status_register_mask: STATUS_REGISTER_MASK_CONDITION_CODES,
+        },
+    );
+
+    Ok(StepResult { cycles: 20 })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    pc.skip_byte();
+    let immediate_data = pc.fetch_next_byte(mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        format!("ORI.B"),
+        format!("#${:02X},CCR", immediate_data),
+    ))
+}
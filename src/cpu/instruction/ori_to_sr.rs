@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::cpu::step_log::StepLog;
 use crate::cpu::Cpu;
 use crate::mem::Mem;
@@ -19,7 +19,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     match reg.reg_sr.is_sr_supervisor_set(step_log) {
         true => {
             let immediate_data = pc.fetch_next_word(mem);
@@ -30,7 +30,7 @@ pub fn step<'a>(
 
             reg.reg_sr.set_value(result.result);
 
-            Ok(())
+            Ok(StepResult { cycles: 20 })
         }
         false => Err(StepError::PriviliegeViolation),
     }
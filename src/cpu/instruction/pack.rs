@@ -0,0 +1,93 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, OperationSize, StepError, StepResult,
+};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register, RegisterType},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+fn pack_word(value: u16, adjustment: u16) -> u8 {
+    let value = value.wrapping_add(adjustment);
+    (((value >> 4) & 0x00f0) | (value & 0x000f)) as u8
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let register_type = match instr_word & 0x0008 {
+        0x0008 => RegisterType::Address,
+        _ => RegisterType::Data,
+    };
+    let source_register_index = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    let destination_register_index = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
+    let adjustment = pc.fetch_next_word(mem);
+    match register_type {
+        RegisterType::Data => {
+            let value = reg.get_d_reg_word(source_register_index, step_log);
+            let result = pack_word(value, adjustment);
+            reg.set_d_reg_byte(step_log, destination_register_index, result);
+            Ok(StepResult { cycles: 6 })
+        }
+        RegisterType::Address => {
+            // The two source bytes are read one at a time, the high byte is at the lower address
+            reg.decrement_a_reg(source_register_index, step_log, OperationSize::Byte);
+            let areg_1 = reg.get_a_reg_long(source_register_index, step_log);
+            let value_low = mem.get_byte(step_log, areg_1)?;
+            reg.decrement_a_reg(source_register_index, step_log, OperationSize::Byte);
+            let areg_1 = reg.get_a_reg_long(source_register_index, step_log);
+            let value_high = mem.get_byte(step_log, areg_1)?;
+            let result = pack_word(u16::from_be_bytes([value_high, value_low]), adjustment);
+
+            reg.decrement_a_reg(destination_register_index, step_log, OperationSize::Byte);
+            let areg_2 = reg.get_a_reg_long(destination_register_index, step_log);
+            mem.set_byte(step_log, areg_2, result)?;
+            Ok(StepResult { cycles: 13 })
+        }
+    }
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let register_type = match instr_word & 0x0008 {
+        0x0008 => RegisterType::Address,
+        _ => RegisterType::Data,
+    };
+    let source_register_index = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    let destination_register_index = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
+    let adjustment = pc.fetch_next_word(mem);
+    let operands = match register_type {
+        RegisterType::Data => format!(
+            "D{},D{},#${:04X}",
+            source_register_index, destination_register_index, adjustment
+        ),
+        RegisterType::Address => format!(
+            "-(A{}),-(A{}),#${:04X}",
+            source_register_index, destination_register_index, adjustment
+        ),
+    };
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("PACK"),
+        operands,
+    ))
+}
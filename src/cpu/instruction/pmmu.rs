@@ -0,0 +1,606 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::{mmu::Mmu, Mem},
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// The 68030 MMU instructions are coprocessor 0 general instructions, the command word selects
+// PMOVE, PFLUSH, PLOAD or PTEST. Command words the 68030 doesn't implement are F-line
+// exceptions. PTEST doesn't set the used and modified bits of the descriptors it searches.
+
+#[derive(Copy, Clone, PartialEq)]
+enum PmmuRegister {
+    Tc,
+    Srp,
+    Crp,
+    Tt0,
+    Tt1,
+    Mmusr,
+}
+
+#[derive(Copy, Clone)]
+enum FunctionCode {
+    Sfc,
+    Dfc,
+    DataRegister(usize),
+    Immediate(u8),
+}
+
+enum PmmuCommand {
+    Pmove {
+        register: PmmuRegister,
+        to_memory: bool,
+        flush_disable: bool,
+    },
+    Pflusha,
+    Pflush {
+        function_code: FunctionCode,
+        mask: u8,
+        with_address: bool,
+    },
+    Pload {
+        function_code: FunctionCode,
+        read: bool,
+    },
+    Ptest {
+        function_code: FunctionCode,
+        level: u16,
+        read: bool,
+        address_register: Option<usize>,
+    },
+}
+
+fn get_function_code(command_word: u16) -> Option<FunctionCode> {
+    match command_word & 0x001f {
+        0b00000 => Some(FunctionCode::Sfc),
+        0b00001 => Some(FunctionCode::Dfc),
+        0b01000..=0b01111 => Some(FunctionCode::DataRegister((command_word & 0b111) as usize)),
+        0b10000..=0b10111 => Some(FunctionCode::Immediate((command_word & 0b111) as u8)),
+        _ => None,
+    }
+}
+
+fn get_command(command_word: u16) -> Option<PmmuCommand> {
+    let to_memory = command_word & 0x0200 != 0;
+    let flush_disable = command_word & 0x0100 != 0;
+    let pmove = |register| PmmuCommand::Pmove {
+        register,
+        to_memory,
+        flush_disable,
+    };
+    match (command_word >> 13, (command_word >> 10) & 0b111) {
+        (0b000, 0b010) if command_word & 0x00ff == 0 => Some(pmove(PmmuRegister::Tt0)),
+        (0b000, 0b011) if command_word & 0x00ff == 0 => Some(pmove(PmmuRegister::Tt1)),
+        (0b010, 0b000) if command_word & 0x00ff == 0 => Some(pmove(PmmuRegister::Tc)),
+        (0b010, 0b010) if command_word & 0x00ff == 0 => Some(pmove(PmmuRegister::Srp)),
+        (0b010, 0b011) if command_word & 0x00ff == 0 => Some(pmove(PmmuRegister::Crp)),
+        (0b011, 0b000) if command_word & 0x01ff == 0 => Some(pmove(PmmuRegister::Mmusr)),
+        (0b001, 0b001) if command_word & 0x03ff == 0 => Some(PmmuCommand::Pflusha),
+        (0b001, 0b100 | 0b110) if command_word & 0x0300 == 0 => Some(PmmuCommand::Pflush {
+            function_code: get_function_code(command_word)?,
+            mask: ((command_word >> 5) & 0b111) as u8,
+            with_address: command_word & 0x0800 != 0,
+        }),
+        (0b001, 0b000) if command_word & 0x01e0 == 0 => Some(PmmuCommand::Pload {
+            function_code: get_function_code(command_word)?,
+            read: to_memory,
+        }),
+        (0b100, level) => Some(PmmuCommand::Ptest {
+            function_code: get_function_code(command_word)?,
+            level,
+            read: to_memory,
+            address_register: match command_word & 0x0100 {
+                0x0100 => Some(((command_word >> 5) & 0b111) as usize),
+                _ => None,
+            },
+        }),
+        _ => None,
+    }
+}
+
+fn get_register_name(register: PmmuRegister) -> &'static str {
+    match register {
+        PmmuRegister::Tc => "TC",
+        PmmuRegister::Srp => "SRP",
+        PmmuRegister::Crp => "CRP",
+        PmmuRegister::Tt0 => "TT0",
+        PmmuRegister::Tt1 => "TT1",
+        PmmuRegister::Mmusr => "MMUSR",
+    }
+}
+
+fn get_function_code_format(function_code: FunctionCode) -> String {
+    match function_code {
+        FunctionCode::Sfc => String::from("SFC"),
+        FunctionCode::Dfc => String::from("DFC"),
+        FunctionCode::DataRegister(register) => format!("D{}", register),
+        FunctionCode::Immediate(value) => format!("#{}", value),
+    }
+}
+
+fn get_function_code_value(
+    function_code: FunctionCode,
+    reg: &Register,
+    step_log: &mut StepLog,
+) -> u8 {
+    match function_code {
+        FunctionCode::Sfc => reg.reg_sfc as u8,
+        FunctionCode::Dfc => reg.reg_dfc as u8,
+        FunctionCode::DataRegister(register) => {
+            (reg.get_d_reg_long(register, step_log) & 0b111) as u8
+        }
+        FunctionCode::Immediate(value) => value,
+    }
+}
+
+fn is_ea_pc_relative(instr_word: u16) -> bool {
+    matches!(instr_word & 0x003f, 0b111010 | 0b111011)
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        // PFLUSHA and PFLUSH without an address have an all zero effective address field
+        true => {
+            instr_word & 0x003f == 0
+                || crate::cpu::match_check_ea_only_control_addressing_modes_pos_0(instr_word)
+        }
+        false => false,
+    }
+}
+
+fn get_ea_address(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<u32, StepError> {
+    if instr_word & 0x003f == 0 {
+        return Err(StepError::IllegalInstruction);
+    }
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |_| Ok(OperationSize::Long),
+    )?;
+    Ok(ea_data.get_address(pc, reg, mem, step_log))
+}
+
+fn pmove_to_memory(
+    register: PmmuRegister,
+    address: u32,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<(), StepError> {
+    let (tc, tt0, tt1, crp, srp, mmusr) = mem.with_mmu(|mmu| {
+        (
+            mmu.reg_tc,
+            mmu.reg_tt0,
+            mmu.reg_tt1,
+            mmu.reg_crp,
+            mmu.reg_srp,
+            mmu.reg_mmusr,
+        )
+    });
+    match register {
+        PmmuRegister::Tc => mem.set_long(step_log, address, tc),
+        PmmuRegister::Tt0 => mem.set_long(step_log, address, tt0),
+        PmmuRegister::Tt1 => mem.set_long(step_log, address, tt1),
+        PmmuRegister::Mmusr => mem.set_word(step_log, address, mmusr),
+        PmmuRegister::Crp | PmmuRegister::Srp => {
+            let value = match register {
+                PmmuRegister::Crp => crp,
+                _ => srp,
+            };
+            mem.set_long(step_log, address, (value >> 32) as u32)?;
+            mem.set_long(step_log, address.wrapping_add(4), value as u32)
+        }
+    }
+}
+
+// Loading TC or a root pointer with an invalid value is an MMU configuration error. The ATC
+// is flushed, unless the flush disable bit is set.
+fn pmove_from_memory(
+    register: PmmuRegister,
+    flush_disable: bool,
+    address: u32,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<(), StepError> {
+    let value = match register {
+        PmmuRegister::Mmusr => mem.get_word(step_log, address)? as u64,
+        PmmuRegister::Crp | PmmuRegister::Srp => {
+            let high = mem.get_long(step_log, address)? as u64;
+            let low = mem.get_long(step_log, address.wrapping_add(4))? as u64;
+            (high << 32) | low
+        }
+        _ => mem.get_long(step_log, address)? as u64,
+    };
+    let valid = match register {
+        PmmuRegister::Tc => Mmu::is_valid_translation_control(value as u32),
+        PmmuRegister::Crp | PmmuRegister::Srp => Mmu::is_valid_root_pointer(value),
+        _ => true,
+    };
+    if !valid {
+        return Err(StepError::MmuConfigurationError);
+    }
+    mem.with_mmu(|mmu| {
+        match register {
+            PmmuRegister::Tc => mmu.reg_tc = value as u32,
+            PmmuRegister::Srp => mmu.reg_srp = value,
+            PmmuRegister::Crp => mmu.reg_crp = value,
+            PmmuRegister::Tt0 => mmu.reg_tt0 = value as u32,
+            PmmuRegister::Tt1 => mmu.reg_tt1 = value as u32,
+            PmmuRegister::Mmusr => mmu.reg_mmusr = value as u16,
+        }
+        if register != PmmuRegister::Mmusr && !flush_disable {
+            mmu.flush_all();
+        }
+    });
+    Ok(())
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    if !reg.reg_sr.is_sr_supervisor_set(step_log) {
+        return Err(StepError::PriviliegeViolation);
+    }
+    let command = get_command(pc.fetch_next_word(mem)).ok_or(StepError::IllegalInstruction)?;
+    match command {
+        PmmuCommand::Pmove {
+            register,
+            to_memory,
+            flush_disable,
+        } => {
+            if to_memory && is_ea_pc_relative(instr_word) {
+                return Err(StepError::IllegalInstruction);
+            }
+            let address = get_ea_address(instr_word, pc, reg, mem, step_log)?;
+            match to_memory {
+                true => pmove_to_memory(register, address, mem, step_log)?,
+                false => pmove_from_memory(register, flush_disable, address, mem, step_log)?,
+            }
+            Ok(StepResult { cycles: 20 })
+        }
+        PmmuCommand::Pflusha => {
+            if instr_word & 0x003f != 0 {
+                return Err(StepError::IllegalInstruction);
+            }
+            mem.with_mmu(|mmu| mmu.flush_all());
+            Ok(StepResult { cycles: 12 })
+        }
+        PmmuCommand::Pflush {
+            function_code,
+            mask,
+            with_address,
+        } => {
+            let function_code = get_function_code_value(function_code, reg, step_log);
+            let address = match with_address {
+                true => Some(get_ea_address(instr_word, pc, reg, mem, step_log)?),
+                false if instr_word & 0x003f == 0 => None,
+                false => return Err(StepError::IllegalInstruction),
+            };
+            mem.with_mmu(|mmu| mmu.flush(function_code, mask, address));
+            Ok(StepResult { cycles: 12 })
+        }
+        PmmuCommand::Pload {
+            function_code,
+            read,
+        } => {
+            let function_code = get_function_code_value(function_code, reg, step_log);
+            let address = get_ea_address(instr_word, pc, reg, mem, step_log)?;
+            mem.load_translation(address, function_code, !read);
+            Ok(StepResult { cycles: 20 })
+        }
+        PmmuCommand::Ptest {
+            function_code,
+            level,
+            read,
+            address_register,
+        } => {
+            if level == 0 && address_register.is_some() {
+                return Err(StepError::IllegalInstruction);
+            }
+            let function_code = get_function_code_value(function_code, reg, step_log);
+            let address = get_ea_address(instr_word, pc, reg, mem, step_log)?;
+            let mmusr = match level {
+                0 => mem.with_mmu(|mmu| mmu.test_atc(address, function_code, !read)),
+                _ => {
+                    let search = mem.search_translation_tables(address, function_code, level);
+                    if let Some(address_register) = address_register {
+                        reg.set_a_reg_long(step_log, address_register, search.descriptor_address);
+                    }
+                    search.get_mmusr()
+                }
+            };
+            mem.with_mmu(|mmu| mmu.reg_mmusr = mmusr);
+            Ok(StepResult { cycles: 20 })
+        }
+    }
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let command = get_command(pc.fetch_next_word(mem)).ok_or(StepError::IllegalInstruction)?;
+    let mut get_ea_format = |pc: &mut ProgramCounter| -> Result<String, GetDisassemblyResultError> {
+        let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+            instr_word,
+            reg,
+            mem,
+            step_log,
+            |_| Ok(OperationSize::Long),
+        )?;
+        Ok(format!(
+            "{}",
+            Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem)
+        ))
+    };
+    let (name, operands) = match command {
+        PmmuCommand::Pmove {
+            register,
+            to_memory,
+            flush_disable,
+        } => {
+            let name = match flush_disable {
+                true => "PMOVEFD",
+                false => "PMOVE",
+            };
+            let ea_format = get_ea_format(pc)?;
+            let register_name = get_register_name(register);
+            match to_memory {
+                true => (name, format!("{},{}", register_name, ea_format)),
+                false => (name, format!("{},{}", ea_format, register_name)),
+            }
+        }
+        PmmuCommand::Pflusha => ("PFLUSHA", String::new()),
+        PmmuCommand::Pflush {
+            function_code,
+            mask,
+            with_address,
+        } => {
+            let operands = format!("{},#{}", get_function_code_format(function_code), mask);
+            match with_address {
+                true => ("PFLUSH", format!("{},{}", operands, get_ea_format(pc)?)),
+                false => ("PFLUSH", operands),
+            }
+        }
+        PmmuCommand::Pload {
+            function_code,
+            read,
+        } => {
+            let name = match read {
+                true => "PLOADR",
+                false => "PLOADW",
+            };
+            let operands = format!(
+                "{},{}",
+                get_function_code_format(function_code),
+                get_ea_format(pc)?
+            );
+            (name, operands)
+        }
+        PmmuCommand::Ptest {
+            function_code,
+            level,
+            read,
+            address_register,
+        } => {
+            let name = match read {
+                true => "PTESTR",
+                false => "PTESTW",
+            };
+            let mut operands = format!(
+                "{},{},#{}",
+                get_function_code_format(function_code),
+                get_ea_format(pc)?,
+                level
+            );
+            if let Some(address_register) = address_register {
+                operands.push_str(&format!(",A{}", address_register));
+            }
+            (name, operands)
+        }
+    };
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from(name),
+        operands,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cpu::{instruction::GetDisassemblyResult, CpuModel},
+        mem::{
+            mmu::{MMUSR_INVALID, MMUSR_MODIFIED},
+            rammemory::RamMemory,
+        },
+        modermodem::Modermodem,
+    };
+
+    // TC with 4 KB pages and two levels of 10 bit indexes, the root table at $00001000
+    const TC_4K_10_10: u32 = 0x80c0aa00;
+
+    // Transparent translation of $00xxxxxx and $01xxxxxx, for the code and the stack
+    const TT0_CODE_AND_STACK: u32 = 0x00018107;
+
+    // Maps the logical page $40000000 to the physical page $00003000, with the tables in RAM at
+    // $00001000
+    fn instr_test_setup_with_translation_tables(code: Vec<u8>) -> Modermodem {
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(
+            CpuModel::M68030,
+            code,
+            Some(vec![RamMemory::from_range(0x00001000, 0x00003fff)]),
+        );
+        mm.mem.set_long_no_log(0x00001000 + 0x100 * 4, 0x00002002);
+        mm.mem.set_long_no_log(0x00002000, 0x00003001);
+        // The PMOVE operands: CRP, TC
+        mm.mem.set_long_no_log(0x00000100, 0x7fff0002);
+        mm.mem.set_long_no_log(0x00000104, 0x00001000);
+        mm.mem.set_long_no_log(0x00000108, TC_4K_10_10);
+        mm
+    }
+
+    #[test]
+    fn pmove_to_crp_and_tc_enables_translation() {
+        // arrange
+        let code = [
+            0xf0, 0x10, 0x4c, 0x00, // PMOVE (A0),CRP
+            0xf0, 0x11, 0x40, 0x00, // PMOVE (A1),TC
+        ]
+        .to_vec();
+        let mut mm = instr_test_setup_with_translation_tables(code);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00000100);
+        mm.cpu.register.set_a_reg_long_no_log(1, 0x00000108);
+        mm.mem.with_mmu(|mmu| mmu.reg_tt0 = TT0_CODE_AND_STACK);
+        mm.mem.set_long_no_log(0x00003010, 0x12345678);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("PMOVE"),
+                String::from("(A0),CRP"),
+                vec![0xf010, 0x4c00]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x7fff0002_00001000, mm.mem.with_mmu(|mmu| mmu.reg_crp));
+        assert_eq!(TC_4K_10_10, mm.mem.with_mmu(|mmu| mmu.reg_tc));
+        assert_eq!(0x12345678, mm.mem.get_long_no_log(0x40000010));
+    }
+
+    #[test]
+    fn pmove_invalid_tc_is_mmu_configuration_error() {
+        // arrange
+        let code = [0xf0, 0x10, 0x40, 0x00].to_vec(); // PMOVE (A0),TC
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68030, code, None);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00000100);
+        mm.mem.set_long_no_log(0x00000100, 0x80c0a900);
+        mm.mem.set_long_no_log(0x000000e0, 0x00C01248); // MMU configuration error vector
+                                                        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x00000000, mm.mem.with_mmu(|mmu| mmu.reg_tc));
+        // format $2, vector offset $0E0
+        assert_eq!(0x20e0, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn ptest_reports_search_in_mmusr() {
+        // arrange
+        let code = [
+            0xf0, 0x10, 0x9d, 0x35, // PTESTW #5,(A0),#7,A1
+            0xf0, 0x10, 0x9d, 0x35, // PTESTW #5,(A0),#7,A1
+        ]
+        .to_vec();
+        let mut mm = instr_test_setup_with_translation_tables(code);
+        mm.mem.with_mmu(|mmu| {
+            mmu.reg_crp = 0x7fff0002_00001000;
+            mmu.reg_tc = TC_4K_10_10;
+            mmu.reg_tt0 = TT0_CODE_AND_STACK;
+        });
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x40000010);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("PTESTW"),
+                String::from("#5,(A0),#7,A1"),
+                vec![0xf010, 0x9d35]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(2, mm.mem.with_mmu(|mmu| mmu.reg_mmusr));
+        assert_eq!(0x00002000, mm.cpu.register.get_a_reg_long_no_log(1));
+        // The page descriptor is left unchanged
+        assert_eq!(0x00003001, mm.mem.get_long_no_log(0x00002000));
+        // act
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x40400000);
+        mm.step();
+        // assert
+        assert_eq!(MMUSR_INVALID | 1, mm.mem.with_mmu(|mmu| mmu.reg_mmusr));
+        assert_eq!(0x00001404, mm.cpu.register.get_a_reg_long_no_log(1));
+    }
+
+    #[test]
+    fn ploadw_sets_modified_and_ptest_level_0_finds_atc_entry() {
+        // arrange
+        let code = [
+            0xf0, 0x10, 0x20, 0x15, // PLOADW #5,(A0)
+            0xf0, 0x10, 0x82, 0x15, // PTESTR #5,(A0),#0
+            0xf0, 0x00, 0x24, 0x00, // PFLUSHA
+            0xf0, 0x10, 0x82, 0x15, // PTESTR #5,(A0),#0
+        ]
+        .to_vec();
+        let mut mm = instr_test_setup_with_translation_tables(code);
+        mm.mem.with_mmu(|mmu| {
+            mmu.reg_crp = 0x7fff0002_00001000;
+            mmu.reg_tc = TC_4K_10_10;
+            mmu.reg_tt0 = TT0_CODE_AND_STACK;
+        });
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x40000010);
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(0x00003019, mm.mem.get_long_no_log(0x00002000));
+        assert_eq!(MMUSR_MODIFIED, mm.mem.with_mmu(|mmu| mmu.reg_mmusr));
+        // act
+        mm.step();
+        mm.step();
+        // assert
+        assert_eq!(MMUSR_INVALID, mm.mem.with_mmu(|mmu| mmu.reg_mmusr));
+    }
+
+    #[test]
+    fn pmmu_is_line_1111_on_68020() {
+        // arrange
+        let code = [0xf0, 0x00, 0x24, 0x00].to_vec(); // PFLUSHA
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.mem.set_long_no_log(0x0000002C, 0x00C01248); // Line 1111 vector
+                                                        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+    }
+}
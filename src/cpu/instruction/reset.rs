@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::step_log::StepLog,
     mem::Mem,
@@ -20,12 +20,12 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     // todo!("RESET instruction")
     step_log.add_log_string(String::from("TODO: RESET"));
     mem.set_overlay_enable(true);
     Err(StepError::InstructionError{details: String::from("TODO: RESET instruction")})
-    // Ok(())
+    // Ok(StepResult { cycles: 132 })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,34 +1,26 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, OperationSize, StepError};
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
 use crate::cpu::step_log::StepLog;
-use crate::cpu::{Cpu, StatusRegisterResult};
+use crate::cpu::timing;
+use crate::cpu::{Cpu, RotateDirection};
 use crate::mem::Mem;
-use crate::register::{
-    ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_EXTEND,
-    STATUS_REGISTER_MASK_NEGATIVE, STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
-};
+use crate::register::{ProgramCounter, Register};
 
 // Instruction State
 // =================
-// step: TODO
-// step cc: TODO
-// get_disassembly: TODO
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
 
 // 020+ step: TODO
 // 020+ get_disassembly: TODO
 
-// TODO: Tests!
-
-enum RolrDirection {
-    Right,
-    Left,
-}
-
-impl RolrDirection {
-    pub fn get_format(&self) -> char {
-        match self {
-            RolrDirection::Right => 'R',
-            RolrDirection::Left => 'L',
-        }
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_memory_alterable_addressing_modes_pos_0(instr_word),
+        false => false,
     }
 }
 
@@ -38,7 +30,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -46,78 +38,20 @@ pub fn step<'a>(
         step_log,
         |instr_word| Ok(OperationSize::Word),
     )?;
-    let rolr_direction = match ea_data.instr_word & 0x0100 {
-        0x0100 => RolrDirection::Left,
-        _ => RolrDirection::Right,
-    };
-
-    let value = ea_data.get_value_word(pc, reg, mem, step_log, false);
-    println!("value: ${:08X}", value);
-    let (result, overflow) = match rolr_direction {
-        RolrDirection::Left => {
-            // println!("rolr_direction: left");
-            // let result = value.checked_shl(1).unwrap_or(0);
-            // let overflow = result & 0x10000 != 0;
-            // let result = (result & 0xffff) as u16;
-            let result = value.rotate_left(1);
-            (result, (result & 0x00000001) != 0)
-        }
-        RolrDirection::Right => {
-            // println!("rolr_direction: right");
-            // let result = (value << 1).checked_shr(1).unwrap_or(0);
-            // let overflow = result & 0x0001 != 0;
-            // let result = ((result >> 1) & 0xffff) as u16;
-            let result = value.rotate_right(1);
-
-            (result, (result & 0x00000001) != 0)
-        }
+    let direction = match ea_data.instr_word & 0x0100 {
+        0x0100 => RotateDirection::Left,
+        _ => RotateDirection::Right,
     };
 
-    ea_data.set_value_word(pc, reg, mem, step_log, result, true);
-    let mut status_register = 0x0000;
-    match result {
-        0 => status_register |= STATUS_REGISTER_MASK_ZERO,
-        0x8000..=0xffff => status_register |= STATUS_REGISTER_MASK_NEGATIVE,
-        _ => (),
-    }
-    // println!("shift_count: {}", shift_count);
-    // println!("result: {}", result);
-    // println!("overflow: {}", overflow);
-    match overflow {
-        true => status_register |= STATUS_REGISTER_MASK_EXTEND | STATUS_REGISTER_MASK_CARRY,
-        false => (),
-    }
-    let status_register_result = StatusRegisterResult {
-        status_register,
-        status_register_mask: get_status_register_mask(1),
-    };
+    let value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
+    let (result, status_register_result) = Cpu::rotate(value, direction, 1);
+    ea_data.set_value_word(pc, reg, mem, step_log, result, true)?;
 
-    // println!(
-    //     "status_register_result: ${:04X}-${:04X}",
-    //     status_register_result.status_register, status_register_result.status_register_mask
-    // );
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
-}
-
-fn get_status_register_mask(shift_count: u32) -> u16 {
-    match shift_count == 0 {
-        true => {
-            STATUS_REGISTER_MASK_NEGATIVE
-                | STATUS_REGISTER_MASK_ZERO
-                | STATUS_REGISTER_MASK_OVERFLOW
-                | STATUS_REGISTER_MASK_CARRY
-        }
-        false => {
-            STATUS_REGISTER_MASK_EXTEND
-                | STATUS_REGISTER_MASK_NEGATIVE
-                | STATUS_REGISTER_MASK_ZERO
-                | STATUS_REGISTER_MASK_OVERFLOW
-                | STATUS_REGISTER_MASK_CARRY
-        }
-    }
+    let cycles = timing::get_shift_memory_cycles(&ea_data);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -134,16 +68,16 @@ pub fn get_disassembly<'a>(
         step_log,
         |instr_word| Ok(OperationSize::Word),
     )?;
-    let rolr_direction = match ea_data.instr_word & 0x0100 {
-        0x0100 => RolrDirection::Left,
-        _ => RolrDirection::Right,
+    let direction = match ea_data.instr_word & 0x0100 {
+        0x0100 => RotateDirection::Left,
+        _ => RotateDirection::Right,
     };
     let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
     Ok(GetDisassemblyResult::from_pc(
         pc,
         mem,
-        format!("LS{}.W", rolr_direction.get_format(),),
-        format!("#$01,{}", ea_format),
+        format!("RO{}.W", direction.get_format()),
+        format!("{}", ea_format),
     ))
 }
 
@@ -1,34 +1,27 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, OperationSize, StepError};
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::aint::AInt;
 use crate::cpu::step_log::StepLog;
-use crate::cpu::{Cpu, StatusRegisterResult};
+use crate::cpu::timing;
+use crate::cpu::{Cpu, RotateDirection, StatusRegisterResult};
 use crate::mem::Mem;
-use crate::register::{
-    ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_EXTEND,
-    STATUS_REGISTER_MASK_NEGATIVE, STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
-};
+use crate::register::{ProgramCounter, Register};
 
 // Instruction State
 // =================
-// step: TODO
-// step cc: TODO
-// get_disassembly: TODO
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
 
 // 020+ step: TODO
 // 020+ get_disassembly: TODO
 
-// TODO: Tests!
-
-enum RolrDirection {
-    Right,
-    Left,
-}
-
-impl RolrDirection {
-    pub fn get_format(&self) -> char {
-        match self {
-            RolrDirection::Right => 'R',
-            RolrDirection::Left => 'L',
-        }
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_size000110_from_bit_pos_6(instr_word),
+        false => false,
     }
 }
 
@@ -38,158 +31,63 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
-    let (rolr_direction, operation_size) = match (instr_word & 0x01c0) >> 6 {
-        0b000 => (RolrDirection::Right, OperationSize::Byte),
-        0b001 => (RolrDirection::Right, OperationSize::Word),
-        0b010 => (RolrDirection::Right, OperationSize::Long),
-        0b011 => (RolrDirection::Right, OperationSize::Word),
-        0b100 => (RolrDirection::Left, OperationSize::Byte),
-        0b101 => (RolrDirection::Left, OperationSize::Word),
-        0b110 => (RolrDirection::Left, OperationSize::Long),
-        _ => panic!(),
+) -> Result<StepResult, StepError> {
+    let direction = match instr_word & 0x0100 {
+        0x0100 => RotateDirection::Left,
+        _ => RotateDirection::Right,
     };
+    let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
 
     let dest_register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
-    let shift_count = match instr_word & 0x0020 {
+    let rotate_count = match instr_word & 0x0020 {
         0x0020 => {
             let source_register = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
-            let shift_count = reg.get_d_reg_long(source_register, step_log) % 64;
-            shift_count
+            let rotate_count = reg.get_d_reg_long(source_register, step_log) % 64;
+            rotate_count
         }
         _ => {
-            let shift_count = ((instr_word & 0x0e00) >> 9).into();
-            let shift_count = match shift_count {
-                1..=7 => shift_count,
+            let rotate_count = ((instr_word & 0x0e00) >> 9).into();
+            let rotate_count = match rotate_count {
+                1..=7 => rotate_count,
                 _ => 8,
             };
-            shift_count
+            rotate_count
         }
     };
-    let status_register_result = match operation_size {
-        OperationSize::Byte => {
-            let value = reg.get_d_reg_byte(dest_register, step_log);
 
-            println!("value: {}", value);
-            println!("shift_count: {}", shift_count);
-            let (result, carry) = match rolr_direction {
-                RolrDirection::Left => {
-                    println!("rolr_direction: left");
-                    let result = value.rotate_left(shift_count);
-                    (result, (result & 0x01) != 0)
-                }
-                RolrDirection::Right => {
-                    println!("rolr_direction: right");
-                    let result = value.rotate_right(shift_count);
-                    (result, (result & 0x80) != 0)
-                }
-            };
-            println!("result: {}", result);
-            println!("carry: {}", carry);
-            reg.set_d_reg_byte(step_log, dest_register, result);
-            let mut status_register = 0x0000;
-            match result {
-                0 => status_register |= STATUS_REGISTER_MASK_ZERO,
-                0x80..=0xff => status_register |= STATUS_REGISTER_MASK_NEGATIVE,
-                _ => (),
-            }
+    fn do_register_rotate<T>(
+        dest_register: usize,
+        direction: RotateDirection,
+        rotate_count: u32,
+        reg: &mut Register,
+        step_log: &mut StepLog,
+    ) -> StatusRegisterResult
+    where
+        T: AInt,
+    {
+        let value = reg.get_d_reg::<T>(dest_register, step_log);
+        let (result, status_register_result) = Cpu::rotate(value, direction, rotate_count);
+        reg.set_d_reg(step_log, dest_register, result);
+        status_register_result
+    }
 
-            match carry {
-                true => status_register |= STATUS_REGISTER_MASK_CARRY,
-                false => (),
-            }
-            StatusRegisterResult {
-                status_register,
-                status_register_mask: get_status_register_mask(shift_count),
-            }
+    let status_register_result = match operation_size {
+        OperationSize::Byte => {
+            do_register_rotate::<u8>(dest_register, direction, rotate_count, reg, step_log)
         }
         OperationSize::Word => {
-            let value = reg.get_d_reg_word(dest_register, step_log);
-            // println!("value: {}", value);
-            let (result, overflow) = match rolr_direction {
-                RolrDirection::Left => {
-                    // println!("rolr_direction: left");
-                    let result = value.rotate_left(shift_count);
-                    (result, (result & 0x0001) != 0)
-                }
-                RolrDirection::Right => {
-                    // println!("rolr_direction: right");
-                    let result = value.rotate_right(shift_count);
-                    (result, (result & 0x8000) != 0)
-                }
-            };
-
-            reg.set_d_reg_word(step_log, dest_register, result);
-            let mut status_register = 0x0000;
-            match result {
-                0 => status_register |= STATUS_REGISTER_MASK_ZERO,
-                0x8000..=0xffff => status_register |= STATUS_REGISTER_MASK_NEGATIVE,
-                _ => (),
-            }
-            // println!("shift_count: {}", shift_count);
-            // println!("result: {}", result);
-            // println!("overflow: {}", overflow);
-            match overflow {
-                true => status_register |= STATUS_REGISTER_MASK_EXTEND | STATUS_REGISTER_MASK_CARRY,
-                false => (),
-            }
-            StatusRegisterResult {
-                status_register,
-                status_register_mask: get_status_register_mask(shift_count),
-            }
+            do_register_rotate::<u16>(dest_register, direction, rotate_count, reg, step_log)
         }
         OperationSize::Long => {
-            let value = reg.get_d_reg_long(dest_register, step_log);
-            // println!("value: {}", value);
-            let (result, overflow) = match rolr_direction {
-                RolrDirection::Left => {
-                    // println!("rolr_direction: left");
-                    let result = value.rotate_left(shift_count);
-                    (result, (result & 0x00000001) != 0)
-                }
-                RolrDirection::Right => {
-                    // println!("rolr_direction: right");
-                    let result = value.rotate_right(shift_count);
-                    (result, (result & 0x80000000) != 0)
-                }
-            };
-
-            reg.set_d_reg_long(step_log, dest_register, result);
-            let mut status_register = 0x0000;
-            match result {
-                0 => status_register |= STATUS_REGISTER_MASK_ZERO,
-                0x80000000..=0xffffffff => status_register |= STATUS_REGISTER_MASK_NEGATIVE,
-                _ => (),
-            }
-            // println!("shift_count: {}", shift_count);
-            // println!("result: {}", result);
-            // println!("overflow: {}", overflow);
-            match overflow {
-                true => status_register |= STATUS_REGISTER_MASK_EXTEND | STATUS_REGISTER_MASK_CARRY,
-                false => (),
-            }
-            StatusRegisterResult {
-                status_register,
-                status_register_mask: get_status_register_mask(shift_count),
-            }
+            do_register_rotate::<u32>(dest_register, direction, rotate_count, reg, step_log)
         }
     };
 
-    // println!(
-    //     "status_register_result: ${:04X}-${:04X}",
-    //     status_register_result.status_register, status_register_result.status_register_mask
-    // );
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
-}
-
-fn get_status_register_mask(shift_count: u32) -> u16 {
-    STATUS_REGISTER_MASK_NEGATIVE
-        | STATUS_REGISTER_MASK_ZERO
-        | STATUS_REGISTER_MASK_OVERFLOW
-        | STATUS_REGISTER_MASK_CARRY
+    let cycles = timing::get_shift_register_cycles(operation_size, rotate_count);
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -199,16 +97,11 @@ pub fn get_disassembly<'a>(
     mem: &Mem,
     step_log: &mut StepLog,
 ) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
-    let (rolr_direction, operation_size) = match (instr_word & 0x01c0) >> 6 {
-        0b000 => (RolrDirection::Right, OperationSize::Byte),
-        0b001 => (RolrDirection::Right, OperationSize::Word),
-        0b010 => (RolrDirection::Right, OperationSize::Long),
-        0b011 => (RolrDirection::Right, OperationSize::Word),
-        0b100 => (RolrDirection::Left, OperationSize::Byte),
-        0b101 => (RolrDirection::Left, OperationSize::Word),
-        0b110 => (RolrDirection::Left, OperationSize::Long),
-        _ => panic!(),
+    let direction = match instr_word & 0x0100 {
+        0x0100 => RotateDirection::Left,
+        _ => RotateDirection::Right,
     };
+    let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
 
     let dest_register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
     match instr_word & 0x0020 {
@@ -219,7 +112,7 @@ pub fn get_disassembly<'a>(
                 mem,
                 format!(
                     "RO{}.{}",
-                    rolr_direction.get_format(),
+                    direction.get_format(),
                     operation_size.get_format()
                 ),
                 format!("D{},D{}", source_register, dest_register),
@@ -236,7 +129,7 @@ pub fn get_disassembly<'a>(
                 mem,
                 format!(
                     "RO{}.{}",
-                    rolr_direction.get_format(),
+                    direction.get_format(),
                     operation_size.get_format()
                 ),
                 format!("#${:02X},D{}", count, dest_register),
@@ -0,0 +1,82 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
+use crate::cpu::{Cpu, RotateDirection};
+use crate::mem::Mem;
+use crate::register::{ProgramCounter, Register};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_memory_alterable_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Word),
+    )?;
+    let direction = match ea_data.instr_word & 0x0100 {
+        0x0100 => RotateDirection::Left,
+        _ => RotateDirection::Right,
+    };
+
+    let value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
+    let (result, status_register_result) = Cpu::rotate_with_extend(value, direction, 1, reg.reg_sr.is_sr_extend_set());
+    ea_data.set_value_word(pc, reg, mem, step_log, result, true)?;
+
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+
+    let cycles = timing::get_shift_memory_cycles(&ea_data);
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Word),
+    )?;
+    let direction = match ea_data.instr_word & 0x0100 {
+        0x0100 => RotateDirection::Left,
+        _ => RotateDirection::Right,
+    };
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, None, mem);
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        format!("ROX{}.W", direction.get_format()),
+        format!("{}", ea_format),
+    ))
+}
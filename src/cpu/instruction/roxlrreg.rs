@@ -0,0 +1,144 @@
+use super::{
+    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
+};
+use crate::aint::AInt;
+use crate::cpu::step_log::StepLog;
+use crate::cpu::timing;
+use crate::cpu::{Cpu, RotateDirection, StatusRegisterResult};
+use crate::mem::Mem;
+use crate::register::{ProgramCounter, Register};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_size000110_from_bit_pos_6(instr_word),
+        false => false,
+    }
+}
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let direction = match instr_word & 0x0100 {
+        0x0100 => RotateDirection::Left,
+        _ => RotateDirection::Right,
+    };
+    let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
+
+    let dest_register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    let rotate_count = match instr_word & 0x0020 {
+        0x0020 => {
+            let source_register = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
+            let rotate_count = reg.get_d_reg_long(source_register, step_log) % 64;
+            rotate_count
+        }
+        _ => {
+            let rotate_count = ((instr_word & 0x0e00) >> 9).into();
+            let rotate_count = match rotate_count {
+                1..=7 => rotate_count,
+                _ => 8,
+            };
+            rotate_count
+        }
+    };
+
+    fn do_register_rotate<T>(
+        dest_register: usize,
+        direction: RotateDirection,
+        rotate_count: u32,
+        reg: &mut Register,
+        step_log: &mut StepLog,
+    ) -> StatusRegisterResult
+    where
+        T: AInt,
+    {
+        let value = reg.get_d_reg::<T>(dest_register, step_log);
+        let (result, status_register_result) = Cpu::rotate_with_extend(
+            value,
+            direction,
+            rotate_count,
+            reg.reg_sr.is_sr_extend_set(),
+        );
+        reg.set_d_reg(step_log, dest_register, result);
+        status_register_result
+    }
+
+    let status_register_result = match operation_size {
+        OperationSize::Byte => {
+            do_register_rotate::<u8>(dest_register, direction, rotate_count, reg, step_log)
+        }
+        OperationSize::Word => {
+            do_register_rotate::<u16>(dest_register, direction, rotate_count, reg, step_log)
+        }
+        OperationSize::Long => {
+            do_register_rotate::<u32>(dest_register, direction, rotate_count, reg, step_log)
+        }
+    };
+
+    reg.reg_sr
+        .merge_status_register(step_log, status_register_result);
+
+    let cycles = timing::get_shift_register_cycles(operation_size, rotate_count);
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let direction = match instr_word & 0x0100 {
+        0x0100 => RotateDirection::Left,
+        _ => RotateDirection::Right,
+    };
+    let operation_size = Cpu::extract_size000110_from_bit_pos_6(instr_word).unwrap();
+
+    let dest_register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
+    match instr_word & 0x0020 {
+        0x0020 => {
+            let source_register = Cpu::extract_register_index_from_bit_pos(instr_word, 9)?;
+            Ok(GetDisassemblyResult::from_pc(
+                pc,
+                mem,
+                format!(
+                    "ROX{}.{}",
+                    direction.get_format(),
+                    operation_size.get_format()
+                ),
+                format!("D{},D{}", source_register, dest_register),
+            ))
+        }
+        _ => {
+            let count = (instr_word & 0x0e00) >> 9;
+            let count = match count {
+                1..=7 => count,
+                _ => 8,
+            };
+            Ok(GetDisassemblyResult::from_pc(
+                pc,
+                mem,
+                format!(
+                    "ROX{}.{}",
+                    direction.get_format(),
+                    operation_size.get_format()
+                ),
+                format!("#${:02X},D{}", count, dest_register),
+            ))
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let displacement = pc.fetch_next_word(mem);
+    reg.stack_pop_pc(mem, pc, step_log)?;
+    let sp = reg.get_a_reg_long(7, step_log);
+    reg.set_a_reg_long(
+        step_log,
+        7,
+        sp.wrapping_add(Cpu::sign_extend_word(displacement)),
+    );
+    Ok(StepResult { cycles: 16 })
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let displacement = pc.fetch_next_word(mem);
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("RTD"),
+        format!("#${:04X}", displacement),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::{instruction::GetDisassemblyResult, CpuModel};
+
+    #[test]
+    fn rtd_deallocates_parameters() {
+        // arrange
+        let code = [0x4e, 0x74, 0x00, 0x08].to_vec(); // RTD #$0008
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x010003F0);
+        mm.mem.set_long_no_log(0x010003F0, 0x00C01248);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("RTD"),
+                String::from("#$0008"),
+                vec![0x4e74, 0x0008]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FC, mm.cpu.register.get_a_reg_long_no_log(7));
+    }
+}
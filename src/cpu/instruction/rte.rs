@@ -1,6 +1,6 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
-    cpu::step_log::StepLog,
+    cpu::{step_log::StepLog, Cpu, CpuModel},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -20,16 +20,36 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     match reg.reg_sr.is_sr_supervisor_set(step_log) {
-        true => {
-            let sr = reg.stack_pop_word(mem, step_log);
+        true => match reg.cpu_model {
+            CpuModel::M68000 => {
+                let sr = reg.stack_pop_word(mem, step_log)?;
 
-            reg.stack_pop_pc(mem, pc, step_log);
-            reg.reg_sr.set_value(sr);
+                reg.stack_pop_pc(mem, pc, step_log)?;
+                reg.reg_sr.set_value(sr);
 
-            Ok(())
-        }
+                Ok(StepResult { cycles: 20 })
+            }
+            _ => {
+                // The format/vector word is checked before anything is popped, so a format
+                // error leaves the frame on the stack
+                let sp = reg.get_a_reg_long(7, step_log);
+                let format_vector_word = mem.get_word(step_log, sp.wrapping_add(6))?;
+                let frame_size =
+                    match Cpu::get_exception_frame_size(reg.cpu_model, format_vector_word >> 12) {
+                        Some(frame_size) => frame_size,
+                        None => return Err(StepError::FormatError),
+                    };
+                let sr = mem.get_word(step_log, sp)?;
+                let pc_address = mem.get_long(step_log, sp.wrapping_add(2))?;
+                reg.set_a_reg_long(step_log, 7, sp.wrapping_add(frame_size));
+                pc.jump_long(pc_address);
+                reg.reg_sr.set_value(sr);
+
+                Ok(StepResult { cycles: 24 })
+            }
+        },
         false => Err(StepError::PriviliegeViolation),
     }
 }
@@ -52,11 +72,11 @@ pub fn get_disassembly<'a>(
 #[cfg(test)]
 mod tests {
     use crate::{
-        cpu::instruction::GetDisassemblyResult,
+        cpu::{instruction::GetDisassemblyResult, CpuModel},
         register::{
             STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_EXTEND, STATUS_REGISTER_MASK_NEGATIVE,
             STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_SUPERVISOR_STATE,
-            STATUS_REGISTER_MASK_ZERO,
+            STATUS_REGISTER_MASK_TRACE_1, STATUS_REGISTER_MASK_ZERO,
         },
     };
 
@@ -149,4 +169,85 @@ mod tests {
         assert_eq!(true, mm.cpu.register.reg_sr.is_sr_extend_set());
         assert_eq!(true, mm.cpu.register.reg_sr.is_sr_supervisor_set_no_log());
     }
+
+    #[test]
+    fn rte_restoring_trace_is_not_traced() {
+        // arrange
+        let code = [0x4e, 0x73].to_vec(); // RTE
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(STATUS_REGISTER_MASK_SUPERVISOR_STATE);
+        mm.mem.set_word_no_log(0x010003FA, STATUS_REGISTER_MASK_TRACE_1);
+        mm.mem.set_long_no_log(0x010003FC, 0x00C01248);
+        mm.mem.set_long_no_log(0x00000024, 0x11223344);
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x010003FA);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x01000400, mm.cpu.register.get_ssp_reg());
+        assert_eq!(STATUS_REGISTER_MASK_TRACE_1, mm.cpu.register.reg_sr.get_value());
+    }
+
+    #[test]
+    fn rte_traced() {
+        // arrange
+        let code = [0x4e, 0x73].to_vec(); // RTE
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(
+            STATUS_REGISTER_MASK_SUPERVISOR_STATE | STATUS_REGISTER_MASK_TRACE_1,
+        );
+        mm.mem.set_word_no_log(0x010003FA, STATUS_REGISTER_MASK_CARRY);
+        mm.mem.set_long_no_log(0x010003FC, 0x00C01248);
+        mm.mem.set_long_no_log(0x00000024, 0x11223344);
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x010003FA);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x11223344, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(
+            STATUS_REGISTER_MASK_SUPERVISOR_STATE | STATUS_REGISTER_MASK_CARRY,
+            mm.cpu.register.reg_sr.get_value()
+        );
+        assert_eq!(STATUS_REGISTER_MASK_CARRY, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C01248, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn rte_68010_discards_format_vector_word() {
+        // arrange
+        let code = [0x4e, 0x73].to_vec(); // RTE
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.mem.set_word_no_log(0x010003F8, STATUS_REGISTER_MASK_CARRY);
+        mm.mem.set_long_no_log(0x010003FA, 0x00C01248);
+        mm.mem.set_word_no_log(0x010003FE, 0x0080); // Format $0, TRAP #0
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x010003F8);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x01000400, mm.cpu.register.get_ssp_reg());
+        assert_eq!(STATUS_REGISTER_MASK_CARRY, mm.cpu.register.reg_sr.get_value());
+    }
+
+    #[test]
+    fn rte_68010_format_error() {
+        // arrange
+        let code = [0x4e, 0x73].to_vec(); // RTE
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68010, code, None);
+        mm.mem.set_word_no_log(0x010003F8, STATUS_REGISTER_MASK_CARRY);
+        mm.mem.set_long_no_log(0x010003FA, 0x00C01248);
+        mm.mem.set_word_no_log(0x010003FE, 0xa008); // Format $A only exists on the 68020
+        mm.mem.set_long_no_log(0x00000038, 0x11223344);
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x010003F8);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x11223344, mm.cpu.register.reg_pc.get_address());
+        // The format error frame is pushed below the untouched frame
+        assert_eq!(0x010003F0, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x0038, mm.mem.get_word_no_log(0x010003F6));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003F2));
+        assert_eq!(0xa008, mm.mem.get_word_no_log(0x010003FE));
+    }
 }
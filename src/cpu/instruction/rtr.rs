@@ -0,0 +1,135 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::{
+    cpu::{step_log::StepLog, StatusRegisterResult},
+    mem::Mem,
+    register::{ProgramCounter, Register, STATUS_REGISTER_MASK_CONDITION_CODES},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    // The whole status register word is popped, but only the condition codes are restored
+    let ccr = reg.stack_pop_word(mem, step_log)?;
+
+    reg.stack_pop_pc(mem, pc, step_log)?;
+    reg.reg_sr.merge_status_register(
+        step_log,
+        StatusRegisterResult {
+            status_register: ccr,
+            status_register_mask: STATUS_REGISTER_MASK_CONDITION_CODES,
+        },
+    );
+
+    Ok(StepResult { cycles: 20 })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("RTR"),
+        String::from(""),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cpu::instruction::GetDisassemblyResult,
+        mem::rammemory::RamMemory,
+        register::{
+            STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_EXTEND, STATUS_REGISTER_MASK_NEGATIVE,
+            STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_SUPERVISOR_STATE,
+            STATUS_REGISTER_MASK_ZERO,
+        },
+    };
+
+    #[test]
+    fn rtr_restore_condition_codes() {
+        // arrange
+        let code = [0x4e, 0x77].to_vec(); // RTR
+        let mem_range = RamMemory::from_bytes(
+            0x00F80000,
+            [0x07, 0x15, 0x00, 0xc0, 0x12, 0x48].to_vec(),
+        );
+        let mut mem_ranges = Vec::new();
+        mem_ranges.push(mem_range);
+
+        let mut mm = crate::tests::instr_test_setup(code, Some(mem_ranges));
+        mm.cpu.register.reg_sr.set_value(
+            STATUS_REGISTER_MASK_SUPERVISOR_STATE
+                | STATUS_REGISTER_MASK_NEGATIVE
+                | STATUS_REGISTER_MASK_OVERFLOW,
+        );
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x00F80000);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("RTR"),
+                String::from(""),
+                vec![0x4e77]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xC01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x00F80006, mm.cpu.register.get_a_reg_long_no_log(7));
+        assert_eq!(
+            STATUS_REGISTER_MASK_SUPERVISOR_STATE
+                | STATUS_REGISTER_MASK_EXTEND
+                | STATUS_REGISTER_MASK_ZERO
+                | STATUS_REGISTER_MASK_CARRY,
+            mm.cpu.register.reg_sr.get_value()
+        );
+    }
+
+    #[test]
+    fn rtr_user_mode_pops_from_user_stack() {
+        // arrange
+        let code = [0x4e, 0x77].to_vec(); // RTR
+        let mem_range = RamMemory::from_bytes(
+            0x00F80000,
+            [0xff, 0xea, 0x00, 0xc0, 0x12, 0x48].to_vec(),
+        );
+        let mut mem_ranges = Vec::new();
+        mem_ranges.push(mem_range);
+
+        let mut mm = crate::tests::instr_test_setup(code, Some(mem_ranges));
+        mm.cpu.register.reg_sr.set_value(STATUS_REGISTER_MASK_ZERO);
+        mm.cpu.register.set_a_reg_long_no_log(7, 0x00F80000);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xC01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x00F80006, mm.cpu.register.get_a_reg_long_no_log(7));
+        assert_eq!(0x01000400, mm.cpu.register.get_ssp_reg());
+        assert_eq!(
+            STATUS_REGISTER_MASK_NEGATIVE | STATUS_REGISTER_MASK_OVERFLOW,
+            mm.cpu.register.reg_sr.get_value()
+        );
+    }
+}
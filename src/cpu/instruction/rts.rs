@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::step_log::StepLog,
     mem::Mem,
@@ -20,9 +20,9 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
-    reg.stack_pop_pc(mem, pc, step_log);
-    Ok(())
+) -> Result<StepResult, StepError> {
+    reg.stack_pop_pc(mem, pc, step_log)?;
+    Ok(StepResult { cycles: 16 })
 }
 
 pub fn get_disassembly<'a>(
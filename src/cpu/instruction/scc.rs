@@ -1,5 +1,6 @@
 use super::{
-    GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    EffectiveAddressingMode, GetDisassemblyResult, GetDisassemblyResultError, Instruction,
+    OperationSize, StepError, StepResult,
 };
 use crate::cpu::step_log::StepLog;
 use crate::cpu::Cpu;
@@ -28,7 +29,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let conditional_test = Cpu::extract_conditional_test_pos_8(instr_word);
 
     let ea_data = pc.get_effective_addressing_data_from_bit_pos(
@@ -41,15 +42,21 @@ pub fn step<'a>(
         0,
     )?;
 
-    match reg.reg_sr.evaluate_condition(&conditional_test) {
+    let condition = reg.reg_sr.evaluate_condition(&conditional_test);
+    match condition {
         true => {
-            ea_data.set_value_byte(pc, reg, mem, step_log, 0xff, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, 0xff, true)?;
         }
         false => {
-            ea_data.set_value_byte(pc, reg, mem, step_log, 0x00, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, 0x00, true)?;
         }
     };
-    Ok(())
+    let cycles = match (ea_data.ea_mode, condition) {
+        (EffectiveAddressingMode::DRegDirect { .. }, true) => 6,
+        (EffectiveAddressingMode::DRegDirect { .. }, false) => 4,
+        (_, _) => 8 + ea_data.get_calculation_cycles(),
+    };
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
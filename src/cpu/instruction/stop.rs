@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::step_log::StepLog,
     mem::Mem,
@@ -10,8 +10,10 @@ use crate::{
 // step: DONE
 // step cc: DONE
 // get_disassembly: DONE
-// TODO: A Trace instruction occurs if instruction tracing is enabled. (T0 = 1, T1 = 0) when the
-//       STOP instruction begins executing.
+// A trace exception occurs (and the CPU is not stopped) if tracing is enabled when the STOP
+// instruction begins executing. This is handled in Cpu::execute_next_instruction_step_log.
+// The stopped CPU resumes when an interrupt above the interrupt priority mask loaded from the
+// immediate operand (or a level 7 interrupt) arrives.
 
 // 020+ step: TODO
 // 020+ get_disassembly: TODO
@@ -22,7 +24,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     match reg.reg_sr.is_sr_supervisor_set(step_log) {
         true => {
             let sr = pc.fetch_next_word(mem);
@@ -42,10 +44,12 @@ pub fn get_disassembly<'a>(
     mem: &Mem,
     step_log: &mut StepLog,
 ) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let sr = pc.fetch_next_word(mem);
+
     Ok(GetDisassemblyResult::from_pc(
         pc,
         mem,
         String::from("STOP"),
-        String::from(""),
+        format!("#${:04X}", sr),
     ))
 }
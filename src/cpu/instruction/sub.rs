@@ -1,6 +1,6 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
 use crate::{
-    cpu::{instruction::OperationSize, step_log::StepLog, Cpu, StatusRegisterResult},
+    cpu::{instruction::OperationSize, step_log::StepLog, timing, Cpu, StatusRegisterResult},
     mem::Mem,
     register::{ProgramCounter, Register},
 };
@@ -52,7 +52,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let opmode = Cpu::extract_op_mode_from_bit_pos_6(instr_word);
     let operation_size = match opmode {
         BYTE_WITH_DN_AS_DEST => OperationSize::Byte,
@@ -77,7 +77,7 @@ pub fn step<'a>(
 
     let status_register_result = match opmode {
         BYTE_WITH_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_byte(register, step_log);
             let result = Cpu::sub_bytes(ea_value, reg_value);
 
@@ -85,7 +85,7 @@ pub fn step<'a>(
             result.status_register_result
         }
         WORD_WITH_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_word(register, step_log);
             let result = Cpu::sub_words(ea_value, reg_value);
 
@@ -93,7 +93,7 @@ pub fn step<'a>(
             result.status_register_result
         }
         LONG_WITH_DN_AS_DEST => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_d_reg_long(register, step_log);
             let result = Cpu::sub_longs(ea_value, reg_value);
 
@@ -101,29 +101,29 @@ pub fn step<'a>(
             result.status_register_result
         }
         BYTE_WITH_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_byte(register, step_log);
             let result = Cpu::sub_bytes(reg_value, ea_value);
-            ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true);
+            ea_data.set_value_byte(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
         WORD_WITH_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_word(register, step_log);
             let result = Cpu::sub_words(reg_value, ea_value);
-            ea_data.set_value_word(pc, reg, mem, step_log, result.result, true);
+            ea_data.set_value_word(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
         LONG_WITH_EA_AS_DEST => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, false);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, false)?;
             let reg_value = reg.get_d_reg_long(register, step_log);
             let result = Cpu::sub_longs(reg_value, ea_value);
 
-            ea_data.set_value_long(pc, reg, mem, step_log, result.result, true);
+            ea_data.set_value_long(pc, reg, mem, step_log, result.result, true)?;
             result.status_register_result
         }
         WORD_WITH_AN_AS_DEST => {
-            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
             let ea_value = Cpu::sign_extend_word(ea_value);
             let reg_value = reg.get_a_reg_long(register, step_log);
             let result = Cpu::sub_longs(ea_value, reg_value);
@@ -132,7 +132,7 @@ pub fn step<'a>(
             StatusRegisterResult::cleared()
         }
         LONG_WITH_AN_AS_DEST => {
-            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let ea_value = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
             let reg_value = reg.get_a_reg_long(register, step_log);
             let result = Cpu::sub_longs(ea_value, reg_value);
 
@@ -142,10 +142,18 @@ pub fn step<'a>(
         _ => panic!("Unhandled ea_opmode"),
     };
 
+    let cycles = match opmode {
+        WORD_WITH_AN_AS_DEST | LONG_WITH_AN_AS_DEST => timing::get_ea_to_an_cycles(&ea_data),
+        BYTE_WITH_EA_AS_DEST | WORD_WITH_EA_AS_DEST | LONG_WITH_EA_AS_DEST => {
+            timing::get_dn_to_ea_cycles(&ea_data)
+        }
+        _ => timing::get_ea_to_dn_cycles(&ea_data),
+    };
+
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,5 +1,6 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
     cpu::{step_log::StepLog, Cpu},
@@ -31,7 +32,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let register_type = match instr_word & 0x0008 {
         0x0008 => RegisterType::Address,
         _ => RegisterType::Data,
@@ -82,12 +83,12 @@ pub fn step<'a>(
             OperationSize::Byte => {
                 reg.decrement_a_reg(source_register_index, step_log, operation_size);
                 let source_register_value = reg.get_a_reg_long(source_register_index, step_log);
-                let value_source = mem.get_byte(step_log, source_register_value);
+                let value_source = mem.get_byte(step_log, source_register_value)?;
 
                 reg.decrement_a_reg(destination_register_index, step_log, operation_size);
                 let destination_register_value =
                     reg.get_a_reg_long(destination_register_index, step_log);
-                let value_dest = mem.get_byte(step_log, destination_register_value);
+                let value_dest = mem.get_byte(step_log, destination_register_value)?;
 
                 let result = Cpu::sub_bytes_with_extend(
                     value_source,
@@ -95,18 +96,18 @@ pub fn step<'a>(
                     reg.reg_sr.is_sr_carry_set(),
                 );
 
-                mem.set_byte(step_log, destination_register_value, result.result);
+                mem.set_byte(step_log, destination_register_value, result.result)?;
                 result.status_register_result
             }
             OperationSize::Word => {
                 reg.decrement_a_reg(source_register_index, step_log, operation_size);
                 let source_register_value = reg.get_a_reg_long(source_register_index, step_log);
-                let value_source = mem.get_word(step_log, source_register_value);
+                let value_source = mem.get_word(step_log, source_register_value)?;
 
                 reg.decrement_a_reg(destination_register_index, step_log, operation_size);
                 let destination_register_value =
                     reg.get_a_reg_long(destination_register_index, step_log);
-                let value_dest = mem.get_word(step_log, destination_register_value);
+                let value_dest = mem.get_word(step_log, destination_register_value)?;
 
                 let result = Cpu::sub_words_with_extend(
                     value_source,
@@ -114,18 +115,18 @@ pub fn step<'a>(
                     reg.reg_sr.is_sr_carry_set(),
                 );
 
-                mem.set_word(step_log, destination_register_value, result.result);
+                mem.set_word(step_log, destination_register_value, result.result)?;
                 result.status_register_result
             }
             OperationSize::Long => {
                 reg.decrement_a_reg(source_register_index, step_log, operation_size);
                 let source_register_value = reg.get_a_reg_long(source_register_index, step_log);
-                let value_source = mem.get_long(step_log, source_register_value);
+                let value_source = mem.get_long(step_log, source_register_value)?;
 
                 reg.decrement_a_reg(destination_register_index, step_log, operation_size);
                 let destination_register_value =
                     reg.get_a_reg_long(destination_register_index, step_log);
-                let value_dest = mem.get_long(step_log, destination_register_value);
+                let value_dest = mem.get_long(step_log, destination_register_value)?;
 
                 let result = Cpu::sub_longs_with_extend(
                     value_source,
@@ -133,15 +134,22 @@ pub fn step<'a>(
                     reg.reg_sr.is_sr_carry_set(),
                 );
 
-                mem.set_long(step_log, destination_register_value, result.result);
+                mem.set_long(step_log, destination_register_value, result.result)?;
                 result.status_register_result
             }
         },
     };
 
+    let cycles = match (register_type, operation_size) {
+        (RegisterType::Data, OperationSize::Long) => 8,
+        (RegisterType::Data, _) => 4,
+        (RegisterType::Address, OperationSize::Long) => 30,
+        (RegisterType::Address, _) => 18,
+    };
+
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
-    Ok(())
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
     mem::Mem,
@@ -21,7 +21,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
 
     let long = reg.get_d_reg_long(register, step_log);
@@ -46,7 +46,7 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    Ok(StepResult { cycles: 4 })
 }
 
 pub fn get_disassembly<'a>(
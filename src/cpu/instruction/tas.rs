@@ -0,0 +1,184 @@
+use super::{
+    EffectiveAddressingMode, GetDisassemblyResult, GetDisassemblyResultError, Instruction,
+    OperationSize, StepError, StepResult,
+};
+use crate::{
+    cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
+    mem::Mem,
+    register::{
+        ProgramCounter, Register, STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_NEGATIVE,
+        STATUS_REGISTER_MASK_OVERFLOW, STATUS_REGISTER_MASK_ZERO,
+    },
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => crate::cpu::match_check_ea_only_data_alterable_addressing_modes_pos_0(instr_word),
+        false => false,
+    }
+}
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Byte),
+    )?;
+
+    let value = ea_data.get_value_byte(pc, reg, mem, step_log, false)?;
+
+    let write_inhibited = match ea_data.ea_mode {
+        EffectiveAddressingMode::DRegDirect { .. } => false,
+        _ => mem.is_read_modify_write_inhibited(ea_data.get_address(pc, reg, mem, step_log)),
+    };
+    match write_inhibited {
+        false => {
+            ea_data.set_value_byte(pc, reg, mem, step_log, value | 0x80, true)?;
+        }
+        true => match ea_data.ea_mode {
+            EffectiveAddressingMode::ARegIndirectWithPostIncrement { ea_register, .. } => {
+                reg.increment_a_reg(ea_register, step_log, OperationSize::Byte)
+            }
+            EffectiveAddressingMode::ARegIndirectWithPreDecrement { ea_register, .. } => {
+                reg.decrement_a_reg(ea_register, step_log, OperationSize::Byte)
+            }
+            _ => (),
+        },
+    }
+
+    // The condition codes are set from the operand before bit 7 is set
+    let mut status_register = 0x0000;
+    match value {
+        0 => status_register |= STATUS_REGISTER_MASK_ZERO,
+        0x80..=0xff => status_register |= STATUS_REGISTER_MASK_NEGATIVE,
+        _ => (),
+    };
+    reg.reg_sr.merge_status_register(
+        step_log,
+        StatusRegisterResult {
+            status_register,
+            status_register_mask: STATUS_REGISTER_MASK_CARRY
+                | STATUS_REGISTER_MASK_OVERFLOW
+                | STATUS_REGISTER_MASK_ZERO
+                | STATUS_REGISTER_MASK_NEGATIVE,
+        },
+    );
+
+    let cycles = match ea_data.ea_mode {
+        EffectiveAddressingMode::DRegDirect { .. } => 4,
+        _ => 14 + ea_data.get_calculation_cycles(),
+    };
+    Ok(StepResult { cycles })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
+        instr_word,
+        reg,
+        mem,
+        step_log,
+        |instr_word| Ok(OperationSize::Byte),
+    )?;
+
+    let ea_format = Cpu::get_ea_format(ea_data.ea_mode, pc, Some(OperationSize::Byte), mem);
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("TAS.B"),
+        ea_format.format,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{cpu::instruction::GetDisassemblyResult, mem::rammemory::RamMemory};
+
+    #[test]
+    fn tas_address_register_indirect_sets_bit_7() {
+        // arrange
+        let code = [0x4a, 0xd0].to_vec(); // TAS.B (A0)
+        let mem_range = RamMemory::from_bytes(0x00F80000, [0x40, 0x00].to_vec());
+        let mut mem_ranges = Vec::new();
+        mem_ranges.push(mem_range);
+
+        let mut mm = crate::tests::instr_test_setup(code, Some(mem_ranges));
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00F80000);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(0x001f);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0xc0, mm.mem.get_byte_no_log(0x00F80000));
+        assert_eq!(0x00F80000, mm.cpu.register.get_a_reg_long_no_log(0));
+        assert_eq!(0x0010, mm.cpu.register.reg_sr.get_value() & 0x001f);
+    }
+
+    #[test]
+    fn tas_read_modify_write_inhibited_does_not_write_back() {
+        // arrange
+        let code = [0x4a, 0xd8].to_vec(); // TAS.B (A0)+
+        let mem_range = RamMemory::from_bytes(0x00F80000, [0x01, 0x00].to_vec());
+        let mut mem_ranges = Vec::new();
+        mem_ranges.push(mem_range);
+
+        let mut mm = crate::tests::instr_test_setup(code, Some(mem_ranges));
+        mm.mem.add_read_modify_write_inhibit_range(0x00F80000, 0x00F80001);
+        mm.cpu.register.set_a_reg_long_no_log(0, 0x00F80000);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(0x0000);
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("TAS.B"),
+                String::from("(A0)+"),
+                vec![0x4ad8]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x01, mm.mem.get_byte_no_log(0x00F80000));
+        assert_eq!(0x00F80001, mm.cpu.register.get_a_reg_long_no_log(0));
+        assert_eq!(0x0000, mm.cpu.register.reg_sr.get_value() & 0x001f);
+    }
+
+    #[test]
+    fn tas_data_register_direct_not_affected_by_read_modify_write_inhibit() {
+        // arrange
+        let code = [0x4a, 0xc1].to_vec(); // TAS.B D1
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.add_read_modify_write_inhibit_range(0x00000000, 0xFFFFFFFF);
+        mm.cpu.register.set_d_reg_long_no_log(1, 0x12345600);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x12345680, mm.cpu.register.get_d_reg_long_no_log(1));
+        assert_eq!(true, mm.cpu.register.reg_sr.is_sr_zero_set());
+    }
+}
@@ -0,0 +1,105 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::{
+    cpu::step_log::StepLog,
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let trap_number = (instr_word & 0x000f) as u8;
+    Err(StepError::TRAP_Instruction { trap_number })
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let trap_number = instr_word & 0x000f;
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("TRAP"),
+        format!("#${:02X}", trap_number),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::instruction::GetDisassemblyResult;
+
+    #[test]
+    fn trap_0() {
+        // arrange
+        let code = [0x4e, 0x40].to_vec(); // TRAP #$00
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x0015);
+        mm.cpu.register.set_ssp_reg(0x01000400);
+        mm.mem.set_long_no_log(0x00000080, 0x00C01248); // TRAP #0 vector
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("TRAP"),
+                String::from("#$00"),
+                vec![0x4e40]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x2015, mm.cpu.register.reg_sr.get_value());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x0015, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn trap_15() {
+        // arrange
+        let code = [0x4e, 0x4f].to_vec(); // TRAP #$0F
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.mem.set_long_no_log(0x000000BC, 0x00C01248); // TRAP #15 vector
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("TRAP"),
+                String::from("#$0F"),
+                vec![0x4e4f]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x2000, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
+}
@@ -0,0 +1,148 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, Instruction, StepError, StepResult};
+use crate::{
+    cpu::{step_log::StepLog, Cpu},
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: DONE
+// 020+ get_disassembly: DONE
+
+// The optional operand isn't used by the CPU, it's only there for the trap handler to read
+
+enum TrapccOperand {
+    None,
+    Word,
+    Long,
+}
+
+fn get_trapcc_operand(instr_word: u16) -> Option<TrapccOperand> {
+    match instr_word & 0b111 {
+        0b100 => Some(TrapccOperand::None),
+        0b010 => Some(TrapccOperand::Word),
+        0b011 => Some(TrapccOperand::Long),
+        _ => None,
+    }
+}
+
+pub fn match_check(instruction: &Instruction, instr_word: u16) -> bool {
+    match crate::cpu::match_check(instruction, instr_word) {
+        true => get_trapcc_operand(instr_word).is_some(),
+        false => false,
+    }
+}
+
+pub fn step(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    let conditional_test = Cpu::extract_conditional_test_pos_8(instr_word);
+    let cycles = match get_trapcc_operand(instr_word).unwrap() {
+        TrapccOperand::None => 4,
+        TrapccOperand::Word => {
+            pc.fetch_next_word(mem);
+            6
+        }
+        TrapccOperand::Long => {
+            pc.fetch_next_long(mem);
+            8
+        }
+    };
+
+    match reg.reg_sr.evaluate_condition(&conditional_test) {
+        true => Err(StepError::FRAPcc_TRAPcc_TRAPV_Instruction),
+        false => Ok(StepResult { cycles }),
+    }
+}
+
+pub fn get_disassembly(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    let conditional_test = Cpu::extract_conditional_test_pos_8(instr_word);
+    let (instruction_name, operands) = match get_trapcc_operand(instr_word).unwrap() {
+        TrapccOperand::None => (format!("TRAP{}", conditional_test), String::from("")),
+        TrapccOperand::Word => (
+            format!("TRAP{}.W", conditional_test),
+            format!("#${:04X}", pc.fetch_next_word(mem)),
+        ),
+        TrapccOperand::Long => (
+            format!("TRAP{}.L", conditional_test),
+            format!("#${:08X}", pc.fetch_next_long(mem)),
+        ),
+    };
+
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        instruction_name,
+        operands,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cpu::{instruction::GetDisassemblyResult, CpuModel},
+        register::STATUS_REGISTER_MASK_ZERO,
+    };
+
+    #[test]
+    fn trapeq_w_taken_stacks_pc_after_operand() {
+        // arrange
+        let code = [0x57, 0xfa, 0x12, 0x34].to_vec(); // TRAPEQ.W #$1234
+        let mut mm = crate::tests::instr_test_setup_for_cpu_model(CpuModel::M68020, code, None);
+        mm.cpu.register.reg_sr.set_value(0x2000);
+        mm.cpu
+            .register
+            .reg_sr
+            .set_sr_reg_flags_abcde(STATUS_REGISTER_MASK_ZERO);
+        mm.mem.set_long_no_log(0x0000001C, 0x00C01248); // TRAPcc vector
+                                                        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00004,
+                String::from("TRAPEQ.W"),
+                String::from("#$1234"),
+                vec![0x57fa, 0x1234]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003F4, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x00C00004, mm.mem.get_long_no_log(0x010003F6));
+        // format $2, vector offset $01C
+        assert_eq!(0x201C, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+
+    #[test]
+    fn trapcc_is_illegal_on_68000() {
+        // arrange
+        let code = [0x50, 0xfc].to_vec(); // TRAPT
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.mem.set_long_no_log(0x00000010, 0x00C00100);
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00100, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x00C00000, mm.mem.get_long_no_log(0x010003FC));
+    }
+}
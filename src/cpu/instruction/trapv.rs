@@ -0,0 +1,93 @@
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
+use crate::{
+    cpu::step_log::StepLog,
+    mem::Mem,
+    register::{ProgramCounter, Register},
+};
+
+// Instruction State
+// =================
+// step: DONE
+// step cc: DONE (not affected)
+// get_disassembly: DONE
+
+// 020+ step: TODO
+// 020+ get_disassembly: TODO
+
+pub fn step<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &mut Register,
+    mem: &mut Mem,
+    step_log: &mut StepLog,
+) -> Result<StepResult, StepError> {
+    match reg.reg_sr.is_sr_overflow_set() {
+        true => Err(StepError::FRAPcc_TRAPcc_TRAPV_Instruction),
+        false => Ok(StepResult { cycles: 4 }),
+    }
+}
+
+pub fn get_disassembly<'a>(
+    instr_word: u16,
+    pc: &mut ProgramCounter,
+    reg: &Register,
+    mem: &Mem,
+    step_log: &mut StepLog,
+) -> Result<GetDisassemblyResult, GetDisassemblyResultError> {
+    Ok(GetDisassemblyResult::from_pc(
+        pc,
+        mem,
+        String::from("TRAPV"),
+        String::from(""),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        cpu::instruction::GetDisassemblyResult,
+        register::{STATUS_REGISTER_MASK_CARRY, STATUS_REGISTER_MASK_OVERFLOW},
+    };
+
+    #[test]
+    fn trapv_overflow_clear() {
+        // arrange
+        let code = [0x4e, 0x76].to_vec(); // TRAPV
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(STATUS_REGISTER_MASK_CARRY);
+        mm.mem.set_long_no_log(0x0000001C, 0x00C01248); // TRAPV vector
+        // act assert - debug
+        let debug_result = mm.get_next_disassembly_no_log();
+        assert_eq!(
+            GetDisassemblyResult::from_address_and_address_next(
+                0xC00000,
+                0xC00002,
+                String::from("TRAPV"),
+                String::from(""),
+                vec![0x4e76]
+            ),
+            debug_result
+        );
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C00002, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x01000400, mm.cpu.register.get_ssp_reg());
+    }
+
+    #[test]
+    fn trapv_overflow_set() {
+        // arrange
+        let code = [0x4e, 0x76].to_vec(); // TRAPV
+        let mut mm = crate::tests::instr_test_setup(code, None);
+        mm.cpu.register.reg_sr.set_sr_reg_flags_abcde(STATUS_REGISTER_MASK_OVERFLOW);
+        mm.mem.set_long_no_log(0x0000001C, 0x00C01248); // TRAPV vector
+        // act
+        mm.step();
+        // assert
+        assert_eq!(0x00C01248, mm.cpu.register.reg_pc.get_address());
+        assert_eq!(0x010003FA, mm.cpu.register.get_ssp_reg());
+        assert_eq!(0x2002, mm.mem.get_word_no_log(0x010003FA));
+        assert_eq!(0x00C00002, mm.mem.get_long_no_log(0x010003FC));
+    }
+}
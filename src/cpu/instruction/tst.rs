@@ -1,5 +1,6 @@
 use super::{
     GetDisassemblyResult, GetDisassemblyResultError, Instruction, OperationSize, StepError,
+    StepResult,
 };
 use crate::{
     cpu::{step_log::StepLog, Cpu, StatusRegisterResult},
@@ -35,7 +36,7 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let ea_data = pc.get_effective_addressing_data_from_bit_pos_3_and_reg_pos_0(
         instr_word,
         reg,
@@ -46,7 +47,7 @@ pub fn step<'a>(
 
     let status_register = match ea_data.operation_size {
         OperationSize::Byte => {
-            let source = ea_data.get_value_byte(pc, reg, mem, step_log, true);
+            let source = ea_data.get_value_byte(pc, reg, mem, step_log, true)?;
 
             let mut status_register = 0x0000;
             match source {
@@ -57,7 +58,7 @@ pub fn step<'a>(
             status_register
         }
         OperationSize::Word => {
-            let source = ea_data.get_value_word(pc, reg, mem, step_log, true);
+            let source = ea_data.get_value_word(pc, reg, mem, step_log, true)?;
 
             let mut status_register = 0x0000;
             match source {
@@ -68,7 +69,7 @@ pub fn step<'a>(
             status_register
         }
         OperationSize::Long => {
-            let source = ea_data.get_value_long(pc, reg, mem, step_log, true);
+            let source = ea_data.get_value_long(pc, reg, mem, step_log, true)?;
 
             let status_register = match source {
                 0 => STATUS_REGISTER_MASK_ZERO,
@@ -90,7 +91,8 @@ pub fn step<'a>(
     reg.reg_sr
         .merge_status_register(step_log, status_register_result);
 
-    Ok(())
+    let cycles = 4 + ea_data.get_calculation_cycles();
+    Ok(StepResult { cycles })
 }
 
 pub fn get_disassembly<'a>(
@@ -1,4 +1,4 @@
-use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError};
+use super::{GetDisassemblyResult, GetDisassemblyResultError, StepError, StepResult};
 use crate::{
     cpu::{step_log::StepLog, Cpu},
     mem::Mem,
@@ -20,14 +20,14 @@ pub fn step<'a>(
     reg: &mut Register,
     mem: &mut Mem,
     step_log: &mut StepLog,
-) -> Result<(), StepError> {
+) -> Result<StepResult, StepError> {
     let register = Cpu::extract_register_index_from_bit_pos_0(instr_word)?;
 
     let restored_sp = reg.get_a_reg_long(register, step_log);
 
     reg.set_a_reg_long(step_log, 7, restored_sp);
 
-    let restored_a_reg = reg.stack_pop_long(mem, step_log);
+    let restored_a_reg = reg.stack_pop_long(mem, step_log)?;
     reg.set_a_reg_long(step_log, register, restored_a_reg);
 
     // println!(
@@ -37,7 +37,7 @@ pub fn step<'a>(
     //     reg.get_a_reg_long(7)
     // );
 
-    Ok(())
+    Ok(StepResult { cycles: 12 })
 }
 
 pub fn get_disassembly<'a>(
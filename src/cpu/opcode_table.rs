@@ -0,0 +1,143 @@
+use crate::cpu::instruction::Instruction;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OpcodeTableEntry {
+    Instruction { index: usize },
+    IllegalInstruction,
+    Line1010Emulator,
+    Line1111Emulator,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AmbiguousOpcode {
+    pub opcode: u16,
+    pub first_name: String,
+    pub second_name: String,
+}
+
+impl fmt::Display for AmbiguousOpcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "${:04X} matches both {} and {}",
+            self.opcode, self.first_name, self.second_name
+        )
+    }
+}
+
+// Maps every opcode word to the instruction that decodes it, so the instruction list only
+// has to be searched once, when the CPU is created
+pub struct OpcodeTable {
+    entries: Vec<OpcodeTableEntry>,
+}
+
+impl OpcodeTable {
+    pub fn new(instructions: &[Instruction]) -> Result<OpcodeTable, Vec<AmbiguousOpcode>> {
+        let mut entries: Vec<OpcodeTableEntry> = (0..=0xffff)
+            .map(|instr_word| OpcodeTable::get_unassigned_entry(instr_word as u16))
+            .collect();
+        let mut ambiguous_opcodes = Vec::new();
+        for (index, instruction) in instructions.iter().enumerate() {
+            // Only visit the opcodes that have the fixed bits of the instruction, by counting
+            // through the combinations of the remaining bits
+            let free_bits = !instruction.mask;
+            let mut bits: u16 = 0;
+            loop {
+                let instr_word = (instruction.opcode & instruction.mask) | bits;
+                if (instruction.match_check)(instruction, instr_word) {
+                    match entries[instr_word as usize] {
+                        OpcodeTableEntry::Instruction { index: first_index } => {
+                            ambiguous_opcodes.push(AmbiguousOpcode {
+                                opcode: instr_word,
+                                first_name: instructions[first_index].name.clone(),
+                                second_name: instruction.name.clone(),
+                            });
+                        }
+                        _ => entries[instr_word as usize] = OpcodeTableEntry::Instruction { index },
+                    }
+                }
+                if bits == free_bits {
+                    break;
+                }
+                bits = bits.wrapping_sub(free_bits) & free_bits;
+            }
+        }
+        match ambiguous_opcodes.is_empty() {
+            true => Ok(OpcodeTable { entries }),
+            false => Err(ambiguous_opcodes),
+        }
+    }
+
+    fn get_unassigned_entry(instr_word: u16) -> OpcodeTableEntry {
+        match instr_word & 0xf000 {
+            0xa000 => OpcodeTableEntry::Line1010Emulator,
+            0xf000 => OpcodeTableEntry::Line1111Emulator,
+            _ => OpcodeTableEntry::IllegalInstruction,
+        }
+    }
+
+    pub fn get_entry(&self, instr_word: u16) -> OpcodeTableEntry {
+        self.entries[instr_word as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::instruction::nop;
+
+    fn test_instruction(name: &str, mask: u16, opcode: u16) -> Instruction {
+        Instruction::new(
+            String::from(name),
+            mask,
+            opcode,
+            crate::cpu::match_check,
+            nop::step,
+            nop::get_disassembly,
+        )
+    }
+
+    #[test]
+    fn unassigned_opcodes_map_to_exception_markers() {
+        // arrange
+        let instructions = vec![test_instruction("NOP", 0xffff, 0x4e71)];
+        // act
+        let opcode_table = OpcodeTable::new(&instructions).unwrap();
+        // assert
+        assert_eq!(
+            OpcodeTableEntry::Instruction { index: 0 },
+            opcode_table.get_entry(0x4e71)
+        );
+        assert_eq!(
+            OpcodeTableEntry::IllegalInstruction,
+            opcode_table.get_entry(0x4e72)
+        );
+        assert_eq!(
+            OpcodeTableEntry::Line1010Emulator,
+            opcode_table.get_entry(0xa123)
+        );
+        assert_eq!(
+            OpcodeTableEntry::Line1111Emulator,
+            opcode_table.get_entry(0xffff)
+        );
+    }
+
+    #[test]
+    fn overlapping_instructions_are_reported() {
+        // arrange
+        let instructions = vec![
+            test_instruction("MOVEQ", 0xf100, 0x7000),
+            test_instruction("NOP", 0xffff, 0x4e71),
+            test_instruction("BROKEN", 0xff00, 0x7200),
+        ];
+        // act
+        let ambiguous_opcodes = OpcodeTable::new(&instructions).err().unwrap();
+        // assert
+        assert_eq!(0x100, ambiguous_opcodes.len());
+        assert_eq!(
+            "$7200 matches both MOVEQ and BROKEN",
+            ambiguous_opcodes[0].to_string()
+        );
+    }
+}
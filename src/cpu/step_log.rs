@@ -88,6 +88,17 @@ pub enum StepLogEntry {
         address: u32,
         value: u8,
     },
+    Exception {
+        vector: u32,
+        stacked_pc: u32,
+        stacked_sr: u16,
+        handler_address: u32,
+    },
+    GroupZeroException {
+        access_address: u32,
+        instruction_register: u16,
+        status_word: u16,
+    },
 }
 
 impl Display for StepLogEntry {
@@ -148,6 +159,29 @@ impl Display for StepLogEntry {
             StepLogEntry::WriteMemByte { address, value } => {
                 write!(f, "write_mem.b (${:08X})=${:02X}", address, value)
             }
+            StepLogEntry::Exception {
+                vector,
+                stacked_pc,
+                stacked_sr,
+                handler_address,
+            } => write!(
+                f,
+                "exception vector={} [${:03X}] stacked_pc=${:08X} stacked_sr=${:04X} handler=${:08X}",
+                vector,
+                vector * 4,
+                stacked_pc,
+                stacked_sr,
+                handler_address
+            ),
+            StepLogEntry::GroupZeroException {
+                access_address,
+                instruction_register,
+                status_word,
+            } => write!(
+                f,
+                "group_0_exception access_address=${:08X} ir=${:04X} status=${:04X}",
+                access_address, instruction_register, status_word
+            ),
         }
     }
 }
@@ -202,7 +236,13 @@ impl StepLog {
     }
 
     pub fn log_disassembly(&mut self, cpu: &mut Cpu, mem: &mut Mem) {
-        if cpu.stopped == false && self.disassembly_log_mode.log_disassembly() {
+        // No disassembly from an odd address, the CPU will take an address error instead
+        let odd_address = cpu.register.reg_pc.get_address() & 0x00000001 != 0;
+        if cpu.stopped == false
+            && cpu.halted == false
+            && odd_address == false
+            && self.disassembly_log_mode.log_disassembly()
+        {
             let address = cpu.register.reg_pc.get_address();
             self.cpu_stopped = cpu.stopped;
             self.address = address;
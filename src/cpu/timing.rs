@@ -0,0 +1,321 @@
+use super::{
+    ea::EffectiveAddressingData,
+    instruction::{EffectiveAddressingMode, OperationSize},
+};
+
+// 68000 instruction execution times in clock cycles, from the tables in section 8 of the
+// M68000 8-/16-/32-Bit Microprocessors User's Manual. The times include fetching the
+// instruction and its extension words, and the effective address calculation time.
+
+// Exception processing
+pub const EXCEPTION_CYCLES_ADDRESS_ERROR: u32 = 50;
+pub const EXCEPTION_CYCLES_BUS_ERROR: u32 = 50;
+pub const EXCEPTION_CYCLES_CHK_INSTRUCTION: u32 = 40;
+pub const EXCEPTION_CYCLES_FORMAT_ERROR: u32 = 34;
+pub const EXCEPTION_CYCLES_FPU_PRE_INSTRUCTION: u32 = 34;
+pub const EXCEPTION_CYCLES_ILLEGAL_INSTRUCTION: u32 = 34;
+pub const EXCEPTION_CYCLES_INTERRUPT: u32 = 44;
+pub const EXCEPTION_CYCLES_MMU_CONFIGURATION_ERROR: u32 = 34;
+pub const EXCEPTION_CYCLES_PRIVILEGE_VIOLATION: u32 = 34;
+pub const EXCEPTION_CYCLES_TRACE: u32 = 34;
+pub const EXCEPTION_CYCLES_TRAP_INSTRUCTION: u32 = 34;
+pub const EXCEPTION_CYCLES_TRAPV_INSTRUCTION: u32 = 34;
+pub const EXCEPTION_CYCLES_ZERO_DIVIDE: u32 = 38;
+
+// A stopped or halted CPU doesn't execute anything, but time still passes
+pub const IDLE_CYCLES: u32 = 4;
+
+// ADD, AND, CMP, OR and SUB with a data register as destination
+pub fn get_ea_to_dn_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    match ea_data.operation_size {
+        OperationSize::Long => match ea_data.ea_mode {
+            EffectiveAddressingMode::DRegDirect { .. }
+            | EffectiveAddressingMode::ARegDirect { .. }
+            | EffectiveAddressingMode::ImmediateDataLong { .. } => {
+                8 + ea_data.get_calculation_cycles()
+            }
+            _ => 6 + ea_data.get_calculation_cycles(),
+        },
+        _ => 4 + ea_data.get_calculation_cycles(),
+    }
+}
+
+// ADD, AND, EOR, OR and SUB with a data register as source and <ea> as destination
+pub fn get_dn_to_ea_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    match (ea_data.ea_mode, ea_data.operation_size) {
+        (EffectiveAddressingMode::DRegDirect { .. }, OperationSize::Long) => 8,
+        (EffectiveAddressingMode::DRegDirect { .. }, _) => 4,
+        (_, OperationSize::Long) => 12 + ea_data.get_calculation_cycles(),
+        (_, _) => 8 + ea_data.get_calculation_cycles(),
+    }
+}
+
+// ADDA and SUBA
+pub fn get_ea_to_an_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    match ea_data.operation_size {
+        OperationSize::Long => get_ea_to_dn_cycles(ea_data),
+        _ => 8 + ea_data.get_calculation_cycles(),
+    }
+}
+
+// ADDI, ANDI, EORI, ORI and SUBI. The immediate data fetch is included.
+pub fn get_immediate_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    match (ea_data.ea_mode, ea_data.operation_size) {
+        (EffectiveAddressingMode::DRegDirect { .. }, OperationSize::Long) => 16,
+        (EffectiveAddressingMode::DRegDirect { .. }, _) => 8,
+        (_, OperationSize::Long) => 20 + ea_data.get_calculation_cycles(),
+        (_, _) => 12 + ea_data.get_calculation_cycles(),
+    }
+}
+
+// CMPI doesn't write the result back
+pub fn get_compare_immediate_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    match (ea_data.ea_mode, ea_data.operation_size) {
+        (EffectiveAddressingMode::DRegDirect { .. }, OperationSize::Long) => 14,
+        (EffectiveAddressingMode::DRegDirect { .. }, _) => 8,
+        (_, OperationSize::Long) => 12 + ea_data.get_calculation_cycles(),
+        (_, _) => 8 + ea_data.get_calculation_cycles(),
+    }
+}
+
+// ADDQ and SUBQ
+pub fn get_quick_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    match (ea_data.ea_mode, ea_data.operation_size) {
+        (EffectiveAddressingMode::ARegDirect { .. }, _) => 8,
+        (EffectiveAddressingMode::DRegDirect { .. }, OperationSize::Long) => 8,
+        (EffectiveAddressingMode::DRegDirect { .. }, _) => 4,
+        (_, OperationSize::Long) => 12 + ea_data.get_calculation_cycles(),
+        (_, _) => 8 + ea_data.get_calculation_cycles(),
+    }
+}
+
+// CLR, NEG, NEGX and NOT
+pub fn get_single_operand_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    match (ea_data.ea_mode, ea_data.operation_size) {
+        (EffectiveAddressingMode::DRegDirect { .. }, OperationSize::Long) => 6,
+        (EffectiveAddressingMode::DRegDirect { .. }, _) => 4,
+        (_, OperationSize::Long) => 12 + ea_data.get_calculation_cycles(),
+        (_, _) => 8 + ea_data.get_calculation_cycles(),
+    }
+}
+
+// ASL, ASR, LSL, LSR, ROL, ROR, ROXL and ROXR on a data register
+pub fn get_shift_register_cycles(operation_size: OperationSize, shift_count: u32) -> u32 {
+    match operation_size {
+        OperationSize::Long => 8 + 2 * shift_count,
+        _ => 6 + 2 * shift_count,
+    }
+}
+
+// ASL, ASR, LSL, LSR, ROL, ROR, ROXL and ROXR on memory (always a single bit word shift)
+pub fn get_shift_memory_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    8 + ea_data.get_calculation_cycles()
+}
+
+// BCHG, BCLR and BSET. The bit number is taken into account for data registers, since the
+// 68000 needs two more cycles to modify the upper word.
+pub fn get_bit_modify_cycles(
+    ea_data: &EffectiveAddressingData,
+    bit_number_static: bool,
+    bit_number: u32,
+    clear: bool,
+) -> u32 {
+    let static_cycles = match bit_number_static {
+        true => 4,
+        false => 0,
+    };
+    match ea_data.ea_mode {
+        EffectiveAddressingMode::DRegDirect { .. } => {
+            let clear_cycles = match clear {
+                true => 2,
+                false => 0,
+            };
+            let upper_word_cycles = match bit_number {
+                0..=15 => 0,
+                _ => 2,
+            };
+            6 + static_cycles + clear_cycles + upper_word_cycles
+        }
+        _ => 8 + static_cycles + ea_data.get_calculation_cycles(),
+    }
+}
+
+// BTST
+pub fn get_bit_test_cycles(ea_data: &EffectiveAddressingData, bit_number_static: bool) -> u32 {
+    let static_cycles = match bit_number_static {
+        true => 4,
+        false => 0,
+    };
+    match ea_data.ea_mode {
+        EffectiveAddressingMode::DRegDirect { .. } => 6 + static_cycles,
+        _ => 4 + static_cycles + ea_data.get_calculation_cycles(),
+    }
+}
+
+// LEA and PEA only use control addressing modes, and don't fetch the operand. The time is the
+// address calculation time without the operand fetch.
+pub fn get_control_address_cycles(ea_mode: &EffectiveAddressingMode) -> u32 {
+    match ea_mode {
+        EffectiveAddressingMode::ARegIndirectWithDisplacement { .. } => 4,
+        EffectiveAddressingMode::ARegIndirectWithIndexOrMemoryIndirect { .. } => 8,
+        EffectiveAddressingMode::AbsoluteShortAddressing { .. } => 4,
+        EffectiveAddressingMode::AbsolutLongAddressing { .. } => 8,
+        EffectiveAddressingMode::PcIndirectWithDisplacement { .. } => 4,
+        EffectiveAddressingMode::PcIndirectWithIndexOrPcMemoryIndirect { .. } => 8,
+        _ => 0,
+    }
+}
+
+// JMP and JSR overlap the address calculation with the prefetch from the new address
+pub fn get_jump_address_cycles(ea_mode: &EffectiveAddressingMode) -> u32 {
+    match ea_mode {
+        EffectiveAddressingMode::ARegIndirectWithDisplacement { .. } => 2,
+        EffectiveAddressingMode::ARegIndirectWithIndexOrMemoryIndirect { .. } => 6,
+        EffectiveAddressingMode::AbsoluteShortAddressing { .. } => 2,
+        EffectiveAddressingMode::AbsolutLongAddressing { .. } => 4,
+        EffectiveAddressingMode::PcIndirectWithDisplacement { .. } => 2,
+        EffectiveAddressingMode::PcIndirectWithIndexOrPcMemoryIndirect { .. } => 6,
+        _ => 0,
+    }
+}
+
+// The destination of MOVE doesn't use the extra read cycles for -(An)
+pub fn get_move_destination_cycles(ea_data: &EffectiveAddressingData) -> u32 {
+    match ea_data.ea_mode {
+        EffectiveAddressingMode::ARegIndirectWithPreDecrement { .. } => {
+            match ea_data.operation_size {
+                OperationSize::Long => 8,
+                _ => 4,
+            }
+        }
+        _ => ea_data.get_calculation_cycles(),
+    }
+}
+
+// MOVEM takes the address calculation time of a word operand, without the extra read cycles
+// for -(An), plus the transfer time for each register
+pub fn get_movem_cycles(
+    ea_data: &EffectiveAddressingData,
+    memory_to_register: bool,
+    register_count: u32,
+) -> u32 {
+    let base_cycles = match memory_to_register {
+        true => 12,
+        false => 8,
+    };
+    let address_cycles = match ea_data.ea_mode {
+        EffectiveAddressingMode::ARegIndirectWithPostIncrement { .. }
+        | EffectiveAddressingMode::ARegIndirectWithPreDecrement { .. } => 0,
+        _ => ea_data.ea_mode.get_calculation_cycles(OperationSize::Word) - 4,
+    };
+    let register_cycles = match ea_data.operation_size {
+        OperationSize::Long => 8,
+        _ => 4,
+    };
+    base_cycles + address_cycles + register_count * register_cycles
+}
+
+// MULU takes 38 + 2n cycles, where n is the number of ones in the source
+pub fn get_mulu_cycles(source: u16) -> u32 {
+    38 + 2 * source.count_ones()
+}
+
+// MULS takes 38 + 2n cycles, where n is the number of 10 or 01 bit patterns in the source
+// concatenated with a zero as the least significant bit
+pub fn get_muls_cycles(source: u16) -> u32 {
+    let source = (source as u32) << 1;
+    38 + 2 * ((source ^ (source >> 1)) & 0xffff).count_ones()
+}
+
+// The DIVU time depends on the quotient bits, this follows the microcode of the 68000. The
+// divisor is non zero.
+pub fn get_divu_cycles(dividend: u32, divisor: u16) -> u32 {
+    let divisor = divisor as u32;
+    if (dividend >> 16) >= divisor {
+        // Overflow is detected early
+        return 10;
+    }
+    let mut cycles = 76;
+    let mut dividend = dividend;
+    let divisor = divisor << 16;
+    for _ in 0..15 {
+        let carry = dividend & 0x80000000 != 0;
+        dividend <<= 1;
+        if carry {
+            dividend = dividend.wrapping_sub(divisor);
+        } else {
+            cycles += 4;
+            if dividend >= divisor {
+                dividend -= divisor;
+                cycles -= 2;
+            }
+        }
+    }
+    cycles
+}
+
+// The DIVS time depends on the signs and the quotient bits, this follows the microcode of the
+// 68000. The divisor is non zero.
+pub fn get_divs_cycles(dividend: u32, divisor: u16) -> u32 {
+    let dividend = dividend as i32;
+    let divisor = divisor as i16;
+    let mut cycles = 12;
+    if dividend < 0 {
+        cycles += 2;
+    }
+    let absolute_dividend = dividend.unsigned_abs();
+    let absolute_divisor = divisor.unsigned_abs() as u32;
+    if (absolute_dividend >> 16) >= absolute_divisor {
+        // Overflow is detected early
+        return cycles + 4;
+    }
+    cycles += 110;
+    if divisor >= 0 {
+        match dividend >= 0 {
+            true => cycles -= 2,
+            false => cycles += 2,
+        }
+    }
+    let mut absolute_quotient = absolute_dividend / absolute_divisor;
+    for _ in 0..15 {
+        if absolute_quotient & 0x8000 == 0 {
+            cycles += 2;
+        }
+        absolute_quotient <<= 1;
+    }
+    cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mulu_cycles_depend_on_number_of_ones() {
+        assert_eq!(38, get_mulu_cycles(0x0000));
+        assert_eq!(70, get_mulu_cycles(0xffff));
+        assert_eq!(54, get_mulu_cycles(0x00ff));
+    }
+
+    #[test]
+    fn muls_cycles_depend_on_bit_patterns() {
+        assert_eq!(38, get_muls_cycles(0x0000));
+        assert_eq!(40, get_muls_cycles(0xffff));
+        assert_eq!(70, get_muls_cycles(0x5555));
+    }
+
+    #[test]
+    fn divu_cycles() {
+        assert_eq!(10, get_divu_cycles(0x00010000, 0x0001));
+        assert_eq!(136, get_divu_cycles(0x00000000, 0x0001));
+        assert_eq!(76 + 4 * 15 - 2 * 15, get_divu_cycles(0x0000ffff, 0x0001));
+    }
+
+    #[test]
+    fn divs_cycles() {
+        assert_eq!(16, get_divs_cycles(0x00010000, 0x0001));
+        assert_eq!(18, get_divs_cycles(0xffff0000, 0x0001));
+        assert_eq!(150, get_divs_cycles(0x00000000, 0x0001));
+        assert_eq!(154, get_divs_cycles(0xffffffff, 0xffff));
+    }
+}
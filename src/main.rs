@@ -4,17 +4,18 @@
 
 // TODO: [X] Clean up the step log code!
 // TODO: [ ] Generics everything, maybe typed byte/word/long?
-// TODO: [ ] Interrupts: VHPOS
-// TODO: [ ] Interrupts: CIA timers
+// TODO: [X] Interrupts: VHPOS
+// TODO: [X] Interrupts: CIA timers
 // TODO: [ ] Then Bugfix SUBX
 // TODO: [ ] Prefix _all_ tests with instruction name and size
-// TODO: [ ] Missing tests for ROLR-instructions
+// TODO: [X] Missing tests for ROLR-instructions
 // TODO: [ ] Missing tests for Scc-instruction
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
 
+use crate::mem::beamcounter::AgnusModel;
 use crate::mem::custommemory::CustomMemory;
 use {
     cpu::Cpu,
@@ -25,6 +26,7 @@ use crate::kickstart_debug_1_2::KickstartDebug_1_2;
 
 use crate::modermodem::Modermodem;
 
+mod clock;
 mod cpu;
 mod kickstart;
 mod kickstart_debug_1_2;
@@ -35,7 +37,8 @@ mod register;
 mod aint;
 
 use crate::cpu::step_log::{DisassemblyLogMode, StepLog};
-use crate::cpu::CpuSpeed;
+use crate::cpu::fpu::FpuModel;
+use crate::cpu::{CpuModel, CpuSpeed};
 use crate::kickstart::Kickstart;
 
 // static ROM_FILE_PATH: &str = "D:\\Amiga\\ROM\\Kickstart 3.1.rom";
@@ -44,6 +47,28 @@ use crate::kickstart::Kickstart;
 static ROM_FILE_PATH_1_2: &str = "D:\\Amiga\\ROM\\Kickstart 1.2.rom";
 // static ROM_FILE_PATH_2_0: &str = "D:\\Amiga\\ROM\\Kickstart 2.0.rom";
 
+// Kickstart 1.2 only runs on a 68000/68010. The A1200 Kickstart 3.1.4 ROM (with
+// KickstartDebug_3_1_4) needs the 68020, and the 68030 adds the MMU for A3000/A4000 software.
+static CPU_MODEL: CpuModel = CpuModel::M68000;
+
+// PAL or NTSC, for the CPU clock and the frame geometry of the beam counter
+static CPU_SPEED: CpuSpeed = CpuSpeed::PAL_7_093790_MHz;
+static AGNUS_MODEL: AgnusModel = AgnusModel::Ocs;
+
+// Each completed frame is written to this PNG file, e.g. to check the Kickstart insert disk
+// screen when running headless
+static FRAME_PNG_PATH: Option<&str> = None;
+
+// An optional 68881/68882 FPU, only attached to a 68020 or later
+static FPU_MODEL: Option<FpuModel> = None;
+
+// The 68000 prefetch queue, for demos and copy protections that run stale instruction words
+// after overwriting them
+static PREFETCH_QUEUE: bool = false;
+
+// Slows the emulation down to the speed of a real Amiga, otherwise it runs as fast as possible
+static REAL_TIME: bool = false;
+
 fn main() {
     println!("Begin emulation!");
 
@@ -60,9 +85,10 @@ fn main() {
 
     // CUSTOM memory
     // CIA memory
-    let custom_memory = Rc::new(RefCell::new(CustomMemory::new()));
+    let custom_memory = Rc::new(RefCell::new(CustomMemory::new(&CPU_SPEED, AGNUS_MODEL)));
     let cia_memory = Rc::new(RefCell::new(CiaMemory::new()));
     let mut mem = Mem::new(Some(custom_memory.clone()), Some(cia_memory.clone()));
+    mem.set_prefetch_enabled(PREFETCH_QUEUE);
 
     let kickstart = Rc::new(RefCell::new(Kickstart::new(ROM_FILE_PATH_1_2, &mut mem)));
     let kickstart_debug = KickstartDebug_1_2::new();
@@ -86,6 +112,13 @@ fn main() {
     let chip_ram = RamMemory::from_range(0x00000000, 0x0007FFFF);
     mem.add_range(Rc::new(RefCell::new(chip_ram)));
 
+    // TAS doesn't write back to chip RAM or the custom registers on the A500
+    mem.add_read_modify_write_inhibit_range(0x00000000, 0x001FFFFF);
+    mem.add_read_modify_write_inhibit_range(0x00DFF000, 0x00DFFFFF);
+
+    // Chip RAM is also written by the DMA channels, so the 68020/68030 caches must not keep it
+    mem.add_cache_inhibit_range(0x00000000, 0x001FFFFF);
+
     // 0.5 MB of fast ram
     // let fast_ram = RamMemory::from_range(0x00200000, 0x0027FFFF);
     // mem.add_range(Rc::new(RefCell::new(fast_ram)));
@@ -93,7 +126,15 @@ fn main() {
     let ssp_address = mem.get_long_no_log(0x0);
     let pc_address = mem.get_long_no_log(0x4);
 
-    let cpu = Cpu::new(CpuSpeed::PAL_7_093790_MHz, ssp_address, pc_address);
+    let mut cpu = Cpu::new(
+        CPU_MODEL,
+        CPU_SPEED,
+        ssp_address,
+        pc_address,
+    );
+    if let Some(fpu_model) = FPU_MODEL {
+        cpu = cpu.with_fpu(fpu_model);
+    }
     println!("Beginning of ROM");
     mem.print_hex_dump(0xf80000, 0xf801ff);
 
@@ -114,19 +155,24 @@ fn main() {
     //     cpu.print_disassembly(&disassembly_result);
     // }
 
-    // NTSC has 262/263 scan lines
-    // PAL has 312/313 scan lines
-
     let step_log = StepLog::new(DisassemblyLogMode::DisassemblyWithKickstartDebugAndDetails, Box::new(kickstart_debug));
     let mut modermodem = Modermodem::new(kickstart, step_log, cpu, mem, custom_memory, cia_memory);
+    modermodem.set_real_time(REAL_TIME);
 
     let disassembly = modermodem.get_disassembly_no_log(0x00FE930E, 0x00FE9336);
     for disassembly_row in disassembly {
         disassembly_row.print_disassembly(true);
     }
 
+    let mut frame_count = 0;
     loop {
         modermodem.step();
+        if let Some(frame_png_path) = FRAME_PNG_PATH {
+            if modermodem.get_frame_count() != frame_count {
+                frame_count = modermodem.get_frame_count();
+                modermodem.write_frame_png(frame_png_path).unwrap();
+            }
+        }
     }
 }
 
@@ -134,7 +180,8 @@ fn main() {
 
 #[cfg(test)]
 mod tests {
-    use crate::cpu::CpuSpeed;
+    use crate::cpu::fpu::FpuModel;
+    use crate::cpu::{CpuModel, CpuSpeed};
     use crate::mem::ciamemory::CiaMemory;
     use crate::mem::rammemory::RamMemory;
     use crate::modermodem::Modermodem;
@@ -143,6 +190,23 @@ mod tests {
     use std::rc::Rc;
 
     pub(crate) fn instr_test_setup(code: Vec<u8>, mem_ranges: Option<Vec<RamMemory>>) -> Modermodem {
+        instr_test_setup_for_cpu_model(CpuModel::M68000, code, mem_ranges)
+    }
+
+    pub(crate) fn instr_test_setup_with_fpu(
+        code: Vec<u8>,
+        mem_ranges: Option<Vec<RamMemory>>,
+    ) -> Modermodem {
+        let mut modermodem = instr_test_setup_for_cpu_model(CpuModel::M68020, code, mem_ranges);
+        modermodem.cpu = modermodem.cpu.with_fpu(FpuModel::M68881);
+        modermodem
+    }
+
+    pub(crate) fn instr_test_setup_for_cpu_model(
+        cpu_model: CpuModel,
+        code: Vec<u8>,
+        mem_ranges: Option<Vec<RamMemory>>,
+    ) -> Modermodem {
         let mut mem = mem::Mem::new(None, None);
         let code = RamMemory::from_bytes(0x00C00000, code);
         let stack = RamMemory::from_range(0x01000000, 0x010003ff);
@@ -157,7 +221,7 @@ mod tests {
                 mem.add_range(Rc::new(RefCell::new(mem_range)));
             }
         }
-        let cpu = cpu::Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x01000400, 0xC00000);
+        let cpu = cpu::Cpu::new(cpu_model, CpuSpeed::NTSC_7_159090_MHz, 0x01000400, 0xC00000);
         let modermodem = Modermodem::bare(cpu, mem);
         modermodem
     }
@@ -1,29 +1,93 @@
 use self::memory::Memory;
+use crate::mem::cache::{
+    Cache, CACR_CLEAR_DATA_CACHE, CACR_CLEAR_ENTRY_DATA_CACHE, CACR_CLEAR_ENTRY_INSTRUCTION_CACHE,
+    CACR_CLEAR_INSTRUCTION_CACHE, CACR_ENABLE_DATA_CACHE, CACR_ENABLE_INSTRUCTION_CACHE,
+    CACR_FREEZE_DATA_CACHE, CACR_FREEZE_INSTRUCTION_CACHE,
+};
 use crate::mem::ciamemory::CiaMemory;
 use crate::mem::custommemory::CustomMemory;
+use crate::mem::memorypage::{MemoryPage, MEMORY_PAGE_COUNT, MEMORY_PAGE_SIZE};
+use crate::mem::mmu::{
+    Mmu, TableSearch, Translation, FUNCTION_CODE_SUPERVISOR_DATA, FUNCTION_CODE_SUPERVISOR_PROGRAM,
+    FUNCTION_CODE_USER_DATA, FUNCTION_CODE_USER_PROGRAM,
+};
+use crate::mem::prefetch::PrefetchQueue;
 use crate::{
+    cpu::instruction::StepError,
     cpu::step_log::{StepLog, StepLogEntry},
-    mem::unmappedmemory::UnmappedMemory,
+    cpu::BusCycleFault,
+    mem::unmappedmemory::{UnmappedAccessPolicy, UnmappedMemory},
 };
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
+pub mod beamcounter;
+pub mod blitter;
+pub mod cache;
 pub mod ciamemory;
+pub mod copper;
 pub mod custommemory;
+pub mod denise;
 pub mod memory;
+pub mod memorypage;
+pub mod mmu;
+pub mod prefetch;
 pub mod rammemory;
 pub mod rommemory;
+pub mod sprite;
 pub mod unmappedmemory;
 
+struct UnmappedAccessPolicyRange {
+    start_address: u32,
+    end_address: u32,
+    policy: UnmappedAccessPolicy,
+}
+
+struct ReadModifyWriteInhibitRange {
+    start_address: u32,
+    end_address: u32,
+}
+
+struct CacheInhibitRange {
+    start_address: u32,
+    end_address: u32,
+}
+
+// A data access is either satisfied by the data cache, or goes to a physical address
+enum DataRead {
+    Cached(u32),
+    Physical(u32),
+}
+
 pub struct Mem {
     ranges: Vec<Rc<RefCell<dyn Memory>>>,
     default_range: Rc<RefCell<dyn Memory>>,
+    unmapped_access_policy: UnmappedAccessPolicy,
+    unmapped_access_policy_ranges: Vec<UnmappedAccessPolicyRange>,
+    read_modify_write_inhibit_ranges: Vec<ReadModifyWriteInhibitRange>,
+    data_bus: Cell<u16>,
     overlay_memory: Rc<RefCell<dyn Memory>>,
     custom_memory: Option<Rc<RefCell<CustomMemory>>>,
     cia_memory: Option<Rc<RefCell<CiaMemory>>>,
     overlay: bool,
+    // Rebuilt whenever the memory map changes. Each page of the address space refers to one
+    // of the distinct pages, to keep the table small.
+    page_map: Vec<u16>,
+    pages: Vec<MemoryPage>,
+    // The MMU and the caches sit between the CPU and the memory. The CPU keeps the supervisor
+    // state up to date, for the function codes of the bus cycles.
+    mmu: RefCell<Mmu>,
+    translation_enabled: Cell<bool>,
+    supervisor: Cell<bool>,
+    instruction_cache: RefCell<Cache>,
+    data_cache: RefCell<Cache>,
+    cache_inhibit_ranges: Vec<CacheInhibitRange>,
+    prefetch_queue: RefCell<PrefetchQueue>,
 }
 
+const UNMAPPED_PAGE: u16 = 0;
+const SEARCH_PAGE: u16 = 1;
+
 impl Mem {
     pub fn new(
         custom_memory: Option<Rc<RefCell<CustomMemory>>>,
@@ -35,29 +99,423 @@ impl Mem {
         Self {
             ranges,
             default_range,
+            unmapped_access_policy: UnmappedAccessPolicy::Zero,
+            unmapped_access_policy_ranges: Vec::new(),
+            read_modify_write_inhibit_ranges: Vec::new(),
+            data_bus: Cell::new(0x0000),
             overlay_memory,
             custom_memory,
             cia_memory,
             overlay: false,
+            page_map: vec![UNMAPPED_PAGE; MEMORY_PAGE_COUNT],
+            pages: vec![MemoryPage::Unmapped, MemoryPage::Search],
+            mmu: RefCell::new(Mmu::new()),
+            translation_enabled: Cell::new(false),
+            supervisor: Cell::new(true),
+            instruction_cache: RefCell::new(Cache::new()),
+            data_cache: RefCell::new(Cache::new()),
+            cache_inhibit_ranges: Vec::new(),
+            prefetch_queue: RefCell::new(PrefetchQueue::new()),
         }
     }
 
     pub fn add_range(&mut self, range: Rc<RefCell<dyn Memory>>) {
         self.ranges.push(range);
         self.validate_ranges();
+        self.build_pages();
     }
 
     pub fn set_overlay(&mut self, range: Rc<RefCell<dyn Memory>>) {
         self.overlay_memory = range;
         self.overlay = true;
         self.validate_ranges();
+        self.build_pages();
     }
 
     pub fn set_overlay_enable(&mut self, enable: bool) {
         self.overlay = enable;
+        self.build_pages();
         println!("   -Overlay enabled changed to {}", enable);
     }
 
+    fn build_pages(&mut self) {
+        // Only pages below a range or the overlay can have been mapped
+        let mut all_ranges = self.ranges.clone();
+        all_ranges.push(self.overlay_memory.clone());
+        for range in &all_ranges {
+            for page_index in Mem::get_page_indexes(range) {
+                self.page_map[page_index] = UNMAPPED_PAGE;
+            }
+        }
+        self.pages.truncate(SEARCH_PAGE as usize + 1);
+        for range in &self.ranges.clone() {
+            self.map_pages(range);
+        }
+        // The overlay hides the ranges below it
+        if self.overlay {
+            self.map_pages(&self.overlay_memory.clone());
+        }
+    }
+
+    fn get_page_indexes(range: &Rc<RefCell<dyn Memory>>) -> std::ops::RangeInclusive<usize> {
+        let memory = range.borrow();
+        MemoryPage::get_page_index(memory.get_start_address())
+            ..=MemoryPage::get_page_index(memory.get_end_address())
+    }
+
+    fn map_pages(&mut self, range: &Rc<RefCell<dyn Memory>>) {
+        let memory = range.borrow();
+        let start_address = memory.get_start_address();
+        let end_address = memory.get_end_address();
+        let mut range_page = None;
+        for page_index in Mem::get_page_indexes(range) {
+            let page_start_address = page_index as u32 * MEMORY_PAGE_SIZE;
+            let page_end_address = page_start_address + (MEMORY_PAGE_SIZE - 1);
+            self.page_map[page_index] =
+                if start_address <= page_start_address && end_address >= page_end_address {
+                    *range_page.get_or_insert_with(|| {
+                        self.pages.push(match memory.get_direct_memory_page() {
+                            Some(page) => page,
+                            None => MemoryPage::Memory(range.clone()),
+                        });
+                        (self.pages.len() - 1) as u16
+                    })
+                } else {
+                    SEARCH_PAGE
+                };
+        }
+    }
+
+    fn get_page(&self, address: u32) -> &MemoryPage {
+        &self.pages[self.page_map[MemoryPage::get_page_index(address)] as usize]
+    }
+
+    // Policy for all unmapped addresses not covered by add_unmapped_access_policy
+    pub fn set_unmapped_access_policy(&mut self, policy: UnmappedAccessPolicy) {
+        self.unmapped_access_policy = policy;
+    }
+
+    pub fn add_unmapped_access_policy(
+        &mut self,
+        start_address: u32,
+        end_address: u32,
+        policy: UnmappedAccessPolicy,
+    ) {
+        self.unmapped_access_policy_ranges.push(UnmappedAccessPolicyRange {
+            start_address,
+            end_address,
+            policy,
+        });
+    }
+
+    fn get_unmapped_access_policy(&self, address: u32) -> UnmappedAccessPolicy {
+        match self
+            .unmapped_access_policy_ranges
+            .iter()
+            .find(|x| address >= x.start_address && address <= x.end_address)
+        {
+            Some(policy_range) => policy_range.policy,
+            None => self.unmapped_access_policy,
+        }
+    }
+
+    // The A500 chip bus doesn't support the indivisible read-modify-write cycle that TAS uses.
+    // The read completes, but the write never reaches chip RAM or the custom registers.
+    pub fn add_read_modify_write_inhibit_range(&mut self, start_address: u32, end_address: u32) {
+        self.read_modify_write_inhibit_ranges
+            .push(ReadModifyWriteInhibitRange {
+                start_address,
+                end_address,
+            });
+    }
+
+    pub fn is_read_modify_write_inhibited(&self, address: u32) -> bool {
+        self.read_modify_write_inhibit_ranges
+            .iter()
+            .any(|x| address >= x.start_address && address <= x.end_address)
+    }
+
+    // Memory that other bus masters than the CPU write to, like chip RAM, or that has side
+    // effects, is never cached
+    pub fn add_cache_inhibit_range(&mut self, start_address: u32, end_address: u32) {
+        self.cache_inhibit_ranges.push(CacheInhibitRange {
+            start_address,
+            end_address,
+        });
+    }
+
+    fn is_cache_inhibited(&self, address: u32) -> bool {
+        self.cache_inhibit_ranges
+            .iter()
+            .any(|x| address >= x.start_address && address <= x.end_address)
+    }
+
+    pub fn set_supervisor(&self, supervisor: bool) {
+        self.supervisor.set(supervisor);
+    }
+
+    fn get_function_code(&self, program_space: bool) -> u8 {
+        match (self.supervisor.get(), program_space) {
+            (false, false) => FUNCTION_CODE_USER_DATA,
+            (false, true) => FUNCTION_CODE_USER_PROGRAM,
+            (true, false) => FUNCTION_CODE_SUPERVISOR_DATA,
+            (true, true) => FUNCTION_CODE_SUPERVISOR_PROGRAM,
+        }
+    }
+
+    // Access to the MMU registers and the ATC, for the PMMU instructions
+    pub fn with_mmu<T, F: FnOnce(&mut Mmu) -> T>(&self, f: F) -> T {
+        let mut mmu = self.mmu.borrow_mut();
+        let result = f(&mut mmu);
+        self.translation_enabled.set(mmu.is_enabled());
+        result
+    }
+
+    // The table searches read and write the descriptors at physical addresses
+    fn get_physical_long(&self, address: u32) -> u32 {
+        self.get_memory(address)
+            .borrow()
+            .get_long(&mut StepLog::none(), address)
+    }
+
+    fn set_physical_long(&self, address: u32, value: u32) {
+        self.get_memory(address)
+            .borrow_mut()
+            .set_long(&mut StepLog::none(), address, value);
+    }
+
+    // PTEST, the descriptors are left unchanged. The search is the same for reads and writes.
+    pub fn search_translation_tables(
+        &self,
+        address: u32,
+        function_code: u8,
+        max_levels: u16,
+    ) -> TableSearch {
+        self.mmu.borrow().search(
+            address,
+            function_code,
+            false,
+            max_levels,
+            |address| self.get_physical_long(address),
+            |_, _| (),
+        )
+    }
+
+    // PLOAD
+    pub fn load_translation(&self, address: u32, function_code: u8, write: bool) {
+        self.mmu.borrow_mut().load(
+            address,
+            function_code,
+            write,
+            |address| self.get_physical_long(address),
+            |address, value| self.set_physical_long(address, value),
+        );
+    }
+
+    fn translate(
+        &self,
+        address: u32,
+        read: bool,
+        program_space: bool,
+    ) -> Result<Translation, StepError> {
+        let function_code = self.get_function_code(program_space);
+        let translation = self.mmu.borrow_mut().translate(
+            address,
+            function_code,
+            !read,
+            |address| self.get_physical_long(address),
+            |address, value| self.set_physical_long(address, value),
+        );
+        translation.ok_or(StepError::AccessFault {
+            fault: BusCycleFault {
+                address,
+                read,
+                program_space,
+            },
+        })
+    }
+
+    // Translation for the accesses that can't fault, like the debugger's
+    fn get_translation_no_log(&self, address: u32, program_space: bool) -> Translation {
+        if !self.translation_enabled.get() {
+            return Translation {
+                physical_address: address,
+                cache_inhibit: false,
+            };
+        }
+        self.mmu.borrow().get_translation(
+            address,
+            self.get_function_code(program_space),
+            |address| self.get_physical_long(address),
+        )
+    }
+
+    // The instructions are fetched without faults, so a fault on the address of an instruction
+    // is reported before it starts
+    pub fn check_instruction_address(&self, address: u32) -> Result<(), StepError> {
+        if !self.translation_enabled.get() {
+            return Ok(());
+        }
+        self.translate(address, true, true).map(|_| ())
+    }
+
+    // CACR as written with MOVEC. The enable and freeze bits are kept by the caches, and the
+    // clear bits take effect immediately, the clear entry bits for the entry selected by CAAR.
+    pub fn set_cache_control(&self, cacr: u32, caar: u32) {
+        let caches = [
+            (
+                &self.instruction_cache,
+                CACR_ENABLE_INSTRUCTION_CACHE,
+                CACR_FREEZE_INSTRUCTION_CACHE,
+                CACR_CLEAR_ENTRY_INSTRUCTION_CACHE,
+                CACR_CLEAR_INSTRUCTION_CACHE,
+            ),
+            (
+                &self.data_cache,
+                CACR_ENABLE_DATA_CACHE,
+                CACR_FREEZE_DATA_CACHE,
+                CACR_CLEAR_ENTRY_DATA_CACHE,
+                CACR_CLEAR_DATA_CACHE,
+            ),
+        ];
+        for (cache, enable, freeze, clear_entry, clear) in caches {
+            let mut cache = cache.borrow_mut();
+            cache.set_enabled(cacr & enable != 0, cacr & freeze != 0);
+            if cacr & clear_entry != 0 {
+                cache.invalidate_entry(caar);
+            }
+            if cacr & clear != 0 {
+                cache.invalidate_all();
+            }
+        }
+    }
+
+    // Only RAM and ROM are cached. A miss fills the long words of the access, if they are all
+    // in the same 256 byte block and so in the same page.
+    fn get_cached(
+        &self,
+        cache: &RefCell<Cache>,
+        function_code: u8,
+        address: u32,
+        translation: Translation,
+        size_in_bytes: u32,
+    ) -> Option<u32> {
+        if translation.cache_inhibit || !cache.borrow().is_enabled() {
+            return None;
+        }
+        if let Some(value) = cache.borrow().get(function_code, address, size_in_bytes) {
+            return Some(value);
+        }
+        let first_long_address = address & 0xfffffffc;
+        let last_long_address = address.wrapping_add(size_in_bytes - 1) & 0xfffffffc;
+        if (first_long_address ^ last_long_address) & 0xffffff00 != 0 {
+            return None;
+        }
+        let mut long_address = first_long_address;
+        loop {
+            let physical_address = translation
+                .physical_address
+                .wrapping_add(long_address.wrapping_sub(address));
+            if self.is_cache_inhibited(physical_address) {
+                return None;
+            }
+            let value = self.get_page(physical_address).get_long(physical_address)?;
+            cache.borrow_mut().fill(function_code, long_address, value);
+            if long_address == last_long_address {
+                break;
+            }
+            long_address = long_address.wrapping_add(4);
+        }
+        cache.borrow().get(function_code, address, size_in_bytes)
+    }
+
+    fn begin_read(&self, address: u32, size_in_bytes: u32) -> Result<DataRead, StepError> {
+        if !self.translation_enabled.get() && !self.data_cache.borrow().is_enabled() {
+            return Ok(DataRead::Physical(address));
+        }
+        let translation = self.translate(address, true, false)?;
+        let function_code = self.get_function_code(false);
+        match self.get_cached(
+            &self.data_cache,
+            function_code,
+            address,
+            translation,
+            size_in_bytes,
+        ) {
+            Some(value) => Ok(DataRead::Cached(value)),
+            None => Ok(DataRead::Physical(translation.physical_address)),
+        }
+    }
+
+    // The data cache is write through, without allocating entries for writes
+    fn begin_write(&self, address: u32, value: u32, size_in_bytes: u32) -> Result<u32, StepError> {
+        if !self.translation_enabled.get() && !self.data_cache.borrow().is_enabled() {
+            return Ok(address);
+        }
+        let translation = self.translate(address, false, false)?;
+        let mut data_cache = self.data_cache.borrow_mut();
+        if data_cache.is_enabled() {
+            data_cache.update(self.get_function_code(false), address, value, size_in_bytes);
+        }
+        Ok(translation.physical_address)
+    }
+
+    // The 68000 prefetch queue is off by default, instruction words are then read straight from
+    // memory
+    pub fn set_prefetch_enabled(&self, enabled: bool) {
+        self.prefetch_queue.borrow_mut().set_enabled(enabled);
+    }
+
+    // Called by the CPU when the PC doesn't continue with the next instruction
+    pub fn flush_prefetch(&self) {
+        self.prefetch_queue.borrow_mut().flush();
+    }
+
+    pub fn begin_instruction_prefetch(&self, address: u32) {
+        let mut prefetch_queue = self.prefetch_queue.borrow_mut();
+        if prefetch_queue.is_enabled() {
+            prefetch_queue.begin_instruction(address, |x| self.fetch_instruction_word(x));
+        }
+    }
+
+    pub fn get_instruction_word(&self, address: u32) -> u16 {
+        let mut prefetch_queue = self.prefetch_queue.borrow_mut();
+        match prefetch_queue.is_enabled() {
+            true => prefetch_queue.get_word(address, |x| self.fetch_instruction_word(x)),
+            false => self.fetch_instruction_word(address),
+        }
+    }
+
+    // Instruction words are read through the instruction cache. Reads that go out on the bus
+    // leave the word on the data bus, like data reads.
+    fn fetch_instruction_word(&self, address: u32) -> u16 {
+        if !self.translation_enabled.get() && !self.instruction_cache.borrow().is_enabled() {
+            let word = self.get_word_no_log(address);
+            self.data_bus.set(word);
+            return word;
+        }
+        let translation = self.get_translation_no_log(address, true);
+        let function_code = self.get_function_code(true);
+        match self.get_cached(
+            &self.instruction_cache,
+            function_code,
+            address,
+            translation,
+            2,
+        ) {
+            Some(value) => value as u16,
+            None => {
+                let physical_address = translation.physical_address;
+                let word = self
+                    .get_memory(physical_address)
+                    .borrow()
+                    .get_word(&mut StepLog::none(), physical_address);
+                self.data_bus.set(word);
+                word
+            }
+        }
+    }
+
     fn validate_ranges(&self) {
         // TODO: Validate not overlapping Custom registers
         for (pos, range) in self.ranges.iter().enumerate() {
@@ -93,191 +551,304 @@ impl Mem {
         }
     }
 
-    fn get_memory(self: &Mem, address: u32) -> Rc<RefCell<dyn Memory>> {
-        if self.overlay
-            && address >= self.overlay_memory.borrow().get_start_address()
-            && address <= self.overlay_memory.borrow().get_end_address()
-        {
-            println!("OVERLAY access!");
-            return self.overlay_memory.clone();
-        }
-        // let overlay = match self.cia_range.as_any().downcast_ref::<CiaMemory>() {
-        //     Some(s) => s.overlay,
-        //     None => panic!("none"),
-        // };
-        // if !overlay {
-        //     println!("overlay: {}", overlay);
-        // }
-
-        // let overlay = match Any::downcast_ref::<CiaMemory>() {
-        //     Some(s) => panic!("some s"),
-        //     None => panic!("none"),
-        // };
-
-        // if address >= self.cia_range.get_start_address()
-        //     && address <= self.cia_range.get_end_address()
-        // {
-        //     println!("CIA accessed");
-        //     return &self.cia_range;
-        // }
-        let pos = self.ranges.iter().position(|x| {
-            address >= x.borrow().get_start_address() && address <= x.borrow().get_end_address()
-        });
+    fn find_memory(self: &Mem, address: u32) -> Option<Rc<RefCell<dyn Memory>>> {
+        let pos = match self.get_page(address) {
+            MemoryPage::Unmapped => None,
+            MemoryPage::Memory(range) => return Some(range.clone()),
+            _ => {
+                if self.overlay
+                    && address >= self.overlay_memory.borrow().get_start_address()
+                    && address <= self.overlay_memory.borrow().get_end_address()
+                {
+                    return Some(self.overlay_memory.clone());
+                }
+                self.ranges.iter().position(|x| {
+                    address >= x.borrow().get_start_address()
+                        && address <= x.borrow().get_end_address()
+                })
+            }
+        };
         match pos {
             None => {
                 if let Some(custom_memory) = &self.custom_memory {
                     if CustomMemory::is_custom_memory(address) {
-                        return custom_memory.clone();
+                        return Some(custom_memory.clone());
                     }
                 }
                 if let Some(cia_memory) = &self.cia_memory {
                     if CiaMemory::is_cia_memory(address) {
-                        return cia_memory.clone();
+                        return Some(cia_memory.clone());
                     }
                 }
-                self.default_range.clone()
+                None
             }
-            Some(pos) => self.ranges[pos].clone(),
+            Some(pos) => Some(self.ranges[pos].clone()),
         }
     }
 
-    fn get_memory_mut(self: &mut Mem, address: u32) -> Rc<RefCell<dyn Memory>> {
-        if self.overlay
-            && address >= self.overlay_memory.borrow().get_start_address()
-            && address <= self.overlay_memory.borrow().get_end_address()
-        {
-            println!("OVERLAY access!");
-            return self.overlay_memory.clone();
-        }
-        // if address >= self.cia_range.get_start_address()
-        //     && address <= self.cia_range.get_end_address()
-        // {
-        //     println!("CIA accessed");
-        //     return &mut self.cia_range;
-        // }
-        let pos = self.ranges.iter().position(|x| {
+    // DMA by the custom chips only sees chip RAM, never the ROM overlay, and doesn't go through
+    // the MMU or the CPU caches
+    fn get_chip_memory(&self, address: u32) -> Rc<RefCell<dyn Memory>> {
+        match self.ranges.iter().find(|x| {
             address >= x.borrow().get_start_address() && address <= x.borrow().get_end_address()
-        });
-        match pos {
-            None => {
-                if let Some(custom_memory) = &self.custom_memory {
-                    if CustomMemory::is_custom_memory(address) {
-                        return custom_memory.clone();
-                    }
-                }
-                if let Some(cia_memory) = &self.cia_memory {
-                    if CiaMemory::is_cia_memory(address) {
-                        return cia_memory.clone();
-                    }
-                }
-                self.default_range.clone()
-            }
-            Some(pos) => self.ranges[pos].clone(),
+        }) {
+            Some(range) => range.clone(),
+            None => self.default_range.clone(),
+        }
+    }
+
+    pub fn get_chip_word(&self, address: u32) -> u16 {
+        self.get_chip_memory(address)
+            .borrow()
+            .get_word(&mut StepLog::none(), address)
+    }
+
+    pub fn set_chip_word(&self, address: u32, value: u16) {
+        self.get_chip_memory(address)
+            .borrow_mut()
+            .set_word(&mut StepLog::none(), address, value);
+    }
+
+    fn get_memory(self: &Mem, address: u32) -> Rc<RefCell<dyn Memory>> {
+        match self.find_memory(address) {
+            Some(range) => range,
+            None => self.default_range.clone(),
+        }
+    }
+
+    fn get_memory_mut(self: &mut Mem, address: u32) -> Rc<RefCell<dyn Memory>> {
+        self.get_memory(address)
+    }
+
+    // Resolves the memory for a CPU access, applying the unmapped access policy. Returns None
+    // when the access should see the open bus.
+    fn get_memory_for_access(
+        self: &Mem,
+        address: u32,
+        read: bool,
+    ) -> Result<Option<Rc<RefCell<dyn Memory>>>, StepError> {
+        if let Some(range) = self.find_memory(address) {
+            return Ok(Some(range));
+        }
+        match self.get_unmapped_access_policy(address) {
+            UnmappedAccessPolicy::OpenBus => Ok(None),
+            UnmappedAccessPolicy::Zero => Ok(Some(self.default_range.clone())),
+            UnmappedAccessPolicy::BusError => Err(StepError::AccessFault {
+                fault: BusCycleFault {
+                    address,
+                    read,
+                    program_space: false,
+                },
+            }),
         }
     }
 
-    pub fn get_long(self: &Mem, step_log: &mut StepLog, address: u32) -> u32 {
+    fn check_word_alignment(address: u32, read: bool) -> Result<(), StepError> {
         if (address & 0x00000001) != 0 {
-            panic!();
+            return Err(StepError::AddressError {
+                fault: BusCycleFault {
+                    address,
+                    read,
+                    program_space: false,
+                },
+            });
         }
-        let range = self.get_memory(address);
-        let result = range.borrow().get_long(step_log, address);
+        Ok(())
+    }
+
+    pub fn get_long(self: &Mem, step_log: &mut StepLog, address: u32) -> Result<u32, StepError> {
+        Self::check_word_alignment(address, true)?;
+        let address = match self.begin_read(address, 4)? {
+            DataRead::Cached(value) => {
+                step_log.add_step_log_entry(StepLogEntry::ReadMemLong { address, value });
+                return Ok(value);
+            }
+            DataRead::Physical(address) => address,
+        };
+        let result = match self.get_page(address).get_long(address) {
+            Some(result) => result,
+            None => match self.get_memory_for_access(address, true)? {
+                Some(range) => range.borrow().get_long(step_log, address),
+                None => ((self.data_bus.get() as u32) << 16) | self.data_bus.get() as u32,
+            },
+        };
+        self.data_bus.set(result as u16);
         step_log.add_step_log_entry(StepLogEntry::ReadMemLong {
             address,
             value: result,
         });
-        result
+        Ok(result)
     }
 
     pub fn get_long_no_log(self: &Mem, address: u32) -> u32 {
         if (address & 0x00000001) != 0 {
             panic!();
         }
+        let address = self.get_translation_no_log(address, false).physical_address;
         let range = self.get_memory(address);
         let result = range.borrow().get_long(&mut StepLog::none(), address);
         result
     }
 
-    pub fn set_long(self: &mut Mem, step_log: &mut StepLog, address: u32, value: u32) {
-        if (address & 0x00000001) != 0 {
-            panic!();
+    pub fn set_long(
+        self: &mut Mem,
+        step_log: &mut StepLog,
+        address: u32,
+        value: u32,
+    ) -> Result<(), StepError> {
+        Self::check_word_alignment(address, false)?;
+        let address = self.begin_write(address, value, 4)?;
+        if self.get_page(address).set_long(address, value) {
+            step_log.add_step_log_entry(StepLogEntry::WriteMemLong { address, value });
+            self.data_bus.set(value as u16);
+            return Ok(());
         }
-        let range = self.get_memory_mut(address);
+        let range = self.get_memory_for_access(address, false)?;
         step_log.add_step_log_entry(StepLogEntry::WriteMemLong { address, value });
-        let result = range.borrow_mut().set_long(step_log, address, value);
+        self.data_bus.set(value as u16);
+        if let Some(range) = range {
+            range.borrow_mut().set_long(step_log, address, value);
+        }
+        Ok(())
     }
 
     pub fn set_long_no_log(self: &mut Mem, address: u32, value: u32) {
         if (address & 0x00000001) != 0 {
             panic!();
         }
+        let address = self.get_translation_no_log(address, false).physical_address;
         let range = self.get_memory_mut(address);
         let result = range
             .borrow_mut()
             .set_long(&mut StepLog::none(), address, value);
     }
 
-    pub fn get_word(self: &Mem, step_log: &mut StepLog, address: u32) -> u16 {
-        if (address & 0x00000001) != 0 {
-            panic!();
-        }
-        let range = self.get_memory(address);
-        let result = range.borrow().get_word(step_log, address);
+    pub fn get_word(self: &Mem, step_log: &mut StepLog, address: u32) -> Result<u16, StepError> {
+        Self::check_word_alignment(address, true)?;
+        let address = match self.begin_read(address, 2)? {
+            DataRead::Cached(value) => {
+                step_log.add_step_log_entry(StepLogEntry::ReadMemWord {
+                    address,
+                    value: value as u16,
+                });
+                return Ok(value as u16);
+            }
+            DataRead::Physical(address) => address,
+        };
+        let result = match self.get_page(address).get_word(address) {
+            Some(result) => result,
+            None => match self.get_memory_for_access(address, true)? {
+                Some(range) => range.borrow().get_word(step_log, address),
+                None => self.data_bus.get(),
+            },
+        };
+        self.data_bus.set(result);
         step_log.add_step_log_entry(StepLogEntry::ReadMemWord {
             address,
             value: result,
         });
-        result
+        Ok(result)
     }
 
     pub fn get_word_no_log(self: &Mem, address: u32) -> u16 {
         if (address & 0x00000001) != 0 {
             panic!();
         }
+        let address = self.get_translation_no_log(address, false).physical_address;
         let range = self.get_memory(address);
         let result = range.borrow().get_word(&mut StepLog::none(), address);
         result
     }
 
-    pub fn set_word(self: &mut Mem, step_log: &mut StepLog, address: u32, value: u16) {
-        if (address & 0x00000001) != 0 {
-            panic!();
+    pub fn set_word(
+        self: &mut Mem,
+        step_log: &mut StepLog,
+        address: u32,
+        value: u16,
+    ) -> Result<(), StepError> {
+        Self::check_word_alignment(address, false)?;
+        let address = self.begin_write(address, value as u32, 2)?;
+        if self.get_page(address).set_word(address, value) {
+            step_log.add_step_log_entry(StepLogEntry::WriteMemWord { address, value });
+            self.data_bus.set(value);
+            return Ok(());
         }
-        let range = self.get_memory_mut(address);
+        let range = self.get_memory_for_access(address, false)?;
         step_log.add_step_log_entry(StepLogEntry::WriteMemWord { address, value });
-        let result = range.borrow_mut().set_word(step_log, address, value);
+        self.data_bus.set(value);
+        if let Some(range) = range {
+            range.borrow_mut().set_word(step_log, address, value);
+        }
+        Ok(())
     }
 
     pub fn set_word_no_log(self: &mut Mem, address: u32, value: u16) {
         if (address & 0x00000001) != 0 {
             panic!();
         }
+        let address = self.get_translation_no_log(address, false).physical_address;
         let range = self.get_memory_mut(address);
         let result = range
             .borrow_mut()
             .set_word(&mut StepLog::none(), address, value);
     }
 
-    pub fn get_byte(self: &Mem, step_log: &mut StepLog, address: u32) -> u8 {
-        let range = self.get_memory(address);
-        let result = range.borrow().get_byte(step_log, address);
+    pub fn get_byte(self: &Mem, step_log: &mut StepLog, address: u32) -> Result<u8, StepError> {
+        let address = match self.begin_read(address, 1)? {
+            DataRead::Cached(value) => {
+                step_log.add_step_log_entry(StepLogEntry::ReadMemByte {
+                    address,
+                    value: value as u8,
+                });
+                return Ok(value as u8);
+            }
+            DataRead::Physical(address) => address,
+        };
+        let result = match self.get_page(address).get_byte(address) {
+            Some(result) => result,
+            None => match self.get_memory_for_access(address, true)? {
+                Some(range) => range.borrow().get_byte(step_log, address),
+                None => match address & 0x00000001 {
+                    0 => (self.data_bus.get() >> 8) as u8,
+                    _ => self.data_bus.get() as u8,
+                },
+            },
+        };
+        self.data_bus.set(((result as u16) << 8) | result as u16);
         step_log.add_step_log_entry(StepLogEntry::ReadMemByte {
             address,
             value: result,
         });
-        result
+        Ok(result)
     }
 
     pub fn get_byte_no_log(self: &Mem, address: u32) -> u8 {
+        let address = self.get_translation_no_log(address, false).physical_address;
         let range = self.get_memory(address);
         let result = range.borrow().get_byte(&mut StepLog::none(), address);
         result
     }
 
-    pub fn set_byte(self: &mut Mem, step_log: &mut StepLog, address: u32, value: u8) {
-        let range = self.get_memory_mut(address);
+    pub fn set_byte(
+        self: &mut Mem,
+        step_log: &mut StepLog,
+        address: u32,
+        value: u8,
+    ) -> Result<(), StepError> {
+        let address = self.begin_write(address, value as u32, 1)?;
+        if self.get_page(address).set_byte(address, value) {
+            step_log.add_step_log_entry(StepLogEntry::WriteMemByte { address, value });
+            self.data_bus.set(((value as u16) << 8) | value as u16);
+            return Ok(());
+        }
+        let range = self.get_memory_for_access(address, false)?;
         step_log.add_step_log_entry(StepLogEntry::WriteMemByte { address, value });
-        let set_byte_result = range.borrow_mut().set_byte(step_log, address, value);
+        // The 68000 puts the byte on both halves of the data bus when writing
+        self.data_bus.set(((value as u16) << 8) | value as u16);
+        let set_byte_result = match range {
+            Some(range) => range.borrow_mut().set_byte(step_log, address, value),
+            None => None,
+        };
         match set_byte_result {
             Some(r) => {
                 if let Some(overlay) = r.set_overlay {
@@ -286,9 +857,11 @@ impl Mem {
             }
             None => (),
         }
+        Ok(())
     }
 
     pub fn set_byte_no_log(self: &mut Mem, address: u32, value: u8) {
+        let address = self.get_translation_no_log(address, false).physical_address;
         let range = self.get_memory_mut(address);
         let set_byte_result = range
             .borrow_mut()
@@ -297,6 +870,7 @@ impl Mem {
             Some(r) => {
                 if let Some(overlay) = r.set_overlay {
                     self.overlay = overlay;
+                    self.build_pages();
                 }
             }
             None => (),
@@ -335,3 +909,150 @@ impl Mem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::cache::CACR_ENABLE_DATA_CACHE;
+    use crate::mem::rammemory::RamMemory;
+
+    #[test]
+    fn ram_written_through_page_is_shared_with_range() {
+        // arrange
+        let ram = Rc::new(RefCell::new(RamMemory::from_range(0x00000000, 0x0007ffff)));
+        let mut mem = Mem::new(None, None);
+        mem.add_range(ram.clone());
+        // act
+        mem.set_long(&mut StepLog::none(), 0x00012340, 0x11223344)
+            .ok()
+            .unwrap();
+        ram.borrow_mut()
+            .set_word(&mut StepLog::none(), 0x00012344, 0x5566);
+        // assert
+        assert_eq!(
+            0x11223344,
+            ram.borrow().get_long(&mut StepLog::none(), 0x00012340)
+        );
+        assert_eq!(
+            0x5566,
+            mem.get_word(&mut StepLog::none(), 0x00012344).ok().unwrap()
+        );
+    }
+
+    #[test]
+    fn ranges_sharing_a_page_are_searched() {
+        // arrange
+        let mut mem = Mem::new(None, None);
+        mem.add_range(Rc::new(RefCell::new(RamMemory::from_range(
+            0x00020000, 0x000200ff,
+        ))));
+        mem.add_range(Rc::new(RefCell::new(RamMemory::from_range(
+            0x00020100, 0x000201ff,
+        ))));
+        // act
+        mem.set_byte(&mut StepLog::none(), 0x000200ff, 0x12)
+            .ok()
+            .unwrap();
+        mem.set_byte(&mut StepLog::none(), 0x00020100, 0x34)
+            .ok()
+            .unwrap();
+        // assert
+        assert_eq!(0x12, mem.get_byte_no_log(0x000200ff));
+        assert_eq!(0x34, mem.get_byte_no_log(0x00020100));
+        assert_eq!(0x00, mem.get_byte_no_log(0x00020200));
+    }
+
+    #[test]
+    fn overlay_hides_ram_until_disabled() {
+        // arrange
+        let mut mem = Mem::new(None, None);
+        mem.add_range(Rc::new(RefCell::new(RamMemory::from_range(
+            0x00000000, 0x0007ffff,
+        ))));
+        mem.set_long_no_log(0x00000004, 0x00000001);
+        mem.set_overlay(Rc::new(RefCell::new(RamMemory::from_bytes(
+            0x00000000,
+            vec![0x00; 0x00010000],
+        ))));
+        // act/assert
+        assert_eq!(
+            0x00000000,
+            mem.get_long(&mut StepLog::none(), 0x00000004).ok().unwrap()
+        );
+        mem.set_overlay_enable(false);
+        assert_eq!(
+            0x00000001,
+            mem.get_long(&mut StepLog::none(), 0x00000004).ok().unwrap()
+        );
+        mem.set_overlay_enable(true);
+        assert_eq!(
+            0x00000000,
+            mem.get_long(&mut StepLog::none(), 0x00000004).ok().unwrap()
+        );
+    }
+
+    #[test]
+    fn data_cache_misses_writes_by_other_bus_masters_until_cleared() {
+        // arrange
+        let ram = Rc::new(RefCell::new(RamMemory::from_range(0x00000000, 0x0007ffff)));
+        let mut mem = Mem::new(None, None);
+        mem.add_range(ram.clone());
+        mem.set_long_no_log(0x00001000, 0x11111111);
+        mem.set_cache_control(CACR_ENABLE_DATA_CACHE, 0x00000000);
+        mem.get_long(&mut StepLog::none(), 0x00001000).ok().unwrap();
+        // act
+        ram.borrow_mut()
+            .set_long(&mut StepLog::none(), 0x00001000, 0x22222222);
+        // assert
+        assert_eq!(
+            0x1111,
+            mem.get_word(&mut StepLog::none(), 0x00001002).ok().unwrap()
+        );
+        mem.set_word(&mut StepLog::none(), 0x00001000, 0x3333)
+            .ok()
+            .unwrap();
+        assert_eq!(
+            0x33331111,
+            mem.get_long(&mut StepLog::none(), 0x00001000).ok().unwrap()
+        );
+        mem.set_cache_control(CACR_ENABLE_DATA_CACHE | CACR_CLEAR_DATA_CACHE, 0x00000000);
+        assert_eq!(
+            0x33332222,
+            mem.get_long(&mut StepLog::none(), 0x00001000).ok().unwrap()
+        );
+    }
+
+    #[test]
+    fn translated_access_to_invalid_page_is_access_fault() {
+        // arrange
+        let mut mem = Mem::new(None, None);
+        mem.add_range(Rc::new(RefCell::new(RamMemory::from_range(
+            0x00000000, 0x0007ffff,
+        ))));
+        // 32 KB pages, with an early termination page descriptor at the first level that maps
+        // logical $01xxxxxx to physical $00xxxxxx
+        mem.set_long_no_log(0x00001004, 0x00000001);
+        mem.with_mmu(|mmu| {
+            mmu.reg_crp = 0x7fff0002_00001000;
+            mmu.reg_tc = 0x80f08900;
+        });
+        mem.set_long(&mut StepLog::none(), 0x01002000, 0x12345678)
+            .ok()
+            .unwrap();
+        // act
+        let result = mem.get_long(&mut StepLog::none(), 0x02002000);
+        // assert
+        assert_eq!(0x12345678, mem.get_long_no_log(0x01002000));
+        assert_eq!(0x00000019, mem.get_long_no_log(0x00001004));
+        assert!(matches!(
+            result,
+            Err(StepError::AccessFault {
+                fault: BusCycleFault {
+                    address: 0x02002000,
+                    read: true,
+                    program_space: false,
+                }
+            })
+        ));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::cpu::CpuSpeed;
+
+// The Agnus beam counter, advanced by one color clock at a time. A line is $E3 color clocks
+// (HRM page 23) on both PAL and NTSC. A long frame has 313 lines on PAL and 263 lines on NTSC,
+// and a short frame one line less. Frames are long unless interlace is enabled, in which case
+// long and short frames alternate.
+
+// The chip id in bits 14-8 of VPOSR
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AgnusModel {
+    // 8361 (NTSC), 8367 (PAL) and the 8370/8371 Fat Agnus
+    Ocs,
+    // 8372 Fat Agnus, with V9 and V10 of the vertical position in VPOSR
+    Ecs,
+}
+
+pub const VPOSR_LOF: u16 = 0x8000;
+
+const HPOS_MAX: u16 = 0xe2;
+const PAL_LONG_FRAME_LINES: u16 = 313;
+const NTSC_LONG_FRAME_LINES: u16 = 263;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BeamEvent {
+    None,
+    // Horizontal sync, the beam is at the start of the next line
+    StartOfLine,
+    // Vertical sync, the beam is at the start of line 0
+    StartOfFrame,
+}
+
+pub struct BeamCounter {
+    pal: bool,
+    agnus_model: AgnusModel,
+    hpos: u16,
+    vpos: u16,
+    long_frame: bool,
+    interlace: bool,
+}
+
+impl BeamCounter {
+    pub fn new(cpu_speed: &CpuSpeed, agnus_model: AgnusModel) -> BeamCounter {
+        BeamCounter {
+            pal: matches!(cpu_speed, CpuSpeed::PAL_7_093790_MHz),
+            agnus_model,
+            hpos: 0,
+            vpos: 0,
+            long_frame: true,
+            interlace: false,
+        }
+    }
+
+    pub fn get_hpos(&self) -> u16 {
+        self.hpos
+    }
+
+    pub fn get_vpos(&self) -> u16 {
+        self.vpos
+    }
+
+    pub fn is_long_frame(&self) -> bool {
+        self.long_frame
+    }
+
+    // The LACE bit of BPLCON0
+    pub fn set_interlace(&mut self, interlace: bool) {
+        self.interlace = interlace;
+    }
+
+    fn get_frame_lines(&self) -> u16 {
+        let long_frame_lines = match self.pal {
+            true => PAL_LONG_FRAME_LINES,
+            false => NTSC_LONG_FRAME_LINES,
+        };
+        match self.long_frame {
+            true => long_frame_lines,
+            false => long_frame_lines - 1,
+        }
+    }
+
+    fn get_agnus_id(&self) -> u16 {
+        match (self.agnus_model, self.pal) {
+            (AgnusModel::Ocs, true) => 0x00,
+            (AgnusModel::Ocs, false) => 0x10,
+            (AgnusModel::Ecs, true) => 0x20,
+            (AgnusModel::Ecs, false) => 0x30,
+        }
+    }
+
+    fn get_vpos_high_mask(&self) -> u16 {
+        match self.agnus_model {
+            AgnusModel::Ocs => 0x0001,
+            AgnusModel::Ecs => 0x0007,
+        }
+    }
+
+    pub fn step_color_clock(&mut self) -> BeamEvent {
+        if self.hpos < HPOS_MAX {
+            self.hpos += 1;
+            return BeamEvent::None;
+        }
+        self.hpos = 0;
+        self.vpos += 1;
+        if self.vpos < self.get_frame_lines() {
+            return BeamEvent::StartOfLine;
+        }
+        self.vpos = 0;
+        if self.interlace {
+            self.long_frame = !self.long_frame;
+        }
+        BeamEvent::StartOfFrame
+    }
+
+    // LOF, the chip id and the high bits of the vertical position
+    pub fn read_vposr(&self) -> u16 {
+        let lof = match self.long_frame {
+            true => VPOSR_LOF,
+            false => 0x0000,
+        };
+        lof | (self.get_agnus_id() << 8) | ((self.vpos >> 8) & self.get_vpos_high_mask())
+    }
+
+    // V7-V0 and H8-H1
+    pub fn read_vhposr(&self) -> u16 {
+        ((self.vpos & 0x00ff) << 8) | self.hpos
+    }
+
+    pub fn write_vposw(&mut self, value: u16) {
+        self.long_frame = value & VPOSR_LOF == VPOSR_LOF;
+        let mask = self.get_vpos_high_mask();
+        self.vpos = (self.vpos & 0x00ff) | ((value & mask) << 8);
+    }
+
+    pub fn write_vhposw(&mut self, value: u16) {
+        self.vpos = (self.vpos & 0xff00) | (value >> 8);
+        self.hpos = (value & 0x00ff).min(HPOS_MAX);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step_to_next_frame(beam_counter: &mut BeamCounter) -> u32 {
+        let mut lines = 0;
+        loop {
+            match beam_counter.step_color_clock() {
+                BeamEvent::None => (),
+                BeamEvent::StartOfLine => lines += 1,
+                BeamEvent::StartOfFrame => return lines + 1,
+            }
+        }
+    }
+
+    #[test]
+    fn pal_frame_has_313_lines_of_227_color_clocks() {
+        // arrange
+        let mut beam_counter = BeamCounter::new(&CpuSpeed::PAL_7_093790_MHz, AgnusModel::Ocs);
+        // act
+        let lines = step_to_next_frame(&mut beam_counter);
+        for _ in 0..0xe3 {
+            beam_counter.step_color_clock();
+        }
+        // assert
+        assert_eq!(313, lines);
+        assert_eq!(0x8000, beam_counter.read_vposr());
+        assert_eq!(0x0100, beam_counter.read_vhposr());
+    }
+
+    #[test]
+    fn ntsc_interlace_alternates_long_and_short_frames() {
+        // arrange
+        let mut beam_counter = BeamCounter::new(&CpuSpeed::NTSC_7_159090_MHz, AgnusModel::Ecs);
+        beam_counter.set_interlace(true);
+        // act
+        let long_frame_lines = step_to_next_frame(&mut beam_counter);
+        let vposr_short_frame = beam_counter.read_vposr();
+        let short_frame_lines = step_to_next_frame(&mut beam_counter);
+        // assert
+        assert_eq!(263, long_frame_lines);
+        assert_eq!(0x3000, vposr_short_frame);
+        assert_eq!(262, short_frame_lines);
+        assert!(beam_counter.is_long_frame());
+    }
+
+    #[test]
+    fn vposw_and_vhposw_set_the_beam_position() {
+        // arrange
+        let mut beam_counter = BeamCounter::new(&CpuSpeed::PAL_7_093790_MHz, AgnusModel::Ocs);
+        // act
+        beam_counter.write_vposw(0x0001);
+        beam_counter.write_vhposw(0x38e2);
+        let vposr = beam_counter.read_vposr();
+        let event = beam_counter.step_color_clock();
+        // assert
+        assert_eq!(0x0001, vposr);
+        assert_eq!(BeamEvent::StartOfFrame, event);
+        assert_eq!(0, beam_counter.get_vpos());
+    }
+}
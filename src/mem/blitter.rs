@@ -0,0 +1,501 @@
+use crate::mem::Mem;
+
+// The Blitter. Writing BLTSIZE starts a blit, which is then run a word (or a line pixel) at a
+// time by step(). For now the custom chips run the whole blit at once, without taking DMA slots
+// from the other channels.
+//  - Area mode: D = minterm(A, B, C) for each word of a rectangle. A is masked by the first and
+//    last word masks, and A and B are shifted right (left in descending mode) by ASH and BSH,
+//    with the bits shifted in from the previous word of the channel. Fill mode, only useful in
+//    descending mode, fills each row of D from right to left between the set bits.
+//  - Line mode: draws a line of BLTSIZE height pixels, using C and D as the bitplane and A as
+//    the pixel mask. The Bresenham error term is kept in BLTAPTL, with BLTAMOD (4dy-4dx) added
+//    when it's positive, and BLTBMOD (4dy) when it's negative. B is the line texture.
+
+pub const BLTCON0_USEA: u16 = 0x0800;
+pub const BLTCON0_USEB: u16 = 0x0400;
+pub const BLTCON0_USEC: u16 = 0x0200;
+pub const BLTCON0_USED: u16 = 0x0100;
+
+pub const BLTCON1_SIGN: u16 = 0x0040;
+pub const BLTCON1_EFE: u16 = 0x0010;
+pub const BLTCON1_SUD: u16 = 0x0010;
+pub const BLTCON1_IFE: u16 = 0x0008;
+pub const BLTCON1_SUL: u16 = 0x0008;
+pub const BLTCON1_FCI: u16 = 0x0004;
+pub const BLTCON1_AUL: u16 = 0x0004;
+pub const BLTCON1_DESC: u16 = 0x0002;
+pub const BLTCON1_SING: u16 = 0x0002;
+pub const BLTCON1_LINE: u16 = 0x0001;
+
+pub struct Blitter {
+    pub bltcon0: u16,
+    pub bltcon1: u16,
+    pub bltafwm: u16,
+    pub bltalwm: u16,
+    pub bltapt: u32,
+    pub bltbpt: u32,
+    pub bltcpt: u32,
+    pub bltdpt: u32,
+    pub bltamod: u16,
+    pub bltbmod: u16,
+    pub bltcmod: u16,
+    pub bltdmod: u16,
+    pub bltadat: u16,
+    pub bltbdat: u16,
+    pub bltcdat: u16,
+    busy: bool,
+    zero: bool,
+    width: u16,
+    height: u16,
+    x: u16,
+    y: u16,
+    a_old: u16,
+    b_old: u16,
+    fill_carry: bool,
+    // Line mode
+    a_shift: u16,
+    b_shift: u16,
+    sign: bool,
+    line_dot_drawn: bool,
+}
+
+impl Blitter {
+    pub fn new() -> Blitter {
+        Blitter {
+            bltcon0: 0x0000,
+            bltcon1: 0x0000,
+            bltafwm: 0xffff,
+            bltalwm: 0xffff,
+            bltapt: 0x00000000,
+            bltbpt: 0x00000000,
+            bltcpt: 0x00000000,
+            bltdpt: 0x00000000,
+            bltamod: 0x0000,
+            bltbmod: 0x0000,
+            bltcmod: 0x0000,
+            bltdmod: 0x0000,
+            bltadat: 0x0000,
+            bltbdat: 0x0000,
+            bltcdat: 0x0000,
+            busy: false,
+            zero: true,
+            width: 0,
+            height: 0,
+            x: 0,
+            y: 0,
+            a_old: 0x0000,
+            b_old: 0x0000,
+            fill_carry: false,
+            a_shift: 0,
+            b_shift: 0,
+            sign: false,
+            line_dot_drawn: false,
+        }
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+
+    // BZERO, set when all the D words of the last blit were zero
+    pub fn is_zero(&self) -> bool {
+        self.zero
+    }
+
+    // BLTSIZE, a height of 0 is 1024 rows and a width of 0 is 64 words
+    pub fn start(&mut self, bltsize: u16) {
+        self.height = match bltsize >> 6 {
+            0 => 1024,
+            height => height,
+        };
+        self.width = match bltsize & 0x003f {
+            0 => 64,
+            width => width,
+        };
+        self.x = 0;
+        self.y = 0;
+        self.a_old = 0x0000;
+        self.b_old = 0x0000;
+        self.fill_carry = self.bltcon1 & BLTCON1_FCI == BLTCON1_FCI;
+        self.zero = true;
+        self.a_shift = self.bltcon0 >> 12;
+        self.b_shift = self.bltcon1 >> 12;
+        self.sign = self.bltcon1 & BLTCON1_SIGN == BLTCON1_SIGN;
+        self.line_dot_drawn = false;
+        self.busy = true;
+    }
+
+    // Runs the whole blit
+    pub fn run(&mut self, mem: &Mem) {
+        while self.busy {
+            self.step(mem);
+        }
+    }
+
+    // Processes one word in area mode, or one pixel in line mode
+    pub fn step(&mut self, mem: &Mem) {
+        if !self.busy {
+            return;
+        }
+        match self.bltcon1 & BLTCON1_LINE {
+            BLTCON1_LINE => self.step_line(mem),
+            _ => self.step_area(mem),
+        }
+    }
+
+    pub fn get_minterm(minterm: u8, a: u16, b: u16, c: u16) -> u16 {
+        let mut d = 0x0000;
+        for i in 0..8 {
+            if minterm & (1 << i) != 0 {
+                let a = if i & 0b100 != 0 { a } else { !a };
+                let b = if i & 0b010 != 0 { b } else { !b };
+                let c = if i & 0b001 != 0 { c } else { !c };
+                d |= a & b & c;
+            }
+        }
+        d
+    }
+
+    fn is_channel_used(&self, channel: u16) -> bool {
+        self.bltcon0 & channel == channel
+    }
+
+    fn is_descending(&self) -> bool {
+        self.bltcon1 & BLTCON1_DESC == BLTCON1_DESC
+    }
+
+    fn add_to_pointer(&self, pointer: u32, value: i32) -> u32 {
+        match self.is_descending() {
+            true => pointer.wrapping_sub(value as u32),
+            false => pointer.wrapping_add(value as u32),
+        }
+    }
+
+    fn add_modulo(&self, pointer: u32, modulo: u16) -> u32 {
+        self.add_to_pointer(pointer, (modulo & 0xfffe) as i16 as i32)
+    }
+
+    fn get_shifted(&self, old: u16, new: u16, shift: u16) -> u16 {
+        match self.is_descending() {
+            true => ((((new as u32) << 16) | old as u32) >> (16 - shift)) as u16,
+            false => ((((old as u32) << 16) | new as u32) >> shift) as u16,
+        }
+    }
+
+    // The fill works from the rightmost bit, toggling the fill carry on each set bit
+    fn fill(&mut self, d: u16) -> u16 {
+        let inclusive = self.bltcon1 & BLTCON1_IFE == BLTCON1_IFE;
+        let mut result = 0x0000;
+        for i in 0..16 {
+            let bit = (d >> i) & 0x0001 == 0x0001;
+            let fill = match inclusive {
+                true => {
+                    let fill = self.fill_carry || bit;
+                    self.fill_carry ^= bit;
+                    fill
+                }
+                false => {
+                    self.fill_carry ^= bit;
+                    self.fill_carry
+                }
+            };
+            if fill {
+                result |= 1 << i;
+            }
+        }
+        result
+    }
+
+    fn step_area(&mut self, mem: &Mem) {
+        if self.is_channel_used(BLTCON0_USEA) {
+            self.bltadat = mem.get_chip_word(self.bltapt);
+            self.bltapt = self.add_to_pointer(self.bltapt, 2);
+        }
+        if self.is_channel_used(BLTCON0_USEB) {
+            self.bltbdat = mem.get_chip_word(self.bltbpt);
+            self.bltbpt = self.add_to_pointer(self.bltbpt, 2);
+        }
+        if self.is_channel_used(BLTCON0_USEC) {
+            self.bltcdat = mem.get_chip_word(self.bltcpt);
+            self.bltcpt = self.add_to_pointer(self.bltcpt, 2);
+        }
+
+        let mut a = self.bltadat;
+        if self.x == 0 {
+            a &= self.bltafwm;
+        }
+        if self.x == self.width - 1 {
+            a &= self.bltalwm;
+        }
+        let a_shifted = self.get_shifted(self.a_old, a, self.a_shift);
+        let b_shifted = self.get_shifted(self.b_old, self.bltbdat, self.b_shift);
+        self.a_old = a;
+        self.b_old = self.bltbdat;
+
+        let mut d = Blitter::get_minterm(self.bltcon0 as u8, a_shifted, b_shifted, self.bltcdat);
+        if self.bltcon1 & (BLTCON1_IFE | BLTCON1_EFE) != 0 {
+            d = self.fill(d);
+        }
+        if d != 0x0000 {
+            self.zero = false;
+        }
+        if self.is_channel_used(BLTCON0_USED) {
+            mem.set_chip_word(self.bltdpt, d);
+            self.bltdpt = self.add_to_pointer(self.bltdpt, 2);
+        }
+
+        self.x += 1;
+        if self.x < self.width {
+            return;
+        }
+        self.x = 0;
+        self.fill_carry = self.bltcon1 & BLTCON1_FCI == BLTCON1_FCI;
+        if self.is_channel_used(BLTCON0_USEA) {
+            self.bltapt = self.add_modulo(self.bltapt, self.bltamod);
+        }
+        if self.is_channel_used(BLTCON0_USEB) {
+            self.bltbpt = self.add_modulo(self.bltbpt, self.bltbmod);
+        }
+        if self.is_channel_used(BLTCON0_USEC) {
+            self.bltcpt = self.add_modulo(self.bltcpt, self.bltcmod);
+        }
+        if self.is_channel_used(BLTCON0_USED) {
+            self.bltdpt = self.add_modulo(self.bltdpt, self.bltdmod);
+        }
+        self.y += 1;
+        if self.y == self.height {
+            self.busy = false;
+        }
+    }
+
+    fn step_line_x(&mut self, left: bool) {
+        match left {
+            true => {
+                self.a_shift = self.a_shift.wrapping_sub(1) & 0x000f;
+                if self.a_shift == 15 {
+                    self.bltcpt = self.bltcpt.wrapping_sub(2);
+                }
+            }
+            false => {
+                self.a_shift = (self.a_shift + 1) & 0x000f;
+                if self.a_shift == 0 {
+                    self.bltcpt = self.bltcpt.wrapping_add(2);
+                }
+            }
+        }
+    }
+
+    fn step_line_y(&mut self, up: bool) {
+        let modulo = (self.bltcmod & 0xfffe) as i16 as i32 as u32;
+        self.bltcpt = match up {
+            true => self.bltcpt.wrapping_sub(modulo),
+            false => self.bltcpt.wrapping_add(modulo),
+        };
+        self.line_dot_drawn = false;
+    }
+
+    // The octant is given by SUD, SUL and AUL. With SUD set X is stepped for every pixel, and Y
+    // when the error term is positive, otherwise the other way around. SUL and AUL select the
+    // direction of the sometimes and always steps.
+    fn step_line(&mut self, mem: &Mem) {
+        let single = self.bltcon1 & BLTCON1_SING == BLTCON1_SING;
+        let mut a = (self.bltadat & self.bltafwm) >> self.a_shift;
+        if single && self.line_dot_drawn {
+            a = 0x0000;
+        }
+        let b = match (self.bltbdat >> self.b_shift) & 0x0001 {
+            0 => 0x0000,
+            _ => 0xffff,
+        };
+        self.bltcdat = mem.get_chip_word(self.bltcpt);
+        let d = Blitter::get_minterm(self.bltcon0 as u8, a, b, self.bltcdat);
+        if d != 0x0000 {
+            self.zero = false;
+        }
+        // D is written to the word of the current pixel, the first one is at BLTDPT
+        let d_address = match self.y {
+            0 => self.bltdpt,
+            _ => self.bltcpt,
+        };
+        mem.set_chip_word(d_address, d);
+        self.line_dot_drawn = true;
+        self.b_shift = self.b_shift.wrapping_sub(1) & 0x000f;
+
+        let sud = self.bltcon1 & BLTCON1_SUD == BLTCON1_SUD;
+        let sul = self.bltcon1 & BLTCON1_SUL == BLTCON1_SUL;
+        let aul = self.bltcon1 & BLTCON1_AUL == BLTCON1_AUL;
+        if !self.sign {
+            match sud {
+                true => self.step_line_y(sul),
+                false => self.step_line_x(sul),
+            }
+        }
+        match sud {
+            true => self.step_line_x(aul),
+            false => self.step_line_y(aul),
+        }
+        let error = self.bltapt as u16 as i16;
+        let error = match self.sign {
+            true => error.wrapping_add(self.bltbmod as i16),
+            false => error.wrapping_add(self.bltamod as i16),
+        };
+        self.sign = error < 0;
+        self.bltapt = (self.bltapt & 0xffff0000) | error as u16 as u32;
+        self.bltdpt = self.bltcpt;
+
+        self.y += 1;
+        if self.y == self.height {
+            self.busy = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::step_log::StepLog;
+    use crate::cpu::CpuSpeed;
+    use crate::mem::beamcounter::AgnusModel;
+    use crate::mem::custommemory::{CustomMemory, DMACONR_BBUSY, DMACONR_BZERO, INTREQ_BLIT};
+    use crate::mem::memory::Memory;
+    use crate::mem::rammemory::RamMemory;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn blitter_test_setup(words: &[(u32, u16)]) -> Mem {
+        let mut mem = Mem::new(None, None);
+        let chip_ram = RamMemory::from_range(0x00000000, 0x00000fff);
+        mem.add_range(Rc::new(RefCell::new(chip_ram)));
+        for (address, word) in words {
+            mem.set_chip_word(*address, *word);
+        }
+        mem
+    }
+
+    #[test]
+    fn minterms_combine_sources() {
+        // assert
+        assert_eq!(0x00f0, Blitter::get_minterm(0xf0, 0x00f0, 0x0ff0, 0x3333)); // D = A
+        assert_eq!(0x0ff0, Blitter::get_minterm(0xcc, 0x00f0, 0x0ff0, 0x3333)); // D = B
+        assert_eq!(0x3333, Blitter::get_minterm(0xaa, 0x00f0, 0x0ff0, 0x3333)); // D = C
+        assert_eq!(0x33c3, Blitter::get_minterm(0x6a, 0x00f0, 0x0ff0, 0x3333)); // D = AB ^ C
+        assert_eq!(0xffff, Blitter::get_minterm(0xff, 0x00f0, 0x0ff0, 0x3333));
+    }
+
+    #[test]
+    fn area_copy_with_shift_masks_and_modulo() {
+        // arrange
+        let mem = blitter_test_setup(&[
+            (0x100, 0xffff),
+            (0x102, 0xffff),
+            (0x104, 0x1234),
+            (0x106, 0x5678),
+        ]);
+        let mut blitter = Blitter::new();
+        blitter.bltcon0 = 0x49f0; // ASH 4, USEA, USED, D = A
+        blitter.bltafwm = 0x0fff;
+        blitter.bltalwm = 0xfff0;
+        blitter.bltapt = 0x100;
+        blitter.bltdpt = 0x200;
+        blitter.bltdmod = 2;
+        // act
+        blitter.start((2 << 6) | 2);
+        blitter.run(&mem);
+        // assert
+        assert_eq!(0x00ff, mem.get_chip_word(0x200));
+        assert_eq!(0xffff, mem.get_chip_word(0x202));
+        // The last word of the previous row is shifted in
+        assert_eq!(0x0023, mem.get_chip_word(0x206));
+        assert_eq!(0x4567, mem.get_chip_word(0x208));
+        assert!(!blitter.is_busy());
+        assert!(!blitter.is_zero());
+    }
+
+    #[test]
+    fn descending_shifts_left() {
+        // arrange
+        let mem = blitter_test_setup(&[(0x100, 0x1234), (0x102, 0x5678)]);
+        let mut blitter = Blitter::new();
+        blitter.bltcon0 = 0x49f0; // ASH 4, USEA, USED, D = A
+        blitter.bltcon1 = BLTCON1_DESC;
+        blitter.bltapt = 0x102;
+        blitter.bltdpt = 0x202;
+        // act
+        blitter.start((1 << 6) | 2);
+        blitter.run(&mem);
+        // assert
+        assert_eq!(0x2345, mem.get_chip_word(0x200));
+        assert_eq!(0x6780, mem.get_chip_word(0x202));
+    }
+
+    #[test]
+    fn inclusive_and_exclusive_fill() {
+        // arrange
+        let mem = blitter_test_setup(&[(0x100, 0x0810), (0x102, 0x0810)]);
+        let mut blitter = Blitter::new();
+        blitter.bltcon0 = 0x09f0; // USEA, USED, D = A
+        blitter.bltcon1 = BLTCON1_DESC | BLTCON1_IFE;
+        blitter.bltapt = 0x100;
+        blitter.bltdpt = 0x200;
+        // act
+        blitter.start((1 << 6) | 1);
+        blitter.run(&mem);
+        blitter.bltcon1 = BLTCON1_DESC | BLTCON1_EFE;
+        blitter.bltapt = 0x102;
+        blitter.bltdpt = 0x202;
+        blitter.start((1 << 6) | 1);
+        blitter.run(&mem);
+        // assert
+        assert_eq!(0x0ff0, mem.get_chip_word(0x200));
+        assert_eq!(0x07f0, mem.get_chip_word(0x202));
+    }
+
+    #[test]
+    fn line_mode_draws_octant() {
+        // arrange
+        // From (1, 0) to (5, 2) in a 4 byte wide bitplane, dx = 4 and dy = 2. X is stepped
+        // right for every pixel, and Y down when the error term is positive.
+        let mem = blitter_test_setup(&[]);
+        let mut blitter = Blitter::new();
+        blitter.bltcon0 = 0x1bca; // ASH 1, USEA, USEC, USED, D = AB + !AC
+        blitter.bltcon1 = BLTCON1_SUD | BLTCON1_LINE;
+        blitter.bltadat = 0x8000;
+        blitter.bltbdat = 0xffff;
+        blitter.bltapt = (4 * 2 - 2 * 4) as u32;
+        blitter.bltamod = (4 * 2 - 4 * 4) as i16 as u16;
+        blitter.bltbmod = 4 * 2;
+        blitter.bltcpt = 0x100;
+        blitter.bltdpt = 0x100;
+        blitter.bltcmod = 4;
+        blitter.bltdmod = 4;
+        // act
+        blitter.start((5 << 6) | 2);
+        blitter.run(&mem);
+        // assert
+        assert_eq!(0x4000, mem.get_chip_word(0x100));
+        assert_eq!(0x3000, mem.get_chip_word(0x104));
+        assert_eq!(0x0c00, mem.get_chip_word(0x108));
+    }
+
+    #[test]
+    fn bltsize_write_starts_blit_when_blitter_dma_is_enabled() {
+        // arrange
+        let mem = blitter_test_setup(&[(0x200, 0xffff)]);
+        let mut custom_memory = CustomMemory::new(&CpuSpeed::PAL_7_093790_MHz, AgnusModel::Ocs);
+        let step_log = &mut StepLog::none();
+        custom_memory.set_word(step_log, 0xDFF040, 0x0100); // BLTCON0 USED, D = 0
+        custom_memory.set_word(step_log, 0xDFF056, 0x0200); // BLTDPTL
+        custom_memory.set_word(step_log, 0xDFF058, (1 << 6) | 1); // BLTSIZE
+                                                                  // act
+        custom_memory.step_color_clock(&mem);
+        let dmaconr_dma_disabled = custom_memory.read_dmacon_bits(step_log);
+        custom_memory.set_word(step_log, 0xDFF096, 0x8240); // DMACON SET|DMAEN|BLTEN
+        custom_memory.step_color_clock(&mem);
+        let dmaconr = custom_memory.read_dmacon_bits(step_log);
+        // assert
+        assert_eq!(DMACONR_BBUSY, dmaconr_dma_disabled & DMACONR_BBUSY);
+        assert_eq!(0x0000, mem.get_chip_word(0x200));
+        assert_eq!(DMACONR_BZERO, dmaconr & (DMACONR_BBUSY | DMACONR_BZERO));
+        assert_eq!(INTREQ_BLIT, custom_memory.intreq & INTREQ_BLIT);
+    }
+}
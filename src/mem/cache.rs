@@ -0,0 +1,171 @@
+// The 256 byte on-chip instruction and data caches of the 68020/68030. Both are direct mapped,
+// with 16 lines of four long words, and tagged with the logical address and the supervisor bit
+// of the function code. The data cache is write through, a write only updates the long words
+// that are already cached.
+
+pub const CACR_ENABLE_INSTRUCTION_CACHE: u32 = 0x00000001;
+pub const CACR_FREEZE_INSTRUCTION_CACHE: u32 = 0x00000002;
+pub const CACR_CLEAR_ENTRY_INSTRUCTION_CACHE: u32 = 0x00000004;
+pub const CACR_CLEAR_INSTRUCTION_CACHE: u32 = 0x00000008;
+// The 68030 only
+pub const CACR_ENABLE_DATA_CACHE: u32 = 0x00000100;
+pub const CACR_FREEZE_DATA_CACHE: u32 = 0x00000200;
+pub const CACR_CLEAR_ENTRY_DATA_CACHE: u32 = 0x00000400;
+pub const CACR_CLEAR_DATA_CACHE: u32 = 0x00000800;
+
+const CACHE_LINE_COUNT: usize = 16;
+const CACHE_LINE_LONG_COUNT: usize = 4;
+
+#[derive(Copy, Clone, Default)]
+struct CacheLine {
+    tag: u32,
+    valid: [bool; CACHE_LINE_LONG_COUNT],
+    data: [u32; CACHE_LINE_LONG_COUNT],
+}
+
+pub struct Cache {
+    lines: [CacheLine; CACHE_LINE_COUNT],
+    enabled: bool,
+    frozen: bool,
+}
+
+impl Cache {
+    pub fn new() -> Cache {
+        Cache {
+            lines: [CacheLine::default(); CACHE_LINE_COUNT],
+            enabled: false,
+            frozen: false,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    // A frozen cache is still read, but not filled
+    pub fn set_enabled(&mut self, enabled: bool, frozen: bool) {
+        self.enabled = enabled;
+        self.frozen = frozen;
+    }
+
+    fn get_tag(function_code: u8, address: u32) -> u32 {
+        ((address >> 8) << 1) | ((function_code >> 2) & 0b1) as u32
+    }
+
+    fn get_line_index(address: u32) -> usize {
+        ((address >> 4) as usize) % CACHE_LINE_COUNT
+    }
+
+    fn get_long_index(address: u32) -> usize {
+        ((address >> 2) as usize) % CACHE_LINE_LONG_COUNT
+    }
+
+    fn get_byte(&self, function_code: u8, address: u32) -> Option<u8> {
+        let line = &self.lines[Cache::get_line_index(address)];
+        let long_index = Cache::get_long_index(address);
+        match line.tag == Cache::get_tag(function_code, address) && line.valid[long_index] {
+            true => Some((line.data[long_index] >> ((3 - (address & 0b11)) * 8)) as u8),
+            false => None,
+        }
+    }
+
+    // The value is only cached when all of its bytes are
+    pub fn get(&self, function_code: u8, address: u32, size_in_bytes: u32) -> Option<u32> {
+        let mut value = 0;
+        for i in 0..size_in_bytes {
+            let byte = self.get_byte(function_code, address.wrapping_add(i))?;
+            value = (value << 8) | byte as u32;
+        }
+        Some(value)
+    }
+
+    // Fills the long word at an address. A different tag replaces the whole line.
+    pub fn fill(&mut self, function_code: u8, address: u32, value: u32) {
+        if self.frozen {
+            return;
+        }
+        let tag = Cache::get_tag(function_code, address);
+        let line = &mut self.lines[Cache::get_line_index(address)];
+        if line.tag != tag {
+            line.tag = tag;
+            line.valid = [false; CACHE_LINE_LONG_COUNT];
+        }
+        let long_index = Cache::get_long_index(address);
+        line.data[long_index] = value;
+        line.valid[long_index] = true;
+    }
+
+    pub fn update(&mut self, function_code: u8, address: u32, value: u32, size_in_bytes: u32) {
+        for i in 0..size_in_bytes {
+            let address = address.wrapping_add(i);
+            let line = &mut self.lines[Cache::get_line_index(address)];
+            let long_index = Cache::get_long_index(address);
+            if line.tag == Cache::get_tag(function_code, address) && line.valid[long_index] {
+                let shift = (3 - (address & 0b11)) * 8;
+                let byte = (value >> ((size_in_bytes - 1 - i) * 8)) & 0xff;
+                line.data[long_index] =
+                    (line.data[long_index] & !(0xff << shift)) | (byte << shift);
+            }
+        }
+    }
+
+    pub fn invalidate_all(&mut self) {
+        for line in self.lines.iter_mut() {
+            line.valid = [false; CACHE_LINE_LONG_COUNT];
+        }
+    }
+
+    // The entry selected by CAAR, index bits 7-4 and long word bits 3-2
+    pub fn invalidate_entry(&mut self, address: u32) {
+        self.lines[Cache::get_line_index(address)].valid[Cache::get_long_index(address)] = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::mmu::{FUNCTION_CODE_SUPERVISOR_DATA, FUNCTION_CODE_USER_DATA};
+
+    #[test]
+    fn filled_long_is_read_back_with_the_same_function_code() {
+        // arrange
+        let mut cache = Cache::new();
+        cache.set_enabled(true, false);
+        // act
+        cache.fill(FUNCTION_CODE_SUPERVISOR_DATA, 0x00001234, 0x11223344);
+        // assert
+        assert_eq!(
+            Some(0x2233),
+            cache.get(FUNCTION_CODE_SUPERVISOR_DATA, 0x00001235, 2)
+        );
+        assert_eq!(None, cache.get(FUNCTION_CODE_USER_DATA, 0x00001234, 4));
+        assert_eq!(
+            None,
+            cache.get(FUNCTION_CODE_SUPERVISOR_DATA, 0x00001236, 4)
+        );
+    }
+
+    #[test]
+    fn write_updates_cached_bytes_and_fill_of_other_tag_replaces_line() {
+        // arrange
+        let mut cache = Cache::new();
+        cache.set_enabled(true, false);
+        cache.fill(FUNCTION_CODE_USER_DATA, 0x00001230, 0x11223344);
+        cache.fill(FUNCTION_CODE_USER_DATA, 0x00001234, 0x55667788);
+        // act
+        cache.update(FUNCTION_CODE_USER_DATA, 0x00001233, 0xaabb, 2);
+        // assert
+        assert_eq!(
+            Some(0x112233aa),
+            cache.get(FUNCTION_CODE_USER_DATA, 0x00001230, 4)
+        );
+        assert_eq!(
+            Some(0xbb667788),
+            cache.get(FUNCTION_CODE_USER_DATA, 0x00001234, 4)
+        );
+        // act
+        cache.fill(FUNCTION_CODE_USER_DATA, 0x00002230, 0x99999999);
+        // assert
+        assert_eq!(None, cache.get(FUNCTION_CODE_USER_DATA, 0x00001234, 4));
+    }
+}
@@ -120,7 +120,10 @@ impl CiaChip {
             0x0b => 0x00,
             0x0c => self.sp,
             0x0d => {
-                let result = self.icr_data;
+                let result = match self.is_interrupt_requested() {
+                    true => self.icr_data | 0x80, // IR
+                    false => self.icr_data,
+                };
                 unsafe {
                     // This is a pain - we actually write to a register when reading from it
                     // which breaks the entire pattern of using mut only for write calls.
@@ -194,6 +197,11 @@ impl CiaChip {
         }
     }
 
+    // The /IRQ line is active as long as any ICR data bit is enabled in the ICR mask
+    fn is_interrupt_requested(&self) -> bool {
+        self.icr_data & self.icr_mask & 0x1f != 0x00
+    }
+
     // Simulate a single clock cycle
     pub fn step_clock_cycle(&mut self) {
         // Timer A
@@ -228,9 +236,11 @@ impl CiaChip {
                 self.event_queue.push_back("Timer B Interrupt".to_string());
             }
         }
+    }
 
-        // TOD
-        self.tod += 1;
+    // The 24 bit TOD counter is clocked by vertical sync on CIA-A and horizontal sync on CIA-B
+    fn step_tod(&mut self) {
+        self.tod = (self.tod + 1) & 0x00ffffff;
     }
 }
 
@@ -370,6 +380,24 @@ impl CiaMemory {
         self.cia_b.step_clock_cycle();
     }
 
+    pub fn step_vertical_sync(&mut self) {
+        self.cia_a.step_tod();
+    }
+
+    pub fn step_horizontal_sync(&mut self) {
+        self.cia_b.step_tod();
+    }
+
+    // CIA-A /IRQ is connected to INT2 (PORTS)
+    pub fn is_cia_a_interrupt_requested(&self) -> bool {
+        self.cia_a.is_interrupt_requested()
+    }
+
+    // CIA-B /IRQ is connected to INT6 (EXTER)
+    pub fn is_cia_b_interrupt_requested(&self) -> bool {
+        self.cia_b.is_interrupt_requested()
+    }
+
     pub fn is_cia_memory(address: u32) -> bool {
         match address {
             0x00bf0000..=0x00bfffff => true,
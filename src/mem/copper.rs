@@ -0,0 +1,237 @@
+use crate::mem::Mem;
+
+// The Copper. It fetches one word of its instruction stream from chip RAM on each of its DMA
+// slots (the even color clocks of a line), and is restarted from COP1LC at vertical blank.
+//  - MOVE writes the second word to a custom register
+//  - WAIT stops the Copper until the beam reaches a position, and unless the blitter finished
+//    disable bit is set, until the blitter is done
+//  - SKIP skips the next instruction if the beam has reached a position
+// Registers below $40 can never be written, and $40-$7E only when CDANG is set in COPCON. A
+// MOVE to a protected register stops the Copper until it's restarted.
+
+pub const COPCON_CDANG: u16 = 0x0002;
+
+const COPPER_WAIT_BLITTER_FINISHED_DISABLE: u16 = 0x8000;
+
+#[derive(Debug, PartialEq, Eq)]
+enum CopperState {
+    Stopped,
+    FetchFirstWord,
+    FetchSecondWord,
+    Wait,
+}
+
+pub struct Copper {
+    pub cop1lc: u32,
+    pub cop2lc: u32,
+    pub copcon: u16,
+    pc: u32,
+    state: CopperState,
+    ir1: u16,
+    ir2: u16,
+}
+
+impl Copper {
+    pub fn new() -> Copper {
+        Copper {
+            cop1lc: 0x00000000,
+            cop2lc: 0x00000000,
+            copcon: 0x0000,
+            pc: 0x00000000,
+            state: CopperState::Stopped,
+            ir1: 0x0000,
+            ir2: 0x0000,
+        }
+    }
+
+    pub fn get_pc(&self) -> u32 {
+        self.pc
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.state == CopperState::Stopped
+    }
+
+    // COPJMP1/COPJMP2 and vertical blank
+    pub fn jump(&mut self, address: u32) {
+        self.pc = address;
+        self.state = CopperState::FetchFirstWord;
+    }
+
+    fn is_register_protected(&self, register: u16) -> bool {
+        match register {
+            0x000..=0x03f => true,
+            0x040..=0x07f => self.copcon & COPCON_CDANG != COPCON_CDANG,
+            _ => false,
+        }
+    }
+
+    // The vertical position is compared with V7-V0 only, and the horizontal with H8-H2. V7 is
+    // always compared, bit 15 of the second word is the blitter finished disable bit.
+    fn is_beam_position_reached(&self, vpos: u16, hpos: u16) -> bool {
+        let beam = ((vpos & 0x00ff) << 8) | (hpos & 0x00fe);
+        let mask = (self.ir2 & 0x7ffe) | 0x8000;
+        beam & mask >= self.ir1 & mask
+    }
+
+    fn fetch_word(&mut self, mem: &Mem) -> u16 {
+        let word = mem.get_chip_word(self.pc);
+        self.pc = self.pc.wrapping_add(2);
+        word
+    }
+
+    // Runs one DMA slot, and returns the register and value of a MOVE
+    pub fn step_dma_slot(
+        &mut self,
+        mem: &Mem,
+        vpos: u16,
+        hpos: u16,
+        blitter_busy: bool,
+    ) -> Option<(u16, u16)> {
+        match self.state {
+            CopperState::Stopped => None,
+            CopperState::FetchFirstWord => {
+                self.ir1 = self.fetch_word(mem);
+                self.state = CopperState::FetchSecondWord;
+                None
+            }
+            CopperState::FetchSecondWord => {
+                self.ir2 = self.fetch_word(mem);
+                self.state = CopperState::FetchFirstWord;
+                match (self.ir1 & 0x0001, self.ir2 & 0x0001) {
+                    (0, _) => {
+                        // MOVE
+                        let register = self.ir1 & 0x01fe;
+                        if self.is_register_protected(register) {
+                            self.state = CopperState::Stopped;
+                            return None;
+                        }
+                        Some((register, self.ir2))
+                    }
+                    (_, 0) => {
+                        // WAIT
+                        self.state = CopperState::Wait;
+                        None
+                    }
+                    _ => {
+                        // SKIP
+                        if self.is_beam_position_reached(vpos, hpos) {
+                            self.pc = self.pc.wrapping_add(4);
+                        }
+                        None
+                    }
+                }
+            }
+            CopperState::Wait => {
+                let blitter_finished = !blitter_busy
+                    || self.ir2 & COPPER_WAIT_BLITTER_FINISHED_DISABLE
+                        == COPPER_WAIT_BLITTER_FINISHED_DISABLE;
+                if blitter_finished && self.is_beam_position_reached(vpos, hpos) {
+                    self.state = CopperState::FetchFirstWord;
+                }
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cpu::step_log::StepLog;
+    use crate::cpu::CpuSpeed;
+    use crate::mem::beamcounter::AgnusModel;
+    use crate::mem::custommemory::{CustomMemory, INTREQ_COPER};
+    use crate::mem::memory::Memory;
+    use crate::mem::rammemory::RamMemory;
+    use crate::mem::Mem;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const COPPER_LIST_ADDRESS: u32 = 0x00000100;
+
+    fn copper_test_setup(copper_list: &[u16]) -> (Mem, CustomMemory) {
+        let mut mem = Mem::new(None, None);
+        let chip_ram = RamMemory::from_range(0x00000000, 0x000003ff);
+        mem.add_range(Rc::new(RefCell::new(chip_ram)));
+        for (i, word) in copper_list.iter().enumerate() {
+            mem.set_chip_word(COPPER_LIST_ADDRESS + 2 * i as u32, *word);
+        }
+        let mut custom_memory = CustomMemory::new(&CpuSpeed::PAL_7_093790_MHz, AgnusModel::Ocs);
+        let step_log = &mut StepLog::none();
+        custom_memory.set_word(step_log, 0xDFF080, 0x0000); // COP1LCH
+        custom_memory.set_word(step_log, 0xDFF082, COPPER_LIST_ADDRESS as u16); // COP1LCL
+        custom_memory.set_word(step_log, 0xDFF096, 0x8280); // DMACON SET|DMAEN|COPEN
+        custom_memory.set_word(step_log, 0xDFF088, 0x0000); // COPJMP1
+        (mem, custom_memory)
+    }
+
+    fn step_color_clocks(mem: &Mem, custom_memory: &mut CustomMemory, color_clocks: u32) {
+        for _ in 0..color_clocks {
+            custom_memory.step_color_clock(mem);
+        }
+    }
+
+    #[test]
+    fn copper_move_writes_register() {
+        // arrange
+        let copper_list = [0x0180, 0x0f00, 0xffff, 0xfffe]; // MOVE #$0F00,COLOR00 ; WAIT end
+        let (mem, mut custom_memory) = copper_test_setup(&copper_list);
+        // act
+        step_color_clocks(&mem, &mut custom_memory, 4);
+        // assert
+        assert_eq!(0x0f00, custom_memory.color_rgb4[0]);
+    }
+
+    #[test]
+    fn copper_wait_for_line_then_move_raises_coper() {
+        // arrange
+        let copper_list = [
+            0x0a01, 0xff00, // WAIT line $0A
+            0x009c, 0x8010, // MOVE #$8010,INTREQ
+            0xffff, 0xfffe, // WAIT end
+        ];
+        let (mem, mut custom_memory) = copper_test_setup(&copper_list);
+        // act
+        step_color_clocks(&mem, &mut custom_memory, 9 * 0xe3);
+        let intreq_before_line = custom_memory.intreq;
+        step_color_clocks(&mem, &mut custom_memory, 0xe3 + 8);
+        // assert
+        assert_eq!(0x0000, intreq_before_line & INTREQ_COPER);
+        assert_eq!(INTREQ_COPER, custom_memory.intreq & INTREQ_COPER);
+    }
+
+    #[test]
+    fn copper_skip_skips_next_instruction_when_beam_is_past_position() {
+        // arrange
+        let copper_list = [
+            0x0001, 0xff01, // SKIP line 0
+            0x0180, 0x0f00, // MOVE #$0F00,COLOR00
+            0x0182, 0x00f0, // MOVE #$00F0,COLOR01
+        ];
+        let (mem, mut custom_memory) = copper_test_setup(&copper_list);
+        // act
+        step_color_clocks(&mem, &mut custom_memory, 12);
+        // assert
+        assert_eq!(0x0000, custom_memory.color_rgb4[0]);
+        assert_eq!(0x00f0, custom_memory.color_rgb4[1]);
+    }
+
+    #[test]
+    fn copper_move_to_protected_register_stops_copper_unless_cdang() {
+        // arrange
+        let copper_list = [
+            0x0040, 0x09f0, // MOVE #$09F0,BLTCON0
+            0x0180, 0x0f00, // MOVE #$0F00,COLOR00
+        ];
+        let (mem, mut custom_memory) = copper_test_setup(&copper_list);
+        // act
+        step_color_clocks(&mem, &mut custom_memory, 8);
+        let stopped = custom_memory.copper.is_stopped();
+        custom_memory.set_word(&mut StepLog::none(), 0xDFF02E, 0x0002); // COPCON CDANG
+        custom_memory.set_word(&mut StepLog::none(), 0xDFF088, 0x0000); // COPJMP1
+        step_color_clocks(&mem, &mut custom_memory, 8);
+        // assert
+        assert!(stopped);
+        assert_eq!(0x0f00, custom_memory.color_rgb4[0]);
+    }
+}
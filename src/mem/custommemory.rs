@@ -1,13 +1,39 @@
-use crate::cpu::{step_log::StepLog, Cpu};
+use crate::cpu::{step_log::StepLog, Cpu, CpuSpeed};
 
+use super::beamcounter::{AgnusModel, BeamCounter, BeamEvent};
+use super::blitter::Blitter;
+use super::copper::Copper;
+use super::denise::{Denise, BPLCON0_LACE};
 use super::memory::{Memory, SetMemoryResult};
+use super::Mem;
 use std::{any::Any, fmt};
 
+pub const INTENA_INTEN: u16 = 0x4000;
+pub const INTREQ_EXTER: u16 = 0x2000;
+pub const INTREQ_BLIT: u16 = 0x0040;
+pub const INTREQ_VERTB: u16 = 0x0020;
+pub const INTREQ_COPER: u16 = 0x0010;
+pub const INTREQ_PORTS: u16 = 0x0008;
+
+pub const DMACONR_BBUSY: u16 = 0x4000;
+pub const DMACONR_BZERO: u16 = 0x2000;
+pub const DMACON_DMAEN: u16 = 0x0200;
+pub const DMACON_BPLEN: u16 = 0x0100;
+pub const DMACON_COPEN: u16 = 0x0080;
+pub const DMACON_BLTEN: u16 = 0x0040;
+pub const DMACON_SPREN: u16 = 0x0020;
+
+// Chip RAM pointers are word aligned, and limited to the 2 MB the ECS Agnus can address
+pub const CHIP_RAM_POINTER_MASK: u32 = 0x001ffffe;
+
 pub struct CustomMemory {
     pub dmacon: u16, // 096 / 002
-    pub vhpos: u32,  // --- / 004-006
+    pub beam_counter: BeamCounter, // 02A-02C / 004-006
     pub intena: u16, // 09A / 01C
     pub intreq: u16, // 09C / 01E
+    pub copper: Copper, // 02E, 080-08A / ---
+    pub blitter: Blitter, // 040-074 / ---
+    pub denise: Denise, // 08E-098, 0E0-17E / 00E
     pub color_rgb4: [u16; 32],
 }
 
@@ -66,13 +92,15 @@ impl Memory for CustomMemory {
             }
             0xDFF004 => {
                 // VPOSR
-                // step_log.add_log_sting("CUSTOM: TODO: Reading VPOSR".to_string());
-                (self.vhpos >> 16) as u16
+                self.beam_counter.read_vposr()
             }
             0xDFF006 => {
                 // VHPOSR
-                // step_log.add_log_sting("CUSTOM: TODO: Reading VHPOSR".to_string());
-                (self.vhpos & 0x0000ffff) as u16
+                self.beam_counter.read_vhposr()
+            }
+            0xDFF00E => {
+                // CLXDAT
+                self.denise.read_clxdat()
             }
             0xDFF01C => {
                 // INTENAR
@@ -133,6 +161,155 @@ impl Memory for CustomMemory {
                 step_log.add_log_string("CUSTOM: TODO: Writing INTREQR, nothingness".to_string());
                 ()
             }
+            0xDFF02A => {
+                // VPOSW
+                step_log.add_log_string(format!("CUSTOM: Writing VPOSW ${:04X}", value));
+                self.beam_counter.write_vposw(value);
+            }
+            0xDFF02C => {
+                // VHPOSW
+                step_log.add_log_string(format!("CUSTOM: Writing VHPOSW ${:04X}", value));
+                self.beam_counter.write_vhposw(value);
+            }
+            0xDFF02E => {
+                // COPCON
+                self.copper.copcon = value;
+            }
+            0xDFF040 => {
+                // BLTCON0
+                self.blitter.bltcon0 = value;
+            }
+            0xDFF042 => {
+                // BLTCON1
+                self.blitter.bltcon1 = value;
+            }
+            0xDFF044 => {
+                // BLTAFWM
+                self.blitter.bltafwm = value;
+            }
+            0xDFF046 => {
+                // BLTALWM
+                self.blitter.bltalwm = value;
+            }
+            0xDFF048 => {
+                // BLTCPTH
+                self.blitter.bltcpt = Self::set_pointer_high(self.blitter.bltcpt, value);
+            }
+            0xDFF04A => {
+                // BLTCPTL
+                self.blitter.bltcpt = Self::set_pointer_low(self.blitter.bltcpt, value);
+            }
+            0xDFF04C => {
+                // BLTBPTH
+                self.blitter.bltbpt = Self::set_pointer_high(self.blitter.bltbpt, value);
+            }
+            0xDFF04E => {
+                // BLTBPTL
+                self.blitter.bltbpt = Self::set_pointer_low(self.blitter.bltbpt, value);
+            }
+            0xDFF050 => {
+                // BLTAPTH
+                self.blitter.bltapt = Self::set_pointer_high(self.blitter.bltapt, value);
+            }
+            0xDFF052 => {
+                // BLTAPTL
+                self.blitter.bltapt = Self::set_pointer_low(self.blitter.bltapt, value);
+            }
+            0xDFF054 => {
+                // BLTDPTH
+                self.blitter.bltdpt = Self::set_pointer_high(self.blitter.bltdpt, value);
+            }
+            0xDFF056 => {
+                // BLTDPTL
+                self.blitter.bltdpt = Self::set_pointer_low(self.blitter.bltdpt, value);
+            }
+            0xDFF058 => {
+                // BLTSIZE
+                step_log.add_log_string(format!(
+                    "CUSTOM: Starting blit, BLTCON0 ${:04X} BLTCON1 ${:04X} BLTSIZE ${:04X}",
+                    self.blitter.bltcon0, self.blitter.bltcon1, value
+                ));
+                self.blitter.start(value);
+            }
+            0xDFF060 => {
+                // BLTCMOD
+                self.blitter.bltcmod = value;
+            }
+            0xDFF062 => {
+                // BLTBMOD
+                self.blitter.bltbmod = value;
+            }
+            0xDFF064 => {
+                // BLTAMOD
+                self.blitter.bltamod = value;
+            }
+            0xDFF066 => {
+                // BLTDMOD
+                self.blitter.bltdmod = value;
+            }
+            0xDFF070 => {
+                // BLTCDAT
+                self.blitter.bltcdat = value;
+            }
+            0xDFF072 => {
+                // BLTBDAT
+                self.blitter.bltbdat = value;
+            }
+            0xDFF074 => {
+                // BLTADAT
+                self.blitter.bltadat = value;
+            }
+            0xDFF080 => {
+                // COP1LCH
+                self.copper.cop1lc = Self::set_pointer_high(self.copper.cop1lc, value);
+            }
+            0xDFF082 => {
+                // COP1LCL
+                self.copper.cop1lc = Self::set_pointer_low(self.copper.cop1lc, value);
+            }
+            0xDFF084 => {
+                // COP2LCH
+                self.copper.cop2lc = Self::set_pointer_high(self.copper.cop2lc, value);
+            }
+            0xDFF086 => {
+                // COP2LCL
+                self.copper.cop2lc = Self::set_pointer_low(self.copper.cop2lc, value);
+            }
+            0xDFF088 => {
+                // COPJMP1
+                step_log.add_log_string(format!(
+                    "CUSTOM: Copper jump to COP1LC ${:06X}",
+                    self.copper.cop1lc
+                ));
+                self.copper.jump(self.copper.cop1lc);
+            }
+            0xDFF08A => {
+                // COPJMP2
+                step_log.add_log_string(format!(
+                    "CUSTOM: Copper jump to COP2LC ${:06X}",
+                    self.copper.cop2lc
+                ));
+                self.copper.jump(self.copper.cop2lc);
+            }
+            0xDFF08C => {
+                // COPINS, only used by the Copper itself
+            }
+            0xDFF08E => {
+                // DIWSTRT
+                self.denise.diwstrt = value;
+            }
+            0xDFF090 => {
+                // DIWSTOP
+                self.denise.diwstop = value;
+            }
+            0xDFF092 => {
+                // DDFSTRT
+                self.denise.ddfstrt = value;
+            }
+            0xDFF094 => {
+                // DDFSTOP
+                self.denise.ddfstop = value;
+            }
             0xDFF096 => {
                 // DMACON
                 match value & 0x8000 {
@@ -144,6 +321,10 @@ impl Memory for CustomMemory {
                     }
                 }
             }
+            0xDFF098 => {
+                // CLXCON
+                self.denise.clxcon = value;
+            }
             0xDFF09A => {
                 // INTENA
                 match value & 0x8000 {
@@ -166,9 +347,54 @@ impl Memory for CustomMemory {
                     }
                 }
             }
-            // 0xDFF100 => {
-            //     // BPLCON0
-            // }
+            0xDFF0E0..=0xDFF0F6 => {
+                // BPL1PTH-BPL6PTL
+                let plane = (address as usize - 0xDFF0E0) / 4;
+                let pointer = self.denise.bplpt[plane];
+                self.denise.bplpt[plane] = match address & 0x2 {
+                    0 => Self::set_pointer_high(pointer, value),
+                    _ => Self::set_pointer_low(pointer, value),
+                };
+            }
+            0xDFF100 => {
+                // BPLCON0
+                self.denise.bplcon0 = value;
+                self.beam_counter.set_interlace(value & BPLCON0_LACE == BPLCON0_LACE);
+            }
+            0xDFF102 => {
+                // BPLCON1
+                self.denise.bplcon1 = value;
+            }
+            0xDFF104 => {
+                // BPLCON2
+                self.denise.bplcon2 = value;
+            }
+            0xDFF108 => {
+                // BPL1MOD
+                self.denise.bpl1mod = value;
+            }
+            0xDFF10A => {
+                // BPL2MOD
+                self.denise.bpl2mod = value;
+            }
+            0xDFF120..=0xDFF13E => {
+                // SPR0PTH-SPR7PTL
+                let sprite = &mut self.denise.sprites[(address as usize - 0xDFF120) / 4];
+                sprite.pt = match address & 0x2 {
+                    0 => Self::set_pointer_high(sprite.pt, value),
+                    _ => Self::set_pointer_low(sprite.pt, value),
+                };
+            }
+            0xDFF140..=0xDFF17E => {
+                // SPRxPOS, SPRxCTL, SPRxDATA and SPRxDATB
+                let sprite = &mut self.denise.sprites[(address as usize - 0xDFF140) / 8];
+                match address & 0x6 {
+                    0x0 => sprite.write_pos(value),
+                    0x2 => sprite.write_ctl(value),
+                    0x4 => sprite.write_data(value),
+                    _ => sprite.write_datb(value),
+                }
+            }
             0xDFF180..=0xDFF1Be => {
                 // COLORxx
                 let color_index = (address as usize - 0xDFF180) / 2;
@@ -200,12 +426,15 @@ impl Memory for CustomMemory {
 }
 
 impl CustomMemory {
-    pub fn new() -> CustomMemory {
+    pub fn new(cpu_speed: &CpuSpeed, agnus_model: AgnusModel) -> CustomMemory {
         CustomMemory {
             dmacon: 0x0000,
-            vhpos: 0x00000000,
+            beam_counter: BeamCounter::new(cpu_speed, agnus_model),
             intena: 0x0000,
             intreq: 0x0000,
+            copper: Copper::new(),
+            blitter: Blitter::new(),
+            denise: Denise::new(),
             color_rgb4: [0x0000; 32],
         }
     }
@@ -275,8 +504,15 @@ impl CustomMemory {
         self.dmacon = dmacon;
     }
 
+    // BBUSY and BZERO are the status of the blitter
     pub fn read_dmacon_bits(&self, step_log: &mut StepLog) -> u16 {
-        let result = self.dmacon & 0x7fff;
+        let mut result = self.dmacon & 0x7fff & !(DMACONR_BBUSY | DMACONR_BZERO);
+        if self.blitter.is_busy() {
+            result |= DMACONR_BBUSY;
+        }
+        if self.blitter.is_zero() {
+            result |= DMACONR_BZERO;
+        }
         step_log.add_log_string(format!("CUSTOM: Reading DMACONR, returns ${:04X}", result));
         result
     }
@@ -343,6 +579,85 @@ impl CustomMemory {
         result
     }
 
+    fn set_pointer_high(pointer: u32, value: u16) -> u32 {
+        ((pointer & 0x0000ffff) | ((value as u32) << 16)) & CHIP_RAM_POINTER_MASK
+    }
+
+    fn set_pointer_low(pointer: u32, value: u16) -> u32 {
+        ((pointer & 0xffff0000) | value as u32) & CHIP_RAM_POINTER_MASK
+    }
+
+    fn is_dma_enabled(&self, channel: u16) -> bool {
+        let bits = DMACON_DMAEN | channel;
+        self.dmacon & bits == bits
+    }
+
+    pub fn is_blitter_busy(&self) -> bool {
+        self.blitter.is_busy()
+    }
+
+    // Advances the beam counter by one color clock. The vertical blank interrupt is requested,
+    // and the Copper restarted, when the beam reaches line 0. The DMA of the custom chips reads
+    // and writes chip RAM through the memory map. Each line is rendered when the beam has passed
+    // it. A started blit is run at once, and requests the blitter finished interrupt.
+    pub fn step_color_clock(&mut self, mem: &Mem) -> BeamEvent {
+        let line = self.beam_counter.get_vpos();
+        let long_frame = self.beam_counter.is_long_frame();
+        let beam_event = self.beam_counter.step_color_clock();
+        if beam_event != BeamEvent::None {
+            let bitplane_dma = self.is_dma_enabled(DMACON_BPLEN);
+            let sprite_dma = self.is_dma_enabled(DMACON_SPREN);
+            let palette = &self.color_rgb4;
+            self.denise
+                .render_line(mem, line, long_frame, palette, bitplane_dma, sprite_dma);
+        }
+        if beam_event == BeamEvent::StartOfFrame {
+            self.denise.end_frame();
+            self.request_interrupt(INTREQ_VERTB);
+            self.copper.jump(self.copper.cop1lc);
+        }
+
+        let vpos = self.beam_counter.get_vpos();
+        let hpos = self.beam_counter.get_hpos();
+        if hpos & 0x0001 == 0 && self.is_dma_enabled(DMACON_COPEN) {
+            let blitter_busy = self.is_blitter_busy();
+            if let Some((register, value)) =
+                self.copper.step_dma_slot(mem, vpos, hpos, blitter_busy)
+            {
+                self.set_word(&mut StepLog::none(), 0xDFF000 + register as u32, value);
+            }
+        }
+        if self.blitter.is_busy() && self.is_dma_enabled(DMACON_BLTEN) {
+            self.blitter.run(mem);
+            self.request_interrupt(INTREQ_BLIT);
+        }
+        beam_event
+    }
+
+    // Used by the hardware (CIA, Agnus, etc) to request interrupts, as opposed to the CPU
+    // writing to INTREQ
+    pub fn request_interrupt(&mut self, bits: u16) {
+        self.intreq |= bits & 0x3fff;
+    }
+
+    // Maps the pending and enabled interrupts to the level put on the CPU IPL pins, according
+    // to the table below
+    pub fn get_interrupt_priority_level(&self) -> u8 {
+        if self.intena & INTENA_INTEN != INTENA_INTEN {
+            return 0;
+        }
+        let pending = self.intreq & self.intena & 0x3fff;
+        match pending {
+            0x2000..=0x3fff => 6, // EXTER
+            0x0800..=0x1fff => 5, // DSKSYN, RBF
+            0x0080..=0x07ff => 4, // AUD0-AUD3
+            0x0010..=0x007f => 3, // COPER, VERTB, BLIT
+            0x0008..=0x000f => 2, // PORTS
+            0x0001..=0x0007 => 1, // TBE, DSKBLK, SOFT
+            _ => 0,
+        }
+    }
+
 
     // 14    INTEN       Master interrupt (enable only,
     //                                     no request)
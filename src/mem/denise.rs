@@ -0,0 +1,482 @@
+use crate::mem::sprite::{get_collision_bits, get_sprite_color, Sprite};
+use crate::mem::Mem;
+use std::cell::Cell;
+use std::fs::File;
+use std::io::BufWriter;
+
+// Bitplane DMA and the Denise display output. A line is rendered when the beam has passed it,
+// so register changes by the Copper take effect from the next line.
+//  - Agnus fetches the bitplanes on the lines inside the vertical display window, as many words
+//    per line as DDFSTRT/DDFSTOP give, and then adds BPL1MOD to the odd and BPL2MOD to the even
+//    bitplane pointers.
+//  - Denise shows the bitplanes inside the horizontal display window, delayed by the scroll
+//    values of BPLCON1. Outside the display window the background color (COLOR00) is shown.
+// The framebuffer covers the whole raster, including the blanking, in hires pixels and two rows
+// per line. Without interlace each line fills both rows, with interlace the long frame fills
+// the even rows and the short frame the odd rows.
+// Sprites are shown inside the display window, in front of or behind the playfield as given by
+// the PF2P priority code of BPLCON2, and always in front of the background.
+
+pub const BPLCON0_HIRES: u16 = 0x8000;
+pub const BPLCON0_HAM: u16 = 0x0800;
+pub const BPLCON0_DBLPF: u16 = 0x0400;
+pub const BPLCON0_LACE: u16 = 0x0004;
+
+// 227 color clocks of 4 hires pixels
+pub const FRAMEBUFFER_WIDTH: usize = 227 * 4;
+// The 313 lines of a PAL long frame
+pub const FRAMEBUFFER_HEIGHT: usize = 313 * 2;
+
+const MAX_PLANE_COUNT: usize = 6;
+
+pub struct Denise {
+    pub bplcon0: u16,
+    pub bplcon1: u16,
+    pub bplcon2: u16,
+    pub bpl1mod: u16,
+    pub bpl2mod: u16,
+    pub diwstrt: u16,
+    pub diwstop: u16,
+    pub ddfstrt: u16,
+    pub ddfstop: u16,
+    pub bplpt: [u32; MAX_PLANE_COUNT],
+    pub sprites: [Sprite; 8],
+    pub clxcon: u16,
+    clxdat: Cell<u16>,
+    framebuffer: Vec<u8>,
+    frame_count: u64,
+}
+
+impl Denise {
+    pub fn new() -> Denise {
+        Denise {
+            bplcon0: 0x0000,
+            bplcon1: 0x0000,
+            bplcon2: 0x0000,
+            bpl1mod: 0x0000,
+            bpl2mod: 0x0000,
+            diwstrt: 0x0000,
+            diwstop: 0x0000,
+            ddfstrt: 0x0000,
+            ddfstop: 0x0000,
+            bplpt: [0x00000000; MAX_PLANE_COUNT],
+            sprites: [Sprite::new(); 8],
+            clxcon: 0x0000,
+            clxdat: Cell::new(0x0000),
+            framebuffer: vec![0x00; FRAMEBUFFER_WIDTH * FRAMEBUFFER_HEIGHT * 3],
+            frame_count: 0,
+        }
+    }
+
+    pub fn get_frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    // Called at vertical blank, when the frame in the framebuffer is complete
+    pub fn end_frame(&mut self) {
+        self.frame_count += 1;
+        for sprite in self.sprites.iter_mut() {
+            sprite.restart_dma();
+        }
+    }
+
+    // Reading CLXDAT clears it
+    pub fn read_clxdat(&self) -> u16 {
+        self.clxdat.replace(0x0000)
+    }
+
+    pub fn get_framebuffer(&self) -> &[u8] {
+        &self.framebuffer
+    }
+
+    pub fn get_pixel_rgb(&self, x: usize, y: usize) -> (u8, u8, u8) {
+        let index = (y * FRAMEBUFFER_WIDTH + x) * 3;
+        (
+            self.framebuffer[index],
+            self.framebuffer[index + 1],
+            self.framebuffer[index + 2],
+        )
+    }
+
+    pub fn write_png(&self, file_path: &str) -> std::io::Result<()> {
+        let file = File::create(file_path)?;
+        let mut encoder = png::Encoder::new(
+            BufWriter::new(file),
+            FRAMEBUFFER_WIDTH as u32,
+            FRAMEBUFFER_HEIGHT as u32,
+        );
+        encoder.set_color(png::ColorType::RGB);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.framebuffer)?;
+        Ok(())
+    }
+
+    pub fn get_plane_count(&self) -> usize {
+        (((self.bplcon0 >> 12) & 0x0007) as usize).min(MAX_PLANE_COUNT)
+    }
+
+    fn is_hires(&self) -> bool {
+        self.bplcon0 & BPLCON0_HIRES == BPLCON0_HIRES
+    }
+
+    fn is_ham(&self) -> bool {
+        self.bplcon0 & BPLCON0_HAM == BPLCON0_HAM
+    }
+
+    // Extra half brite, six bitplanes without HAM or dual playfield
+    fn is_extra_half_brite(&self) -> bool {
+        self.get_plane_count() == 6 && self.bplcon0 & (BPLCON0_HAM | BPLCON0_DBLPF) == 0
+    }
+
+    pub fn is_interlace(&self) -> bool {
+        self.bplcon0 & BPLCON0_LACE == BPLCON0_LACE
+    }
+
+    // In lores pixels, the horizontal stop has an implied H8 and the vertical stop an implied
+    // V8 that is the inverse of V7
+    fn get_display_window(&self) -> (u16, u16, u16, u16) {
+        let hstart = self.diwstrt & 0x00ff;
+        let vstart = self.diwstrt >> 8;
+        let hstop = (self.diwstop & 0x00ff) | 0x0100;
+        let vstop = match self.diwstop & 0x8000 {
+            0 => (self.diwstop >> 8) | 0x0100,
+            _ => self.diwstop >> 8,
+        };
+        (hstart, vstart, hstop, vstop)
+    }
+
+    fn get_fetch_word_count(&self) -> usize {
+        let ddfstrt = self.ddfstrt & 0x00fc;
+        let ddfstop = (self.ddfstop & 0x00fc).max(ddfstrt);
+        match self.is_hires() {
+            true => ((ddfstop - ddfstrt) / 4 + 2) as usize,
+            false => ((ddfstop - ddfstrt) / 8 + 1) as usize,
+        }
+    }
+
+    // The lores pixel where the first fetched bitplane pixel is shown. DDFSTRT $38 (lores) and
+    // $3C (hires) line up with DIWSTRT $81.
+    fn get_first_pixel_position(&self) -> i32 {
+        let ddfstrt = (self.ddfstrt & 0x00fc) as i32;
+        match self.is_hires() {
+            true => ddfstrt * 2 + 9,
+            false => ddfstrt * 2 + 17,
+        }
+    }
+
+    fn fetch_bitplanes(&mut self, mem: &Mem) -> Vec<Vec<u16>> {
+        let word_count = self.get_fetch_word_count();
+        let mut planes = Vec::new();
+        for plane in 0..self.get_plane_count() {
+            let mut words = Vec::with_capacity(word_count);
+            for _ in 0..word_count {
+                words.push(mem.get_chip_word(self.bplpt[plane]));
+                self.bplpt[plane] = self.bplpt[plane].wrapping_add(2);
+            }
+            let modulo = match plane % 2 {
+                0 => self.bpl1mod,
+                _ => self.bpl2mod,
+            };
+            let modulo = (modulo & 0xfffe) as i16 as i32 as u32;
+            self.bplpt[plane] = self.bplpt[plane].wrapping_add(modulo);
+            planes.push(words);
+        }
+        planes
+    }
+
+    fn get_rgb8(rgb4: u16) -> (u8, u8, u8) {
+        let r = ((rgb4 >> 8) & 0x000f) as u8;
+        let g = ((rgb4 >> 4) & 0x000f) as u8;
+        let b = (rgb4 & 0x000f) as u8;
+        (r * 17, g * 17, b * 17)
+    }
+
+    fn set_pixel_rgb4(&mut self, x: usize, y: usize, rgb4: u16) {
+        let (r, g, b) = Denise::get_rgb8(rgb4);
+        let index = (y * FRAMEBUFFER_WIDTH + x) * 3;
+        self.framebuffer[index] = r;
+        self.framebuffer[index + 1] = g;
+        self.framebuffer[index + 2] = b;
+    }
+
+    // Hold and modify, the two top bitplanes select whether the color comes from the palette,
+    // or is the previous color with the blue, red or green component replaced
+    fn get_ham_rgb4(color_index: u16, previous_rgb4: u16, palette: &[u16; 32]) -> u16 {
+        let value = color_index & 0x000f;
+        match (color_index >> 4) & 0b11 {
+            0b00 => palette[value as usize],
+            0b01 => (previous_rgb4 & 0x0ff0) | value,
+            0b10 => (previous_rgb4 & 0x00ff) | (value << 8),
+            _ => (previous_rgb4 & 0x0f0f) | (value << 4),
+        }
+    }
+
+    // Renders a line that the beam has passed, fetching its bitplanes when bitplane DMA is
+    // enabled and the line is inside the vertical display window, and its sprites when sprite
+    // DMA is enabled
+    pub fn render_line(
+        &mut self,
+        mem: &Mem,
+        vpos: u16,
+        long_frame: bool,
+        palette: &[u16; 32],
+        bitplane_dma: bool,
+        sprite_dma: bool,
+    ) {
+        let first_row = 2 * vpos as usize;
+        if first_row + 1 >= FRAMEBUFFER_HEIGHT {
+            return;
+        }
+        if sprite_dma {
+            for sprite in self.sprites.iter_mut() {
+                sprite.step_dma_line(mem, vpos);
+            }
+        }
+        let (hstart, vstart, hstop, vstop) = self.get_display_window();
+        let inside_vertical = vpos >= vstart && vpos < vstop;
+        let planes = match inside_vertical && bitplane_dma {
+            true => self.fetch_bitplanes(mem),
+            false => Vec::new(),
+        };
+
+        let hires = self.is_hires();
+        let ham = self.is_ham();
+        let extra_half_brite = self.is_extra_half_brite();
+        let first_pixel_position = self.get_first_pixel_position();
+        // The odd bitplanes are delayed by PF1H and the even bitplanes by PF2H
+        let delays = [
+            (self.bplcon1 & 0x000f) as i32,
+            ((self.bplcon1 >> 4) & 0x000f) as i32,
+        ];
+        // The sprite pairs below the priority code are in front of the playfield
+        let playfield_priority = (self.bplcon2 >> 3) & 0x0007;
+        let mut ham_rgb4 = palette[0];
+        let mut line = vec![palette[0]; FRAMEBUFFER_WIDTH];
+        for (x, rgb4) in line.iter_mut().enumerate() {
+            let lores_x = (x / 2) as u16;
+            if !inside_vertical || lores_x < hstart || lores_x >= hstop {
+                continue;
+            }
+            let mut color_index = 0;
+            for (plane, words) in planes.iter().enumerate() {
+                let delay = delays[plane % 2];
+                let pixel = match hires {
+                    true => x as i32 - 2 * (first_pixel_position + delay),
+                    false => lores_x as i32 - (first_pixel_position + delay),
+                };
+                if pixel < 0 || pixel as usize >= words.len() * 16 {
+                    continue;
+                }
+                let word = words[pixel as usize / 16];
+                let bit = (word >> (15 - (pixel as usize % 16))) & 0x0001;
+                color_index |= bit << plane;
+            }
+            *rgb4 = match (ham, extra_half_brite) {
+                (true, _) => {
+                    ham_rgb4 = Denise::get_ham_rgb4(color_index, ham_rgb4, palette);
+                    ham_rgb4
+                }
+                (false, true) if color_index >= 32 => {
+                    (palette[color_index as usize - 32] >> 1) & 0x0777
+                }
+                _ => palette[color_index as usize & 0x1f],
+            };
+            let collision_bits =
+                get_collision_bits(self.clxcon, color_index, &self.sprites, lores_x);
+            self.clxdat.set(self.clxdat.get() | collision_bits);
+            if let Some((sprite_color, pair)) = get_sprite_color(&self.sprites, lores_x) {
+                if color_index == 0 || (pair as u16) < playfield_priority {
+                    *rgb4 = palette[sprite_color];
+                }
+            }
+        }
+
+        let rows = match (self.is_interlace(), long_frame) {
+            (false, _) => vec![first_row, first_row + 1],
+            (true, true) => vec![first_row],
+            (true, false) => vec![first_row + 1],
+        };
+        for row in rows {
+            for (x, rgb4) in line.iter().enumerate() {
+                self.set_pixel_rgb4(x, row, *rgb4);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::rammemory::RamMemory;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const LINE: u16 = 0x2c;
+
+    fn denise_test_setup(bplcon0: u16, words: &[(u32, u16)]) -> (Mem, Denise) {
+        let mut mem = Mem::new(None, None);
+        let chip_ram = RamMemory::from_range(0x00000000, 0x00000fff);
+        mem.add_range(Rc::new(RefCell::new(chip_ram)));
+        for (address, word) in words {
+            mem.set_chip_word(*address, *word);
+        }
+        let mut denise = Denise::new();
+        denise.bplcon0 = bplcon0;
+        denise.diwstrt = 0x2c81;
+        denise.diwstop = 0x2cc1;
+        denise.ddfstrt = 0x0038;
+        denise.ddfstop = 0x00d0;
+        for plane in 0..MAX_PLANE_COUNT {
+            denise.bplpt[plane] = 0x100 * (plane as u32 + 1);
+        }
+        (mem, denise)
+    }
+
+    fn get_palette() -> [u16; 32] {
+        let mut palette = [0x0000; 32];
+        palette[0] = 0x0005;
+        palette[1] = 0x0fff;
+        palette[2] = 0x0f00;
+        palette[3] = 0x00f0;
+        palette
+    }
+
+    // The hires pixel of a lores pixel inside the display window
+    fn get_x(lores_pixel: usize) -> usize {
+        (0x81 + lores_pixel) * 2
+    }
+
+    #[test]
+    fn lores_bitplanes_are_shown_inside_display_window() {
+        // arrange
+        let (mem, mut denise) = denise_test_setup(0x2200, &[(0x100, 0xa000), (0x200, 0x6000)]);
+        denise.bpl1mod = 0x0010;
+        // act
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        // assert
+        assert_eq!(
+            (0xff, 0x00, 0x00),
+            denise.get_pixel_rgb(get_x(1), 2 * LINE as usize)
+        );
+        assert_eq!(
+            (0xff, 0xff, 0xff),
+            denise.get_pixel_rgb(get_x(0) + 1, 2 * LINE as usize)
+        );
+        assert_eq!(
+            (0x00, 0xff, 0x00),
+            denise.get_pixel_rgb(get_x(2), 2 * LINE as usize + 1)
+        );
+        assert_eq!(
+            (0x00, 0x00, 0x55),
+            denise.get_pixel_rgb(get_x(3), 2 * LINE as usize)
+        );
+        assert_eq!(
+            (0x00, 0x00, 0x55),
+            denise.get_pixel_rgb(get_x(0) - 1, 2 * LINE as usize)
+        );
+        assert_eq!(0x100 + 40 + 0x10, denise.bplpt[0]);
+        assert_eq!(0x200 + 40, denise.bplpt[1]);
+    }
+
+    #[test]
+    fn hires_and_scroll_delay() {
+        // arrange
+        let (mem, mut denise) = denise_test_setup(0x9000, &[(0x100, 0x8000)]);
+        denise.ddfstrt = 0x003c;
+        denise.ddfstop = 0x00d4;
+        denise.bplcon1 = 0x0001;
+        // act
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        // assert
+        assert_eq!(
+            (0x00, 0x00, 0x55),
+            denise.get_pixel_rgb(get_x(0), 2 * LINE as usize)
+        );
+        assert_eq!(
+            (0xff, 0xff, 0xff),
+            denise.get_pixel_rgb(get_x(1), 2 * LINE as usize)
+        );
+        assert_eq!(
+            (0x00, 0x00, 0x55),
+            denise.get_pixel_rgb(get_x(1) + 1, 2 * LINE as usize)
+        );
+        assert_eq!(0x100 + 80, denise.bplpt[0]);
+    }
+
+    #[test]
+    fn extra_half_brite_halves_palette_color() {
+        // arrange
+        let (mem, mut denise) = denise_test_setup(0x6200, &[(0x100, 0x8000), (0x600, 0x8000)]);
+        // act
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        // assert
+        assert_eq!(
+            (0x77, 0x77, 0x77),
+            denise.get_pixel_rgb(get_x(0), 2 * LINE as usize)
+        );
+    }
+
+    #[test]
+    fn ham_modifies_previous_color() {
+        // arrange
+        // Pixel 0: palette color 2, pixel 1: modify blue to $A, pixel 2: modify green to $5
+        let (mem, mut denise) = denise_test_setup(
+            0x6a00,
+            &[
+                (0x100, 0x2000),
+                (0x200, 0xc000),
+                (0x300, 0x2000),
+                (0x400, 0x4000),
+                (0x500, 0x6000),
+                (0x600, 0x2000),
+            ],
+        );
+        // act
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        // assert
+        assert_eq!(
+            (0xff, 0x00, 0x00),
+            denise.get_pixel_rgb(get_x(0), 2 * LINE as usize)
+        );
+        assert_eq!(
+            (0xff, 0x00, 0xaa),
+            denise.get_pixel_rgb(get_x(1), 2 * LINE as usize)
+        );
+        assert_eq!(
+            (0xff, 0x55, 0xaa),
+            denise.get_pixel_rgb(get_x(2), 2 * LINE as usize)
+        );
+    }
+
+    #[test]
+    fn interlace_long_frame_fills_even_rows() {
+        // arrange
+        let (mem, mut denise) = denise_test_setup(0x1204, &[(0x100, 0x8000)]);
+        // act
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        // assert
+        assert_eq!(
+            (0xff, 0xff, 0xff),
+            denise.get_pixel_rgb(get_x(0), 2 * LINE as usize)
+        );
+        assert_eq!(
+            (0x00, 0x00, 0x00),
+            denise.get_pixel_rgb(get_x(0), 2 * LINE as usize + 1)
+        );
+    }
+
+    #[test]
+    fn frame_is_written_to_png() {
+        // arrange
+        let denise = Denise::new();
+        let file_path = std::env::temp_dir().join("rust-amiga-emul-denise-test.png");
+        let file_path = file_path.to_str().unwrap();
+        // act
+        denise.write_png(file_path).unwrap();
+        // assert
+        let bytes = std::fs::read(file_path).unwrap();
+        std::fs::remove_file(file_path).unwrap();
+        assert_eq!(b"\x89PNG", &bytes[0..4]);
+    }
+}
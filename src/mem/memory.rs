@@ -2,6 +2,8 @@ use std::{any::Any, fmt::Display};
 
 use crate::cpu::step_log::StepLog;
 
+use super::memorypage::MemoryPage;
+
 pub trait Memory: Any + Display {
     fn as_any(&self) -> &dyn Any;
 
@@ -20,6 +22,12 @@ pub trait Memory: Any + Display {
         address: u32,
         value: u8,
     ) -> Option<SetMemoryResult>;
+
+    // Memory that can be accessed directly through its bytes returns the page for it, so Mem
+    // can skip the dynamic dispatch and RefCell borrow
+    fn get_direct_memory_page(&self) -> Option<MemoryPage> {
+        None
+    }
 }
 
 pub struct SetMemoryResult {
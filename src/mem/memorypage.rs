@@ -0,0 +1,145 @@
+use super::memory::Memory;
+use super::rammemory::RAM_MEMORY_PADDING;
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+// The address space is split into 64 KB pages, each looked up directly from the high word of
+// the address
+pub const MEMORY_PAGE_SHIFT: u32 = 16;
+pub const MEMORY_PAGE_COUNT: usize = 1 << (32 - MEMORY_PAGE_SHIFT);
+pub const MEMORY_PAGE_SIZE: u32 = 1 << MEMORY_PAGE_SHIFT;
+
+#[derive(Clone)]
+pub enum MemoryPage {
+    // Not covered by any range, the custom chips, the CIAs or the unmapped access policy decide
+    Unmapped,
+    // Only partly covered by a range, the ranges have to be searched
+    Search,
+    // Covered by a single RAM range, accessed directly through its bytes
+    Ram {
+        start_address: u32,
+        bytes: Rc<[Cell<u8>]>,
+    },
+    // Covered by a single ROM range, read directly through its bytes
+    Rom {
+        start_address: u32,
+        bytes: Rc<[u8]>,
+    },
+    // Covered by a single range of any other kind
+    Memory(Rc<RefCell<dyn Memory>>),
+}
+
+impl MemoryPage {
+    pub fn get_page_index(address: u32) -> usize {
+        (address >> MEMORY_PAGE_SHIFT) as usize
+    }
+
+    pub fn get_long(&self, address: u32) -> Option<u32> {
+        match self {
+            MemoryPage::Ram {
+                start_address,
+                bytes,
+            } => {
+                let index = (address - start_address) as usize + RAM_MEMORY_PADDING;
+                Some(
+                    ((bytes[index].get() as u32) << 24)
+                        | ((bytes[index + 1].get() as u32) << 16)
+                        | ((bytes[index + 2].get() as u32) << 8)
+                        | bytes[index + 3].get() as u32,
+                )
+            }
+            MemoryPage::Rom {
+                start_address,
+                bytes,
+            } => {
+                let index = (address - start_address) as usize;
+                Some(
+                    ((bytes[index] as u32) << 24)
+                        | ((bytes[index + 1] as u32) << 16)
+                        | ((bytes[index + 2] as u32) << 8)
+                        | bytes[index + 3] as u32,
+                )
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_word(&self, address: u32) -> Option<u16> {
+        match self {
+            MemoryPage::Ram {
+                start_address,
+                bytes,
+            } => {
+                let index = (address - start_address) as usize + RAM_MEMORY_PADDING;
+                Some(((bytes[index].get() as u16) << 8) | bytes[index + 1].get() as u16)
+            }
+            MemoryPage::Rom {
+                start_address,
+                bytes,
+            } => {
+                let index = (address - start_address) as usize;
+                Some(((bytes[index] as u16) << 8) | bytes[index + 1] as u16)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn get_byte(&self, address: u32) -> Option<u8> {
+        match self {
+            MemoryPage::Ram {
+                start_address,
+                bytes,
+            } => Some(bytes[(address - start_address) as usize + RAM_MEMORY_PADDING].get()),
+            MemoryPage::Rom {
+                start_address,
+                bytes,
+            } => Some(bytes[(address - start_address) as usize]),
+            _ => None,
+        }
+    }
+
+    // Writes to ROM are left to RomMemory, which logs them
+    pub fn set_long(&self, address: u32, value: u32) -> bool {
+        match self {
+            MemoryPage::Ram {
+                start_address,
+                bytes,
+            } => {
+                let index = (address - start_address) as usize + RAM_MEMORY_PADDING;
+                for (i, byte) in value.to_be_bytes().iter().enumerate() {
+                    bytes[index + i].set(*byte);
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn set_word(&self, address: u32, value: u16) -> bool {
+        match self {
+            MemoryPage::Ram {
+                start_address,
+                bytes,
+            } => {
+                let index = (address - start_address) as usize + RAM_MEMORY_PADDING;
+                bytes[index].set((value >> 8) as u8);
+                bytes[index + 1].set(value as u8);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn set_byte(&self, address: u32, value: u8) -> bool {
+        match self {
+            MemoryPage::Ram {
+                start_address,
+                bytes,
+            } => {
+                bytes[(address - start_address) as usize + RAM_MEMORY_PADDING].set(value);
+                true
+            }
+            _ => false,
+        }
+    }
+}
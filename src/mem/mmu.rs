@@ -0,0 +1,711 @@
+// The 68030 paged memory management unit. It translates the logical addresses of the CPU bus
+// cycles to physical addresses, using the transparent translation registers or a search
+// through the translation tables in memory. The results of the table searches are kept in
+// the address translation cache (ATC).
+
+pub const FUNCTION_CODE_USER_DATA: u8 = 1;
+pub const FUNCTION_CODE_USER_PROGRAM: u8 = 2;
+pub const FUNCTION_CODE_SUPERVISOR_DATA: u8 = 5;
+pub const FUNCTION_CODE_SUPERVISOR_PROGRAM: u8 = 6;
+
+const TC_ENABLE: u32 = 0x80000000;
+const TC_SUPERVISOR_ROOT_POINTER_ENABLE: u32 = 0x02000000;
+const TC_FUNCTION_CODE_LOOKUP: u32 = 0x01000000;
+
+const TT_ENABLE: u32 = 0x00008000;
+const TT_CACHE_INHIBIT: u32 = 0x00000400;
+const TT_READ: u32 = 0x00000200;
+const TT_READ_WRITE_MASK: u32 = 0x00000100;
+
+const DESCRIPTOR_TYPE_INVALID: u32 = 0b00;
+const DESCRIPTOR_TYPE_PAGE: u32 = 0b01;
+const DESCRIPTOR_TYPE_VALID_4_BYTE: u32 = 0b10;
+const DESCRIPTOR_TYPE_VALID_8_BYTE: u32 = 0b11;
+
+const DESCRIPTOR_WRITE_PROTECT: u32 = 0x00000004;
+const DESCRIPTOR_USED: u32 = 0x00000008;
+const DESCRIPTOR_MODIFIED: u32 = 0x00000010;
+const DESCRIPTOR_CACHE_INHIBIT: u32 = 0x00000040;
+// Only in the long format descriptors
+const DESCRIPTOR_SUPERVISOR: u32 = 0x00000100;
+const DESCRIPTOR_LOWER_LIMIT: u32 = 0x80000000;
+
+pub const MMUSR_BUS_ERROR: u16 = 0x8000;
+pub const MMUSR_LIMIT_VIOLATION: u16 = 0x4000;
+pub const MMUSR_SUPERVISOR_ONLY: u16 = 0x2000;
+pub const MMUSR_WRITE_PROTECTED: u16 = 0x0800;
+pub const MMUSR_INVALID: u16 = 0x0400;
+pub const MMUSR_MODIFIED: u16 = 0x0200;
+pub const MMUSR_TRANSPARENT: u16 = 0x0040;
+
+// The 68030 searches at most seven levels, with function code lookup and four table indexes
+// and an indirect descriptor
+pub const MAX_TABLE_SEARCH_LEVELS: u16 = 7;
+
+const ATC_ENTRY_COUNT: usize = 22;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Translation {
+    pub physical_address: u32,
+    pub cache_inhibit: bool,
+}
+
+#[derive(Copy, Clone)]
+struct AtcEntry {
+    function_code: u8,
+    logical_page: u32,
+    physical_page: u32,
+    write_protected: bool,
+    supervisor_only: bool,
+    modified: bool,
+    cache_inhibit: bool,
+}
+
+// Outcome of a search through the translation tables, the same as the 68030 reports in MMUSR
+// for PTEST
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct TableSearch {
+    pub physical_address: u32,
+    pub descriptor_address: u32,
+    pub levels: u16,
+    pub limit_violation: bool,
+    pub supervisor_only: bool,
+    pub write_protected: bool,
+    pub invalid: bool,
+    pub modified: bool,
+    pub cache_inhibit: bool,
+}
+
+impl TableSearch {
+    pub fn get_mmusr(&self) -> u16 {
+        let mut mmusr = self.levels & 0x0007;
+        let flags = [
+            (self.limit_violation, MMUSR_LIMIT_VIOLATION),
+            (self.supervisor_only, MMUSR_SUPERVISOR_ONLY),
+            (self.write_protected, MMUSR_WRITE_PROTECTED),
+            (self.invalid, MMUSR_INVALID),
+            (self.modified, MMUSR_MODIFIED),
+        ];
+        for (set, mask) in flags {
+            if set {
+                mmusr |= mask;
+            }
+        }
+        mmusr
+    }
+}
+
+pub struct Mmu {
+    pub reg_tc: u32,
+    pub reg_tt0: u32,
+    pub reg_tt1: u32,
+    pub reg_crp: u64,
+    pub reg_srp: u64,
+    pub reg_mmusr: u16,
+    atc: Vec<AtcEntry>,
+}
+
+impl Mmu {
+    pub fn new() -> Mmu {
+        Mmu {
+            reg_tc: 0x00000000,
+            reg_tt0: 0x00000000,
+            reg_tt1: 0x00000000,
+            reg_crp: 0x0000000000000000,
+            reg_srp: 0x0000000000000000,
+            reg_mmusr: 0x0000,
+            atc: Vec::with_capacity(ATC_ENTRY_COUNT),
+        }
+    }
+
+    // Address translation is only done when TC or one of the transparent translation registers
+    // is enabled
+    pub fn is_enabled(&self) -> bool {
+        self.reg_tc & TC_ENABLE != 0
+            || self.reg_tt0 & TT_ENABLE != 0
+            || self.reg_tt1 & TT_ENABLE != 0
+    }
+
+    // An enabled TC needs a page size of at least 256 bytes, and the initial shift, the table
+    // indexes up to the first zero one and the page size adding up to 32 bits
+    pub fn is_valid_translation_control(value: u32) -> bool {
+        if value & TC_ENABLE == 0 {
+            return true;
+        }
+        let page_size = (value >> 20) & 0xf;
+        if page_size < 8 {
+            return false;
+        }
+        let initial_shift = (value >> 16) & 0xf;
+        let table_index_bits: u32 = Mmu::get_table_index_widths(value).iter().sum();
+        initial_shift + table_index_bits + page_size == 32
+    }
+
+    // Root pointers with an invalid descriptor type are an MMU configuration error
+    pub fn is_valid_root_pointer(value: u64) -> bool {
+        (value >> 32) as u32 & 0b11 != DESCRIPTOR_TYPE_INVALID
+    }
+
+    fn get_table_index_widths(tc: u32) -> Vec<u32> {
+        [12, 8, 4, 0]
+            .iter()
+            .map(|shift| (tc >> shift) & 0xf)
+            .take_while(|width| *width != 0)
+            .collect()
+    }
+
+    fn get_page_mask(&self) -> u32 {
+        (1u32 << ((self.reg_tc >> 20) & 0xf)) - 1
+    }
+
+    fn match_transparent_translation_register(
+        tt: u32,
+        address: u32,
+        function_code: u8,
+        write: bool,
+    ) -> bool {
+        if tt & TT_ENABLE == 0 {
+            return false;
+        }
+        let address_base = tt >> 24;
+        let address_mask = (tt >> 16) & 0xff;
+        let function_code_base = (tt >> 4) & 0b111;
+        let function_code_mask = tt & 0b111;
+        let read = tt & TT_READ != 0;
+        ((address >> 24) ^ address_base) & !address_mask & 0xff == 0
+            && (function_code as u32 ^ function_code_base) & !function_code_mask & 0b111 == 0
+            && (tt & TT_READ_WRITE_MASK != 0 || read != write)
+    }
+
+    // The transparently translated accesses aren't translated at all, the value is whether the
+    // access is cache inhibited
+    pub fn match_transparent_translation(
+        &self,
+        address: u32,
+        function_code: u8,
+        write: bool,
+    ) -> Option<bool> {
+        [self.reg_tt0, self.reg_tt1]
+            .iter()
+            .find(|tt| {
+                Mmu::match_transparent_translation_register(**tt, address, function_code, write)
+            })
+            .map(|tt| tt & TT_CACHE_INHIBIT != 0)
+    }
+
+    pub fn flush_all(&mut self) {
+        self.atc.clear();
+    }
+
+    // Flushes the ATC entries of the function codes selected by the mask, and optionally only
+    // those of the page of an address
+    pub fn flush(&mut self, function_code: u8, function_code_mask: u8, address: Option<u32>) {
+        let page_mask = self.get_page_mask();
+        self.atc.retain(|entry| {
+            let function_code_match =
+                (entry.function_code ^ function_code) & function_code_mask & 0b111 == 0;
+            let address_match = match address {
+                Some(address) => entry.logical_page == address & !page_mask,
+                None => true,
+            };
+            !(function_code_match && address_match)
+        });
+    }
+
+    fn find_atc_entry(&self, address: u32, function_code: u8) -> Option<&AtcEntry> {
+        let logical_page = address & !self.get_page_mask();
+        self.atc.iter().find(|entry| {
+            entry.function_code == function_code && entry.logical_page == logical_page
+        })
+    }
+
+    // Only valid translations are kept, the oldest entry is replaced when the ATC is full
+    fn load_atc_entry(&mut self, address: u32, function_code: u8, search: &TableSearch) {
+        if search.invalid || search.limit_violation {
+            return;
+        }
+        let page_mask = self.get_page_mask();
+        let logical_page = address & !page_mask;
+        self.atc.retain(|entry| {
+            !(entry.function_code == function_code && entry.logical_page == logical_page)
+        });
+        if self.atc.len() == ATC_ENTRY_COUNT {
+            self.atc.remove(0);
+        }
+        self.atc.push(AtcEntry {
+            function_code,
+            logical_page,
+            physical_page: search.physical_address & !page_mask,
+            write_protected: search.write_protected,
+            supervisor_only: search.supervisor_only,
+            modified: search.modified,
+            cache_inhibit: search.cache_inhibit,
+        });
+    }
+
+    // Searches the translation tables for a logical address, setting the used bits of the
+    // descriptors on the way and the modified bit of the page descriptor for a write. A search
+    // stops early after max_levels descriptors, for PTEST.
+    pub fn search<R, W>(
+        &self,
+        address: u32,
+        function_code: u8,
+        write: bool,
+        max_levels: u16,
+        read_long: R,
+        write_long: W,
+    ) -> TableSearch
+    where
+        R: Fn(u32) -> u32,
+        W: Fn(u32, u32),
+    {
+        let mut search = TableSearch {
+            physical_address: address,
+            ..TableSearch::default()
+        };
+        let supervisor = function_code & 0b100 != 0;
+        let root_pointer = match supervisor && self.reg_tc & TC_SUPERVISOR_ROOT_POINTER_ENABLE != 0
+        {
+            true => self.reg_srp,
+            false => self.reg_crp,
+        };
+        let mut descriptor_type = (root_pointer >> 32) as u32 & 0b11;
+        if descriptor_type == DESCRIPTOR_TYPE_PAGE {
+            // The root pointer itself maps the whole address space unchanged
+            return search;
+        }
+        let mut limit = Some((root_pointer >> 32) as u32);
+        let mut table_address = root_pointer as u32 & 0xfffffff0;
+
+        // The function code lookup level comes first, then the table indexes below the initial
+        // shift. Each level is the index and the number of address bits left below it.
+        let mut remaining_bits = 32 - ((self.reg_tc >> 16) & 0xf);
+        let mut levels = vec![];
+        if self.reg_tc & TC_FUNCTION_CODE_LOOKUP != 0 {
+            levels.push((function_code as u32, remaining_bits));
+        }
+        for width in Mmu::get_table_index_widths(self.reg_tc) {
+            remaining_bits -= width;
+            let index = (address >> remaining_bits) & ((1 << width) - 1);
+            levels.push((index, remaining_bits));
+        }
+
+        let mut page_descriptor = None;
+        for (index, remaining_bits) in levels {
+            if search.levels >= max_levels {
+                return search;
+            }
+            if let Some(limit) = limit {
+                let limit_index = (limit >> 16) & 0x7fff;
+                let lower_limit = limit & DESCRIPTOR_LOWER_LIMIT != 0;
+                if (lower_limit && index < limit_index) || (!lower_limit && index > limit_index) {
+                    search.limit_violation = true;
+                    return search;
+                }
+            }
+            let long_format = descriptor_type == DESCRIPTOR_TYPE_VALID_8_BYTE;
+            let descriptor_address = match long_format {
+                true => table_address.wrapping_add(index * 8),
+                false => table_address.wrapping_add(index * 4),
+            };
+            let descriptor = read_long(descriptor_address);
+            search.levels += 1;
+            search.descriptor_address = descriptor_address;
+            descriptor_type = descriptor & 0b11;
+            if descriptor_type == DESCRIPTOR_TYPE_INVALID {
+                search.invalid = true;
+                return search;
+            }
+            let descriptor_address_field = match long_format {
+                true => read_long(descriptor_address.wrapping_add(4)),
+                false => descriptor,
+            };
+            search.write_protected |= descriptor & DESCRIPTOR_WRITE_PROTECT != 0;
+            search.supervisor_only |= long_format && descriptor & DESCRIPTOR_SUPERVISOR != 0;
+            if descriptor_type == DESCRIPTOR_TYPE_PAGE {
+                page_descriptor = Some((
+                    descriptor_address,
+                    descriptor,
+                    descriptor_address_field,
+                    remaining_bits,
+                ));
+                break;
+            }
+            if descriptor & DESCRIPTOR_USED == 0 {
+                write_long(descriptor_address, descriptor | DESCRIPTOR_USED);
+            }
+            limit = match long_format {
+                true => Some(descriptor),
+                false => None,
+            };
+            table_address = descriptor_address_field & 0xfffffff0;
+        }
+
+        let (descriptor_address, descriptor, descriptor_address_field, remaining_bits) =
+            match page_descriptor {
+                Some(page_descriptor) => page_descriptor,
+                None => {
+                    // A table descriptor after the last table index is an indirect descriptor,
+                    // pointing to the page descriptor
+                    if search.levels >= max_levels {
+                        return search;
+                    }
+                    let long_format = descriptor_type == DESCRIPTOR_TYPE_VALID_8_BYTE;
+                    let descriptor_address = table_address & 0xfffffffc;
+                    let descriptor = read_long(descriptor_address);
+                    search.levels += 1;
+                    search.descriptor_address = descriptor_address;
+                    if descriptor & 0b11 != DESCRIPTOR_TYPE_PAGE {
+                        search.invalid = true;
+                        return search;
+                    }
+                    let descriptor_address_field = match long_format {
+                        true => read_long(descriptor_address.wrapping_add(4)),
+                        false => descriptor,
+                    };
+                    search.write_protected |= descriptor & DESCRIPTOR_WRITE_PROTECT != 0;
+                    search.supervisor_only |=
+                        long_format && descriptor & DESCRIPTOR_SUPERVISOR != 0;
+                    (
+                        descriptor_address,
+                        descriptor,
+                        descriptor_address_field,
+                        remaining_bits,
+                    )
+                }
+            };
+
+        let mut updated_descriptor = descriptor | DESCRIPTOR_USED;
+        if write && !search.write_protected {
+            updated_descriptor |= DESCRIPTOR_MODIFIED;
+        }
+        if updated_descriptor != descriptor {
+            write_long(descriptor_address, updated_descriptor);
+        }
+        search.modified = updated_descriptor & DESCRIPTOR_MODIFIED != 0;
+        search.cache_inhibit = descriptor & DESCRIPTOR_CACHE_INHIBIT != 0;
+        // An early termination page descriptor maps all the address bits below its level
+        let block_mask = match remaining_bits {
+            32 => 0xffffffff,
+            _ => (1u32 << remaining_bits) - 1,
+        };
+        search.physical_address =
+            (descriptor_address_field & 0xffffff00 & !block_mask) | (address & block_mask);
+        search
+    }
+
+    // PLOAD searches the tables and loads the result into the ATC
+    pub fn load<R, W>(
+        &mut self,
+        address: u32,
+        function_code: u8,
+        write: bool,
+        read_long: R,
+        write_long: W,
+    ) where
+        R: Fn(u32) -> u32,
+        W: Fn(u32, u32),
+    {
+        let search = self.search(
+            address,
+            function_code,
+            write,
+            MAX_TABLE_SEARCH_LEVELS,
+            read_long,
+            write_long,
+        );
+        self.load_atc_entry(address, function_code, &search);
+    }
+
+    // Translation of a bus cycle, None is a fault. A write to a page that isn't marked as
+    // modified in the ATC searches the tables again, to set the modified bit.
+    pub fn translate<R, W>(
+        &mut self,
+        address: u32,
+        function_code: u8,
+        write: bool,
+        read_long: R,
+        write_long: W,
+    ) -> Option<Translation>
+    where
+        R: Fn(u32) -> u32,
+        W: Fn(u32, u32),
+    {
+        if let Some(cache_inhibit) =
+            self.match_transparent_translation(address, function_code, write)
+        {
+            return Some(Translation {
+                physical_address: address,
+                cache_inhibit,
+            });
+        }
+        if self.reg_tc & TC_ENABLE == 0 {
+            return Some(Translation {
+                physical_address: address,
+                cache_inhibit: false,
+            });
+        }
+        let entry = match self.find_atc_entry(address, function_code) {
+            Some(entry) if !write || entry.modified || entry.write_protected => *entry,
+            _ => {
+                let search = self.search(
+                    address,
+                    function_code,
+                    write,
+                    MAX_TABLE_SEARCH_LEVELS,
+                    read_long,
+                    write_long,
+                );
+                self.load_atc_entry(address, function_code, &search);
+                *self.find_atc_entry(address, function_code)?
+            }
+        };
+        let supervisor = function_code & 0b100 != 0;
+        if (entry.supervisor_only && !supervisor) || (write && entry.write_protected) {
+            return None;
+        }
+        Some(Translation {
+            physical_address: entry.physical_page | (address & self.get_page_mask()),
+            cache_inhibit: entry.cache_inhibit,
+        })
+    }
+
+    // Translation without any side effects, for the debugger and the instruction fetches.
+    // Addresses that would fault are left untranslated.
+    pub fn get_translation<R>(&self, address: u32, function_code: u8, read_long: R) -> Translation
+    where
+        R: Fn(u32) -> u32,
+    {
+        if let Some(cache_inhibit) =
+            self.match_transparent_translation(address, function_code, false)
+        {
+            return Translation {
+                physical_address: address,
+                cache_inhibit,
+            };
+        }
+        if self.reg_tc & TC_ENABLE == 0 {
+            return Translation {
+                physical_address: address,
+                cache_inhibit: false,
+            };
+        }
+        if let Some(entry) = self.find_atc_entry(address, function_code) {
+            return Translation {
+                physical_address: entry.physical_page | (address & self.get_page_mask()),
+                cache_inhibit: entry.cache_inhibit,
+            };
+        }
+        let search = self.search(
+            address,
+            function_code,
+            false,
+            MAX_TABLE_SEARCH_LEVELS,
+            read_long,
+            |_, _| (),
+        );
+        match search.invalid || search.limit_violation {
+            true => Translation {
+                physical_address: address,
+                cache_inhibit: false,
+            },
+            false => Translation {
+                physical_address: search.physical_address,
+                cache_inhibit: search.cache_inhibit,
+            },
+        }
+    }
+
+    // PTEST at level 0 only looks at the transparent translation registers and the ATC
+    pub fn test_atc(&self, address: u32, function_code: u8, write: bool) -> u16 {
+        if self
+            .match_transparent_translation(address, function_code, write)
+            .is_some()
+        {
+            return MMUSR_TRANSPARENT;
+        }
+        match self.find_atc_entry(address, function_code) {
+            Some(entry) => {
+                let mut mmusr = 0x0000;
+                if entry.write_protected {
+                    mmusr |= MMUSR_WRITE_PROTECTED;
+                }
+                if entry.supervisor_only && function_code & 0b100 == 0 {
+                    mmusr |= MMUSR_SUPERVISOR_ONLY;
+                }
+                if entry.modified {
+                    mmusr |= MMUSR_MODIFIED;
+                }
+                mmusr
+            }
+            None => MMUSR_INVALID,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    // TC with 4 KB pages and two levels of 10 bit indexes
+    const TC_4K_10_10: u32 = 0x80c0aa00;
+
+    fn table_memory(descriptors: &[(u32, u32)]) -> RefCell<HashMap<u32, u32>> {
+        RefCell::new(descriptors.iter().cloned().collect())
+    }
+
+    #[test]
+    fn translation_control_is_validated() {
+        assert!(Mmu::is_valid_translation_control(TC_4K_10_10));
+        assert!(Mmu::is_valid_translation_control(0x00000000));
+        // 128 byte pages
+        assert!(!Mmu::is_valid_translation_control(0x8070aa00));
+        // 4 KB pages and 19 index bits
+        assert!(!Mmu::is_valid_translation_control(0x80c0a900));
+    }
+
+    #[test]
+    fn two_level_short_table_search_sets_used_and_modified() {
+        // arrange
+        let memory = table_memory(&[
+            (0x00001000 + 0x004 * 4, 0x00002002), // level A index 4, short table
+            (0x00002000 + 0x123 * 4, 0x00345001), // level B index $123, page
+        ]);
+        let mut mmu = Mmu::new();
+        mmu.reg_tc = TC_4K_10_10;
+        mmu.reg_crp = 0x7fff0002_00001000;
+        // act
+        let translation = mmu.translate(
+            0x01123abc,
+            FUNCTION_CODE_USER_DATA,
+            true,
+            |address| *memory.borrow().get(&address).unwrap_or(&0),
+            |address, value| {
+                memory.borrow_mut().insert(address, value);
+            },
+        );
+        // assert
+        assert_eq!(
+            Some(Translation {
+                physical_address: 0x00345abc,
+                cache_inhibit: false
+            }),
+            translation
+        );
+        assert_eq!(0x0000200a, memory.borrow()[&0x00001010]);
+        assert_eq!(0x00345019, memory.borrow()[&0x0000248c]);
+    }
+
+    #[test]
+    fn write_to_write_protected_page_faults() {
+        // arrange
+        let memory = table_memory(&[
+            (0x00001000, 0x00002002),
+            (0x00002000, 0x00003005), // write protected page
+        ]);
+        let mut mmu = Mmu::new();
+        mmu.reg_tc = TC_4K_10_10;
+        mmu.reg_crp = 0x7fff0002_00001000;
+        let read_long = |address| *memory.borrow().get(&address).unwrap_or(&0);
+        // act assert
+        assert!(mmu
+            .translate(
+                0x00000010,
+                FUNCTION_CODE_USER_DATA,
+                false,
+                read_long,
+                |_, _| ()
+            )
+            .is_some());
+        assert!(mmu
+            .translate(
+                0x00000010,
+                FUNCTION_CODE_USER_DATA,
+                true,
+                read_long,
+                |_, _| ()
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn early_termination_page_descriptor_maps_whole_level() {
+        // arrange
+        let memory = table_memory(&[(0x00001000 + 0x3ff * 4, 0x00c00001)]);
+        let mmu = Mmu {
+            reg_tc: TC_4K_10_10,
+            reg_crp: 0x7fff0002_00001000,
+            ..Mmu::new()
+        };
+        // act
+        let search = mmu.search(
+            0xffc12345,
+            FUNCTION_CODE_SUPERVISOR_DATA,
+            false,
+            MAX_TABLE_SEARCH_LEVELS,
+            |address| *memory.borrow().get(&address).unwrap_or(&0),
+            |_, _| (),
+        );
+        // assert
+        assert_eq!(0x00c12345, search.physical_address);
+        assert_eq!(1, search.levels);
+    }
+
+    #[test]
+    fn invalid_descriptor_and_limit_are_reported() {
+        // arrange
+        let memory = table_memory(&[]);
+        let mmu = Mmu {
+            reg_tc: TC_4K_10_10,
+            // Long format root pointer with an upper limit of index 1
+            reg_crp: 0x00010002_00001000,
+            ..Mmu::new()
+        };
+        let read_long = |address| *memory.borrow().get(&address).unwrap_or(&0);
+        // act
+        let invalid = mmu.search(
+            0x00400000,
+            FUNCTION_CODE_USER_DATA,
+            false,
+            MAX_TABLE_SEARCH_LEVELS,
+            read_long,
+            |_, _| (),
+        );
+        let limit = mmu.search(
+            0x00800000,
+            FUNCTION_CODE_USER_DATA,
+            false,
+            MAX_TABLE_SEARCH_LEVELS,
+            read_long,
+            |_, _| (),
+        );
+        // assert
+        assert_eq!(MMUSR_INVALID | 1, invalid.get_mmusr());
+        assert_eq!(MMUSR_LIMIT_VIOLATION, limit.get_mmusr());
+    }
+
+    #[test]
+    fn transparent_translation_matches_address_and_function_code() {
+        // arrange
+        let mmu = Mmu {
+            // $00xxxxxx, supervisor function codes, read and write, cache inhibited
+            reg_tt0: 0x0000_8743,
+            ..Mmu::new()
+        };
+        // act assert
+        assert_eq!(
+            Some(true),
+            mmu.match_transparent_translation(0x00dff180, FUNCTION_CODE_SUPERVISOR_DATA, true)
+        );
+        assert_eq!(
+            None,
+            mmu.match_transparent_translation(0x00dff180, FUNCTION_CODE_USER_DATA, true)
+        );
+        assert_eq!(
+            None,
+            mmu.match_transparent_translation(0x01000000, FUNCTION_CODE_SUPERVISOR_DATA, true)
+        );
+    }
+}
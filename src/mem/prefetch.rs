@@ -0,0 +1,90 @@
+// The two word prefetch queue of the 68000 (IRC and IRD). When an instruction begins, its opcode
+// and the word following it have already been read from memory, and every word the instruction
+// takes from the queue starts the read of the word following it. Writes to memory
+// don't update the queue, so code that overwrites the instruction words it's about to execute
+// runs the stale words. The queue is refilled from the new PC after a branch, an exception and
+// RTE.
+
+pub struct PrefetchQueue {
+    enabled: bool,
+    // The words that have been read ahead, with their addresses
+    words: Vec<(u32, u16)>,
+}
+
+impl PrefetchQueue {
+    pub fn new() -> PrefetchQueue {
+        PrefetchQueue {
+            enabled: false,
+            words: Vec::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.flush();
+    }
+
+    pub fn flush(&mut self) {
+        self.words.clear();
+    }
+
+    fn prefetch(&mut self, address: u32, read_word: impl Fn(u32) -> u16) -> u16 {
+        match self.words.iter().find(|(a, _)| *a == address) {
+            Some((_, word)) => *word,
+            None => {
+                let word = read_word(address);
+                self.words.push((address, word));
+                word
+            }
+        }
+    }
+
+    // Drops the words behind the instruction and makes sure the opcode and the word following
+    // it are in the queue
+    pub fn begin_instruction(&mut self, address: u32, read_word: impl Fn(u32) -> u16) {
+        self.words.retain(|(a, _)| a.wrapping_sub(address) < 4);
+        self.prefetch(address, &read_word);
+        self.prefetch(address.wrapping_add(2), &read_word);
+    }
+
+    // Takes a word from the queue, reading it if it hasn't been prefetched, and prefetches the
+    // word following it
+    pub fn get_word(&mut self, address: u32, read_word: impl Fn(u32) -> u16) -> u16 {
+        let word = self.prefetch(address, &read_word);
+        self.prefetch(address.wrapping_add(2), &read_word);
+        word
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn words_are_read_once_until_flushed() {
+        // arrange
+        let memory = RefCell::new(vec![0x1111u16, 0x2222, 0x3333, 0x4444, 0x5555, 0x6666]);
+        let read_word = |address: u32| memory.borrow()[(address / 2) as usize];
+        let mut queue = PrefetchQueue::new();
+        queue.set_enabled(true);
+        queue.begin_instruction(0, read_word);
+        // act
+        assert_eq!(0x1111, queue.get_word(0, read_word));
+        memory.borrow_mut()[1] = 0xaaaa;
+        memory.borrow_mut()[2] = 0xbbbb;
+        memory.borrow_mut()[3] = 0xcccc;
+        // assert
+        assert_eq!(0x2222, queue.get_word(2, read_word));
+        assert_eq!(0xbbbb, queue.get_word(4, read_word));
+        assert_eq!(0xcccc, queue.get_word(6, read_word));
+        // act
+        queue.flush();
+        // assert
+        assert_eq!(0xaaaa, queue.get_word(2, read_word));
+    }
+}
@@ -1,21 +1,25 @@
 use super::memory::{Memory, SetMemoryResult};
+use super::memorypage::MemoryPage;
 use crate::cpu::step_log::StepLog;
-use byteorder::{BigEndian, ReadBytesExt};
 use std::{
     any::Any,
+    cell::Cell,
     convert::TryInto,
     fmt::{self},
+    rc::Rc,
 };
 
 // TODO: See if there is a cleaner way to handle "overlapping" memory access, like
 //       when reading a 4 byte long from the last 2 bytes of RAM memory. Currently
 //       hacked by allocating 4 extra bytes at the start and end of the RAM memory.
+pub const RAM_MEMORY_PADDING: usize = 4;
 
 pub struct RamMemory {
     pub start_address: u32,
     pub end_address: u32,
     length: usize,
-    bytes: Vec<u8>,
+    // Shared with the memory pages of Mem, which access the bytes directly
+    bytes: Rc<[Cell<u8>]>,
 }
 
 impl fmt::Display for RamMemory {
@@ -47,39 +51,36 @@ impl Memory for RamMemory {
 
     fn get_long(self: &RamMemory, step_log: &mut StepLog, address: u32) -> u32 {
         let index = self.remap_address_to_index(address);
-        let mut bytes = &self.bytes[index..index + 4];
-        let result = bytes.read_u32::<BigEndian>().unwrap();
+        let result = ((self.bytes[index].get() as u32) << 24)
+            | ((self.bytes[index + 1].get() as u32) << 16)
+            | ((self.bytes[index + 2].get() as u32) << 8)
+            | self.bytes[index + 3].get() as u32;
         result
     }
 
     fn set_long(self: &mut RamMemory, step_log: &mut StepLog, address: u32, value: u32) {
         let index = self.remap_address_to_index(address);
-        self.bytes[index] = ((value >> 24) & 0x000000ff) as u8;
-        self.bytes[index + 1] = ((value >> 16) & 0x000000ff) as u8;
-        self.bytes[index + 2] = ((value >> 8) & 0x000000ff) as u8;
-        self.bytes[index + 3] = ((value) & 0x000000ff) as u8;
+        self.bytes[index].set(((value >> 24) & 0x000000ff) as u8);
+        self.bytes[index + 1].set(((value >> 16) & 0x000000ff) as u8);
+        self.bytes[index + 2].set(((value >> 8) & 0x000000ff) as u8);
+        self.bytes[index + 3].set(((value) & 0x000000ff) as u8);
     }
 
     fn get_word(self: &RamMemory, step_log: &mut StepLog, address: u32) -> u16 {
         let index = self.remap_address_to_index(address);
-        let mut bytes = &self.bytes[index..index + 2];
-        let result = bytes.read_u16::<BigEndian>().unwrap();
-        // let b0 : u16 = self.bytes[index].into();
-        // let b1 : u16 = self.bytes[index + 1].into();
-        // let result = (b0 << 8) + b1;
+        let result = ((self.bytes[index].get() as u16) << 8) | self.bytes[index + 1].get() as u16;
         result
     }
 
     fn set_word(self: &mut RamMemory, step_log: &mut StepLog, address: u32, value: u16) {
         let index = self.remap_address_to_index(address);
-        self.bytes[index] = ((value >> 8) & 0x000000ff) as u8;
-        self.bytes[index + 1] = ((value) & 0x000000ff) as u8;
+        self.bytes[index].set(((value >> 8) & 0x000000ff) as u8);
+        self.bytes[index + 1].set(((value) & 0x000000ff) as u8);
     }
 
     fn get_byte(self: &RamMemory, step_log: &mut StepLog, address: u32) -> u8 {
         let index = self.remap_address_to_index(address);
-        let mut bytes = &self.bytes[index..index + 1];
-        let result = bytes.read_u8().unwrap();
+        let result = self.bytes[index].get();
         result
     }
 
@@ -90,15 +91,24 @@ impl Memory for RamMemory {
         value: u8,
     ) -> Option<SetMemoryResult> {
         let index = self.remap_address_to_index(address);
-        self.bytes[index] = ((value) & 0x000000ff) as u8;
+        self.bytes[index].set(((value) & 0x000000ff) as u8);
         None
     }
+
+    fn get_direct_memory_page(&self) -> Option<MemoryPage> {
+        Some(MemoryPage::Ram {
+            start_address: self.start_address,
+            bytes: self.bytes.clone(),
+        })
+    }
 }
 
 impl RamMemory {
     pub fn from_range<'a>(start_address: u32, end_address: u32) -> RamMemory {
         let length = end_address as usize - start_address as usize + 1;
-        let bytes = vec![0; length + 8];
+        let bytes = (0..length + 2 * RAM_MEMORY_PADDING)
+            .map(|_| Cell::new(0))
+            .collect();
         let mem = RamMemory {
             start_address,
             end_address,
@@ -112,15 +122,15 @@ impl RamMemory {
         assert_eq!(true, length > 0);
         let end_address = start_address + length - 1;
         let length = bytes.len();
-        let mut new_bytes = vec![0; length + 8];
+        let mut new_bytes = vec![0; length + 2 * RAM_MEMORY_PADDING];
         for i in 0..length {
-            new_bytes[i + 4] = bytes[i];
+            new_bytes[i + RAM_MEMORY_PADDING] = bytes[i];
         }
         let mem = RamMemory {
             start_address,
             end_address,
             length,
-            bytes: new_bytes,
+            bytes: new_bytes.into_iter().map(Cell::new).collect(),
         };
         mem
     }
@@ -129,8 +139,8 @@ impl RamMemory {
         if address < self.start_address || address > self.end_address {
             panic!("Can't remap address to index. Address {:#010x} not in range of {:#010x} to {:#010x}", address, self.start_address, self.end_address)
         }
-        let index = address - self.start_address + 4;
+        let index = address - self.start_address;
 
-        return index.try_into().unwrap();
+        return index as usize + RAM_MEMORY_PADDING;
     }
 }
@@ -1,18 +1,21 @@
 use crate::{cpu::step_log::StepLog, mem::memory::SetMemoryResult};
 
 use super::memory::Memory;
+use super::memorypage::MemoryPage;
 use byteorder::{BigEndian, ReadBytesExt};
 use std::{
     any::Any,
     convert::TryInto,
     fmt::{self},
+    rc::Rc,
 };
 
 pub struct RomMemory {
     pub start_address: u32,
     pub end_address: u32,
     length: usize,
-    bytes: Vec<u8>,
+    // Shared with the memory pages of Mem, which read the bytes directly
+    bytes: Rc<[u8]>,
 }
 
 impl fmt::Display for RomMemory {
@@ -83,6 +86,13 @@ impl Memory for RomMemory {
         step_log.add_log_string(format!("ROM: Trying to set_byte: ${:08X}", address));
         None
     }
+
+    fn get_direct_memory_page(&self) -> Option<MemoryPage> {
+        Some(MemoryPage::Rom {
+            start_address: self.start_address,
+            bytes: self.bytes.clone(),
+        })
+    }
 }
 
 impl RomMemory {
@@ -95,7 +105,7 @@ impl RomMemory {
             start_address: start_address,
             end_address,
             length: length,
-            bytes: bytes,
+            bytes: bytes.into(),
         };
         Ok(mem)
     }
@@ -0,0 +1,355 @@
+use crate::mem::Mem;
+
+// The eight hardware sprites, 16 lores pixels wide with three colors and transparent. Writing
+// SPRxCTL disarms a sprite and writing SPRxDATA arms it, an armed sprite is shown on each line
+// at its horizontal start.
+//  - Sprite DMA fetches SPRxPOS/SPRxCTL from SPRxPT on the first line after vertical blank, and
+//    SPRxDATA/SPRxDATB on each line from the vertical start to the vertical stop. On the stop
+//    line the next SPRxPOS/SPRxCTL are fetched, a pair of zero words ends the sprite.
+//  - Sprites 0/1, 2/3, 4/5 and 6/7 share COLOR17-19, 21-23, 25-27 and 29-31. When the odd
+//    sprite of a pair is attached, the pair is one sprite with 15 colors, COLOR17-31.
+//  - CLXDAT collects the collisions between the playfields and the sprite pairs, enabled and
+//    matched by CLXCON.
+
+pub const SPRCTL_ATTACH: u16 = 0x0080;
+
+// The first line after vertical blank (HRM page 94)
+const SPRITE_DMA_FIRST_LINE: u16 = 0x19;
+
+const CLXCON_ENSP_SHIFT: u16 = 12;
+const CLXCON_ENBP_SHIFT: u16 = 6;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SpriteDmaState {
+    FetchControl,
+    WaitStart,
+    FetchData,
+    Done,
+}
+
+#[derive(Copy, Clone)]
+pub struct Sprite {
+    pub pt: u32,
+    pub pos: u16,
+    pub ctl: u16,
+    pub data: u16,
+    pub datb: u16,
+    armed: bool,
+    dma_state: SpriteDmaState,
+}
+
+impl Sprite {
+    pub fn new() -> Sprite {
+        Sprite {
+            pt: 0x00000000,
+            pos: 0x0000,
+            ctl: 0x0000,
+            data: 0x0000,
+            datb: 0x0000,
+            armed: false,
+            dma_state: SpriteDmaState::FetchControl,
+        }
+    }
+
+    pub fn is_armed(&self) -> bool {
+        self.armed
+    }
+
+    pub fn is_attached(&self) -> bool {
+        self.ctl & SPRCTL_ATTACH == SPRCTL_ATTACH
+    }
+
+    pub fn write_pos(&mut self, value: u16) {
+        self.pos = value;
+    }
+
+    pub fn write_ctl(&mut self, value: u16) {
+        self.ctl = value;
+        self.armed = false;
+    }
+
+    pub fn write_data(&mut self, value: u16) {
+        self.data = value;
+        self.armed = true;
+    }
+
+    pub fn write_datb(&mut self, value: u16) {
+        self.datb = value;
+    }
+
+    // H8-H1 in SPRxPOS and H0 in SPRxCTL
+    pub fn get_hstart(&self) -> u16 {
+        ((self.pos & 0x00ff) << 1) | (self.ctl & 0x0001)
+    }
+
+    // V7-V0 in SPRxPOS and V8 in SPRxCTL
+    pub fn get_vstart(&self) -> u16 {
+        (self.pos >> 8) | ((self.ctl & 0x0004) << 6)
+    }
+
+    // V7-V0 and V8 in SPRxCTL
+    pub fn get_vstop(&self) -> u16 {
+        (self.ctl >> 8) | ((self.ctl & 0x0002) << 7)
+    }
+
+    // Called at vertical blank
+    pub fn restart_dma(&mut self) {
+        self.dma_state = SpriteDmaState::FetchControl;
+    }
+
+    fn fetch_word(&mut self, mem: &Mem) -> u16 {
+        let word = mem.get_chip_word(self.pt);
+        self.pt = self.pt.wrapping_add(2);
+        word
+    }
+
+    fn fetch_control(&mut self, mem: &Mem) {
+        let pos = self.fetch_word(mem);
+        let ctl = self.fetch_word(mem);
+        self.write_pos(pos);
+        self.write_ctl(ctl);
+        self.dma_state = match (pos, ctl) {
+            (0x0000, 0x0000) => SpriteDmaState::Done,
+            _ => SpriteDmaState::WaitStart,
+        };
+    }
+
+    // Runs the sprite DMA of a line
+    pub fn step_dma_line(&mut self, mem: &Mem, vpos: u16) {
+        if self.dma_state == SpriteDmaState::WaitStart && vpos == self.get_vstart() {
+            self.dma_state = SpriteDmaState::FetchData;
+        }
+        match self.dma_state {
+            SpriteDmaState::FetchControl if vpos >= SPRITE_DMA_FIRST_LINE => {
+                self.fetch_control(mem);
+            }
+            SpriteDmaState::FetchData if vpos == self.get_vstop() => {
+                self.fetch_control(mem);
+            }
+            SpriteDmaState::FetchData => {
+                let data = self.fetch_word(mem);
+                let datb = self.fetch_word(mem);
+                self.write_datb(datb);
+                self.write_data(data);
+            }
+            _ => (),
+        }
+    }
+
+    // The two bit color of the sprite at a lores pixel, 0 is transparent. The sprite position
+    // is one pixel left of the display window position, HSTART $80 lines up with DIWSTRT $81.
+    pub fn get_pixel(&self, lores_x: u16) -> u16 {
+        let first_pixel = self.get_hstart() + 1;
+        if !self.armed || lores_x < first_pixel || lores_x >= first_pixel + 16 {
+            return 0;
+        }
+        let bit = 15 - (lores_x - first_pixel);
+        (((self.datb >> bit) & 0x0001) << 1) | ((self.data >> bit) & 0x0001)
+    }
+}
+
+// The color register of the sprites at a lores pixel, and the sprite pair it's from. Lower
+// numbered sprites are in front of higher numbered sprites.
+pub fn get_sprite_color(sprites: &[Sprite; 8], lores_x: u16) -> Option<(usize, usize)> {
+    for pair in 0..4 {
+        let even = sprites[2 * pair].get_pixel(lores_x) as usize;
+        let odd = sprites[2 * pair + 1].get_pixel(lores_x) as usize;
+        let attached = sprites[2 * pair + 1].is_attached();
+        let value = match (attached, even) {
+            (true, _) => (odd << 2) | even,
+            (false, 0) => odd,
+            (false, _) => even,
+        };
+        match (attached, value) {
+            (_, 0) => (),
+            (true, _) => return Some((16 + value, pair)),
+            (false, _) => return Some((16 + 4 * pair + value, pair)),
+        }
+    }
+    None
+}
+
+// The CLXDAT bits of a lores pixel. Playfield 1 is the odd bitplanes and playfield 2 the even
+// bitplanes, a playfield matches when its enabled bitplanes have the match values of CLXCON.
+// The odd sprites take part only when enabled in CLXCON.
+pub fn get_collision_bits(
+    clxcon: u16,
+    color_index: u16,
+    sprites: &[Sprite; 8],
+    lores_x: u16,
+) -> u16 {
+    let enabled_planes = (clxcon >> CLXCON_ENBP_SHIFT) & 0x003f;
+    let matched = !((color_index ^ clxcon) & enabled_planes) & 0x003f;
+    let playfield1 = matched & 0b010101 == 0b010101;
+    let playfield2 = matched & 0b101010 == 0b101010;
+
+    let mut sprite_pairs = [false; 4];
+    for (pair, present) in sprite_pairs.iter_mut().enumerate() {
+        let odd_enabled = (clxcon >> (CLXCON_ENSP_SHIFT + pair as u16)) & 0x0001 == 0x0001;
+        *present = sprites[2 * pair].get_pixel(lores_x) != 0
+            || (odd_enabled && sprites[2 * pair + 1].get_pixel(lores_x) != 0);
+    }
+
+    let mut bits = 0x0000;
+    if playfield1 && playfield2 {
+        bits |= 0x0001;
+    }
+    for (pair, present) in sprite_pairs.iter().enumerate() {
+        if *present && playfield1 {
+            bits |= 0x0002 << pair;
+        }
+        if *present && playfield2 {
+            bits |= 0x0020 << pair;
+        }
+    }
+    let mut bit = 0x0200;
+    for first in 0..4 {
+        for second in first + 1..4 {
+            if sprite_pairs[first] && sprite_pairs[second] {
+                bits |= bit;
+            }
+            bit <<= 1;
+        }
+    }
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mem::denise::Denise;
+    use crate::mem::rammemory::RamMemory;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    const LINE: u16 = 0x2c;
+
+    fn sprite_test_setup(bplcon0: u16, words: &[(u32, u16)]) -> (Mem, Denise) {
+        let mut mem = Mem::new(None, None);
+        let chip_ram = RamMemory::from_range(0x00000000, 0x00000fff);
+        mem.add_range(Rc::new(RefCell::new(chip_ram)));
+        for (address, word) in words {
+            mem.set_chip_word(*address, *word);
+        }
+        let mut denise = Denise::new();
+        denise.bplcon0 = bplcon0;
+        denise.diwstrt = 0x2c81;
+        denise.diwstop = 0x2cc1;
+        denise.ddfstrt = 0x0038;
+        denise.ddfstop = 0x00d0;
+        denise.bplpt[0] = 0x100;
+        (mem, denise)
+    }
+
+    fn get_palette() -> [u16; 32] {
+        let mut palette = [0x0000; 32];
+        palette[0] = 0x0005;
+        palette[1] = 0x0fff;
+        palette[17] = 0x0f00;
+        palette[18] = 0x00f0;
+        palette[23] = 0x0ff0;
+        palette
+    }
+
+    // The hires pixel of a lores pixel inside the display window
+    fn get_x(lores_pixel: usize) -> usize {
+        (0x81 + lores_pixel) * 2
+    }
+
+    fn set_sprite(sprite: &mut Sprite, ctl: u16, data: u16, datb: u16) {
+        sprite.write_pos(0x2c40);
+        sprite.write_ctl(ctl);
+        sprite.write_datb(datb);
+        sprite.write_data(data);
+    }
+
+    #[test]
+    fn armed_sprite_is_shown_in_front_of_background() {
+        // arrange
+        let (mem, mut denise) = sprite_test_setup(0x0200, &[]);
+        set_sprite(&mut denise.sprites[0], 0x2d00, 0x8000, 0x4000);
+        // act
+        denise.render_line(&mem, LINE, true, &get_palette(), false, false);
+        // assert
+        let row = 2 * LINE as usize;
+        assert_eq!((0xff, 0x00, 0x00), denise.get_pixel_rgb(get_x(0), row));
+        assert_eq!((0x00, 0xff, 0x00), denise.get_pixel_rgb(get_x(1) + 1, row));
+        assert_eq!((0x00, 0x00, 0x55), denise.get_pixel_rgb(get_x(2), row));
+        assert_eq!((0x00, 0x00, 0x55), denise.get_pixel_rgb(get_x(0) - 1, row));
+    }
+
+    #[test]
+    fn sprite_dma_fetches_control_and_data_words() {
+        // arrange
+        let words = [(0x300, 0x2c40), (0x302, 0x2d00), (0x304, 0xc000)];
+        let (mem, mut denise) = sprite_test_setup(0x0200, &words);
+        denise.sprites[0].pt = 0x300;
+        // act
+        for line in 0..LINE + 2 {
+            denise.render_line(&mem, line, true, &get_palette(), false, true);
+        }
+        // assert
+        let row = 2 * LINE as usize;
+        assert_eq!((0xff, 0x00, 0x00), denise.get_pixel_rgb(get_x(1), row));
+        assert_eq!((0x00, 0x00, 0x55), denise.get_pixel_rgb(get_x(1), row + 2));
+        assert!(!denise.sprites[0].is_armed());
+        assert_eq!(0x30c, denise.sprites[0].pt);
+    }
+
+    #[test]
+    fn attached_sprites_have_15_colors() {
+        // arrange
+        let (mem, mut denise) = sprite_test_setup(0x0200, &[]);
+        set_sprite(&mut denise.sprites[0], 0x2d00, 0x8000, 0x8000);
+        set_sprite(
+            &mut denise.sprites[1],
+            0x2d00 | SPRCTL_ATTACH,
+            0x8000,
+            0x0000,
+        );
+        // act
+        denise.render_line(&mem, LINE, true, &get_palette(), false, false);
+        // assert
+        let color = denise.get_pixel_rgb(get_x(0), 2 * LINE as usize);
+        assert_eq!((0xff, 0xff, 0x00), color);
+    }
+
+    #[test]
+    fn bplcon2_puts_sprites_behind_or_in_front_of_playfield() {
+        // arrange
+        let (mem, mut denise) = sprite_test_setup(0x1200, &[(0x100, 0xc000)]);
+        set_sprite(&mut denise.sprites[0], 0x2d00, 0xe000, 0x0000);
+        // act
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        let behind = denise.get_pixel_rgb(get_x(0), 2 * LINE as usize);
+        let transparent = denise.get_pixel_rgb(get_x(2), 2 * LINE as usize);
+        denise.bplpt[0] = 0x100;
+        denise.bplcon2 = 0x0024;
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        let in_front = denise.get_pixel_rgb(get_x(0), 2 * LINE as usize);
+        // assert
+        assert_eq!((0xff, 0xff, 0xff), behind);
+        assert_eq!((0xff, 0x00, 0x00), transparent);
+        assert_eq!((0xff, 0x00, 0x00), in_front);
+    }
+
+    #[test]
+    fn sprite_playfield_collision_is_set_in_clxdat_until_read() {
+        // arrange
+        let (mem, mut denise) = sprite_test_setup(0x1200, &[(0x100, 0x0080)]);
+        set_sprite(&mut denise.sprites[0], 0x2d00, 0x0100, 0x0000);
+        denise.clxcon = 0x00c3; // ENBP1, ENBP2, MVBP1, MVBP2
+                                // act
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        let no_collision = denise.read_clxdat();
+        set_sprite(&mut denise.sprites[0], 0x2d00, 0x0180, 0x0000);
+        denise.bplpt[0] = 0x100;
+        denise.render_line(&mem, LINE, true, &get_palette(), true, false);
+        let collision = denise.read_clxdat();
+        let cleared = denise.read_clxdat();
+        // assert
+        assert_eq!(0x0000, no_collision);
+        assert_eq!(0x0002, collision);
+        assert_eq!(0x0000, cleared);
+    }
+}
@@ -6,6 +6,17 @@ use std::{
     fmt::{self},
 };
 
+// What happens when the CPU accesses an address that isn't mapped to any memory
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum UnmappedAccessPolicy {
+    // Reads return the last value seen on the data bus, writes are ignored
+    OpenBus,
+    // Reads return zero, writes are ignored
+    Zero,
+    // The access is terminated with /BERR, raising a bus error exception
+    BusError,
+}
+
 pub struct UnmappedMemory {
     pub start_address: u32,
     pub end_address: u32,
@@ -1,14 +1,17 @@
+use crate::clock::{
+    ClockDivider, MasterClock, MASTER_TICKS_PER_COLOR_CLOCK, MASTER_TICKS_PER_E_CLOCK,
+};
 use crate::cpu::instruction::GetDisassemblyResult;
 use crate::cpu::step_log::StepLog;
 use crate::cpu::Cpu;
 use crate::kickstart::Kickstart;
 use crate::mem::ciamemory::CiaMemory;
-use crate::mem::custommemory::CustomMemory;
+use crate::mem::beamcounter::BeamEvent;
+use crate::mem::custommemory::{CustomMemory, INTREQ_EXTER, INTREQ_PORTS};
 use crate::mem::Mem;
 use crate::register::ProgramCounter;
 use std::cell::RefCell;
 use std::rc::Rc;
-use std::time::{Duration, Instant};
 
 pub struct Modermodem {
     kickstart: Option<Rc<RefCell<Kickstart>>>,
@@ -17,13 +20,14 @@ pub struct Modermodem {
     custom_memory: Option<Rc<RefCell<CustomMemory>>>,
     cia_memory: Option<Rc<RefCell<CiaMemory>>>,
     step_log: StepLog,
-    previous_now: Option<Instant>,
-    emulator_time: Duration,
-    emulator_time_next_log: Duration,
+    master_clock: MasterClock,
+    color_clock: ClockDivider,
+    e_clock: ClockDivider,
 }
 
 impl Modermodem {
     pub fn bare(cpu: Cpu, mem: Mem) -> Self {
+        let master_clock = MasterClock::new(&cpu.cpu_speed);
         Self {
             kickstart: None,
             cpu,
@@ -31,9 +35,9 @@ impl Modermodem {
             step_log: StepLog::none(),
             custom_memory: None,
             cia_memory: None,
-            previous_now: None,
-            emulator_time: Duration::ZERO,
-            emulator_time_next_log: Duration::ZERO,
+            master_clock,
+            color_clock: ClockDivider::new(MASTER_TICKS_PER_COLOR_CLOCK),
+            e_clock: ClockDivider::new(MASTER_TICKS_PER_E_CLOCK),
         }
     }
 
@@ -45,6 +49,7 @@ impl Modermodem {
         custom_memory: Rc<RefCell<CustomMemory>>,
         cia_memory: Rc<RefCell<CiaMemory>>,
     ) -> Self {
+        let master_clock = MasterClock::new(&cpu.cpu_speed);
         Self {
             kickstart: Some(kickstart),
             cpu,
@@ -52,56 +57,99 @@ impl Modermodem {
             step_log,
             custom_memory: Some(custom_memory),
             cia_memory: Some(cia_memory),
-            previous_now: None,
-            emulator_time: Duration::ZERO,
-            emulator_time_next_log: Duration::ZERO,
+            master_clock,
+            color_clock: ClockDivider::new(MASTER_TICKS_PER_COLOR_CLOCK),
+            e_clock: ClockDivider::new(MASTER_TICKS_PER_E_CLOCK),
         }
     }
 
-    pub fn step(&mut self) {
-        let now = Instant::now();
-        let passed = match self.previous_now {
-            Some(time) => now.duration_since(time),
-            None => now.duration_since(now),
-        };
-        self.previous_now = Some(now);
-        let last_cycle = self.emulator_time.as_nanos() * self.cpu.cpu_speed.get_hz() / 1000000000;
-        self.emulator_time += passed;
-
-        let this_cycle = self.emulator_time.as_nanos() * self.cpu.cpu_speed.get_hz() / 1000000000;
-
-        // println!("emulator_time: {}.{} [{}]", self.emulator_time.as_secs(), self.emulator_time.subsec_millis(), passed.as_nanos());
-        if self.emulator_time > self.emulator_time_next_log {
-            println!(
-                "last_cycle: {} this_cycle: {} num_cycles: [{}]",
-                last_cycle,
-                this_cycle,
-                this_cycle - last_cycle
-            );
-            self.emulator_time_next_log += Duration::from_secs(1);
+    pub fn get_master_clock_ticks(&self) -> u64 {
+        self.master_clock.get_ticks()
+    }
+
+    // By default the emulation runs as fast as possible. In real time mode it's slowed down to
+    // the speed of a real Amiga.
+    pub fn set_real_time(&mut self, real_time: bool) {
+        self.master_clock.set_real_time(real_time);
+    }
+
+    // Executes one instruction and returns the number of CPU clock cycles it took. The rest of
+    // the chips are then advanced by the same amount of master clock time, so a run is
+    // deterministic and doesn't depend on the speed of the host.
+    pub fn step(&mut self) -> u32 {
+        if let Some(custom_memory) = &self.custom_memory {
+            let interrupt_priority_level = custom_memory.borrow().get_interrupt_priority_level();
+            self.cpu.set_interrupt_priority_level(interrupt_priority_level);
         }
 
         self.step_log.reset_log();
         self.step_log.log_disassembly(&mut self.cpu, &mut self.mem);
-        self.cpu
+        let cycles = self
+            .cpu
             .execute_next_instruction_step_log(&mut self.mem, &mut self.step_log);
         self.step_log.print(&mut self.cpu, &mut self.mem);
 
-        // step custom register stuff
-        // TODO: Move to inside CustomMemory
+        self.master_clock.add_cpu_cycles(cycles);
+        let master_ticks = self.master_clock.get_ticks();
+
+        let color_clocks = self.color_clock.advance_to(master_ticks);
+        let mut horizontal_syncs = 0;
+        let mut vertical_syncs = 0;
         if let Some(custom_memory) = &self.custom_memory {
             let mut custom_memory = custom_memory.borrow_mut();
-            let mut new_vhpos = custom_memory.vhpos + 1;
-            // e2 is the max horizontal position (according to hrm page 23)
-            if new_vhpos & 0x00ff > 0xe2 {
-                new_vhpos &= 0xff00;
-                new_vhpos += 0x0100;
+            for _ in 0..color_clocks {
+                match custom_memory.step_color_clock(&self.mem) {
+                    BeamEvent::None => (),
+                    BeamEvent::StartOfLine => horizontal_syncs += 1,
+                    BeamEvent::StartOfFrame => {
+                        horizontal_syncs += 1;
+                        vertical_syncs += 1;
+                    }
+                }
             }
-            custom_memory.vhpos = new_vhpos;
         }
+
+        let e_clocks = self.e_clock.advance_to(master_ticks);
         if let Some(cia_memory) = &self.cia_memory {
             let mut cia_memory = cia_memory.borrow_mut();
-            cia_memory.step_clock_cycle();
+            for _ in 0..e_clocks {
+                cia_memory.step_clock_cycle();
+            }
+            for _ in 0..horizontal_syncs {
+                cia_memory.step_horizontal_sync();
+            }
+            for _ in 0..vertical_syncs {
+                cia_memory.step_vertical_sync();
+            }
+
+            if let Some(custom_memory) = &self.custom_memory {
+                let mut custom_memory = custom_memory.borrow_mut();
+                if cia_memory.is_cia_a_interrupt_requested() {
+                    custom_memory.request_interrupt(INTREQ_PORTS);
+                }
+                if cia_memory.is_cia_b_interrupt_requested() {
+                    custom_memory.request_interrupt(INTREQ_EXTER);
+                }
+            }
+        }
+
+        self.master_clock.throttle();
+        cycles
+    }
+
+    // The number of frames completed by the display, 0 without custom chips
+    pub fn get_frame_count(&self) -> u64 {
+        match &self.custom_memory {
+            Some(custom_memory) => custom_memory.borrow().denise.get_frame_count(),
+            None => 0,
+        }
+    }
+
+    // Writes the last completed frame to a PNG file
+    pub fn write_frame_png(&self, file_path: &str) -> std::io::Result<()> {
+        match &self.custom_memory {
+            Some(custom_memory) => custom_memory.borrow().denise.write_png(file_path),
+            None => Ok(()),
         }
     }
 
@@ -2,10 +2,12 @@ use std::fmt;
 
 use crate::{
     cpu::{
-        Cpu,
+        Cpu, CpuModel,
         ea::EffectiveAddressingData,
+        fpu::Fpu,
         instruction::{
-            ConditionalTest, EffectiveAddressingMode, InstructionError, OperationSize, ScaleFactor,
+            ConditionalTest, Displacement, EffectiveAddressingMode, IndexRegister,
+            InstructionError, OperationSize, ScaleFactor, StepError,
         },
         StatusRegisterResult, step_log::{StepLog, StepLogEntry},
     },
@@ -18,9 +20,14 @@ pub const STATUS_REGISTER_MASK_OVERFLOW: u16 = 0b0000000000000010;
 pub const STATUS_REGISTER_MASK_ZERO: u16 = 0b0000000000000100;
 pub const STATUS_REGISTER_MASK_NEGATIVE: u16 = 0b0000000000001000;
 pub const STATUS_REGISTER_MASK_EXTEND: u16 = 0b0000000000010000;
+pub const STATUS_REGISTER_MASK_CONDITION_CODES: u16 = 0b0000000000011111;
+
+pub const STATUS_REGISTER_MASK_INTERRUPT_PRIORITY: u16 = 0b0000011100000000;
 
 pub const STATUS_REGISTER_MASK_MASTER_INTERRUPT_STATE: u16 = 0b0001000000000000;
 pub const STATUS_REGISTER_MASK_SUPERVISOR_STATE: u16 = 0b0010000000000000;
+pub const STATUS_REGISTER_MASK_TRACE_0: u16 = 0b0100000000000000;
+pub const STATUS_REGISTER_MASK_TRACE_1: u16 = 0b1000000000000000;
 
 #[derive(Copy, Clone, Debug, std::cmp::PartialEq)]
 pub enum RegisterType {
@@ -112,14 +119,19 @@ impl ProgramCounter {
         self.address_next = self.address_next.wrapping_add(1);
     }
 
+    // Byte immediate data is the low byte of an instruction word
     pub fn fetch_next_byte(&mut self, mem: &Mem) -> u8 {
-        let word = mem.get_byte_no_log(self.address_next);
+        let word = mem.get_instruction_word(self.address_next & 0xfffffffe);
+        let byte = match self.address_next & 0x00000001 {
+            0 => (word >> 8) as u8,
+            _ => word as u8,
+        };
         self.address_next = self.address_next.wrapping_add(1);
-        word
+        byte
     }
 
     pub fn peek_next_word(&self, mem: &Mem) -> u16 {
-        let word = mem.get_word_no_log(self.address_next);
+        let word = mem.get_instruction_word(self.address_next);
         word
     }
 
@@ -128,15 +140,16 @@ impl ProgramCounter {
     }
 
     pub fn fetch_next_word(&mut self, mem: &Mem) -> u16 {
-        let word = mem.get_word_no_log(self.address_next);
+        let word = mem.get_instruction_word(self.address_next);
         self.address_next = self.address_next.wrapping_add(2);
         word
     }
 
     pub fn fetch_next_long(&mut self, mem: &Mem) -> u32 {
-        let word = mem.get_long_no_log(self.address_next);
+        let high = mem.get_instruction_word(self.address_next);
+        let low = mem.get_instruction_word(self.address_next.wrapping_add(2));
         self.address_next = self.address_next.wrapping_add(4);
-        word
+        ((high as u32) << 16) | low as u32
     }
 
     pub fn get_step_next_pc(&self) -> ProgramCounter {
@@ -158,6 +171,10 @@ impl ProgramCounter {
         self.address
     }
 
+    pub fn is_jump(&self) -> bool {
+        self.address_jump.is_some()
+    }
+
     pub fn get_address_next(&self) -> u32 {
         self.address_next
     }
@@ -169,7 +186,7 @@ impl ProgramCounter {
         mem: &Mem,
         step_log: &mut StepLog,
         get_operation_size_func: T,
-    ) -> Result<EffectiveAddressingData, InstructionError>
+    ) -> Result<EffectiveAddressingData, StepError>
         where
             T: Fn(u16) -> Result<OperationSize, InstructionError>,
     {
@@ -193,7 +210,7 @@ impl ProgramCounter {
         get_operation_size_func: T,
         bit_pos: u8,
         reg_bit_pos: u8,
-    ) -> Result<EffectiveAddressingData, InstructionError>
+    ) -> Result<EffectiveAddressingData, StepError>
         where
             T: Fn(u16) -> Result<OperationSize, InstructionError>,
     {
@@ -247,7 +264,18 @@ impl ProgramCounter {
                     _ => 'B',      // brief
                 };
                 if extension_word_format == 'F' {
-                    todo!("Full extension word format not implemented")
+                    let ea_mode = self.get_full_extension_word_a_reg_mode(
+                        extension_word,
+                        ea_register,
+                        reg,
+                        mem,
+                        step_log,
+                    )?;
+                    return Ok(EffectiveAddressingData::create(
+                        instr_word,
+                        operation_size,
+                        ea_mode,
+                    ));
                 }
 
                 // TODO: Fix step_log here
@@ -307,13 +335,26 @@ impl ProgramCounter {
                     }
                 }
                 0b011 => {
+                    // The PC is the address of the extension word
+                    let pc_address = self.address_next;
                     let extension_word = self.fetch_next_word(mem);
                     let extension_word_format = match extension_word & 0x0100 {
                         0x0100 => 'F', // full
                         _ => 'B',      // brief
                     };
                     if extension_word_format == 'F' {
-                        todo!("Full extension word format not implemented")
+                        let ea_mode = self.get_full_extension_word_pc_mode(
+                            extension_word,
+                            pc_address,
+                            reg,
+                            mem,
+                            step_log,
+                        )?;
+                        return Ok(EffectiveAddressingData::create(
+                            instr_word,
+                            operation_size,
+                            ea_mode,
+                        ));
                     }
 
                     let register = Cpu::extract_register_index_from_bit_pos(extension_word, 12)?;
@@ -396,6 +437,183 @@ impl ProgramCounter {
             ea_mode,
         ))
     }
+
+    fn get_full_extension_word_a_reg_mode(
+        &mut self,
+        extension_word: u16,
+        ea_register: usize,
+        reg: &Register,
+        mem: &Mem,
+        step_log: &mut StepLog,
+    ) -> Result<EffectiveAddressingMode, StepError> {
+        let base_address = reg.get_a_reg_long(ea_register, step_log);
+        let full =
+            self.fetch_full_extension_word(extension_word, base_address, reg, mem, step_log)?;
+        let ea_register = match full.base_suppressed {
+            true => None,
+            false => Some(ea_register),
+        };
+        let ea_mode = match full.memory_indirect {
+            MemoryIndirect::None => {
+                EffectiveAddressingMode::ARegIndirectWithIndexBaseDisplacement {
+                    ea_register,
+                    ea_address: full.ea_address,
+                    base_displacement: full.base_displacement,
+                    index: full.index,
+                }
+            }
+            MemoryIndirect::PreIndexed { outer_displacement } => {
+                EffectiveAddressingMode::MemoryIndirectPreIndexed {
+                    ea_register,
+                    ea_address: full.ea_address,
+                    base_displacement: full.base_displacement,
+                    index: full.index,
+                    outer_displacement,
+                }
+            }
+            MemoryIndirect::PostIndexed { outer_displacement } => {
+                EffectiveAddressingMode::MemoryIndirectPostIndexed {
+                    ea_register,
+                    ea_address: full.ea_address,
+                    base_displacement: full.base_displacement,
+                    index: full.index,
+                    outer_displacement,
+                }
+            }
+        };
+        Ok(ea_mode)
+    }
+
+    fn get_full_extension_word_pc_mode(
+        &mut self,
+        extension_word: u16,
+        pc_address: u32,
+        reg: &Register,
+        mem: &Mem,
+        step_log: &mut StepLog,
+    ) -> Result<EffectiveAddressingMode, StepError> {
+        let full =
+            self.fetch_full_extension_word(extension_word, pc_address, reg, mem, step_log)?;
+        let pc_suppressed = full.base_suppressed;
+        let ea_mode = match full.memory_indirect {
+            MemoryIndirect::None => EffectiveAddressingMode::PcIndirectWithIndexBaseDisplacement {
+                pc_suppressed,
+                ea_address: full.ea_address,
+                base_displacement: full.base_displacement,
+                index: full.index,
+            },
+            MemoryIndirect::PreIndexed { outer_displacement } => {
+                EffectiveAddressingMode::PcMemoryIndirectPreIndexed {
+                    pc_suppressed,
+                    ea_address: full.ea_address,
+                    base_displacement: full.base_displacement,
+                    index: full.index,
+                    outer_displacement,
+                }
+            }
+            MemoryIndirect::PostIndexed { outer_displacement } => {
+                EffectiveAddressingMode::PcMemoryIndirectPostIndexed {
+                    pc_suppressed,
+                    ea_address: full.ea_address,
+                    base_displacement: full.base_displacement,
+                    index: full.index,
+                    outer_displacement,
+                }
+            }
+        };
+        Ok(ea_mode)
+    }
+
+    // Full extension word format (68020+):
+    // 15: D/A, 14-12: register, 11: W/L, 10-9: scale, 8: 1, 7: BS, 6: IS, 5-4: BD SIZE, 3: 0,
+    // 2-0: I/IS
+    fn fetch_full_extension_word(
+        &mut self,
+        extension_word: u16,
+        base_address: u32,
+        reg: &Register,
+        mem: &Mem,
+        step_log: &mut StepLog,
+    ) -> Result<FullExtensionWord, StepError> {
+        if reg.cpu_model == CpuModel::M68000 || (extension_word & 0x0008) != 0 {
+            return Err(StepError::IllegalInstruction);
+        }
+        let base_suppressed = (extension_word & 0x0080) != 0;
+        let index_suppressed = (extension_word & 0x0040) != 0;
+        let index_indirect_selection = extension_word & 0x0007;
+        let base_displacement = match (extension_word >> 4) & 0x0003 {
+            0b01 => Displacement::Null,
+            0b10 => Displacement::Word(self.fetch_next_word(mem)),
+            0b11 => Displacement::Long(self.fetch_next_long(mem)),
+            _ => return Err(StepError::IllegalInstruction),
+        };
+        let outer_displacement = match index_indirect_selection & 0x0003 {
+            0b10 => Displacement::Word(self.fetch_next_word(mem)),
+            0b11 => Displacement::Long(self.fetch_next_long(mem)),
+            _ => Displacement::Null,
+        };
+        let memory_indirect = match (index_suppressed, index_indirect_selection) {
+            (_, 0b000) => MemoryIndirect::None,
+            (_, 0b001..=0b011) => MemoryIndirect::PreIndexed { outer_displacement },
+            (false, 0b101..=0b111) => MemoryIndirect::PostIndexed { outer_displacement },
+            _ => return Err(StepError::IllegalInstruction),
+        };
+        let index = match index_suppressed {
+            true => None,
+            false => Some(IndexRegister {
+                register_type: match extension_word & 0x8000 {
+                    0x8000 => RegisterType::Address,
+                    _ => RegisterType::Data,
+                },
+                register: Cpu::extract_register_index_from_bit_pos(extension_word, 12)?,
+                index_size: match extension_word & 0x0800 {
+                    0x0800 => OperationSize::Long,
+                    _ => OperationSize::Word,
+                },
+                scale_factor: Cpu::extract_scale_factor_from_bit_pos(extension_word, 9),
+            }),
+        };
+        let index_value = match &index {
+            Some(index) => reg.get_index_register_value(index, step_log),
+            None => 0,
+        };
+        let base_address = match base_suppressed {
+            true => 0,
+            false => base_address,
+        };
+        let base_address = base_address.wrapping_add(base_displacement.get_value());
+        let ea_address = match memory_indirect {
+            MemoryIndirect::None => base_address.wrapping_add(index_value),
+            MemoryIndirect::PreIndexed { outer_displacement } => mem
+                .get_long(step_log, base_address.wrapping_add(index_value))?
+                .wrapping_add(outer_displacement.get_value()),
+            MemoryIndirect::PostIndexed { outer_displacement } => mem
+                .get_long(step_log, base_address)?
+                .wrapping_add(index_value)
+                .wrapping_add(outer_displacement.get_value()),
+        };
+        Ok(FullExtensionWord {
+            base_suppressed,
+            ea_address,
+            base_displacement,
+            index,
+            memory_indirect,
+        })
+    }
+}
+
+enum MemoryIndirect {
+    None,
+    PreIndexed { outer_displacement: Displacement },
+    PostIndexed { outer_displacement: Displacement },
+}
+
+struct FullExtensionWord {
+    base_suppressed: bool,
+    ea_address: u32,
+    base_displacement: Displacement,
+    index: Option<IndexRegister>,
+    memory_indirect: MemoryIndirect,
 }
 
 pub struct Register {
@@ -405,6 +623,17 @@ pub struct Register {
     reg_ssp: u32,
     pub reg_sr: StatusRegister,
     pub reg_pc: ProgramCounter,
+    pub cpu_model: CpuModel,
+    // 68010+ control registers, only accessible through MOVEC
+    pub reg_vbr: u32,
+    pub reg_sfc: u32,
+    pub reg_dfc: u32,
+    // 68020+ control registers
+    pub reg_cacr: u32,
+    pub reg_caar: u32,
+    pub reg_msp: u32,
+    // The optional 68881/68882 coprocessor
+    pub fpu: Option<Fpu>,
 }
 
 impl Register {
@@ -416,6 +645,14 @@ impl Register {
             reg_ssp: 0x00000000,
             reg_sr: StatusRegister::from_word(STATUS_REGISTER_MASK_SUPERVISOR_STATE),
             reg_pc: ProgramCounter::from_address(0x00000000),
+            cpu_model: CpuModel::M68000,
+            reg_vbr: 0x00000000,
+            reg_sfc: 0x00000000,
+            reg_dfc: 0x00000000,
+            reg_cacr: 0x00000000,
+            reg_caar: 0x00000000,
+            reg_msp: 0x00000000,
+            fpu: None,
         };
         register
     }
@@ -432,6 +669,19 @@ impl Register {
         T::get_from_long(value)
     }
 
+    // Value of an index register, sign extended when word sized and scaled
+    pub fn get_index_register_value(&self, index: &IndexRegister, step_log: &mut StepLog) -> u32 {
+        let register_value = match index.register_type {
+            RegisterType::Address => self.get_a_reg_long(index.register, step_log),
+            RegisterType::Data => self.get_d_reg_long(index.register, step_log),
+        };
+        let register_value = match index.index_size {
+            OperationSize::Long => register_value,
+            _ => Cpu::sign_extend_word(Cpu::get_word_from_long(register_value)),
+        };
+        register_value.wrapping_mul(index.scale_factor.scale_as_int())
+    }
+
     pub fn get_d_reg_long(&self, reg_index: usize, step_log: &mut StepLog) -> u32 {
         let value = self.reg_d[reg_index];
         step_log.add_step_log_entry(StepLogEntry::ReadRegister {
@@ -859,44 +1109,60 @@ impl Register {
         self.reg_usp = value;
     }
 
-    pub fn stack_push_pc(&mut self, mem: &mut Mem, step_log: &mut StepLog) {
+    pub fn stack_push_pc(&mut self, mem: &mut Mem, step_log: &mut StepLog) -> Result<(), StepError> {
         let pc = self.reg_pc.address;
         self.decrement_a_reg(7, step_log, OperationSize::Long);
         let sp = self.get_a_reg_long(7, step_log);
-        mem.set_long(step_log, sp, pc);
+        mem.set_long(step_log, sp, pc)
     }
 
-    pub fn stack_pop_pc(&mut self, mem: &mut Mem, pc: &mut ProgramCounter, step_log: &mut StepLog) {
+    pub fn stack_pop_pc(
+        &mut self,
+        mem: &mut Mem,
+        pc: &mut ProgramCounter,
+        step_log: &mut StepLog,
+    ) -> Result<(), StepError> {
         let sp = self.get_a_reg_long(7, step_log);
-        let pc_address = mem.get_long(step_log, sp);
+        let pc_address = mem.get_long(step_log, sp)?;
         self.increment_a_reg(7, step_log, OperationSize::Long);
         pc.address_jump = Some(pc_address);
+        Ok(())
     }
 
-    pub fn stack_push_word(&mut self, mem: &mut Mem, step_log: &mut StepLog, value: u16) {
+    pub fn stack_push_word(
+        &mut self,
+        mem: &mut Mem,
+        step_log: &mut StepLog,
+        value: u16,
+    ) -> Result<(), StepError> {
         self.decrement_a_reg(7, step_log, OperationSize::Word);
         let sp = self.get_a_reg_long(7, step_log);
-        mem.set_word(step_log, sp, value);
+        mem.set_word(step_log, sp, value)
     }
 
-    pub fn stack_pop_word(&mut self, mem: &mut Mem, step_log: &mut StepLog) -> u16 {
+    pub fn stack_pop_word(&mut self, mem: &mut Mem, step_log: &mut StepLog) -> Result<u16, StepError> {
         let sp = self.get_a_reg_long(7, step_log);
-        let result = mem.get_word(step_log, sp);
+        let result = mem.get_word(step_log, sp)?;
         self.increment_a_reg(7, step_log, OperationSize::Word);
-        result
+        Ok(result)
     }
 
-    pub fn stack_push_long(&mut self, mem: &mut Mem, step_log: &mut StepLog, value: u32) {
+    pub fn stack_push_long(
+        &mut self,
+        mem: &mut Mem,
+        step_log: &mut StepLog,
+        value: u32,
+    ) -> Result<(), StepError> {
         self.decrement_a_reg(7, step_log, OperationSize::Long);
         let sp = self.get_a_reg_long(7, step_log);
-        mem.set_long(step_log, sp, value);
+        mem.set_long(step_log, sp, value)
     }
 
-    pub fn stack_pop_long(&mut self, mem: &mut Mem, step_log: &mut StepLog) -> u32 {
+    pub fn stack_pop_long(&mut self, mem: &mut Mem, step_log: &mut StepLog) -> Result<u32, StepError> {
         let sp = self.get_a_reg_long(7, step_log);
-        let result = mem.get_long(step_log, sp);
+        let result = mem.get_long(step_log, sp)?;
         self.increment_a_reg(7, step_log, OperationSize::Long);
-        result
+        Ok(result)
     }
 
     pub fn print_registers(&self) {
@@ -1032,6 +1298,19 @@ impl StatusRegister {
         self.reg_sr |= STATUS_REGISTER_MASK_SUPERVISOR_STATE;
     }
 
+    pub fn clear_trace(&mut self) {
+        self.reg_sr &= !(STATUS_REGISTER_MASK_TRACE_0 | STATUS_REGISTER_MASK_TRACE_1);
+    }
+
+    pub fn get_interrupt_priority_mask(&self) -> u8 {
+        ((self.reg_sr & STATUS_REGISTER_MASK_INTERRUPT_PRIORITY) >> 8) as u8
+    }
+
+    pub fn set_interrupt_priority_mask(&mut self, level: u8) {
+        self.reg_sr = (self.reg_sr & !STATUS_REGISTER_MASK_INTERRUPT_PRIORITY)
+            | (((level as u16) << 8) & STATUS_REGISTER_MASK_INTERRUPT_PRIORITY);
+    }
+
     pub fn is_sr_carry_set(&self) -> bool {
         return (self.reg_sr & STATUS_REGISTER_MASK_CARRY) == STATUS_REGISTER_MASK_CARRY;
     }
@@ -1052,6 +1331,10 @@ impl StatusRegister {
         return (self.reg_sr & STATUS_REGISTER_MASK_EXTEND) == STATUS_REGISTER_MASK_EXTEND;
     }
 
+    pub fn is_sr_trace_set(&self) -> bool {
+        return (self.reg_sr & STATUS_REGISTER_MASK_TRACE_1) == STATUS_REGISTER_MASK_TRACE_1;
+    }
+
     pub fn is_sr_supervisor_set(&self, step_log: &mut StepLog) -> bool {
         let value = (self.reg_sr & STATUS_REGISTER_MASK_SUPERVISOR_STATE)
             == STATUS_REGISTER_MASK_SUPERVISOR_STATE;
@@ -27,6 +27,8 @@ class ParsedLineType(Enum):
     STATUS_REGISTER = 8
     PROGRAM_COUNTER_REGISTER = 9
     SOURCE_CODE = 10
+    CYCLES = 11
+    CPU_MODEL = 12
 
 
 class ParsedLine(object):
@@ -43,6 +45,8 @@ class ParsedLine(object):
     status_flags: list[str]
     status_register: int
     program_counter: int
+    cycles: int
+    cpu_model: str
     source_code_instruction: str
     source_code_operands: str
 
@@ -59,6 +63,8 @@ class ParsedLine(object):
                  status_flags: list[str] = None,
                  status_register: int = None,
                  program_counter: int = None,
+                 cycles: int = None,
+                 cpu_model: str = None,
                  source_code_instruction: str = None,
                  source_code_operands: str = None):
         self.line_type = ParsedLineType.BLANK
@@ -97,6 +103,12 @@ class ParsedLine(object):
         elif program_counter:
             self.line_type = ParsedLineType.PROGRAM_COUNTER_REGISTER
             self.program_counter = program_counter
+        elif cycles is not None:
+            self.line_type = ParsedLineType.CYCLES
+            self.cycles = cycles
+        elif cpu_model is not None:
+            self.line_type = ParsedLineType.CPU_MODEL
+            self.cpu_model = cpu_model
         elif source_code_instruction or source_code_operands:
             self.line_type = ParsedLineType.SOURCE_CODE
             self.source_code_instruction = source_code_instruction
@@ -142,6 +154,7 @@ class TestCase(object):
     arrange_reg_data: ParsedLine
     arrange_reg_address: ParsedLine
     arrange_reg_sr: ParsedLine
+    arrange_cpu_model: ParsedLine
     arrange_code: ParsedLine
     arrange_mem: [ParsedLine]
     assert_reg_data: ParsedLine
@@ -149,6 +162,7 @@ class TestCase(object):
     assert_reg_sr_flags: ParsedLine
     assert_reg_sr: ParsedLine
     assert_reg_pc: ParsedLine
+    assert_reg_cycles: ParsedLine
     assert_code: ParsedLine
     assert_mem: [ParsedLine]
 
@@ -157,6 +171,7 @@ class TestCase(object):
         self.arrange_reg_data = None
         self.arrange_reg_address = None
         self.arrange_reg_sr = None
+        self.arrange_cpu_model = None
         self.arrange_code = None
         self.arrange_mem = []
         self.assert_reg_data = None
@@ -164,9 +179,15 @@ class TestCase(object):
         self.assert_reg_sr_flags = None
         self.assert_reg_sr = None
         self.assert_reg_pc = None
+        self.assert_reg_cycles = None
         self.assert_code = None
         self.assert_mem = []
 
+    def get_cpu_model_string(self):
+        if self.arrange_cpu_model is None:
+            return "CpuModel::M68000"
+        return f"CpuModel::M{self.arrange_cpu_model.cpu_model}"
+
     @staticmethod
     def get_d_reg_string(registers: list[int]):
         result = ""
@@ -268,7 +289,7 @@ class TestCase(object):
         file.write(f"    mem.add_range(Rc::new(RefCell::new(cia_memory)));\n")
         for arr_mem in self.arrange_mem:
             file.write(f"    mem.add_range(Rc::new(RefCell::new(arrange_mem_{arr_mem.address:08x})));\n")
-        file.write(f"    let cpu = Cpu::new(CpuSpeed::NTSC_7_159090_MHz, 0x00000000, {self.arrange_code.address:08x});\n")
+        file.write(f"    let cpu = Cpu::new({self.get_cpu_model_string()}, CpuSpeed::NTSC_7_159090_MHz, 0x00000000, {self.arrange_code.address:08x});\n")
         file.write(f"    let mut modermodem = Modermodem::bare(cpu, mem);\n")
         file.write(f"\n")
 
@@ -310,7 +331,7 @@ class TestCase(object):
             0x{self.arrange_code.address:08x},
             0x{assert_pc:08x},
             String::from("{self.assert_code.source_code_instruction}"),
-            String::from("{self.assert_code.source_code_operands}"),
+            String::from("{self.assert_code.source_code_operands or ''}"),
             vec![{arrange_code_words_hex}]
             ),
             get_disassembly_result
@@ -319,7 +340,10 @@ class TestCase(object):
 
         # Act
         file.write(f"    // act\n")
-        file.write(f"    modermodem.step();\n")
+        if self.assert_reg_cycles is not None:
+            file.write(f"    let cycles = modermodem.step();\n")
+        else:
+            file.write(f"    modermodem.step();\n")
         file.write(f"\n")
 
         # Assert - regs
@@ -339,6 +363,8 @@ class TestCase(object):
         else:
             file.write("       0x0000\n")
         file.write(f"    );\n")
+        if self.assert_reg_cycles is not None:
+            file.write(f"    assert_eq!({self.assert_reg_cycles.cycles}, cycles);\n")
         file.write(f"\n")
 
         # Assert - mem
@@ -466,7 +492,7 @@ class TestSet:
         file.write("use crate::cpu::instruction::GetDisassemblyResult;\n")
         file.write("use crate::mem::memory::Memory;\n")
         file.write("use crate::mem::ciamemory::CiaMemory;\n")
-        file.write("use crate::cpu::{Cpu, CpuSpeed};\n")
+        file.write("use crate::cpu::{Cpu, CpuModel, CpuSpeed};\n")
         file.write("use crate::mem::Mem;\n")
         file.write("use crate::modermodem::Modermodem;\n")
         file.write("use crate::register::STATUS_REGISTER_MASK_CARRY;\n")
@@ -663,6 +689,8 @@ address_register_line_regex = r"^A0(\s+.*)$"
 status_register_flags_line_regex = r"^SR_FLAGS(\s+.*)$"
 status_register_line_regex = r"^SR(\s+.*)$"
 pc_register_line_regex = r"^PC(\s+.*)$"
+cycles_line_regex = r"^CYCLES\s+([0-9]+)$"
+cpu_model_line_regex = r"^CPU_MODEL\s+(68000|68010|68020)$"
 
 
 def get_data_register_line(line_stripped):
@@ -685,6 +713,14 @@ def get_pc_register_line(line_stripped):
     return re.search(pc_register_line_regex, line_stripped)
 
 
+def get_cycles_line(line_stripped):
+    return re.search(cycles_line_regex, line_stripped)
+
+
+def get_cpu_model_line(line_stripped):
+    return re.search(cpu_model_line_regex, line_stripped)
+
+
 bytes_regex = "([0-9a-fA-F]{2})"
 words_regex = "([0-9a-fA-F]{4})"
 longs_regex = "([0-9a-fA-F]{8})"
@@ -837,6 +873,22 @@ def parse_line(line_number, line_raw) -> ParsedLine:
                 f" Expected 1 32-bit integer hexadecimal value (without $ or 0x prefixes). Found {len(longs)} integers: {longs}")
             sys.exit(1)
         line = ParsedLine(line_number=line_number, line_raw=line_raw, program_counter=longs[0])
+    elif line_stripped.startswith('CYCLES'):
+        cycles_line = get_cycles_line(line_stripped)
+        if cycles_line is None:
+            print_err(f"{line_number:5d}: {line_raw}")
+            print_err(f"Unable to parse content of cycles.")
+            print_err(f" Expected 1 decimal integer value with the number of clock cycles used by the instruction.")
+            sys.exit(1)
+        line = ParsedLine(line_number=line_number, line_raw=line_raw, cycles=int(cycles_line.group(1)))
+    elif line_stripped.startswith('CPU_MODEL'):
+        cpu_model_line = get_cpu_model_line(line_stripped)
+        if cpu_model_line is None:
+            print_err(f"{line_number:5d}: {line_raw}")
+            print_err(f"Unable to parse content of cpu model.")
+            print_err(f" Expected one of 68000, 68010 or 68020.")
+            sys.exit(1)
+        line = ParsedLine(line_number=line_number, line_raw=line_raw, cpu_model=cpu_model_line.group(1))
     elif line_stripped.startswith('>'):
         code_parts = line_stripped[1:].strip().split()
         if len(code_parts) == 2:
@@ -847,7 +899,7 @@ def parse_line(line_number, line_raw) -> ParsedLine:
                               source_code_operands=source_code_operands)
         elif len(code_parts) == 1:
             source_code_instruction = code_parts[0]
-            source_code_operands = code_parts[1]
+            source_code_operands = None
             line = ParsedLine(line_number=line_number, line_raw=line_raw,
                               source_code_instruction=source_code_instruction,
                               source_code_operands=source_code_operands)
@@ -1047,6 +1099,32 @@ def get_test_case(test_name: str, parsed_lines: list[ParsedLine], current_line:
                     print_err(
                         f"Syntax Error parsing. Unexpected program counter register found outside of 'assert_reg'.")
                     sys.exit(1)
+                case (ParsedLineType.CYCLES, ParseState.ASSERT_REG):
+                    if test_case.assert_reg_cycles is not None:
+                        print_err(f"{parsed_line.line_number:5d}: {parsed_line.line_raw}")
+                        print_err(
+                            f"Syntax Error parsing. Found multiple rows of cycles for 'assert_reg'.")
+                        sys.exit(1)
+                    else:
+                        test_case.assert_reg_cycles = parsed_line
+                case (ParsedLineType.CYCLES, _):
+                    print_err(f"{parsed_line.line_number:5d}: {parsed_line.line_raw}")
+                    print_err(
+                        f"Syntax Error parsing. Unexpected cycles found outside of 'assert_reg'.")
+                    sys.exit(1)
+                case (ParsedLineType.CPU_MODEL, ParseState.ARRANGE_REG):
+                    if test_case.arrange_cpu_model is not None:
+                        print_err(f"{parsed_line.line_number:5d}: {parsed_line.line_raw}")
+                        print_err(
+                            f"Syntax Error parsing. Found multiple rows of cpu model for 'arrange_reg'.")
+                        sys.exit(1)
+                    else:
+                        test_case.arrange_cpu_model = parsed_line
+                case (ParsedLineType.CPU_MODEL, _):
+                    print_err(f"{parsed_line.line_number:5d}: {parsed_line.line_raw}")
+                    print_err(
+                        f"Syntax Error parsing. Unexpected cpu model found outside of 'arrange_reg'.")
+                    sys.exit(1)
                 case (ParsedLineType.SOURCE_CODE, ParseState.ASSERT_CODE):
                     if test_case.assert_code is not None:
                         print_err(f"{parsed_line.line_number:5d}: {parsed_line.line_raw}")